@@ -0,0 +1,217 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CliError, Result};
+
+/// Attestation format version written to and required in attestation files.
+const VERSION: u32 = 1;
+
+/// Domain separator prefixed to the signed provenance bytes.
+const DOMAIN: &[u8] = b"dusk-forge-attestation-v1";
+
+/// Where and how a contract WASM was built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Provenance {
+    /// `rustc --version` of the toolchain that built the WASM.
+    pub toolchain: String,
+    /// Git commit of the project, if it is a git checkout.
+    pub commit: Option<String>,
+    /// Whether the checkout had uncommitted changes.
+    pub dirty: bool,
+    /// BLAKE3 hash of the schema JSON reported by the data-driver.
+    pub schema_blake3: String,
+    /// BLAKE3 hash of the contract WASM.
+    pub wasm_blake3: String,
+    /// Network the contract is deployed to, from `--network`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// Chain ID of that network, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u8>,
+}
+
+/// Provenance signed by the builder's Ed25519 key, shipped alongside the
+/// contract WASM.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Attestation {
+    pub version: u32,
+    pub provenance: Provenance,
+    /// Hex-encoded Ed25519 public key of the signer.
+    pub signer: String,
+    /// Hex-encoded Ed25519 signature over the provenance.
+    pub signature: String,
+}
+
+impl Attestation {
+    pub fn sign(provenance: Provenance, key: &SigningKey) -> Result<Self> {
+        let signature = key.sign(&signed_bytes(&provenance)?);
+        Ok(Self {
+            version: VERSION,
+            provenance,
+            signer: to_hex(key.verifying_key().as_bytes()),
+            signature: to_hex(&signature.to_bytes()),
+        })
+    }
+
+    /// Check the signature, returning the provenance it covers.
+    pub fn verify(&self) -> Result<&Provenance> {
+        if self.version != VERSION {
+            return Err(CliError::Verification(format!(
+                "unsupported attestation version {} (expected {VERSION})",
+                self.version
+            )));
+        }
+
+        let signer = VerifyingKey::from_bytes(&from_hex(&self.signer, "signer")?)
+            .map_err(|err| CliError::Verification(format!("invalid attestation signer: {err}")))?;
+        let signature = Signature::from_bytes(&from_hex(&self.signature, "signature")?);
+
+        signer
+            .verify_strict(&signed_bytes(&self.provenance)?, &signature)
+            .map_err(|_| {
+                CliError::Verification(
+                    "attestation signature does not match its provenance".to_string(),
+                )
+            })?;
+
+        Ok(&self.provenance)
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            CliError::InvalidInput(format!("cannot read attestation {}: {err}", path.display()))
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+}
+
+/// Default attestation path for a contract WASM:
+/// `<name>.wasm.attestation.json`.
+pub fn path_for(wasm_path: &Path) -> PathBuf {
+    let mut path = wasm_path.as_os_str().to_owned();
+    path.push(".attestation.json");
+    PathBuf::from(path)
+}
+
+/// Load an Ed25519 signing key stored as a 32-byte hex seed.
+pub fn load_key(path: &Path) -> Result<SigningKey> {
+    let content = fs::read_to_string(path).map_err(|err| {
+        CliError::InvalidInput(format!("cannot read key {}: {err}", path.display()))
+    })?;
+    let seed = from_hex(content.trim(), "signing key")
+        .map_err(|err| CliError::InvalidInput(format!("{}: {err}", path.display())))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn signed_bytes(provenance: &Provenance) -> Result<Vec<u8>> {
+    let mut bytes = DOMAIN.to_vec();
+    bytes.extend(serde_json::to_vec(provenance)?);
+    Ok(bytes)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex<const N: usize>(hex: &str, what: &str) -> Result<[u8; N]> {
+    let hex = hex.trim_start_matches("0x");
+    let invalid = || CliError::InvalidInput(format!("{what} must be {N} hex-encoded bytes"));
+
+    if hex.len() != N * 2 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0u8; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provenance() -> Provenance {
+        Provenance {
+            toolchain: "rustc 1.85.0 (4d91de4e4 2025-02-17)".to_string(),
+            commit: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+            dirty: false,
+            schema_blake3: "aa".repeat(32),
+            wasm_blake3: "bb".repeat(32),
+            network: None,
+            chain_id: None,
+        }
+    }
+
+    #[test]
+    fn signed_attestation_verifies() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let attestation = Attestation::sign(provenance(), &key).expect("sign");
+
+        assert_eq!(attestation.signer, to_hex(key.verifying_key().as_bytes()));
+        assert_eq!(attestation.verify().expect("verify"), &provenance());
+    }
+
+    #[test]
+    fn network_is_signed_and_optional() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut attestation = Attestation::sign(provenance(), &key).expect("sign");
+        let json = serde_json::to_string(&attestation).expect("serialize");
+        assert!(!json.contains("network"), "{json}");
+
+        attestation.provenance.chain_id = Some(2);
+        assert!(attestation.verify().is_err());
+
+        let attested = Provenance {
+            network: Some("testnet".to_string()),
+            chain_id: Some(2),
+            ..provenance()
+        };
+        let attestation = Attestation::sign(attested.clone(), &key).expect("sign");
+        assert_eq!(attestation.verify().expect("verify"), &attested);
+    }
+
+    #[test]
+    fn tampered_provenance_is_rejected() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut attestation = Attestation::sign(provenance(), &key).expect("sign");
+        attestation.provenance.wasm_blake3 = "cc".repeat(32);
+
+        let err = attestation.verify().expect_err("tampered attestation");
+        assert!(err.to_string().contains("does not match"), "{err}");
+    }
+
+    #[test]
+    fn loads_hex_seed_key_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("builder.key");
+        fs::write(&path, format!("0x{}\n", "07".repeat(32))).expect("write key");
+
+        let key = load_key(&path).expect("load key");
+        assert_eq!(key.to_bytes(), [7; 32]);
+
+        fs::write(&path, "1234").expect("write key");
+        let err = load_key(&path).expect_err("short key");
+        assert!(err.to_string().contains("32 hex-encoded bytes"), "{err}");
+    }
+
+    #[test]
+    fn attestation_path_sits_next_to_wasm() {
+        assert_eq!(
+            path_for(Path::new("target/contract/counter.wasm")),
+            PathBuf::from("target/contract/counter.wasm.attestation.json")
+        );
+    }
+}
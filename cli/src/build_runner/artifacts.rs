@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{registry, wasm_opt};
+use crate::error::Result;
+use crate::project::metadata::ProjectMetadata;
+use crate::toolchain;
+
+/// File name of the manifest written to the base target directory after each
+/// build, so tests and CI can locate artifacts without hard-coding paths.
+pub const MANIFEST_FILE: &str = "forge-artifacts.json";
+
+/// Environment variable holding the manifest path when tests run.
+pub const MANIFEST_ENV: &str = "DUSK_FORGE_ARTIFACTS";
+
+/// Manifest format version.
+const VERSION: u32 = 1;
+
+/// Cargo profile artifacts are built with.
+const CARGO_PROFILE: &str = "release";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ArtifactManifest {
+    pub version: u32,
+    pub crate_name: String,
+    /// Cargo profile of the build.
+    pub cargo_profile: String,
+    /// `Forge.toml` profile applied to the build, if any.
+    pub forge_profile: Option<String>,
+    /// `rustc --version` of the project's toolchain.
+    pub toolchain: String,
+    /// `wasm-opt --version` of the optimizer run on the artifacts, if any.
+    #[serde(default)]
+    pub wasm_opt: Option<String>,
+    /// Artifacts by kind: `contract`, `data-driver` and `schema`.
+    pub artifacts: BTreeMap<String, Artifact>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Artifact {
+    pub path: PathBuf,
+    pub blake3: String,
+    pub size: u64,
+}
+
+impl Artifact {
+    pub(super) fn read(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            blake3: blake3::hash(&bytes).to_hex().to_string(),
+            size: bytes.len() as u64,
+        })
+    }
+}
+
+/// Record freshly built artifacts in the project's manifest.
+///
+/// Entries of other kinds are kept from the previous manifest as long as it
+/// describes the same crate and toolchain and the recorded file is
+/// unchanged, so building the contract and the data-driver separately still
+/// yields a complete manifest. The contract is also registered in the
+/// workspace [`registry`].
+pub fn record(project: &ProjectMetadata, built: &[(&str, &Path)]) -> Result<PathBuf> {
+    let path = project.artifact_manifest_path.clone();
+    let mut manifest = ArtifactManifest {
+        version: VERSION,
+        crate_name: project.crate_name.clone(),
+        cargo_profile: CARGO_PROFILE.to_string(),
+        forge_profile: project.profile.clone(),
+        toolchain: toolchain::rustc_version(&project.project_dir)?,
+        wasm_opt: wasm_opt::recorded_version(project),
+        artifacts: BTreeMap::new(),
+    };
+
+    if let Some(previous) = read(&path) {
+        manifest.artifacts = retained(&manifest, previous);
+    }
+    for (kind, artifact) in built {
+        manifest
+            .artifacts
+            .insert((*kind).to_string(), Artifact::read(artifact)?);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&manifest)? + "\n")?;
+    registry::record(project, &manifest)?;
+    Ok(path)
+}
+
+fn read(path: &Path) -> Option<ArtifactManifest> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// The artifacts of `previous` still valid for a build described by
+/// `current`.
+fn retained(current: &ArtifactManifest, previous: ArtifactManifest) -> BTreeMap<String, Artifact> {
+    let same_build = previous.version == current.version
+        && previous.crate_name == current.crate_name
+        && previous.toolchain == current.toolchain
+        && previous.wasm_opt == current.wasm_opt
+        && previous.forge_profile == current.forge_profile;
+    if !same_build {
+        return BTreeMap::new();
+    }
+
+    previous
+        .artifacts
+        .into_iter()
+        .filter(|(_, artifact)| Artifact::read(&artifact.path).is_ok_and(|now| now == *artifact))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(toolchain: &str, artifacts: BTreeMap<String, Artifact>) -> ArtifactManifest {
+        ArtifactManifest {
+            version: VERSION,
+            crate_name: "counter".to_string(),
+            cargo_profile: CARGO_PROFILE.to_string(),
+            forge_profile: None,
+            toolchain: toolchain.to_string(),
+            wasm_opt: Some("wasm-opt version 119 (version_119)".to_string()),
+            artifacts,
+        }
+    }
+
+    #[test]
+    fn keeps_unchanged_artifacts_of_the_same_build() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let wasm = dir.path().join("counter.wasm");
+        let schema = dir.path().join("counter.schema.json");
+        fs::write(&wasm, b"\0asm").expect("write wasm");
+        fs::write(&schema, b"{}").expect("write schema");
+
+        let previous = manifest(
+            "rustc 1.85.0",
+            BTreeMap::from([
+                ("contract".to_string(), Artifact::read(&wasm).expect("hash")),
+                ("schema".to_string(), Artifact::read(&schema).expect("hash")),
+            ]),
+        );
+        fs::write(&schema, b"{\"name\":\"Counter\"}").expect("rewrite schema");
+
+        let current = manifest("rustc 1.85.0", BTreeMap::new());
+        let kept = retained(&current, previous.clone());
+        assert_eq!(kept.keys().collect::<Vec<_>>(), ["contract"]);
+
+        let current = manifest("rustc 1.86.0", BTreeMap::new());
+        assert!(retained(&current, previous.clone()).is_empty());
+
+        let mut current = manifest("rustc 1.85.0", BTreeMap::new());
+        current.wasm_opt = None;
+        assert!(retained(&current, previous).is_empty());
+    }
+}
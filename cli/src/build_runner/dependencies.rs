@@ -0,0 +1,97 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cargo_metadata::{
+    CargoOpt, DependencyKind, Metadata, MetadataCommand, Node, Package, PackageId, TargetKind,
+};
+
+use crate::project::metadata::ProjectMetadata;
+use crate::toolchain::WASM_TARGET;
+
+/// The dependency graph of `project` built for the WASM target with
+/// `features`.
+pub fn wasm_metadata(
+    project: &ProjectMetadata,
+    features: &[&str],
+) -> cargo_metadata::Result<Metadata> {
+    MetadataCommand::new()
+        .manifest_path(&project.manifest_path)
+        .features(CargoOpt::SomeFeatures(
+            features.iter().map(ToString::to_string).collect(),
+        ))
+        .other_options(
+            [
+                "--locked".to_string(),
+                "--filter-platform".to_string(),
+                WASM_TARGET.to_string(),
+            ]
+            .into_iter()
+            .chain(super::local_forge_config_args())
+            .collect::<Vec<_>>(),
+        )
+        .exec()
+}
+
+/// The package of `project` in `metadata`.
+pub fn root<'a>(metadata: &'a Metadata, project: &ProjectMetadata) -> Option<&'a Package> {
+    metadata
+        .packages
+        .iter()
+        .find(|pkg| pkg.manifest_path.as_std_path() == project.manifest_path)
+}
+
+/// The normal dependencies of `node` compiled into the WASM, leaving out
+/// proc macros, which run on the host.
+pub fn normal_dependencies(
+    node: &Node,
+    packages: &BTreeMap<&PackageId, &Package>,
+) -> Vec<PackageId> {
+    node.deps
+        .iter()
+        .filter(|dep| {
+            dep.dep_kinds
+                .iter()
+                .any(|info| info.kind == DependencyKind::Normal)
+        })
+        .filter(|dep| {
+            packages
+                .get(&dep.pkg)
+                .is_some_and(|pkg| !is_proc_macro(pkg))
+        })
+        .map(|dep| dep.pkg.clone())
+        .collect()
+}
+
+/// The packages compiled into the WASM of `root`, `root` included.
+///
+/// `cargo metadata` merges the features of build and dev dependencies into
+/// the graph, so only the crates reachable from `root` through normal
+/// dependencies are part of the build.
+pub fn reachable(metadata: &Metadata, root: &PackageId) -> BTreeSet<PackageId> {
+    let Some(resolve) = &metadata.resolve else {
+        return BTreeSet::from([root.clone()]);
+    };
+    let nodes: BTreeMap<_, _> = resolve.nodes.iter().map(|node| (&node.id, node)).collect();
+    let packages: BTreeMap<_, _> = metadata.packages.iter().map(|pkg| (&pkg.id, pkg)).collect();
+
+    let mut reachable = BTreeSet::from([root.clone()]);
+    let mut queue = vec![root.clone()];
+    while let Some(id) = queue.pop() {
+        for dep in nodes
+            .get(&id)
+            .map(|node| normal_dependencies(node, &packages))
+            .unwrap_or_default()
+        {
+            if reachable.insert(dep.clone()) {
+                queue.push(dep);
+            }
+        }
+    }
+    reachable
+}
+
+fn is_proc_macro(package: &Package) -> bool {
+    package
+        .targets
+        .iter()
+        .any(|target| target.is_kind(TargetKind::ProcMacro))
+}
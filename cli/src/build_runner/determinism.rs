@@ -0,0 +1,654 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use wasmparser::{KnownCustom, Name, Operator, Parser, Payload, TypeRef};
+
+use crate::error::{CliError, Result};
+use crate::project::config::BuildConfig;
+
+/// Function names listed per crate before the rest are summarized.
+const MAX_LISTED_FUNCTIONS: usize = 3;
+
+/// Instruction classes the VM rejects because their results may differ
+/// between hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Violation {
+    Float,
+    Atomic,
+    RelaxedSimd,
+    MemoryGrow,
+}
+
+impl Violation {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Float => "floating-point instructions",
+            Self::Atomic => "atomic instructions",
+            Self::RelaxedSimd => "relaxed SIMD instructions",
+            Self::MemoryGrow => "`memory.grow` past the `max-memory-pages` limit",
+        }
+    }
+
+    /// The class of `op`, by variant of wasmparser's operator table.
+    /// Relaxed SIMD comes first, as some of its instructions take floats.
+    fn of(op: &Operator) -> Option<Self> {
+        match op {
+            Operator::I8x16RelaxedSwizzle
+            | Operator::I32x4RelaxedTruncF32x4S
+            | Operator::I32x4RelaxedTruncF32x4U
+            | Operator::I32x4RelaxedTruncF64x2SZero
+            | Operator::I32x4RelaxedTruncF64x2UZero
+            | Operator::F32x4RelaxedMadd
+            | Operator::F32x4RelaxedNmadd
+            | Operator::F64x2RelaxedMadd
+            | Operator::F64x2RelaxedNmadd
+            | Operator::I8x16RelaxedLaneselect
+            | Operator::I16x8RelaxedLaneselect
+            | Operator::I32x4RelaxedLaneselect
+            | Operator::I64x2RelaxedLaneselect
+            | Operator::F32x4RelaxedMin
+            | Operator::F32x4RelaxedMax
+            | Operator::F64x2RelaxedMin
+            | Operator::F64x2RelaxedMax
+            | Operator::I16x8RelaxedQ15mulrS
+            | Operator::I16x8RelaxedDotI8x16I7x16S
+            | Operator::I32x4RelaxedDotI8x16I7x16AddS => Some(Self::RelaxedSimd),
+            Operator::MemoryAtomicNotify { .. }
+            | Operator::MemoryAtomicWait32 { .. }
+            | Operator::MemoryAtomicWait64 { .. }
+            | Operator::AtomicFence
+            | Operator::I32AtomicLoad { .. }
+            | Operator::I64AtomicLoad { .. }
+            | Operator::I32AtomicLoad8U { .. }
+            | Operator::I32AtomicLoad16U { .. }
+            | Operator::I64AtomicLoad8U { .. }
+            | Operator::I64AtomicLoad16U { .. }
+            | Operator::I64AtomicLoad32U { .. }
+            | Operator::I32AtomicStore { .. }
+            | Operator::I64AtomicStore { .. }
+            | Operator::I32AtomicStore8 { .. }
+            | Operator::I32AtomicStore16 { .. }
+            | Operator::I64AtomicStore8 { .. }
+            | Operator::I64AtomicStore16 { .. }
+            | Operator::I64AtomicStore32 { .. }
+            | Operator::I32AtomicRmwAdd { .. }
+            | Operator::I64AtomicRmwAdd { .. }
+            | Operator::I32AtomicRmw8AddU { .. }
+            | Operator::I32AtomicRmw16AddU { .. }
+            | Operator::I64AtomicRmw8AddU { .. }
+            | Operator::I64AtomicRmw16AddU { .. }
+            | Operator::I64AtomicRmw32AddU { .. }
+            | Operator::I32AtomicRmwSub { .. }
+            | Operator::I64AtomicRmwSub { .. }
+            | Operator::I32AtomicRmw8SubU { .. }
+            | Operator::I32AtomicRmw16SubU { .. }
+            | Operator::I64AtomicRmw8SubU { .. }
+            | Operator::I64AtomicRmw16SubU { .. }
+            | Operator::I64AtomicRmw32SubU { .. }
+            | Operator::I32AtomicRmwAnd { .. }
+            | Operator::I64AtomicRmwAnd { .. }
+            | Operator::I32AtomicRmw8AndU { .. }
+            | Operator::I32AtomicRmw16AndU { .. }
+            | Operator::I64AtomicRmw8AndU { .. }
+            | Operator::I64AtomicRmw16AndU { .. }
+            | Operator::I64AtomicRmw32AndU { .. }
+            | Operator::I32AtomicRmwOr { .. }
+            | Operator::I64AtomicRmwOr { .. }
+            | Operator::I32AtomicRmw8OrU { .. }
+            | Operator::I32AtomicRmw16OrU { .. }
+            | Operator::I64AtomicRmw8OrU { .. }
+            | Operator::I64AtomicRmw16OrU { .. }
+            | Operator::I64AtomicRmw32OrU { .. }
+            | Operator::I32AtomicRmwXor { .. }
+            | Operator::I64AtomicRmwXor { .. }
+            | Operator::I32AtomicRmw8XorU { .. }
+            | Operator::I32AtomicRmw16XorU { .. }
+            | Operator::I64AtomicRmw8XorU { .. }
+            | Operator::I64AtomicRmw16XorU { .. }
+            | Operator::I64AtomicRmw32XorU { .. }
+            | Operator::I32AtomicRmwXchg { .. }
+            | Operator::I64AtomicRmwXchg { .. }
+            | Operator::I32AtomicRmw8XchgU { .. }
+            | Operator::I32AtomicRmw16XchgU { .. }
+            | Operator::I64AtomicRmw8XchgU { .. }
+            | Operator::I64AtomicRmw16XchgU { .. }
+            | Operator::I64AtomicRmw32XchgU { .. }
+            | Operator::I32AtomicRmwCmpxchg { .. }
+            | Operator::I64AtomicRmwCmpxchg { .. }
+            | Operator::I32AtomicRmw8CmpxchgU { .. }
+            | Operator::I32AtomicRmw16CmpxchgU { .. }
+            | Operator::I64AtomicRmw8CmpxchgU { .. }
+            | Operator::I64AtomicRmw16CmpxchgU { .. }
+            | Operator::I64AtomicRmw32CmpxchgU { .. }
+            | Operator::GlobalAtomicGet { .. }
+            | Operator::GlobalAtomicSet { .. }
+            | Operator::GlobalAtomicRmwAdd { .. }
+            | Operator::GlobalAtomicRmwSub { .. }
+            | Operator::GlobalAtomicRmwAnd { .. }
+            | Operator::GlobalAtomicRmwOr { .. }
+            | Operator::GlobalAtomicRmwXor { .. }
+            | Operator::GlobalAtomicRmwXchg { .. }
+            | Operator::GlobalAtomicRmwCmpxchg { .. }
+            | Operator::TableAtomicGet { .. }
+            | Operator::TableAtomicSet { .. }
+            | Operator::TableAtomicRmwXchg { .. }
+            | Operator::TableAtomicRmwCmpxchg { .. }
+            | Operator::StructAtomicGet { .. }
+            | Operator::StructAtomicGetS { .. }
+            | Operator::StructAtomicGetU { .. }
+            | Operator::StructAtomicSet { .. }
+            | Operator::StructAtomicRmwAdd { .. }
+            | Operator::StructAtomicRmwSub { .. }
+            | Operator::StructAtomicRmwAnd { .. }
+            | Operator::StructAtomicRmwOr { .. }
+            | Operator::StructAtomicRmwXor { .. }
+            | Operator::StructAtomicRmwXchg { .. }
+            | Operator::StructAtomicRmwCmpxchg { .. }
+            | Operator::ArrayAtomicGet { .. }
+            | Operator::ArrayAtomicGetS { .. }
+            | Operator::ArrayAtomicGetU { .. }
+            | Operator::ArrayAtomicSet { .. }
+            | Operator::ArrayAtomicRmwAdd { .. }
+            | Operator::ArrayAtomicRmwSub { .. }
+            | Operator::ArrayAtomicRmwAnd { .. }
+            | Operator::ArrayAtomicRmwOr { .. }
+            | Operator::ArrayAtomicRmwXor { .. }
+            | Operator::ArrayAtomicRmwXchg { .. }
+            | Operator::ArrayAtomicRmwCmpxchg { .. } => Some(Self::Atomic),
+            Operator::F32Load { .. }
+            | Operator::F64Load { .. }
+            | Operator::F32Store { .. }
+            | Operator::F64Store { .. }
+            | Operator::F32Const { .. }
+            | Operator::F64Const { .. }
+            | Operator::F32Eq
+            | Operator::F32Ne
+            | Operator::F32Lt
+            | Operator::F32Gt
+            | Operator::F32Le
+            | Operator::F32Ge
+            | Operator::F64Eq
+            | Operator::F64Ne
+            | Operator::F64Lt
+            | Operator::F64Gt
+            | Operator::F64Le
+            | Operator::F64Ge
+            | Operator::F32Abs
+            | Operator::F32Neg
+            | Operator::F32Ceil
+            | Operator::F32Floor
+            | Operator::F32Trunc
+            | Operator::F32Nearest
+            | Operator::F32Sqrt
+            | Operator::F32Add
+            | Operator::F32Sub
+            | Operator::F32Mul
+            | Operator::F32Div
+            | Operator::F32Min
+            | Operator::F32Max
+            | Operator::F32Copysign
+            | Operator::F64Abs
+            | Operator::F64Neg
+            | Operator::F64Ceil
+            | Operator::F64Floor
+            | Operator::F64Trunc
+            | Operator::F64Nearest
+            | Operator::F64Sqrt
+            | Operator::F64Add
+            | Operator::F64Sub
+            | Operator::F64Mul
+            | Operator::F64Div
+            | Operator::F64Min
+            | Operator::F64Max
+            | Operator::F64Copysign
+            | Operator::I32TruncF32S
+            | Operator::I32TruncF32U
+            | Operator::I32TruncF64S
+            | Operator::I32TruncF64U
+            | Operator::I64TruncF32S
+            | Operator::I64TruncF32U
+            | Operator::I64TruncF64S
+            | Operator::I64TruncF64U
+            | Operator::F32ConvertI32S
+            | Operator::F32ConvertI32U
+            | Operator::F32ConvertI64S
+            | Operator::F32ConvertI64U
+            | Operator::F32DemoteF64
+            | Operator::F64ConvertI32S
+            | Operator::F64ConvertI32U
+            | Operator::F64ConvertI64S
+            | Operator::F64ConvertI64U
+            | Operator::F64PromoteF32
+            | Operator::I32ReinterpretF32
+            | Operator::I64ReinterpretF64
+            | Operator::F32ReinterpretI32
+            | Operator::F64ReinterpretI64
+            | Operator::I32TruncSatF32S
+            | Operator::I32TruncSatF32U
+            | Operator::I32TruncSatF64S
+            | Operator::I32TruncSatF64U
+            | Operator::I64TruncSatF32S
+            | Operator::I64TruncSatF32U
+            | Operator::I64TruncSatF64S
+            | Operator::I64TruncSatF64U
+            | Operator::F32x4ExtractLane { .. }
+            | Operator::F32x4ReplaceLane { .. }
+            | Operator::F64x2ExtractLane { .. }
+            | Operator::F64x2ReplaceLane { .. }
+            | Operator::F32x4Splat
+            | Operator::F64x2Splat
+            | Operator::F32x4Eq
+            | Operator::F32x4Ne
+            | Operator::F32x4Lt
+            | Operator::F32x4Gt
+            | Operator::F32x4Le
+            | Operator::F32x4Ge
+            | Operator::F64x2Eq
+            | Operator::F64x2Ne
+            | Operator::F64x2Lt
+            | Operator::F64x2Gt
+            | Operator::F64x2Le
+            | Operator::F64x2Ge
+            | Operator::F32x4Ceil
+            | Operator::F32x4Floor
+            | Operator::F32x4Trunc
+            | Operator::F32x4Nearest
+            | Operator::F32x4Abs
+            | Operator::F32x4Neg
+            | Operator::F32x4Sqrt
+            | Operator::F32x4Add
+            | Operator::F32x4Sub
+            | Operator::F32x4Mul
+            | Operator::F32x4Div
+            | Operator::F32x4Min
+            | Operator::F32x4Max
+            | Operator::F32x4PMin
+            | Operator::F32x4PMax
+            | Operator::F64x2Ceil
+            | Operator::F64x2Floor
+            | Operator::F64x2Trunc
+            | Operator::F64x2Nearest
+            | Operator::F64x2Abs
+            | Operator::F64x2Neg
+            | Operator::F64x2Sqrt
+            | Operator::F64x2Add
+            | Operator::F64x2Sub
+            | Operator::F64x2Mul
+            | Operator::F64x2Div
+            | Operator::F64x2Min
+            | Operator::F64x2Max
+            | Operator::F64x2PMin
+            | Operator::F64x2PMax
+            | Operator::I32x4TruncSatF32x4S
+            | Operator::I32x4TruncSatF32x4U
+            | Operator::F32x4ConvertI32x4S
+            | Operator::F32x4ConvertI32x4U
+            | Operator::I32x4TruncSatF64x2SZero
+            | Operator::I32x4TruncSatF64x2UZero
+            | Operator::F64x2ConvertLowI32x4S
+            | Operator::F64x2ConvertLowI32x4U
+            | Operator::F32x4DemoteF64x2Zero
+            | Operator::F64x2PromoteLowF32x4 => Some(Self::Float),
+            _ => None,
+        }
+    }
+}
+
+/// What the scan accepts beyond the instructions the VM always allows.
+#[derive(Debug, Clone, Copy, Default)]
+struct Rules {
+    /// Accept floating-point instructions, from `[build] allow-floats`.
+    allow_floats: bool,
+    /// Largest linear memory, in 64 KiB pages, from
+    /// `[build] max-memory-pages`.
+    max_memory_pages: Option<u64>,
+}
+
+impl Rules {
+    /// Whether `violation` is accepted by configuration.
+    fn allows(self, violation: Violation) -> bool {
+        violation == Violation::Float && self.allow_floats
+    }
+}
+
+impl From<&BuildConfig> for Rules {
+    fn from(build: &BuildConfig) -> Self {
+        Self {
+            allow_floats: build.allow_floats,
+            max_memory_pages: build.max_memory_pages,
+        }
+    }
+}
+
+/// Reject a contract WASM using nondeterministic features the VM disallows,
+/// or growing its memory past the configured limit.
+///
+/// Offending functions are grouped by the crate their (demangled) name
+/// belongs to, so the dependency that pulled them in can be identified. Runs
+/// before wasm-opt, which strips the name section.
+///
+/// The memory limit covers the initial size of each memory and each
+/// `memory.grow` by a constant number of pages; growth by a computed amount
+/// is left to the VM's own limit.
+pub fn check(wasm_path: &Path, build: &BuildConfig) -> Result<()> {
+    let bytes = fs::read(wasm_path)?;
+    scan(&bytes, Rules::from(build)).map_err(|err| CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: match err {
+            ScanError::Parse(err) => {
+                format!("cannot scan for nondeterministic instructions: {err}")
+            }
+            ScanError::Rejected(report) => {
+                format!("uses features the Dusk VM does not allow:\n{report}")
+            }
+        },
+    })
+}
+
+#[derive(Debug)]
+enum ScanError {
+    Parse(wasmparser::BinaryReaderError),
+    Rejected(String),
+}
+
+impl From<wasmparser::BinaryReaderError> for ScanError {
+    fn from(err: wasmparser::BinaryReaderError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+fn scan(bytes: &[u8], rules: Rules) -> std::result::Result<(), ScanError> {
+    let mut imported_functions = 0u32;
+    let mut defined_functions = 0u32;
+    let mut findings: BTreeMap<u32, Vec<Violation>> = BTreeMap::new();
+    let mut names: BTreeMap<u32, String> = BTreeMap::new();
+    let mut memory_issues = Vec::new();
+    // Initial size in pages of each memory, imported ones first
+    let mut memory_pages = Vec::new();
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    match import?.ty {
+                        TypeRef::Func(_) => imported_functions += 1,
+                        TypeRef::Memory(memory) => memory_pages.push(memory.initial),
+                        _ => {}
+                    }
+                }
+            }
+            Payload::MemorySection(reader) => {
+                for memory in reader {
+                    let memory = memory?;
+                    if memory.shared {
+                        memory_issues.push("shared linear memory".to_string());
+                    }
+                    if memory.memory64 {
+                        memory_issues.push("64-bit linear memory".to_string());
+                    }
+                    memory_pages.push(memory.initial);
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                let index = imported_functions + defined_functions;
+                defined_functions += 1;
+
+                let mut operators = body.get_operators_reader()?;
+                let mut previous = None;
+                while !operators.eof() {
+                    let op = operators.read()?;
+                    let violation = if let Operator::MemoryGrow { mem } = &op {
+                        let initial = memory_pages.get(*mem as usize).copied().unwrap_or(0);
+                        match (previous, rules.max_memory_pages) {
+                            (Some(delta), Some(max)) if initial.saturating_add(delta) > max => {
+                                Some(Violation::MemoryGrow)
+                            }
+                            _ => None,
+                        }
+                    } else {
+                        Violation::of(&op).filter(|violation| !rules.allows(*violation))
+                    };
+                    if let Some(violation) = violation {
+                        let found = findings.entry(index).or_default();
+                        if !found.contains(&violation) {
+                            found.push(violation);
+                        }
+                    }
+                    // Pages requested by a constant operand of `memory.grow`
+                    previous = match op {
+                        Operator::I32Const { value } => u64::try_from(value).ok(),
+                        Operator::I64Const { value } => u64::try_from(value).ok(),
+                        _ => None,
+                    };
+                }
+            }
+            Payload::CustomSection(section) => {
+                if let KnownCustom::Name(reader) = section.as_known() {
+                    for name in reader {
+                        if let Name::Function(map) = name? {
+                            for naming in map {
+                                let naming = naming?;
+                                names.insert(naming.index, naming.name.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(max) = rules.max_memory_pages {
+        memory_issues.extend(
+            memory_pages
+                .iter()
+                .filter(|pages| **pages > max)
+                .map(|pages| {
+                    format!(
+                        "linear memory of {pages} pages, over the `max-memory-pages` limit of {max}"
+                    )
+                }),
+        );
+    }
+
+    if findings.is_empty() && memory_issues.is_empty() {
+        return Ok(());
+    }
+
+    // (violation, crate) -> offending functions
+    let mut grouped: BTreeMap<(Violation, String), Vec<String>> = BTreeMap::new();
+    for (index, violations) in findings {
+        let function = names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| format!("func[{index}]"));
+        let krate = crate_name(&function).to_string();
+        for violation in violations {
+            grouped
+                .entry((violation, krate.clone()))
+                .or_default()
+                .push(function.clone());
+        }
+    }
+
+    let mut lines: Vec<String> = grouped
+        .into_iter()
+        .map(|((violation, krate), functions)| {
+            let mut listed = functions
+                .iter()
+                .take(MAX_LISTED_FUNCTIONS)
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            if functions.len() > MAX_LISTED_FUNCTIONS {
+                listed.push_str(&format!(
+                    " and {} more",
+                    functions.len() - MAX_LISTED_FUNCTIONS
+                ));
+            }
+            format!("  - {} in crate `{krate}` ({listed})", violation.label())
+        })
+        .collect();
+    lines.extend(
+        memory_issues
+            .into_iter()
+            .map(|issue| format!("  - {issue}")),
+    );
+
+    Err(ScanError::Rejected(lines.join("\n")))
+}
+
+/// Crate a demangled function name belongs to, e.g. `core` for
+/// `<f64 as core::fmt::Display>::fmt`.
+pub(super) fn crate_name(function: &str) -> &str {
+    let path = function.trim_start_matches('<');
+    let path = match path.split_once(" as ") {
+        Some((ty, _)) if ty.contains("::") => ty,
+        Some((_, trait_path)) => trait_path,
+        None => path,
+    };
+    path.split("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a module with one `() -> ()` function named `name` whose body
+    /// is `ops` (without the trailing `end`).
+    fn module(name: &str, ops: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\0asm\x01\0\0\0".to_vec();
+        // type section: one `() -> ()` signature
+        bytes.extend([0x01, 0x04, 0x01, 0x60, 0x00, 0x00]);
+        // function section: one function of type 0
+        bytes.extend([0x03, 0x02, 0x01, 0x00]);
+        // code section: no locals, `ops`, end
+        let body_len = u8::try_from(ops.len() + 2).unwrap();
+        bytes.extend([0x0a, body_len + 2, 0x01, body_len, 0x00]);
+        bytes.extend(ops);
+        bytes.push(0x0b);
+        // name section: function 0 -> name
+        let name_len = u8::try_from(name.len()).unwrap();
+        let subsection_len = name_len + 3;
+        bytes.extend([0x00, subsection_len + 7, 0x04]);
+        bytes.extend(b"name");
+        bytes.extend([0x01, subsection_len, 0x01, 0x00, name_len]);
+        bytes.extend(name.as_bytes());
+        bytes
+    }
+
+    /// Insert a memory section declaring one memory of `pages` initial
+    /// pages into `module`, after its function section.
+    fn with_memory(module: &[u8], pages: u8) -> Vec<u8> {
+        // header, type section and function section
+        let (head, tail) = module.split_at(8 + 6 + 4);
+        let mut bytes = head.to_vec();
+        bytes.extend([0x05, 0x03, 0x01, 0x00, pages]);
+        bytes.extend(tail);
+        bytes
+    }
+
+    #[test]
+    fn accepts_integer_code() {
+        // i32.const 0; drop
+        let wasm = module("my_contract::counter", &[0x41, 0x00, 0x1a]);
+
+        scan(&wasm, Rules::default()).expect("integer-only module should pass");
+    }
+
+    #[test]
+    fn rejects_floats_naming_the_crate() {
+        // f32.const 0.0; drop
+        let wasm = module(
+            "<f64 as core::fmt::Display>::fmt",
+            &[0x43, 0x00, 0x00, 0x00, 0x00, 0x1a],
+        );
+
+        let Err(ScanError::Rejected(report)) = scan(&wasm, Rules::default()) else {
+            panic!("float module should be rejected");
+        };
+        assert_eq!(
+            report,
+            "  - floating-point instructions in crate `core` (<f64 as core::fmt::Display>::fmt)"
+        );
+    }
+
+    #[test]
+    fn allows_floats_when_configured() {
+        // f64.const 0.0; drop
+        let wasm = module("my_contract::ratio", &[0x44, 0, 0, 0, 0, 0, 0, 0, 0, 0x1a]);
+        let rules = Rules {
+            allow_floats: true,
+            ..Rules::default()
+        };
+
+        scan(&wasm, rules).expect("floats should pass when allowed");
+    }
+
+    #[test]
+    fn classifies_operators_by_variant() {
+        assert_eq!(Violation::of(&Operator::F64Sqrt), Some(Violation::Float));
+        assert_eq!(
+            Violation::of(&Operator::I32TruncSatF32S),
+            Some(Violation::Float)
+        );
+        assert_eq!(
+            Violation::of(&Operator::F32x4RelaxedMadd),
+            Some(Violation::RelaxedSimd)
+        );
+        assert_eq!(
+            Violation::of(&Operator::AtomicFence),
+            Some(Violation::Atomic)
+        );
+        assert_eq!(Violation::of(&Operator::I64Add), None);
+    }
+
+    #[test]
+    fn rejects_constant_memory_grow_past_the_limit() {
+        // i32.const 20; memory.grow 0; drop
+        let grow = [0x41, 20, 0x40, 0x00, 0x1a];
+        let wasm = with_memory(&module("my_contract::grow", &grow), 1);
+        let rules = |max| Rules {
+            max_memory_pages: Some(max),
+            ..Rules::default()
+        };
+
+        scan(&wasm, rules(21)).expect("growing to the limit should pass");
+        let Err(ScanError::Rejected(report)) = scan(&wasm, rules(16)) else {
+            panic!("growing past the limit should be rejected");
+        };
+        assert_eq!(
+            report,
+            "  - `memory.grow` past the `max-memory-pages` limit in crate `my_contract` (my_contract::grow)"
+        );
+    }
+
+    #[test]
+    fn rejects_initial_memory_over_the_limit() {
+        let wasm = with_memory(&module("my_contract::noop", &[]), 17);
+        let rules = Rules {
+            max_memory_pages: Some(16),
+            ..Rules::default()
+        };
+
+        let Err(ScanError::Rejected(report)) = scan(&wasm, rules) else {
+            panic!("oversized memory should be rejected");
+        };
+        assert_eq!(
+            report,
+            "  - linear memory of 17 pages, over the `max-memory-pages` limit of 16"
+        );
+    }
+
+    #[test]
+    fn crate_names_from_demangled_paths() {
+        assert_eq!(crate_name("my_contract::Counter::set"), "my_contract");
+        assert_eq!(
+            crate_name("<types::Item as serde::Serialize>::serialize"),
+            "types"
+        );
+        assert_eq!(crate_name("<f32 as core::ops::Add>::add"), "core");
+        assert_eq!(crate_name("func[3]"), "func[3]");
+    }
+}
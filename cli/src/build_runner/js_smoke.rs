@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+use wasmparser::{Parser, Payload};
+
+use crate::data_driver_wasm::DataDriverWasm;
+use crate::error::{CliError, Result};
+use crate::random_input;
+
+/// Exports a `data-driver-js` build hands to JavaScript hosts such as w3sper.
+pub const JS_EXPORTS: &[&str] = &[
+    "memory",
+    "init",
+    "get_schema",
+    "get_last_error",
+    "encode_input_fn",
+    "decode_input_fn",
+    "decode_output_fn",
+    "decode_event",
+];
+
+/// Seed of the inputs round-tripped, so builds check the same values.
+const SEED: u64 = 0;
+
+/// What the smoke test of a data-driver covered.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Functions whose input survived an encode/decode round trip.
+    pub round_tripped: usize,
+    /// Functions whose input types carry no layout in the schema, so no
+    /// input could be generated for them.
+    pub skipped: Vec<String>,
+}
+
+/// Smoke-test a `data-driver-js` WASM: check it exports the JS entrypoints,
+/// instantiate it, and round-trip one generated input per function through
+/// `encode_input_fn` and `decode_input_fn`.
+///
+/// An input passes when decoding its encoding and encoding that again gives
+/// the same bytes, which holds whatever JSON spelling the driver prefers.
+pub fn check(wasm_path: &Path) -> Result<Summary> {
+    let bytes = fs::read(wasm_path)?;
+    let missing = missing_exports(&bytes).map_err(|err| CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: format!("cannot read the data-driver exports: {err}"),
+    })?;
+    if !missing.is_empty() {
+        return Err(CliError::DataDriver(format!(
+            "{} is built with `data-driver-js` but does not export {}",
+            wasm_path.display(),
+            missing.join(", ")
+        )));
+    }
+
+    let mut driver = DataDriverWasm::load(wasm_path)?;
+    let schema: Value = serde_json::from_str(&driver.get_schema_json()?)?;
+    let functions = schema
+        .get("functions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|function| function.get("name").and_then(Value::as_str));
+
+    let mut summary = Summary::default();
+    for function in functions {
+        let Ok((input, _)) = random_input::generate(&schema, function, Some(SEED)) else {
+            summary.skipped.push(function.to_string());
+            continue;
+        };
+        let fail = |err: CliError| {
+            CliError::DataDriver(format!(
+                "smoke test of `{function}` with input {input} failed: {err}"
+            ))
+        };
+
+        let encoded = driver
+            .encode_input(function, &input.to_string())
+            .map_err(fail)?;
+        let decoded = driver.decode_input(function, &encoded).map_err(fail)?;
+        let reencoded = driver.encode_input(function, &decoded).map_err(fail)?;
+        if reencoded != encoded {
+            return Err(CliError::DataDriver(format!(
+                "smoke test of `{function}` with input {input} failed: decoding gives {decoded}, \
+                 which encodes differently"
+            )));
+        }
+        summary.round_tripped += 1;
+    }
+    Ok(summary)
+}
+
+/// The [`JS_EXPORTS`] the module does not export.
+fn missing_exports(
+    bytes: &[u8],
+) -> std::result::Result<Vec<&'static str>, wasmparser::BinaryReaderError> {
+    let mut exported = Vec::new();
+    for payload in Parser::new(0).parse_all(bytes) {
+        if let Payload::ExportSection(reader) = payload? {
+            for export in reader {
+                exported.push(export?.name.to_string());
+            }
+        }
+    }
+    Ok(JS_EXPORTS
+        .iter()
+        .copied()
+        .filter(|name| !exported.iter().any(|export| export == name))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_missing_js_exports() {
+        let mut module = b"\0asm\x01\0\0\0".to_vec();
+        // memory section: one memory of at least one page
+        module.extend([5, 3, 1, 0, 1]);
+        // export section: the memory as `memory`
+        module.extend([7, 10, 1, 6]);
+        module.extend(b"memory");
+        module.extend([2, 0]);
+
+        assert_eq!(
+            missing_exports(&module).expect("module parses"),
+            &JS_EXPORTS[1..]
+        );
+        assert!(missing_exports(b"not wasm").is_err());
+    }
+}
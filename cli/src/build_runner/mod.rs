@@ -1,3 +1,14 @@
+pub mod artifacts;
+pub mod dependencies;
+mod determinism;
+#[cfg(feature = "schema")]
+pub mod js_smoke;
+pub mod names;
+mod no_std;
+pub mod panics;
+pub mod registry;
+pub mod size_budget;
+pub mod timings;
 pub mod wasm_opt;
 
 use std::env;
@@ -10,8 +21,14 @@ use crate::project::detect;
 use crate::project::metadata::ProjectMetadata;
 use crate::toolchain::{self, WASM_TARGET};
 
-const CONTRACT_FEATURE: &str = "contract";
-const STACK_SIZE: u32 = 65_536;
+/// Cargo feature the contract WASM is built with.
+pub const CONTRACT_FEATURE: &str = "contract";
+
+/// Environment variable `#[contract(metadata)]` embeds as the source hash.
+const SOURCE_HASH_ENV: &str = "DUSK_FORGE_SOURCE_HASH";
+
+/// Environment variable the contract schema embeds as its `abi` version.
+const ABI_VERSION_ENV: &str = "DUSK_FORGE_ABI_VERSION";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BuildTarget {
@@ -33,15 +50,43 @@ impl BuildTarget {
             Self::DataDriver => project.data_driver_wasm_path.clone(),
         }
     }
+
+    /// Environment variable holding the artifact path when tests are
+    /// compiled, for `include_bytes!(env!(..))`.
+    pub fn wasm_env(self) -> &'static str {
+        match self {
+            Self::Contract => "DUSK_FORGE_CONTRACT_WASM",
+            Self::DataDriver => "DUSK_FORGE_DATA_DRIVER_WASM",
+        }
+    }
 }
 
 pub fn build(project: &ProjectMetadata, target: BuildTarget, verbose: bool) -> Result<PathBuf> {
+    build_with_features(project, target, &[], verbose)
+}
+
+/// Build `target` with `extra_features` enabled next to the target's own
+/// feature.
+pub fn build_with_features(
+    project: &ProjectMetadata,
+    target: BuildTarget,
+    extra_features: &[&str],
+    verbose: bool,
+) -> Result<PathBuf> {
     let mut cmd = Command::new("cargo");
     let toolchain_arg = toolchain::cargo_toolchain_arg(&project.project_dir)?;
     let feature = match target {
         BuildTarget::Contract => CONTRACT_FEATURE,
         BuildTarget::DataDriver => detect::resolve_data_driver_feature(&project.project_dir)?,
     };
+    let features = std::iter::once(feature)
+        .chain(extra_features.iter().copied())
+        .collect::<Vec<_>>();
+    // The data-driver runs off-chain and may use `std`
+    if target == BuildTarget::Contract {
+        no_std::check(project, &features)?;
+    }
+    let features = features.join(",");
 
     cmd.arg(&toolchain_arg)
         .arg("build")
@@ -50,7 +95,7 @@ pub fn build(project: &ProjectMetadata, target: BuildTarget, verbose: bool) -> R
         .arg("--target")
         .arg(WASM_TARGET)
         .arg("--features")
-        .arg(feature)
+        .arg(features)
         .arg("--manifest-path")
         .arg(&project.manifest_path)
         .arg("--color=always");
@@ -61,11 +106,25 @@ pub fn build(project: &ProjectMetadata, target: BuildTarget, verbose: bool) -> R
     };
 
     cmd.env("CARGO_TARGET_DIR", target_dir)
-        .env("RUSTFLAGS", compose_rustflags(target))
+        .env(
+            "RUSTFLAGS",
+            compose_rustflags(target, project.config.build.stack_size),
+        )
         .current_dir(&project.project_dir)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .stdin(Stdio::inherit());
+    if target == BuildTarget::Contract {
+        let target_dirs = [
+            project.contract_target_dir.as_path(),
+            &project.data_driver_target_dir,
+        ];
+        cmd.envs(
+            source_hash(&project.project_dir, &target_dirs).map(|hash| (SOURCE_HASH_ENV, hash)),
+        );
+    }
+    // Both artifacts embed the schema, so both record the version
+    cmd.envs(abi_version(&project.lockfile_path).map(|version| (ABI_VERSION_ENV, version)));
     apply_local_forge_overrides(&mut cmd, verbose);
 
     if verbose {
@@ -83,17 +142,114 @@ pub fn build(project: &ProjectMetadata, target: BuildTarget, verbose: bool) -> R
     let wasm_path = target.wasm_path(project);
     ensure_file_exists(&wasm_path)?;
 
+    // Only the on-chain artifact runs in the VM; the data-driver may use floats
+    if target == BuildTarget::Contract {
+        determinism::check(&wasm_path, &project.config.build)?;
+    }
+
     Ok(wasm_path)
 }
 
+/// The version of `dusk-core` locked in `lockfile`, the host ABI the
+/// contract is compiled against. `None` without a lockfile or when it locks
+/// no single `dusk-core`.
+fn abi_version(lockfile: &Path) -> Option<String> {
+    let source = std::fs::read_to_string(lockfile).ok()?;
+    locked_version(&source, "dusk-core")
+}
+
+/// The version of the package `name` in the `Cargo.lock` `source`, if it is
+/// locked exactly once.
+fn locked_version(source: &str, name: &str) -> Option<String> {
+    let lock: toml::Table = toml::from_str(source).ok()?;
+    let mut versions = lock
+        .get("package")?
+        .as_array()?
+        .iter()
+        .filter(|package| package.get("name").and_then(toml::Value::as_str) == Some(name))
+        .filter_map(|package| package.get("version")?.as_str());
+    match (versions.next(), versions.next()) {
+        (Some(version), None) => Some(version.to_string()),
+        _ => None,
+    }
+}
+
+/// Hex BLAKE3 of the project's sources: the path and contents of every file
+/// in `project_dir` that git tracks or does not ignore, outside
+/// `target_dirs`, in path order.
+///
+/// Unlike the commit, it depends only on the sources, so building the same
+/// sources from any commit reproduces the same WASM. `None` outside a git
+/// checkout.
+fn source_hash(project_dir: &Path, target_dirs: &[&Path]) -> Option<String> {
+    let output = Command::new("git")
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .current_dir(project_dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let mut paths: Vec<_> = output
+        .stdout
+        .split(|&byte| byte == 0)
+        .filter(|path| !path.is_empty())
+        .map(String::from_utf8_lossy)
+        .collect();
+    paths.sort_unstable();
+    paths.dedup();
+
+    let mut hasher = blake3::Hasher::new();
+    for path in paths {
+        let file = project_dir.join(path.as_ref());
+        if target_dirs.iter().any(|dir| file.starts_with(dir)) {
+            continue;
+        }
+        // Tracked files deleted from the working tree are not sources
+        let Ok(contents) = std::fs::read(&file) else {
+            continue;
+        };
+        // Paths cannot contain NUL and content hashes have a fixed length,
+        // so the input is unambiguous
+        hasher.update(path.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(blake3::hash(&contents).as_bytes());
+    }
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+/// Git commit and dirty state of the checkout containing `project_dir`.
+pub fn git_state(project_dir: &Path) -> (Option<String>, bool) {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(project_dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "HEAD"]) {
+        Some(commit) => {
+            let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+            (Some(commit), dirty)
+        }
+        None => (None, false),
+    }
+}
+
 pub fn apply_local_forge_overrides(cmd: &mut Command, verbose: bool) {
     let mut applied = Vec::new();
 
-    if let Some((forge_root, macro_root)) = local_forge_paths() {
-        append_patch_config(cmd, "dusk-forge", &forge_root);
-        append_patch_config(cmd, "dusk-forge-contract", &macro_root);
-        applied.push(format!("dusk-forge -> {}", forge_root.display()));
-        applied.push(format!("dusk-forge-contract -> {}", macro_root.display()));
+    for (crate_name, path) in local_forge_paths() {
+        append_patch_config(cmd, crate_name, &path);
+        applied.push(format!("{crate_name} -> {}", path.display()));
     }
 
     if verbose && !applied.is_empty() {
@@ -101,27 +257,49 @@ pub fn apply_local_forge_overrides(cmd: &mut Command, verbose: bool) {
     }
 }
 
-fn local_forge_paths() -> Option<(PathBuf, PathBuf)> {
+/// The `--config` arguments of [`apply_local_forge_overrides`], for cargo
+/// invocations not built from a [`Command`] such as `cargo metadata`.
+pub fn local_forge_config_args() -> Vec<String> {
+    local_forge_paths()
+        .into_iter()
+        .flat_map(|(crate_name, path)| ["--config".to_string(), patch_config(crate_name, &path)])
+        .collect()
+}
+
+/// The crates of this repository with their local paths, when overrides
+/// apply.
+fn local_forge_paths() -> Vec<(&'static str, PathBuf)> {
     if !should_apply_local_forge_overrides() {
-        return None;
+        return Vec::new();
     }
 
     let cli_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    let forge_root = cli_dir.parent()?.to_path_buf();
-    let macro_root = forge_root.join("contract-macro");
+    let Some(forge_root) = cli_dir.parent() else {
+        return Vec::new();
+    };
+    let paths = vec![
+        ("dusk-forge", forge_root.to_path_buf()),
+        ("dusk-forge-contract", forge_root.join("contract-macro")),
+        ("dusk-forge-standards", forge_root.join("standards")),
+    ];
 
-    if forge_root.join("Cargo.toml").is_file() && macro_root.join("Cargo.toml").is_file() {
-        Some((forge_root, macro_root))
+    if paths
+        .iter()
+        .all(|(_, path)| path.join("Cargo.toml").is_file())
+    {
+        paths
     } else {
-        None
+        Vec::new()
     }
 }
 
 fn append_patch_config(cmd: &mut Command, crate_name: &str, path: &Path) {
+    cmd.arg("--config").arg(patch_config(crate_name, path));
+}
+
+fn patch_config(crate_name: &str, path: &Path) -> String {
     let path_escaped = toml_escape(path.as_os_str());
-    cmd.arg("--config").arg(format!(
-        "patch.crates-io.{crate_name}.path=\"{path_escaped}\""
-    ));
+    format!("patch.crates-io.{crate_name}.path=\"{path_escaped}\"")
 }
 
 fn should_apply_local_forge_overrides() -> bool {
@@ -137,7 +315,7 @@ fn toml_escape(value: &OsStr) -> String {
     raw.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
-fn compose_rustflags(target: BuildTarget) -> String {
+fn compose_rustflags(target: BuildTarget, stack_size: u32) -> String {
     let mut parts: Vec<String> = env::var("RUSTFLAGS")
         .ok()
         .map(|existing| {
@@ -155,7 +333,7 @@ fn compose_rustflags(target: BuildTarget) -> String {
 
     if target == BuildTarget::Contract {
         parts.push("-C".to_string());
-        parts.push(format!("link-args=-zstack-size={STACK_SIZE}"));
+        parts.push(format!("link-args=-zstack-size={stack_size}"));
     }
 
     parts.join(" ")
@@ -177,9 +355,81 @@ fn ensure_file_exists(path: &Path) -> Result<()> {
     if path.exists() {
         Ok(())
     } else {
-        Err(CliError::Message(format!(
-            "expected build artifact not found: {}",
-            path.display()
-        )))
+        Err(CliError::Build {
+            path: path.to_path_buf(),
+            reason: "expected build artifact not found".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_single_locked_version() {
+        let lock = r#"
+version = 4
+
+[[package]]
+name = "dusk-core"
+version = "1.3.0"
+
+[[package]]
+name = "rkyv"
+version = "0.7.45"
+"#;
+        assert_eq!(locked_version(lock, "dusk-core").as_deref(), Some("1.3.0"));
+        assert_eq!(locked_version(lock, "dusk-bytes"), None);
+
+        let duplicated =
+            format!("{lock}\n[[package]]\nname = \"dusk-core\"\nversion = \"0.9.0\"\n");
+        assert_eq!(locked_version(&duplicated, "dusk-core"), None);
+    }
+
+    #[test]
+    fn source_hash_follows_sources_not_commits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=forge",
+                    "-c",
+                    "user.email=forge@dusk.network",
+                ])
+                .args(args)
+                .current_dir(root)
+                .stdout(Stdio::null())
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {args:?} failed");
+        };
+        let target = root.join("target");
+
+        assert_eq!(source_hash(root, &[&target]), None, "not a git checkout");
+
+        git(&["init", "-q"]);
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub fn a() {}\n").unwrap();
+        let untracked = source_hash(root, &[&target]).unwrap();
+
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "first"]);
+        assert_eq!(source_hash(root, &[&target]).unwrap(), untracked);
+
+        // Build output does not count, whether ignored or not
+        std::fs::create_dir_all(&target).unwrap();
+        std::fs::write(target.join("contract.wasm"), "wasm").unwrap();
+        assert_eq!(source_hash(root, &[&target]).unwrap(), untracked);
+
+        std::fs::write(root.join("src/lib.rs"), "pub fn b() {}\n").unwrap();
+        let changed = source_hash(root, &[&target]).unwrap();
+        assert_ne!(changed, untracked);
+
+        // Committing the same sources again keeps the hash
+        git(&["commit", "-q", "-a", "-m", "second"]);
+        assert_eq!(source_hash(root, &[&target]).unwrap(), changed);
     }
 }
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use wasmparser::BinaryReader;
+
+/// Custom section holding the function, local and global names.
+const NAME_SECTION: &str = "name";
+
+/// Length of the WASM magic number and version preceding the sections.
+const HEADER_LEN: usize = 8;
+
+/// The module without its name section.
+///
+/// `dusk-forge build --keep-names` leaves the name section in the contract
+/// for debugging, so sizes and hashes that must match a stripped build are
+/// taken over these bytes. Bytes that do not parse as a module are returned
+/// unchanged.
+pub fn without_names(bytes: &[u8]) -> Cow<'_, [u8]> {
+    match name_section(bytes) {
+        Ok(Some((start, end))) => {
+            let mut stripped = Vec::with_capacity(bytes.len() - (end - start));
+            stripped.extend_from_slice(&bytes[..start]);
+            stripped.extend_from_slice(&bytes[end..]);
+            Cow::Owned(stripped)
+        }
+        _ => Cow::Borrowed(bytes),
+    }
+}
+
+/// BLAKE3 of the module without its name section, as reported and attested
+/// by `dusk-forge verify`.
+pub fn code_blake3(bytes: &[u8]) -> String {
+    blake3::hash(&without_names(bytes)).to_hex().to_string()
+}
+
+/// Byte range of the name section, header included.
+fn name_section(
+    bytes: &[u8],
+) -> std::result::Result<Option<(usize, usize)>, wasmparser::BinaryReaderError> {
+    let Some(sections) = bytes.get(HEADER_LEN..) else {
+        return Ok(None);
+    };
+    let mut reader = BinaryReader::new(sections, HEADER_LEN);
+    while !reader.eof() {
+        let start = reader.original_position();
+        let id = reader.read_u8()?;
+        let size = reader.read_var_u32()? as usize;
+        let content = reader.read_bytes(size)?;
+        if id == 0 && BinaryReader::new(content, 0).read_string()? == NAME_SECTION {
+            return Ok(Some((start, reader.original_position())));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str, data: &[u8]) -> Vec<u8> {
+        let mut content = vec![u8::try_from(name.len()).expect("short name")];
+        content.extend(name.as_bytes());
+        content.extend(data);
+        let mut section = vec![0, u8::try_from(content.len()).expect("short section")];
+        section.extend(content);
+        section
+    }
+
+    #[test]
+    fn strips_only_the_name_section() {
+        let mut module = b"\0asm\x01\0\0\0".to_vec();
+        // type section: one `() -> ()` function type
+        module.extend([1, 4, 1, 0x60, 0, 0]);
+        let stripped = [module.clone(), custom("producers", b"\0")].concat();
+        let named = [
+            module,
+            custom(NAME_SECTION, b"\x01\x04\x01\0\x01f"),
+            custom("producers", b"\0"),
+        ]
+        .concat();
+
+        assert_eq!(without_names(&named), stripped.as_slice());
+        assert_eq!(code_blake3(&named), code_blake3(&stripped));
+        assert_ne!(
+            code_blake3(&named),
+            blake3::hash(&named).to_hex().to_string()
+        );
+        assert!(matches!(without_names(&stripped), Cow::Borrowed(_)));
+        assert_eq!(without_names(b"not wasm"), b"not wasm".as_slice());
+    }
+}
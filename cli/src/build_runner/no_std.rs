@@ -0,0 +1,285 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cargo_metadata::{DependencyKind, Metadata, Package, PackageId};
+
+use super::dependencies;
+use crate::error::{CliError, Result};
+use crate::project::metadata::ProjectMetadata;
+use crate::toolchain::WASM_TARGET;
+use crate::ui;
+
+const STD_FEATURE: &str = "std";
+
+/// How a crate in the contract's dependency graph turns on the `std`
+/// feature of one of its dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Enabler {
+    /// Depends on it with default features, which include `std`.
+    DefaultFeatures,
+    /// Lists `std` in the `features` of the dependency.
+    FeatureList,
+    /// One of its own features, enabled in this build, forwards to `dep/std`.
+    Feature(String),
+}
+
+/// A dependency built with `std`, and the crate enabling it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct StdDependency {
+    package: String,
+    parent: String,
+    enabler: Enabler,
+}
+
+impl StdDependency {
+    fn report(&self, root: &str) -> String {
+        let Self {
+            package,
+            parent,
+            enabler,
+        } = self;
+        let hint = match enabler {
+            Enabler::DefaultFeatures if parent == root => {
+                format!("set `default-features = false` on `{package}` in Cargo.toml")
+            }
+            Enabler::DefaultFeatures => format!(
+                "`{parent}` depends on it with default features; look for a \
+                 `default-features = false` setup of `{parent}`"
+            ),
+            Enabler::FeatureList if parent == root => {
+                format!("remove `std` from the features of `{package}` in Cargo.toml")
+            }
+            Enabler::FeatureList => format!("`{parent}` enables `{package}/std` unconditionally"),
+            Enabler::Feature(feature) if parent == root => {
+                format!("the contract's `{feature}` feature enables it")
+            }
+            Enabler::Feature(feature) => {
+                format!("disable the `{feature}` feature of `{parent}`, which enables it")
+            }
+        };
+        format!("  - {package}: {hint}")
+    }
+}
+
+/// Reject a contract build pulling in `std` through a dependency feature.
+///
+/// Runs before the build, since the errors of a `std` crate compiled for
+/// the WASM target rarely point at the dependency responsible. Crates listed
+/// in `[build] allow-std` are not reported. A graph cargo cannot resolve is
+/// left for the build itself to report.
+pub fn check(project: &ProjectMetadata, features: &[&str]) -> Result<()> {
+    let metadata = match dependencies::wasm_metadata(project, features) {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            ui::warn(format!("skipping the `std` dependency check: {err}"));
+            return Ok(());
+        }
+    };
+
+    let Some(root) = dependencies::root(&metadata, project) else {
+        return Ok(());
+    };
+
+    let allowed = &project.config.build.allow_std;
+    let report: Vec<_> = std_dependencies(&metadata, &root.id)
+        .iter()
+        .filter(|dep| !allowed.contains(&dep.package))
+        .map(|dep| dep.report(&root.name))
+        .collect();
+    if report.is_empty() {
+        return Ok(());
+    }
+
+    Err(CliError::Build {
+        path: project.manifest_path.clone(),
+        reason: format!(
+            "dependencies are built with `std` for {WASM_TARGET}, which contracts cannot use:\n{}\n\
+             add a crate to `[build] allow-std` in Forge.toml if its `std` feature is harmless",
+            report.join("\n")
+        ),
+    })
+}
+
+/// Dependencies of the contract's WASM build with `std` enabled by a crate
+/// of that build.
+///
+/// Only the crates reachable from `root` through normal dependencies are
+/// considered, and a `std` feature none of them enables is left alone.
+fn std_dependencies(metadata: &Metadata, root: &PackageId) -> BTreeSet<StdDependency> {
+    let Some(resolve) = &metadata.resolve else {
+        return BTreeSet::new();
+    };
+    let nodes: BTreeMap<_, _> = resolve.nodes.iter().map(|node| (&node.id, node)).collect();
+    let packages: BTreeMap<_, _> = metadata.packages.iter().map(|pkg| (&pkg.id, pkg)).collect();
+
+    let normal_deps = |node| dependencies::normal_dependencies(node, &packages);
+    let reachable = dependencies::reachable(metadata, root);
+
+    let mut found = BTreeSet::new();
+    for id in &reachable {
+        let (Some(node), Some(parent)) = (nodes.get(id), packages.get(id)) else {
+            continue;
+        };
+        for dep_id in normal_deps(node) {
+            let (Some(dep_node), Some(dep)) = (nodes.get(&dep_id), packages.get(&dep_id)) else {
+                continue;
+            };
+            if !dep_node.features.iter().any(|f| f == STD_FEATURE) {
+                continue;
+            }
+            for enabler in enablers(parent, &node.features, dep) {
+                found.insert(StdDependency {
+                    package: dep.name.clone(),
+                    parent: parent.name.clone(),
+                    enabler,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// The ways `parent`, built with `parent_features`, enables `std` on `dep`.
+fn enablers(parent: &Package, parent_features: &[String], dep: &Package) -> Vec<Enabler> {
+    let Some(declared) = parent
+        .dependencies
+        .iter()
+        .find(|declared| declared.kind == DependencyKind::Normal && declared.name == dep.name)
+    else {
+        return Vec::new();
+    };
+    let key = declared.rename.as_deref().unwrap_or(&declared.name);
+
+    let mut enablers = Vec::new();
+    if declared.features.iter().any(|f| f == STD_FEATURE) {
+        enablers.push(Enabler::FeatureList);
+    }
+    let default_std = dep
+        .features
+        .get("default")
+        .is_some_and(|default| default.iter().any(|f| f == STD_FEATURE));
+    if declared.uses_default_features && default_std {
+        enablers.push(Enabler::DefaultFeatures);
+    }
+    for feature in parent_features {
+        let forwards = parent.features.get(feature).is_some_and(|enables| {
+            enables
+                .iter()
+                .any(|f| *f == format!("{key}/std") || *f == format!("{key}?/std"))
+        });
+        if forwards {
+            enablers.push(Enabler::Feature(feature.clone()));
+        }
+    }
+    enablers
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn package(name: &str, dependencies: Value, features: Value) -> Value {
+        json!({
+            "name": name,
+            "version": "1.0.0",
+            "id": name,
+            "dependencies": dependencies,
+            "targets": [{
+                "name": name,
+                "kind": ["lib"],
+                "crate_types": ["lib"],
+                "src_path": format!("/{name}/src/lib.rs"),
+            }],
+            "features": features,
+            "manifest_path": format!("/{name}/Cargo.toml"),
+            "rust_version": null,
+        })
+    }
+
+    fn dependency(name: &str, kind: Option<&str>, default: bool, features: &[&str]) -> Value {
+        json!({
+            "name": name,
+            "req": "^1",
+            "kind": kind,
+            "optional": false,
+            "uses_default_features": default,
+            "features": features,
+        })
+    }
+
+    fn node(id: &str, deps: &[(&str, &str)], features: &[&str]) -> Value {
+        let deps: Vec<_> = deps
+            .iter()
+            .map(|(dep, kind)| {
+                let kind = if *kind == "normal" {
+                    Value::Null
+                } else {
+                    json!(kind)
+                };
+                json!({ "name": dep, "pkg": dep, "dep_kinds": [{ "kind": kind, "target": null }] })
+            })
+            .collect();
+        json!({ "id": id, "deps": deps, "dependencies": [], "features": features })
+    }
+
+    #[test]
+    fn finds_the_crate_enabling_std() {
+        let metadata: Metadata = serde_json::from_value(json!({
+            "packages": [
+                package(
+                    "counter",
+                    json!([
+                        dependency("hex", None, true, &[]),
+                        dependency("codec", None, false, &[]),
+                        dependency("sha2", None, false, &[]),
+                        dependency("sha2", Some("build"), true, &[]),
+                    ]),
+                    json!({ "contract": [] }),
+                ),
+                package("hex", json!([]), json!({ "default": ["std"], "std": [] })),
+                package(
+                    "codec",
+                    json!([dependency("bytes", None, false, &[])]),
+                    json!({ "default": ["std"], "std": ["bytes/std"] }),
+                ),
+                package("bytes", json!([]), json!({ "std": [] })),
+                package("sha2", json!([]), json!({ "default": ["std"], "std": [] })),
+            ],
+            "workspace_members": ["counter"],
+            "resolve": {
+                "nodes": [
+                    node(
+                        "counter",
+                        &[("hex", "normal"), ("codec", "normal"), ("sha2", "normal"), ("sha2", "build")],
+                        &["contract"],
+                    ),
+                    node("hex", &[], &["default", "std"]),
+                    node("codec", &[("bytes", "normal")], &[]),
+                    node("bytes", &[], &["std"]),
+                    node("sha2", &[], &["default", "std"]),
+                ],
+                "root": "counter",
+            },
+            "workspace_root": "/counter",
+            "target_directory": "/counter/target",
+            "version": 1,
+        }))
+        .unwrap();
+
+        let found = std_dependencies(
+            &metadata,
+            &PackageId {
+                repr: "counter".to_string(),
+            },
+        );
+
+        // `sha2` gets `std` from the build dependency only, and `bytes` from
+        // a `codec` feature this build leaves off
+        let report: Vec<_> = found.iter().map(|dep| dep.report("counter")).collect();
+        assert_eq!(
+            report,
+            ["  - hex: set `default-features = false` on `hex` in Cargo.toml"]
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use wasmparser::{DataKind, KnownCustom, Name, Operator, Parser, Payload, TypeRef};
+
+use super::determinism::crate_name;
+use crate::error::{CliError, Result};
+
+/// Demangled name prefixes of the functions that start a panic. Calls
+/// reaching one of them are panic sites.
+const PANIC_MACHINERY: &[&str] = &[
+    "core::panicking::",
+    "core::option::unwrap_failed",
+    "core::option::expect_failed",
+    "core::result::unwrap_failed",
+    "core::slice::index::",
+    "core::str::slice_error_fail",
+    "core::cell::panic_already_",
+    "alloc::raw_vec::capacity_overflow",
+    "alloc::raw_vec::handle_error",
+    "alloc::alloc::handle_alloc_error",
+    "std::panicking::",
+    "rust_begin_unwind",
+    "__rust_start_panic",
+];
+
+/// Crates whose own panic sites are not reported: the standard library,
+/// whose panics are reported where contract code calls into it, and
+/// `dusk_forge`, whose panics are the deliberate failures of `revert!`,
+/// `ensure!` and the generated wrappers' guards.
+const SKIPPED_CRATES: &[&str] = &["core", "alloc", "std", "dusk_forge"];
+
+/// Crates of the standard library.
+const STD_CRATES: &[&str] = &["core", "alloc", "std"];
+
+/// A call that can panic.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Site {
+    /// `file:line:column` of the panic, when the call passes its location.
+    location: Option<String>,
+    /// Demangled name of the calling function.
+    function: String,
+    /// The function called, when the call passes no location.
+    callee: Option<String>,
+}
+
+/// A `call` instruction.
+struct Call {
+    caller: u32,
+    callee: u32,
+    /// The `i32.const` pushed right before the call, which is where
+    /// `#[track_caller]` functions get their `&Location` argument from.
+    last_const: Option<i32>,
+}
+
+/// Reject a contract WASM that can panic other than through `dusk_forge`,
+/// for `dusk-forge build --deny-panics`.
+///
+/// Panics are found from the name section, which wasm-opt strips, so this
+/// runs on the unoptimized WASM. A call that can reach the panic machinery,
+/// directly or through a `#[track_caller]` function such as `Option::unwrap`
+/// or slice indexing, is reported at the source location it passes, and by
+/// the calling function and callee when it passes none.
+pub fn check(wasm_path: &Path) -> Result<()> {
+    let bytes = fs::read(wasm_path)?;
+    let sites = scan(&bytes).map_err(|err| CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: format!("cannot scan for panics: {err}"),
+    })?;
+    if sites.is_empty() {
+        return Ok(());
+    }
+
+    let report = sites
+        .iter()
+        .map(|site| match &site.location {
+            Some(location) => format!("  - {location} in {}", site.function),
+            None => format!(
+                "  - {} calls {} (no source location)",
+                site.function,
+                site.callee.as_deref().unwrap_or_default()
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Err(CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: format!(
+            "can panic at {} site{} (denied by --deny-panics):\n{report}",
+            sites.len(),
+            if sites.len() == 1 { "" } else { "s" }
+        ),
+    })
+}
+
+fn scan(bytes: &[u8]) -> std::result::Result<Vec<Site>, wasmparser::BinaryReaderError> {
+    let mut imported_functions = 0u32;
+    let mut defined_functions = 0u32;
+    let mut names: BTreeMap<u32, String> = BTreeMap::new();
+    let mut calls = Vec::new();
+    let mut data = Vec::new();
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if matches!(import?.ty, TypeRef::Func(_)) {
+                        imported_functions += 1;
+                    }
+                }
+            }
+            Payload::DataSection(reader) => {
+                for segment in reader {
+                    let segment = segment?;
+                    if let DataKind::Active { offset_expr, .. } = segment.kind
+                        && let Ok(Operator::I32Const { value }) =
+                            offset_expr.get_operators_reader().read()
+                    {
+                        data.push((unsigned(value), segment.data));
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                let caller = imported_functions + defined_functions;
+                defined_functions += 1;
+
+                let mut operators = body.get_operators_reader()?;
+                let mut last_const = None;
+                while !operators.eof() {
+                    match operators.read()? {
+                        Operator::I32Const { value } => last_const = Some(value),
+                        Operator::Call { function_index } => calls.push(Call {
+                            caller,
+                            callee: function_index,
+                            last_const: last_const.take(),
+                        }),
+                        _ => last_const = None,
+                    }
+                }
+            }
+            Payload::CustomSection(section) => {
+                if let KnownCustom::Name(reader) = section.as_known() {
+                    for name in reader {
+                        if let Name::Function(map) = name? {
+                            for naming in map {
+                                let naming = naming?;
+                                names.insert(naming.index, naming.name.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let is_machinery = |index: u32| {
+        names.get(&index).is_some_and(|name| {
+            PANIC_MACHINERY
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+    };
+
+    // Every function from which the machinery can be reached
+    let mut can_panic: BTreeSet<u32> = names
+        .keys()
+        .copied()
+        .filter(|index| is_machinery(*index))
+        .collect();
+    loop {
+        let reached = can_panic.len();
+        for call in &calls {
+            if can_panic.contains(&call.callee) {
+                can_panic.insert(call.caller);
+            }
+        }
+        if can_panic.len() == reached {
+            break;
+        }
+    }
+
+    let name = |index: u32| {
+        names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| format!("func[{index}]"))
+    };
+    let mut sites = BTreeSet::new();
+    for call in &calls {
+        if !can_panic.contains(&call.callee) || is_machinery(call.caller) {
+            continue;
+        }
+        let function = name(call.caller);
+        if SKIPPED_CRATES.contains(&crate_name(&function)) {
+            continue;
+        }
+
+        // A call passing a location is a site at that location, whatever it
+        // calls. Otherwise only calls into the machinery or the standard
+        // library are sites: panics of the contract's own functions are
+        // reported inside them.
+        if let Some(location) = call
+            .last_const
+            .and_then(|address| location(&data, unsigned(address)))
+        {
+            sites.insert(Site {
+                location: Some(location),
+                function,
+                callee: None,
+            });
+        } else {
+            let callee = name(call.callee);
+            if is_machinery(call.callee) || STD_CRATES.contains(&crate_name(&callee)) {
+                sites.insert(Site {
+                    location: None,
+                    function,
+                    callee: Some(callee),
+                });
+            }
+        }
+    }
+
+    Ok(sites.into_iter().collect())
+}
+
+/// The `core::panic::Location` at `address`, as `file:line:column`.
+fn location(data: &[(u32, &[u8])], address: u32) -> Option<String> {
+    let bytes = read(data, address, 16)?;
+    let word = |index: usize| {
+        let start = index * 4;
+        u32::from_le_bytes(bytes[start..start + 4].try_into().expect("4 bytes"))
+    };
+
+    // `Location` is `{ file: &str, line: u32, col: u32 }` without a fixed
+    // field order, so both placements of the string are tried
+    [(0, 1, 2, 3), (2, 3, 0, 1)]
+        .into_iter()
+        .find_map(|(ptr, len, line, column)| {
+            let file = read(data, word(ptr), usize::try_from(word(len)).ok()?)?;
+            let file = std::str::from_utf8(file).ok()?;
+            (file.ends_with(".rs") && word(line) > 0)
+                .then(|| format!("{file}:{}:{}", word(line), word(column)))
+        })
+}
+
+/// `len` bytes of the initial memory at `address`, if a data segment holds
+/// them all.
+fn read<'a>(data: &[(u32, &'a [u8])], address: u32, len: usize) -> Option<&'a [u8]> {
+    data.iter().find_map(|(start, bytes)| {
+        let offset = usize::try_from(address.checked_sub(*start)?).ok()?;
+        bytes.get(offset..offset.checked_add(len)?)
+    })
+}
+
+/// An `i32.const` operand as the address it encodes.
+fn unsigned(value: i32) -> u32 {
+    u32::from_le_bytes(value.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leb(mut value: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let byte = u8::try_from(value & 0x7f).unwrap();
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+        let mut out = vec![id];
+        out.extend(leb(contents.len()));
+        out.extend(contents);
+        out
+    }
+
+    /// Build a module of `() -> ()` functions, given by name and body
+    /// operators (without the trailing `end`), over a memory initialized
+    /// with `data` at address 0.
+    fn module(functions: &[(&str, &[u8])], data: &[u8]) -> Vec<u8> {
+        let count = u8::try_from(functions.len()).unwrap();
+        let mut bytes = b"\0asm\x01\0\0\0".to_vec();
+        bytes.extend(section(1, &[0x01, 0x60, 0x00, 0x00]));
+        let mut types = vec![count];
+        types.extend(std::iter::repeat_n(0x00, functions.len()));
+        bytes.extend(section(3, &types));
+        bytes.extend(section(5, &[0x01, 0x00, 0x01]));
+
+        let mut code = vec![count];
+        for (_, ops) in functions {
+            let mut body = vec![0x00];
+            body.extend(*ops);
+            body.push(0x0b);
+            code.extend(leb(body.len()));
+            code.extend(body);
+        }
+        bytes.extend(section(10, &code));
+
+        let mut segment = vec![0x01, 0x00, 0x41, 0x00, 0x0b];
+        segment.extend(leb(data.len()));
+        segment.extend(data);
+        bytes.extend(section(11, &segment));
+
+        let mut map = vec![count];
+        for (index, (name, _)) in functions.iter().enumerate() {
+            map.extend(leb(index));
+            map.extend(leb(name.len()));
+            map.extend(name.as_bytes());
+        }
+        let mut custom = leb(4);
+        custom.extend(b"name");
+        custom.extend(section(1, &map));
+        bytes.extend(section(0, &custom));
+        bytes
+    }
+
+    /// A `src/lib.rs` string at 0 and its `Location` at 16, line 42 column 17.
+    fn location_data() -> Vec<u8> {
+        let mut data = b"src/lib.rs\0\0\0\0\0\0".to_vec();
+        for word in [0u32, 10, 42, 17] {
+            data.extend(word.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn reports_panic_sites_of_the_contract() {
+        let wasm = module(
+            &[
+                ("core::panicking::panic_fmt::h0123", &[0x00]),
+                ("core::option::Option<T>::unwrap", &[0x10, 0x00]),
+                // unwrap(&LOCATION)
+                ("my_contract::Counter::set", &[0x41, 0x10, 0x10, 0x01]),
+                ("my_contract::helper", &[0x10, 0x00]),
+                ("dusk_forge::revert::fail", &[0x10, 0x00]),
+                ("my_contract::Counter::get", &[0x41, 0x00, 0x1a]),
+                // Reaches `helper`, which is reported itself
+                ("my_contract::Counter::reset", &[0x10, 0x03]),
+                ("my_contract::Counter::revert", &[0x10, 0x04]),
+                ("my_contract::Counter::push", &[0x10, 0x09]),
+                ("alloc::raw_vec::RawVec<T,A>::grow_one", &[0x10, 0x00]),
+            ],
+            &location_data(),
+        );
+
+        let sites = scan(&wasm).expect("valid module");
+        assert_eq!(
+            sites,
+            [
+                Site {
+                    location: None,
+                    function: "my_contract::Counter::push".to_string(),
+                    callee: Some("alloc::raw_vec::RawVec<T,A>::grow_one".to_string()),
+                },
+                Site {
+                    location: None,
+                    function: "my_contract::helper".to_string(),
+                    callee: Some("core::panicking::panic_fmt::h0123".to_string()),
+                },
+                Site {
+                    location: Some("src/lib.rs:42:17".to_string()),
+                    function: "my_contract::Counter::set".to_string(),
+                    callee: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_locations_in_either_field_order() {
+        let data = location_data();
+        assert_eq!(
+            location(&[(0, &data)], 16).as_deref(),
+            Some("src/lib.rs:42:17")
+        );
+
+        let mut swapped = data[..16].to_vec();
+        for word in [42u32, 17, 0, 10] {
+            swapped.extend(word.to_le_bytes());
+        }
+        assert_eq!(
+            location(&[(0, &swapped)], 16).as_deref(),
+            Some("src/lib.rs:42:17")
+        );
+        assert_eq!(location(&[(0, &data)], 0), None);
+        assert_eq!(location(&[(0, &data)], 24), None);
+    }
+}
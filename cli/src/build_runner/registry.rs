@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::artifacts::{Artifact, ArtifactManifest};
+use crate::error::Result;
+use crate::project::metadata::ProjectMetadata;
+
+/// File name of the registry written next to the artifact manifest, listing
+/// the contracts built into the same target directory.
+pub const REGISTRY_FILE: &str = "registry.json";
+
+/// Environment variable holding the registry path when tests and scripts
+/// run.
+pub const REGISTRY_ENV: &str = "DUSK_FORGE_REGISTRY";
+
+/// Registry format version.
+const VERSION: u32 = 1;
+
+/// The contracts of a workspace by schema name, so indexers and tests can
+/// find a sibling contract's schema and artifacts without knowing its crate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Registry {
+    pub version: u32,
+    pub contracts: BTreeMap<String, Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Entry {
+    pub crate_name: String,
+    /// BLAKE3 of the schema JSON, as attested by `dusk-forge verify`.
+    pub schema_blake3: String,
+    /// Artifacts by kind, as in the crate's artifact manifest.
+    pub artifacts: BTreeMap<String, Artifact>,
+}
+
+/// The registry of the target directory `project` builds into.
+pub fn path(project: &ProjectMetadata) -> PathBuf {
+    project.artifact_manifest_path.with_file_name(REGISTRY_FILE)
+}
+
+/// Register the contract described by `manifest` under its schema name.
+///
+/// The crate's previous entries are replaced, and entries whose artifacts
+/// changed on disk since they were registered are dropped. A manifest
+/// without a schema artifact only clears the crate's entries, since the
+/// contract name is read from the schema.
+pub fn record(project: &ProjectMetadata, manifest: &ArtifactManifest) -> Result<PathBuf> {
+    let path = path(project);
+    let mut registry = read(&path).unwrap_or(Registry {
+        version: VERSION,
+        contracts: BTreeMap::new(),
+    });
+    registry.contracts = retained(registry.contracts, &manifest.crate_name);
+
+    if let Some((name, entry)) = entry(manifest) {
+        registry.contracts.insert(name, entry);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&registry)? + "\n")?;
+    Ok(path)
+}
+
+fn read(path: &Path) -> Option<Registry> {
+    let content = fs::read_to_string(path).ok()?;
+    let registry: Registry = serde_json::from_str(&content).ok()?;
+    (registry.version == VERSION).then_some(registry)
+}
+
+/// The entries of other crates whose artifacts are unchanged on disk.
+fn retained(contracts: BTreeMap<String, Entry>, crate_name: &str) -> BTreeMap<String, Entry> {
+    contracts
+        .into_iter()
+        .filter(|(_, entry)| entry.crate_name != crate_name)
+        .filter(|(_, entry)| {
+            entry
+                .artifacts
+                .values()
+                .all(|artifact| Artifact::read(&artifact.path).is_ok_and(|now| now == *artifact))
+        })
+        .collect()
+}
+
+/// The registry entry of `manifest`, under the contract name of its schema.
+fn entry(manifest: &ArtifactManifest) -> Option<(String, Entry)> {
+    let schema = manifest.artifacts.get("schema")?;
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&schema.path).ok()?).ok()?;
+    let name = json.get("name")?.as_str()?.to_string();
+
+    Some((
+        name,
+        Entry {
+            crate_name: manifest.crate_name.clone(),
+            schema_blake3: schema.blake3.clone(),
+            artifacts: manifest.artifacts.clone(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(crate_name: &str, artifacts: BTreeMap<String, Artifact>) -> ArtifactManifest {
+        ArtifactManifest {
+            version: 1,
+            crate_name: crate_name.to_string(),
+            cargo_profile: "release".to_string(),
+            forge_profile: None,
+            toolchain: "rustc 1.85.0".to_string(),
+            wasm_opt: None,
+            artifacts,
+        }
+    }
+
+    #[test]
+    fn registers_contracts_by_schema_name() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let schema = dir.path().join("counter.schema.json");
+        let wasm = dir.path().join("counter.wasm");
+        fs::write(&schema, br#"{"name":"Counter"}"#).expect("write schema");
+        fs::write(&wasm, b"\0asm").expect("write wasm");
+
+        let counter = manifest(
+            "counter",
+            BTreeMap::from([
+                ("contract".to_string(), Artifact::read(&wasm).expect("hash")),
+                ("schema".to_string(), Artifact::read(&schema).expect("hash")),
+            ]),
+        );
+        let (name, entry) = entry(&counter).expect("schema names the contract");
+        assert_eq!(name, "Counter");
+        assert_eq!(entry.crate_name, "counter");
+        assert_eq!(
+            entry.schema_blake3,
+            blake3::hash(br#"{"name":"Counter"}"#).to_hex().to_string()
+        );
+        assert!(super::entry(&manifest("counter", BTreeMap::new())).is_none());
+
+        let token = Entry {
+            crate_name: "token".to_string(),
+            schema_blake3: "00".to_string(),
+            artifacts: BTreeMap::from([(
+                "schema".to_string(),
+                Artifact::read(&schema).expect("hash"),
+            )]),
+        };
+        let contracts = BTreeMap::from([
+            ("OldCounter".to_string(), entry.clone()),
+            ("Token".to_string(), token),
+        ]);
+
+        // The crate's own entries go, siblings stay while unchanged
+        let kept = retained(contracts.clone(), "counter");
+        assert_eq!(kept.keys().collect::<Vec<_>>(), ["Token"]);
+
+        fs::write(&schema, br#"{"name":"Counter","functions":[]}"#).expect("rewrite schema");
+        assert!(retained(contracts, "counter").is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use wasmparser::{KnownCustom, Name, Parser, Payload, TypeRef};
+
+use super::{names, wasm_opt};
+use crate::error::{CliError, Result};
+use crate::project::metadata::ProjectMetadata;
+use crate::ui;
+
+/// Fail when a contract function exceeds its `[build.function-budgets]`
+/// entry.
+///
+/// wasm-opt strips the name section from the artifact, so this runs before
+/// [`wasm_opt::optimize`] and optimizes a scratch copy of the artifact that
+/// keeps the names. Without wasm-opt the unoptimized sizes are checked.
+pub fn check_functions(project: &ProjectMetadata, wasm_path: &Path, verbose: bool) -> Result<()> {
+    let budgets = &project.config.build.function_budgets;
+    if budgets.is_empty() {
+        return Ok(());
+    }
+
+    let named = wasm_path.with_extension("names.wasm");
+    let optimized = project.config.build.wasm_opt
+        && wasm_opt::optimize_keeping_names(wasm_path, &named, verbose)?;
+    let measured = if optimized {
+        named.as_path()
+    } else {
+        wasm_path
+    };
+    let bytes = fs::read(measured);
+    if optimized {
+        let _ = fs::remove_file(&named);
+    }
+    let functions = function_sizes(&bytes?).map_err(|err| CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: format!("cannot read function sizes: {err}"),
+    })?;
+
+    for pattern in unmatched(&functions, budgets) {
+        ui::warn(format!(
+            "function budget `{pattern}` matches no function of the contract"
+        ));
+    }
+
+    let over = over_budget(&functions, budgets);
+    if over.is_empty() {
+        return Ok(());
+    }
+    Err(CliError::Build {
+        path: wasm_path.to_path_buf(),
+        reason: format!("exceeds its function size budgets:\n{}", over.join("\n")),
+    })
+}
+
+/// Fail when the optimized contract is larger than `build.max-wasm-size`.
+///
+/// A name section kept with `--keep-names` is not counted.
+pub fn check_size(project: &ProjectMetadata, wasm_path: &Path) -> Result<()> {
+    let Some(max) = project.config.build.max_wasm_size else {
+        return Ok(());
+    };
+
+    let size = names::without_names(&fs::read(wasm_path)?).len() as u64;
+    if size > max {
+        return Err(CliError::Build {
+            path: wasm_path.to_path_buf(),
+            reason: format!(
+                "{} is over the {} allowed by `max-wasm-size`",
+                ui::format_bytes(size),
+                ui::format_bytes(max)
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Body sizes of the named functions of a module, by name.
+fn function_sizes(
+    bytes: &[u8],
+) -> std::result::Result<Vec<(String, u64)>, wasmparser::BinaryReaderError> {
+    let mut imported_functions = 0u32;
+    let mut defined_functions = 0u32;
+    let mut sizes: BTreeMap<u32, u64> = BTreeMap::new();
+    let mut names: BTreeMap<u32, String> = BTreeMap::new();
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if matches!(import?.ty, TypeRef::Func(_)) {
+                        imported_functions += 1;
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                let index = imported_functions + defined_functions;
+                defined_functions += 1;
+                sizes.insert(index, body.range().len() as u64);
+            }
+            Payload::CustomSection(section) => {
+                if let KnownCustom::Name(reader) = section.as_known() {
+                    for name in reader {
+                        if let Name::Function(map) = name? {
+                            for naming in map {
+                                let naming = naming?;
+                                names.insert(naming.index, naming.name.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(sizes
+        .into_iter()
+        .filter_map(|(index, size)| Some((names.remove(&index)?, size)))
+        .collect())
+}
+
+/// Whether the budget `pattern` applies to `function`: an exact name, with or
+/// without the trailing `::h<hash>` of the symbol, or a `prefix*` pattern.
+fn matches(pattern: &str, function: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => function.starts_with(prefix),
+        None => function == pattern || without_hash(function) == pattern,
+    }
+}
+
+/// `function` without the `::h<16 hex digits>` legacy mangling appends.
+fn without_hash(function: &str) -> &str {
+    match function.rsplit_once("::h") {
+        Some((path, hash)) if hash.len() == 16 && hash.bytes().all(|b| b.is_ascii_hexdigit()) => {
+            path
+        }
+        _ => function,
+    }
+}
+
+/// Report lines for the functions over the budget of a pattern they match.
+fn over_budget(functions: &[(String, u64)], budgets: &BTreeMap<String, u64>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (pattern, budget) in budgets {
+        for (function, size) in functions {
+            if size > budget && matches(pattern, function) {
+                lines.push(format!(
+                    "  - {}: {size} bytes, over the {budget} budgeted for `{pattern}`",
+                    without_hash(function)
+                ));
+            }
+        }
+    }
+    lines
+}
+
+/// Budget patterns no function matches, most likely misspelled.
+fn unmatched<'a>(functions: &[(String, u64)], budgets: &'a BTreeMap<String, u64>) -> Vec<&'a str> {
+    budgets
+        .keys()
+        .filter(|pattern| {
+            !functions
+                .iter()
+                .any(|(function, _)| matches(pattern, function))
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn functions() -> Vec<(String, u64)> {
+        vec![
+            (
+                "my_contract::Bridge::deposit::h0123456789abcdef".to_string(),
+                5000,
+            ),
+            ("serde_json::de::from_slice".to_string(), 800),
+            ("serde_json::ser::to_vec".to_string(), 1500),
+        ]
+    }
+
+    #[test]
+    fn reports_functions_over_budget() {
+        let budgets = BTreeMap::from([
+            ("my_contract::Bridge::deposit".to_string(), 4096),
+            ("serde_json::*".to_string(), 1024),
+        ]);
+
+        assert_eq!(
+            over_budget(&functions(), &budgets),
+            [
+                "  - my_contract::Bridge::deposit: 5000 bytes, over the 4096 budgeted for \
+                 `my_contract::Bridge::deposit`",
+                "  - serde_json::ser::to_vec: 1500 bytes, over the 1024 budgeted for \
+                 `serde_json::*`",
+            ]
+        );
+        assert!(unmatched(&functions(), &budgets).is_empty());
+    }
+
+    #[test]
+    fn finds_unmatched_budgets() {
+        let budgets = BTreeMap::from([
+            ("my_contract::Bridge::deposits".to_string(), 4096),
+            ("serde_json::*".to_string(), 2048),
+        ]);
+
+        assert!(over_budget(&functions(), &budgets).is_empty());
+        assert_eq!(
+            unmatched(&functions(), &budgets),
+            ["my_contract::Bridge::deposits"]
+        );
+    }
+}
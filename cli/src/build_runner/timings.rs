@@ -0,0 +1,301 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::project::metadata::ProjectMetadata;
+
+/// File name of the JSON timings report written next to the artifact
+/// manifest by `dusk-forge build --timings`.
+pub const REPORT_FILE: &str = "forge-timings.json";
+
+/// File name of the HTML rendering of the timings report.
+pub const HTML_FILE: &str = "forge-timings.html";
+
+/// Report format version.
+const VERSION: u32 = 1;
+
+/// Number of runs kept in the report, oldest dropped first.
+const MAX_RUNS: usize = 50;
+
+/// The timed builds of a target directory, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Report {
+    pub version: u32,
+    pub runs: Vec<Run>,
+}
+
+/// One `dusk-forge build --timings` run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Run {
+    pub crate_name: String,
+    pub forge_profile: Option<String>,
+    /// Seconds since the Unix epoch when the run finished.
+    pub finished_at: u64,
+    pub total_ms: u64,
+    pub phases: Vec<Phase>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Phase {
+    /// Artifact the phase worked on, as labelled by the build.
+    pub target: String,
+    pub name: String,
+    pub ms: u64,
+}
+
+/// Wall-clock time of the phases of a build.
+#[derive(Debug)]
+pub struct Timings {
+    started: Instant,
+    phases: Vec<Phase>,
+}
+
+impl Timings {
+    pub fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            phases: Vec::new(),
+        }
+    }
+
+    /// Run `phase` of the build of `target`, recording how long it took
+    /// whether it succeeds or not.
+    pub fn time<T>(&mut self, target: &str, name: &str, phase: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let out = phase();
+        self.phases.push(Phase {
+            target: target.to_string(),
+            name: name.to_string(),
+            ms: millis(started.elapsed()),
+        });
+        out
+    }
+
+    /// The run timed so far, for `project`.
+    pub fn finish(self, project: &ProjectMetadata) -> Run {
+        Run {
+            crate_name: project.crate_name.clone(),
+            forge_profile: project.profile.clone(),
+            finished_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            total_ms: millis(self.started.elapsed()),
+            phases: self.phases,
+        }
+    }
+}
+
+impl Run {
+    /// The phases as aligned table rows, with their share of the run.
+    pub fn breakdown(&self) -> Vec<String> {
+        let width = self
+            .phases
+            .iter()
+            .map(|phase| phase.target.len() + phase.name.len() + 1)
+            .max()
+            .unwrap_or(0);
+        self.phases
+            .iter()
+            .map(|phase| {
+                format!(
+                    "{:<width$}  {:>9}  {:>5.1}%",
+                    format!("{} {}", phase.target, phase.name),
+                    format_ms(phase.ms),
+                    share(phase.ms, self.total_ms)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Add `run` to the timings report of the target directory `project` builds
+/// into, returning the paths of the JSON report and its HTML rendering.
+pub fn record(project: &ProjectMetadata, run: Run) -> Result<(PathBuf, PathBuf)> {
+    let path = project.artifact_manifest_path.with_file_name(REPORT_FILE);
+    let mut report = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Report>(&content).ok())
+        .filter(|report| report.version == VERSION)
+        .unwrap_or(Report {
+            version: VERSION,
+            runs: Vec::new(),
+        });
+    report.runs.push(run);
+    let excess = report.runs.len().saturating_sub(MAX_RUNS);
+    report.runs.drain(..excess);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&report)? + "\n")?;
+    let html_path = path.with_file_name(HTML_FILE);
+    fs::write(&html_path, html(&report))?;
+    Ok((path, html_path))
+}
+
+/// The report as a standalone HTML page: the phase totals over all runs,
+/// then each run, newest first.
+fn html(report: &Report) -> String {
+    let mut totals: Vec<(String, u64)> = Vec::new();
+    for phase in report.runs.iter().flat_map(|run| &run.phases) {
+        let key = format!("{} {}", phase.target, phase.name);
+        match totals.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, ms)) => *ms += phase.ms,
+            None => totals.push((key, phase.ms)),
+        }
+    }
+    let total: u64 = report.runs.iter().map(|run| run.total_ms).sum();
+
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>dusk-forge build timings</title>\n<style>\n\
+         body { font-family: sans-serif; }\n\
+         table { border-collapse: collapse; margin-bottom: 1.5em; }\n\
+         th, td { padding: 2px 12px; text-align: left; }\n\
+         td.ms { text-align: right; font-family: monospace; }\n\
+         .bar { background: #4a90d9; height: 10px; }\n\
+         </style>\n</head>\n<body>\n<h1>dusk-forge build timings</h1>\n",
+    );
+
+    let _ = writeln!(
+        out,
+        "<h2>All runs ({}, {})</h2>",
+        report.runs.len(),
+        format_ms(total)
+    );
+    table(
+        &mut out,
+        totals.iter().map(|(name, ms)| (name.as_str(), *ms)),
+        total,
+    );
+
+    for run in report.runs.iter().rev() {
+        let _ = writeln!(
+            out,
+            "<h2>{}{} at {} ({})</h2>",
+            escape(&run.crate_name),
+            run.forge_profile
+                .as_deref()
+                .map(|profile| format!(" [{}]", escape(profile)))
+                .unwrap_or_default(),
+            run.finished_at,
+            format_ms(run.total_ms)
+        );
+        let rows: Vec<_> = run
+            .phases
+            .iter()
+            .map(|phase| (format!("{} {}", phase.target, phase.name), phase.ms))
+            .collect();
+        table(
+            &mut out,
+            rows.iter().map(|(name, ms)| (name.as_str(), *ms)),
+            run.total_ms,
+        );
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn table<'a>(out: &mut String, rows: impl Iterator<Item = (&'a str, u64)>, total: u64) {
+    out.push_str("<table>\n<tr><th>Phase</th><th>Time</th><th>Share</th><th></th></tr>\n");
+    for (name, ms) in rows {
+        let share = share(ms, total);
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td class=\"ms\">{}</td><td class=\"ms\">{share:.1}%</td>\
+             <td><div class=\"bar\" style=\"width: {:.0}px\"></div></td></tr>",
+            escape(name),
+            format_ms(ms),
+            share * 3.0
+        );
+    }
+    out.push_str("</table>\n");
+}
+
+/// Percentage of `total` spent in `ms`.
+#[allow(clippy::cast_precision_loss)]
+fn share(ms: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        ms as f64 * 100.0 / total as f64
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn format_ms(ms: u64) -> String {
+    if ms >= 1000 {
+        format!("{}.{:02}s", ms / 1000, ms % 1000 / 10)
+    } else {
+        format!("{ms}ms")
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(target: &str, name: &str, ms: u64) -> Phase {
+        Phase {
+            target: target.to_string(),
+            name: name.to_string(),
+            ms,
+        }
+    }
+
+    #[test]
+    fn renders_breakdown_and_cumulative_report() {
+        let run = Run {
+            crate_name: "counter".to_string(),
+            forge_profile: None,
+            finished_at: 1_700_000_000,
+            total_ms: 4000,
+            phases: vec![
+                phase("contract", "compile", 3000),
+                phase("contract", "wasm-opt", 250),
+                phase("data-driver", "compile", 750),
+            ],
+        };
+
+        assert_eq!(
+            run.breakdown(),
+            [
+                "contract compile         3.00s   75.0%",
+                "contract wasm-opt        250ms    6.2%",
+                "data-driver compile      750ms   18.8%",
+            ]
+        );
+
+        let report = Report {
+            version: VERSION,
+            runs: vec![run.clone(), run],
+        };
+        let html = html(&report);
+        assert!(html.contains("<h2>All runs (2, 8.00s)</h2>"));
+        assert!(html.contains("<td>contract compile</td><td class=\"ms\">6.00s</td>"));
+        assert_eq!(
+            html.matches("<h2>counter at 1700000000 (4.00s)</h2>")
+                .count(),
+            2
+        );
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
@@ -1,28 +1,128 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::{Once, OnceLock};
 
 use crate::error::{CliError, Result};
-use crate::tools;
+use crate::project::metadata::ProjectMetadata;
+use crate::{tools, ui};
 
-pub fn optimize_if_available(wasm_path: &Path, verbose: bool) -> Result<bool> {
-    let wasm_opt = match tools::find_in_path("wasm-opt") {
-        Some(path) => path,
-        None => return Ok(false),
+/// binaryen release the builds are checked against. Older wasm-opt builds
+/// are used with a warning.
+pub const RECOMMENDED_VERSION: u32 = 119;
+
+/// A wasm-opt binary and its `--version` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmOpt {
+    pub path: PathBuf,
+    pub version: Option<String>,
+}
+
+impl WasmOpt {
+    /// The binaryen release number of this wasm-opt, if its version line
+    /// could be read.
+    pub fn release(&self) -> Option<u32> {
+        self.version.as_deref().and_then(parse_release)
+    }
+
+    /// Whether this wasm-opt is older than [`RECOMMENDED_VERSION`].
+    pub fn is_outdated(&self) -> bool {
+        self.release()
+            .is_some_and(|release| release < RECOMMENDED_VERSION)
+    }
+}
+
+/// The wasm-opt on `PATH` builds run with.
+pub fn find() -> Option<&'static WasmOpt> {
+    static FOUND: OnceLock<Option<WasmOpt>> = OnceLock::new();
+
+    FOUND
+        .get_or_init(|| {
+            let path = tools::find_in_path("wasm-opt")?;
+            let version = version(&path);
+            Some(WasmOpt { path, version })
+        })
+        .as_ref()
+}
+
+/// The `--version` line of the wasm-opt used on `project`'s artifacts, for
+/// the artifact manifest: `None` when wasm-opt is disabled or not installed.
+pub fn recorded_version(project: &ProjectMetadata) -> Option<String> {
+    if !project.config.build.wasm_opt {
+        return None;
+    }
+    find().and_then(|wasm_opt| wasm_opt.version.clone())
+}
+
+/// Optimize a built artifact unless `build.wasm-opt` is disabled in
+/// Forge.toml, warning when wasm-opt is not installed.
+pub fn optimize(project: &ProjectMetadata, wasm_path: &Path, verbose: bool) -> Result<()> {
+    optimize_with(project, wasm_path, &["-Oz", "--strip-debug"], verbose)
+}
+
+/// Optimize a built artifact like [`optimize`], keeping its name section for
+/// debugging. DWARF is stripped all the same, so the artifact only differs
+/// from an [`optimize`]d one by its name section.
+pub fn optimize_with_names(
+    project: &ProjectMetadata,
+    wasm_path: &Path,
+    verbose: bool,
+) -> Result<()> {
+    optimize_with(project, wasm_path, &["-Oz", "-g", "--strip-dwarf"], verbose)
+}
+
+fn optimize_with(
+    project: &ProjectMetadata,
+    wasm_path: &Path,
+    args: &[&str],
+    verbose: bool,
+) -> Result<()> {
+    if !project.config.build.wasm_opt {
+        if verbose {
+            eprintln!("Skipping wasm-opt (disabled in Forge.toml)");
+        }
+        return Ok(());
+    }
+
+    if !run(wasm_path, wasm_path, args, verbose)? {
+        ui::warn("wasm-opt not found, skipping optimization");
+    }
+
+    Ok(())
+}
+
+/// Optimize `input` into `output` like [`optimize`], keeping the
+/// name section so the optimized functions can be told apart.
+pub fn optimize_keeping_names(input: &Path, output: &Path, verbose: bool) -> Result<bool> {
+    run(input, output, &["-Oz", "-g"], verbose)
+}
+
+fn run(input: &Path, output: &Path, args: &[&str], verbose: bool) -> Result<bool> {
+    static WARN_OUTDATED: Once = Once::new();
+
+    let Some(found) = find() else {
+        return Ok(false);
     };
+    if found.is_outdated() {
+        WARN_OUTDATED.call_once(|| {
+            ui::warn(format!(
+                "{} is older than binaryen version_{RECOMMENDED_VERSION}; \
+                 artifacts may be larger than expected",
+                found.version.as_deref().unwrap_or("wasm-opt")
+            ));
+        });
+    }
+    let wasm_opt = &found.path;
 
-    let mut cmd = Command::new(&wasm_opt);
-    cmd.arg("-Oz")
-        .arg("--strip-debug")
-        .arg(wasm_path)
-        .arg("-o")
-        .arg(wasm_path);
+    let mut cmd = Command::new(wasm_opt);
+    cmd.args(args).arg(input).arg("-o").arg(output);
 
     if verbose {
         eprintln!(
-            "Running: {} -Oz --strip-debug {} -o {}",
+            "Running: {} {} {} -o {}",
             wasm_opt.display(),
-            wasm_path.display(),
-            wasm_path.display()
+            args.join(" "),
+            input.display(),
+            output.display()
         );
     }
 
@@ -36,3 +136,44 @@ pub fn optimize_if_available(wasm_path: &Path, verbose: bool) -> Result<bool> {
 
     Ok(true)
 }
+
+/// The `--version` line of the wasm-opt at `path`.
+fn version(path: &Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().next().map(|line| line.trim().to_string())
+}
+
+/// The release number in a `wasm-opt version 119 (version_119)` line.
+fn parse_release(version: &str) -> Option<u32> {
+    version
+        .split_whitespace()
+        .skip_while(|word| *word != "version")
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_release_from_version_line() {
+        assert_eq!(
+            parse_release("wasm-opt version 119 (version_119)"),
+            Some(119)
+        );
+        assert_eq!(parse_release("wasm-opt version 116"), Some(116));
+        assert_eq!(parse_release("wasm-opt"), None);
+
+        let outdated = WasmOpt {
+            path: PathBuf::from("wasm-opt"),
+            version: Some("wasm-opt version 105".to_string()),
+        };
+        assert!(outdated.is_outdated());
+    }
+}
@@ -1,3 +1,4 @@
+use std::ffi::OsString;
 use std::path::PathBuf;
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
@@ -38,6 +39,19 @@ impl BuildScope {
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// How errors are reported on stderr.
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub error_format: ErrorFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum ErrorFormat {
+    /// A colored `error` line.
+    #[default]
+    Human,
+    /// A JSON object with the error kind, exit code and context.
+    Json,
 }
 
 #[derive(Debug, Subcommand)]
@@ -60,14 +74,55 @@ pub enum Commands {
     Call(CallArgs),
     /// Verify contract and data-driver artifacts.
     Verify(VerifyArgs),
+    /// List the dependencies compiled into the contract WASM with their
+    /// licenses and known RustSec advisories.
+    Audit(AuditArgs),
+    /// Generate a state migration skeleton from a previous state layout.
+    MigrateGen(MigrateGenArgs),
+    /// Write the schema as canonical JSON, or check a committed schema file.
+    FmtSchema(FmtSchemaArgs),
+    /// Work with raw contract events.
+    Events(EventsArgs),
+    /// Build the project and run a deployment or ops script from `scripts/`.
+    Script(ScriptArgs),
     /// Generate shell completion scripts.
     Completions(CompletionsArgs),
+    /// Run a `dusk-forge-<name>` plugin from PATH.
+    #[command(external_subcommand)]
+    External(Vec<OsString>),
+}
+
+impl Commands {
+    /// The subcommand name, as typed on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::New(_) => "new",
+            Self::Build(_) => "build",
+            Self::Test(_) => "test",
+            Self::Check(_) => "check",
+            Self::Expand(_) => "expand",
+            Self::Clean(_) => "clean",
+            Self::Schema(_) => "schema",
+            Self::Call(_) => "call",
+            Self::Verify(_) => "verify",
+            Self::Audit(_) => "audit",
+            Self::MigrateGen(_) => "migrate-gen",
+            Self::FmtSchema(_) => "fmt-schema",
+            Self::Events(_) => "events",
+            Self::Script(_) => "script",
+            Self::Completions(_) => "completions",
+            Self::External(_) => "plugin",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum TemplateChoice {
     Counter,
     Empty,
+    Multisig,
+    Token,
+    Nft,
 }
 
 #[derive(Debug, Args)]
@@ -83,6 +138,10 @@ pub struct NewArgs {
     #[arg(long, value_enum, default_value_t = TemplateChoice::Counter)]
     pub template: TemplateChoice,
 
+    /// Also scaffold a `<name>-types` crate for types shared with clients.
+    #[arg(long)]
+    pub lib_types: bool,
+
     /// Skip `git init` in the created project.
     #[arg(long)]
     pub no_git: bool,
@@ -98,6 +157,15 @@ pub struct ProjectOptions {
     #[arg(long, default_value = ".")]
     pub path: PathBuf,
 
+    /// Forge.toml profile to apply on top of the base settings.
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Directory for the `contract` and `data-driver` build outputs,
+    /// overriding `Forge.toml` and the workspace `target/`.
+    #[arg(long, value_name = "DIR")]
+    pub target_dir: Option<PathBuf>,
+
     /// Enable verbose output.
     #[arg(short, long)]
     pub verbose: bool,
@@ -111,6 +179,51 @@ pub struct BuildArgs {
     /// Which WASM target to build.
     #[arg(value_enum, default_value_t)]
     pub target: BuildScope,
+
+    /// Print how long each build phase took and add the run to the
+    /// `forge-timings.json`/`.html` report next to the artifact manifest.
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Fail when the contract can panic other than through
+    /// `dusk_forge::revert!`/`ensure!`, listing the source locations.
+    #[arg(long)]
+    pub deny_panics: bool,
+
+    /// Keep the WASM name section for debugging. `dusk-forge verify` and
+    /// `max-wasm-size` leave it out, so the build verifies against the hash
+    /// of a stripped one.
+    #[arg(long)]
+    pub keep_names: bool,
+
+    /// Skip the smoke test instantiating a `data-driver-js` build and
+    /// round-tripping one input per function through its JS exports.
+    #[arg(long)]
+    pub skip_js_smoke: bool,
+
+    /// Contract features to enable in both builds, comma-separated. Methods
+    /// gated with `#[cfg(feature = "...")]` are exported, and listed in the
+    /// schema, only when their features are.
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TestReportFormat {
+    /// JUnit XML, as ingested by most CI systems.
+    Junit,
+    /// A JSON object with one entry per test.
+    Json,
+}
+
+impl TestReportFormat {
+    /// The format name, as typed on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Junit => "junit",
+            Self::Json => "json",
+        }
+    }
 }
 
 #[derive(Debug, Args)]
@@ -119,6 +232,28 @@ pub struct TestArgs {
     #[command(flatten)]
     pub project: ProjectOptions,
 
+    /// Build with the `metering` feature and report host calls per test.
+    #[arg(long)]
+    pub metering: bool,
+
+    /// JSON file of `init` arguments, encoded through the data-driver and
+    /// passed to tests as the path in `DUSK_FORGE_INIT_ARGS`.
+    #[arg(long, value_name = "FILE")]
+    pub init_args: Option<PathBuf>,
+
+    /// Show the output of passing tests too (libtest `--nocapture`).
+    #[arg(long)]
+    pub no_capture: bool,
+
+    /// Write a machine-readable report of the test results.
+    #[arg(long, value_name = "FORMAT")]
+    pub report: Option<TestReportFormat>,
+
+    /// Where to write the report [default: `forge-test-report.<xml|json>`
+    /// in the base target directory].
+    #[arg(long, value_name = "FILE", requires = "report")]
+    pub report_path: Option<PathBuf>,
+
     /// Extra args passed through to `cargo test --release`.
     pub cargo_test_args: Vec<String>,
 }
@@ -134,13 +269,79 @@ pub struct ExpandArgs {
 }
 
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct SchemaArgs {
+    #[command(subcommand)]
+    pub command: Option<SchemaCommand>,
+
     #[command(flatten)]
     pub project: ProjectOptions,
 
     /// Pretty-print JSON output.
     #[arg(long)]
     pub pretty: bool,
+
+    /// Resolve the leading crate of import paths against `cargo metadata`
+    /// and add versioned paths to the schema (item paths are not checked).
+    #[arg(long)]
+    pub resolve_deps: bool,
+
+    /// Only print the schema entry of this function.
+    #[arg(long, conflicts_with = "event")]
+    pub function: Option<String>,
+
+    /// Only print the schema entry of the event with this topic (its const
+    /// path or resolved string) or data type.
+    #[arg(long)]
+    pub event: Option<String>,
+
+    /// Print the index plan: the events with `#[event(indexed)]` fields and
+    /// the fields indexers should make searchable.
+    #[arg(long, conflicts_with_all = ["function", "event"])]
+    pub index_plan: bool,
+
+    /// Print only the event topic table: each event's topic string, data
+    /// type, version and indexed and unit fields, for event indexers.
+    #[arg(long, conflicts_with_all = ["function", "event", "index_plan"])]
+    pub events_only: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t)]
+    pub format: SchemaFormat,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SchemaCommand {
+    /// Print the ABI changes since a git revision as a Markdown changelog.
+    Changelog(SchemaChangelogArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SchemaChangelogArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Git revision (tag, branch or commit) to compare against.
+    #[arg(long, value_name = "REF")]
+    pub since: String,
+
+    /// Read the schema at `REF` from this committed file (relative to the
+    /// project directory) instead of rebuilding the project at `REF`.
+    #[arg(long, value_name = "PATH")]
+    pub schema_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum SchemaFormat {
+    /// JSON (compact unless `--pretty`).
+    #[default]
+    Json,
+    /// TOML.
+    Toml,
+    /// Aligned text tables.
+    Table,
+    /// Comma-separated values, one row per event (`--events-only` only).
+    Csv,
 }
 
 #[derive(Debug, Args)]
@@ -148,12 +349,44 @@ pub struct CallArgs {
     #[command(flatten)]
     pub project: ProjectOptions,
 
-    /// Contract function name to encode.
+    /// Contract function name to encode (or event topic with `--event`).
     pub function: String,
 
     /// JSON input payload for the function (use `null` for no input).
     #[arg(long, default_value = "null")]
     pub input: String,
+
+    /// Only print the encoded input, skipping the schema lookup for
+    /// streaming hints. `call` never contacts a node.
+    #[arg(long, conflicts_with = "decode")]
+    pub encode_only: bool,
+
+    /// Decode a hex-encoded rkyv payload to JSON instead of encoding: the
+    /// function's output, or the event's data with `--event`.
+    #[arg(long, value_name = "HEX", conflicts_with = "input")]
+    pub decode: Option<String>,
+
+    /// Treat the positional argument as an event topic when decoding.
+    #[arg(long, requires = "decode")]
+    pub event: bool,
+
+    /// Generate a random input from the parameter types in the schema
+    /// instead of taking `--input`, for quick smoke tests of the encoding.
+    #[arg(long, conflicts_with_all = ["input", "decode"])]
+    pub random: bool,
+
+    /// Seed for `--random`, to generate the same input again.
+    #[arg(long, value_name = "SEED", requires = "random")]
+    pub seed: Option<u64>,
+
+    /// Use this data-driver WASM instead of building the project's.
+    #[arg(long, value_name = "PATH")]
+    pub data_driver: Option<PathBuf>,
+
+    /// Network the payload is meant for: a `Forge.toml` network or one of
+    /// `localnet`, `devnet`, `testnet` and `mainnet`.
+    #[arg(long, value_name = "NAME", conflicts_with = "decode")]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Args)]
@@ -168,6 +401,121 @@ pub struct VerifyArgs {
     /// Skip rebuilding artifacts and verify existing files only.
     #[arg(long)]
     pub skip_build: bool,
+
+    /// Check a provenance attestation against the verified artifacts.
+    #[arg(long, value_name = "PATH", requires = "signer")]
+    pub artifact: Option<PathBuf>,
+
+    /// Hex-encoded Ed25519 key the attestation must be signed by. Required
+    /// with `--artifact`: the key inside the attestation only shows the file
+    /// is intact, not who produced it.
+    #[arg(long, value_name = "PUBKEY", requires = "artifact")]
+    pub signer: Option<String>,
+
+    /// Sign a provenance attestation with a `Forge.toml` key name or key file
+    /// and write it next to the contract WASM.
+    #[arg(long, value_name = "KEY", conflicts_with = "artifact")]
+    pub attest: Option<String>,
+
+    /// Network the contract is deployed to: recorded in the attestation
+    /// with `--attest`, and required to match it with `--artifact`.
+    #[arg(long, value_name = "NAME")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct AuditArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MigrateGenArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Schema JSON of the previous contract version (or its `state` array).
+    pub old_layout: PathBuf,
+
+    /// Use this data-driver WASM instead of building the project's.
+    #[arg(long, value_name = "PATH")]
+    pub data_driver: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct FmtSchemaArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Schema file to write (stdout if omitted).
+    pub file: Option<PathBuf>,
+
+    /// Compare the file with the canonical schema instead of writing it,
+    /// failing if they differ.
+    #[arg(long, requires = "file")]
+    pub check: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct EventsArgs {
+    #[command(subcommand)]
+    pub command: EventsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EventsCommand {
+    /// Decode raw events (topic and hex rkyv data) to JSON through the
+    /// data-driver, printing one JSON object per line.
+    Decode(EventsDecodeArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct EventsDecodeArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// File of events, as a JSON array or one JSON object per line, each with
+    /// a `topic` and hex `data` (stdin if omitted or `-`).
+    pub file: Option<PathBuf>,
+
+    /// Use this data-driver WASM instead of building the project's.
+    #[arg(long, value_name = "PATH")]
+    pub data_driver: Option<PathBuf>,
+
+    /// Report events that fail to decode with an `error` field and carry on,
+    /// instead of stopping at the first one.
+    #[arg(long)]
+    pub keep_going: bool,
+}
+
+#[derive(Debug, Args)]
+#[command(trailing_var_arg = true)]
+pub struct ScriptArgs {
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Script to run: `scripts/<SCRIPT>.rs`, declared as an `[[example]]`
+    /// or `[[bin]]` of the project.
+    #[arg(value_name = "SCRIPT")]
+    pub name: String,
+
+    /// Network the script targets: a `Forge.toml` network or one of
+    /// `localnet`, `devnet`, `testnet` and `mainnet`, passed as
+    /// `DUSK_FORGE_NETWORK`, `DUSK_FORGE_RPC` and `DUSK_FORGE_CHAIN_ID`.
+    #[arg(long, value_name = "NAME")]
+    pub network: Option<String>,
+
+    /// `Forge.toml` key name or key file the script signs with, passed as
+    /// the path in `DUSK_FORGE_KEY`.
+    #[arg(long, value_name = "KEY")]
+    pub key: Option<String>,
+
+    /// Extra args passed through to the script.
+    pub script_args: Vec<String>,
 }
 
 #[derive(Debug, Args)]
@@ -183,7 +531,100 @@ mod tests {
 
     use clap::Parser;
 
-    use super::{Cli, Commands};
+    use super::{
+        BuildScope, Cli, Commands, ErrorFormat, EventsCommand, SchemaCommand, SchemaFormat,
+        TestReportFormat,
+    };
+
+    #[test]
+    fn parses_new_lib_types_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "new", "bridge", "--lib-types"]);
+
+        match cli.command {
+            Commands::New(args) => {
+                assert_eq!(args.name, "bridge");
+                assert!(args.lib_types);
+                assert!(!args.no_git);
+            }
+            other => panic!("expected new command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_build_timings_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "contract", "--timings"]);
+
+        match cli.command {
+            Commands::Build(args) => {
+                assert!(matches!(args.target, BuildScope::Contract));
+                assert!(args.timings);
+                assert!(!args.deny_panics);
+            }
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_build_deny_panics_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "--deny-panics"]);
+
+        match cli.command {
+            Commands::Build(args) => {
+                assert!(matches!(args.target, BuildScope::All));
+                assert!(args.deny_panics);
+            }
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_build_keep_names_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "contract", "--keep-names"]);
+
+        match cli.command {
+            Commands::Build(args) => {
+                assert!(matches!(args.target, BuildScope::Contract));
+                assert!(args.keep_names);
+            }
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_build_skip_js_smoke_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "data-driver", "--skip-js-smoke"]);
+
+        match cli.command {
+            Commands::Build(args) => {
+                assert!(matches!(args.target, BuildScope::DataDriver));
+                assert!(args.skip_js_smoke);
+            }
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_build_features() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "--features", "debug,admin"]);
+
+        match cli.command {
+            Commands::Build(args) => assert_eq!(args.features, ["debug", "admin"]),
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_plugin_command() {
+        let cli = Cli::parse_from(["dusk-forge", "explorer-upload", "--network", "testnet"]);
+
+        assert_eq!(cli.command.name(), "plugin");
+        match cli.command {
+            Commands::External(args) => {
+                assert_eq!(args, ["explorer-upload", "--network", "testnet"]);
+            }
+            other => panic!("expected plugin command, got {other:?}"),
+        }
+    }
 
     #[test]
     fn parses_expand_command() {
@@ -195,6 +636,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_global_error_format() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "--error-format", "json"]);
+
+        assert_eq!(cli.error_format, ErrorFormat::Json);
+        assert_eq!(cli.command.name(), "build");
+        assert_eq!(
+            Cli::parse_from(["dusk-forge", "check"]).error_format,
+            ErrorFormat::Human
+        );
+    }
+
+    #[test]
+    fn parses_fmt_schema_command() {
+        let cli = Cli::parse_from(["dusk-forge", "fmt-schema", "schema.json", "--check"]);
+
+        match cli.command {
+            Commands::FmtSchema(args) => {
+                assert_eq!(args.file, Some(PathBuf::from("schema.json")));
+                assert!(args.check);
+            }
+            other => panic!("expected fmt-schema command, got {other:?}"),
+        }
+        assert!(Cli::try_parse_from(["dusk-forge", "fmt-schema", "--check"]).is_err());
+    }
+
+    #[test]
+    fn parses_events_decode_command() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "events",
+            "decode",
+            "events.ndjson",
+            "--data-driver",
+            "driver.wasm",
+            "--keep-going",
+        ]);
+
+        match cli.command {
+            Commands::Events(args) => match args.command {
+                EventsCommand::Decode(decode) => {
+                    assert_eq!(decode.file, Some(PathBuf::from("events.ndjson")));
+                    assert_eq!(decode.data_driver, Some(PathBuf::from("driver.wasm")));
+                    assert!(decode.keep_going);
+                }
+            },
+            other => panic!("expected events command, got {other:?}"),
+        }
+        assert!(Cli::try_parse_from(["dusk-forge", "events"]).is_err());
+    }
+
     #[test]
     fn parses_clean_command() {
         let cli = Cli::parse_from(["dusk-forge", "clean", "--path", "demo"]);
@@ -205,6 +697,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_profile_option() {
+        let cli = Cli::parse_from(["dusk-forge", "build", "contract", "--profile", "dev"]);
+
+        match cli.command {
+            Commands::Build(args) => assert_eq!(args.project.profile.as_deref(), Some("dev")),
+            other => panic!("expected build command, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parses_completions_command() {
         let cli = Cli::parse_from(["dusk-forge", "completions", "bash"]);
@@ -225,19 +727,249 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_schema_resolve_deps_flag() {
+        let cli = Cli::parse_from(["dusk-forge", "schema", "--resolve-deps"]);
+
+        match cli.command {
+            Commands::Schema(args) => {
+                assert!(args.resolve_deps);
+                assert!(!args.pretty);
+            }
+            other => panic!("expected schema command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_schema_filter_and_format() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "schema",
+            "--function",
+            "counter",
+            "--format",
+            "table",
+        ]);
+
+        match cli.command {
+            Commands::Schema(args) => {
+                assert_eq!(args.function.as_deref(), Some("counter"));
+                assert_eq!(args.format, SchemaFormat::Table);
+            }
+            other => panic!("expected schema command, got {other:?}"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "schema", "--function", "a", "--event", "b"])
+                .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "schema", "--index-plan", "--event", "b"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parses_schema_events_only() {
+        let cli = Cli::parse_from(["dusk-forge", "schema", "--events-only", "--format", "csv"]);
+
+        match cli.command {
+            Commands::Schema(args) => {
+                assert!(args.events_only);
+                assert_eq!(args.format, SchemaFormat::Csv);
+            }
+            other => panic!("expected schema command, got {other:?}"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "schema", "--events-only", "--index-plan"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parses_schema_changelog_command() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "schema",
+            "changelog",
+            "--since",
+            "v0.1.0",
+            "--schema-file",
+            "counter.schema.json",
+        ]);
+
+        match cli.command {
+            Commands::Schema(args) => match args.command {
+                Some(SchemaCommand::Changelog(changelog)) => {
+                    assert_eq!(changelog.since, "v0.1.0");
+                    assert_eq!(
+                        changelog.schema_file,
+                        Some(PathBuf::from("counter.schema.json"))
+                    );
+                }
+                other => panic!("expected changelog subcommand, got {other:?}"),
+            },
+            other => panic!("expected schema command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["dusk-forge", "schema", "changelog"]).is_err());
+    }
+
     #[test]
     fn parses_call_command() {
-        let cli = Cli::parse_from(["dusk-forge", "call", "transfer", "--input", "{\"foo\":1}"]);
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "call",
+            "transfer",
+            "--input",
+            "{\"foo\":1}",
+            "--network",
+            "testnet",
+        ]);
 
         match cli.command {
             Commands::Call(args) => {
                 assert_eq!(args.function, "transfer");
                 assert_eq!(args.input, "{\"foo\":1}");
+                assert_eq!(args.network.as_deref(), Some("testnet"));
             }
             other => panic!("expected call command, got {other:?}"),
         }
     }
 
+    #[test]
+    fn parses_offline_call_flags() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "call",
+            "events::Transfer::TOPIC",
+            "--decode",
+            "0x2a00",
+            "--event",
+            "--data-driver",
+            "driver.wasm",
+        ]);
+
+        match cli.command {
+            Commands::Call(args) => {
+                assert_eq!(args.decode.as_deref(), Some("0x2a00"));
+                assert!(args.event);
+                assert_eq!(args.data_driver, Some(PathBuf::from("driver.wasm")));
+            }
+            other => panic!("expected call command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["dusk-forge", "call", "f", "--event"]).is_err());
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "call", "f", "--encode-only", "--decode", "00"])
+                .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "call", "f", "--input", "1", "--decode", "00"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_random_call_flags() {
+        let cli = Cli::parse_from(["dusk-forge", "call", "transfer", "--random", "--seed", "7"]);
+
+        match cli.command {
+            Commands::Call(args) => {
+                assert!(args.random);
+                assert_eq!(args.seed, Some(7));
+            }
+            other => panic!("expected call command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["dusk-forge", "call", "f", "--seed", "7"]).is_err());
+        assert!(
+            Cli::try_parse_from(["dusk-forge", "call", "f", "--random", "--input", "1"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parses_test_init_args() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "test",
+            "--init-args",
+            "init.json",
+            "test_deploy",
+        ]);
+
+        match cli.command {
+            Commands::Test(args) => {
+                assert_eq!(args.init_args, Some(PathBuf::from("init.json")));
+                assert_eq!(args.cargo_test_args, ["test_deploy"]);
+            }
+            other => panic!("expected test command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_test_report_flags() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "test",
+            "--no-capture",
+            "--report",
+            "junit",
+            "--report-path",
+            "report.xml",
+            "transfer",
+        ]);
+
+        match cli.command {
+            Commands::Test(args) => {
+                assert!(args.no_capture);
+                assert_eq!(args.report, Some(TestReportFormat::Junit));
+                assert_eq!(args.report_path, Some(PathBuf::from("report.xml")));
+                assert_eq!(args.cargo_test_args, ["transfer"]);
+            }
+            other => panic!("expected test command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["dusk-forge", "test", "--report-path", "r.xml"]).is_err());
+    }
+
+    #[test]
+    fn parses_script_command() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "script",
+            "deploy_bridge",
+            "--network",
+            "testnet",
+            "--key",
+            "deployer",
+            "--",
+            "--dry-run",
+        ]);
+
+        match cli.command {
+            Commands::Script(args) => {
+                assert_eq!(args.name, "deploy_bridge");
+                assert_eq!(args.network.as_deref(), Some("testnet"));
+                assert_eq!(args.key.as_deref(), Some("deployer"));
+                assert_eq!(args.script_args, ["--dry-run"]);
+            }
+            other => panic!("expected script command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_migrate_gen_command() {
+        let cli = Cli::parse_from(["dusk-forge", "migrate-gen", "old_layout.json"]);
+
+        match cli.command {
+            Commands::MigrateGen(args) => {
+                assert_eq!(args.old_layout, PathBuf::from("old_layout.json"));
+                assert!(args.data_driver.is_none());
+            }
+            other => panic!("expected migrate-gen command, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parses_verify_command() {
         let cli = Cli::parse_from([
@@ -256,4 +988,54 @@ mod tests {
             other => panic!("expected verify command, got {other:?}"),
         }
     }
+
+    #[test]
+    fn parses_audit_command() {
+        let cli = Cli::parse_from(["dusk-forge", "audit", "--path", "counter", "--json"]);
+
+        match cli.command {
+            Commands::Audit(args) => {
+                assert_eq!(args.project.path, PathBuf::from("counter"));
+                assert!(args.json);
+            }
+            other => panic!("expected audit command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_verify_attestation_flags() {
+        let cli = Cli::parse_from([
+            "dusk-forge",
+            "verify",
+            "--artifact",
+            "counter.wasm.attestation.json",
+            "--signer",
+            "abcd",
+        ]);
+
+        match cli.command {
+            Commands::Verify(args) => {
+                assert_eq!(
+                    args.artifact,
+                    Some(PathBuf::from("counter.wasm.attestation.json"))
+                );
+                assert_eq!(args.signer.as_deref(), Some("abcd"));
+            }
+            other => panic!("expected verify command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["dusk-forge", "verify", "--signer", "abcd"]).is_err());
+        assert!(Cli::try_parse_from(["dusk-forge", "verify", "--artifact", "a.json"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "dusk-forge",
+                "verify",
+                "--artifact",
+                "a.json",
+                "--attest",
+                "deployer"
+            ])
+            .is_err()
+        );
+    }
 }
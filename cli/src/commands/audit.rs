@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::process::{Command, Stdio};
+
+use cargo_metadata::Package;
+use serde::{Deserialize, Serialize};
+
+use crate::build_runner::{self, dependencies};
+use crate::cli::AuditArgs;
+use crate::error::{CliError, Result};
+use crate::project::metadata::ProjectMetadata;
+use crate::project::{detect, metadata};
+use crate::{tools, ui};
+
+/// Advisory kind cargo-audit reports vulnerabilities under.
+const VULNERABILITY: &str = "vulnerability";
+
+/// The dependencies compiled into the contract WASM and their advisories.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Report {
+    crate_name: String,
+    dependencies: Vec<Dependency>,
+    /// `None` when cargo-audit is not installed.
+    advisories: Option<Vec<Advisory>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Dependency {
+    name: String,
+    version: String,
+    /// SPDX expression, or the license file when the crate has none.
+    license: Option<String>,
+    source: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Advisory {
+    /// `vulnerability`, or the cargo-audit warning kind (`unmaintained`,
+    /// `unsound`, `yanked`, ...).
+    kind: String,
+    package: String,
+    version: String,
+    /// RustSec ID, absent for yanked crates.
+    id: Option<String>,
+    title: Option<String>,
+    url: Option<String>,
+}
+
+/// `cargo audit --json` output, reduced to the findings.
+#[derive(Debug, Deserialize)]
+struct AuditOutput {
+    vulnerabilities: Vulnerabilities,
+    #[serde(default)]
+    warnings: BTreeMap<String, Vec<Finding>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Vulnerabilities {
+    list: Vec<Finding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Finding {
+    advisory: Option<AdvisoryInfo>,
+    package: AuditedPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryInfo {
+    id: String,
+    title: String,
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditedPackage {
+    name: String,
+    version: String,
+}
+
+pub fn run(args: AuditArgs) -> Result<()> {
+    let project = metadata::load(&args.project)?;
+    detect::ensure_forge_project(&project.project_dir)?;
+
+    ui::status(format!(
+        "Resolving the contract dependencies of {}",
+        project.crate_name
+    ));
+    let dependencies = contract_dependencies(&project)?;
+
+    let advisories = if tools::find_in_path("cargo-audit").is_some() {
+        ui::status("Checking RustSec advisories with cargo-audit");
+        Some(audit(&project, &dependencies, args.project.verbose)?)
+    } else {
+        ui::warn(
+            "cargo-audit not found; install it with `cargo install cargo-audit` to check the \
+             dependencies against the RustSec advisory database",
+        );
+        None
+    };
+
+    let report = Report {
+        crate_name: project.crate_name.clone(),
+        dependencies,
+        advisories,
+    };
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", render(&report));
+    }
+
+    let vulnerabilities = report
+        .advisories
+        .iter()
+        .flatten()
+        .filter(|advisory| advisory.kind == VULNERABILITY)
+        .count();
+    if vulnerabilities > 0 {
+        return Err(CliError::Verification(format!(
+            "{vulnerabilities} known {} in the contract dependencies",
+            if vulnerabilities == 1 {
+                "vulnerability"
+            } else {
+                "vulnerabilities"
+            }
+        )));
+    }
+
+    ui::success(format!(
+        "Audited {} contract dependencies",
+        report.dependencies.len()
+    ));
+    Ok(())
+}
+
+/// The packages compiled into the contract WASM, without the contract
+/// itself, sorted by name and version.
+fn contract_dependencies(project: &ProjectMetadata) -> Result<Vec<Dependency>> {
+    let metadata = dependencies::wasm_metadata(project, &[build_runner::CONTRACT_FEATURE])?;
+    let root = dependencies::root(&metadata, project).ok_or_else(|| {
+        CliError::Config(format!(
+            "unable to resolve package metadata for {}",
+            project.manifest_path.display()
+        ))
+    })?;
+    let reachable = dependencies::reachable(&metadata, &root.id);
+
+    let mut found: Vec<_> = metadata
+        .packages
+        .iter()
+        .filter(|pkg| pkg.id != root.id && reachable.contains(&pkg.id))
+        .map(Dependency::from)
+        .collect();
+    found.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    Ok(found)
+}
+
+impl From<&Package> for Dependency {
+    fn from(pkg: &Package) -> Self {
+        Self {
+            name: pkg.name.to_string(),
+            version: pkg.version.to_string(),
+            license: pkg
+                .license
+                .clone()
+                .or_else(|| pkg.license_file.as_ref().map(|file| format!("see {file}"))),
+            source: pkg.source.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+/// Run cargo-audit on the project's lockfile, keeping the findings for the
+/// crates compiled into the contract.
+fn audit(
+    project: &ProjectMetadata,
+    dependencies: &[Dependency],
+    verbose: bool,
+) -> Result<Vec<Advisory>> {
+    let mut cmd = Command::new("cargo");
+    cmd.arg("audit")
+        .arg("--json")
+        .arg("--file")
+        .arg(&project.lockfile_path)
+        .current_dir(&project.project_dir)
+        .stderr(Stdio::inherit());
+
+    if verbose {
+        eprintln!("Running: {}", ui::format_command(&cmd));
+    }
+
+    // cargo-audit exits non-zero when it finds vulnerabilities, so its
+    // report is read whatever the status
+    let output = cmd.output()?;
+    parse_audit(&String::from_utf8_lossy(&output.stdout), dependencies).map_err(|_| {
+        CliError::CommandFailed {
+            program: "cargo audit".to_string(),
+            code: output.status.code().unwrap_or(1),
+        }
+    })
+}
+
+/// The findings of a `cargo audit --json` report for `dependencies`, since
+/// the lockfile also holds host-only and workspace-wide crates.
+fn parse_audit(json: &str, dependencies: &[Dependency]) -> Result<Vec<Advisory>> {
+    let output: AuditOutput = serde_json::from_str(json)?;
+    let compiled: BTreeSet<_> = dependencies
+        .iter()
+        .map(|dep| (dep.name.as_str(), dep.version.as_str()))
+        .collect();
+
+    let findings = output
+        .vulnerabilities
+        .list
+        .into_iter()
+        .map(|finding| (VULNERABILITY.to_string(), finding))
+        .chain(output.warnings.into_iter().flat_map(|(kind, findings)| {
+            findings
+                .into_iter()
+                .map(move |finding| (kind.clone(), finding))
+        }));
+
+    Ok(findings
+        .filter(|(_, finding)| {
+            compiled.contains(&(
+                finding.package.name.as_str(),
+                finding.package.version.as_str(),
+            ))
+        })
+        .map(|(kind, finding)| {
+            let (id, title, url) = match finding.advisory {
+                Some(info) => (Some(info.id), Some(info.title), info.url),
+                None => (None, None, None),
+            };
+            Advisory {
+                kind,
+                package: finding.package.name,
+                version: finding.package.version,
+                id,
+                title,
+                url,
+            }
+        })
+        .collect())
+}
+
+/// The report as aligned text: the dependencies, then the advisories.
+fn render(report: &Report) -> String {
+    let rows: Vec<_> = report
+        .dependencies
+        .iter()
+        .map(|dep| {
+            [
+                dep.name.as_str(),
+                dep.version.as_str(),
+                dep.license.as_deref().unwrap_or("unknown"),
+            ]
+        })
+        .collect();
+    let name_width = rows.iter().map(|row| row[0].len()).max().unwrap_or(0);
+    let version_width = rows.iter().map(|row| row[1].len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for [name, version, license] in rows {
+        let _ = writeln!(
+            out,
+            "{name:<name_width$}  {version:<version_width$}  {license}"
+        );
+    }
+
+    match &report.advisories {
+        Some(advisories) if advisories.is_empty() => out.push_str("\nno known advisories\n"),
+        Some(advisories) => {
+            out.push_str("\nadvisories:\n");
+            for advisory in advisories {
+                let _ = write!(
+                    out,
+                    "  {} {}@{}",
+                    advisory.kind, advisory.package, advisory.version
+                );
+                if let Some(id) = &advisory.id {
+                    let _ = write!(out, " {id}");
+                }
+                if let Some(title) = &advisory.title {
+                    let _ = write!(out, ": {title}");
+                }
+                out.push('\n');
+            }
+        }
+        None => {}
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(name: &str, version: &str, license: Option<&str>) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            license: license.map(ToString::to_string),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn keeps_advisories_of_contract_dependencies() {
+        let json = r#"{
+            "database": {},
+            "vulnerabilities": {
+                "found": true,
+                "count": 2,
+                "list": [
+                    {
+                        "advisory": {
+                            "id": "RUSTSEC-2024-0001",
+                            "title": "Out-of-bounds read",
+                            "url": "https://example.com/advisory"
+                        },
+                        "package": { "name": "bytecheck", "version": "0.6.9" }
+                    },
+                    {
+                        "advisory": { "id": "RUSTSEC-2024-0002", "title": "Host only" },
+                        "package": { "name": "tokio", "version": "1.0.0" }
+                    }
+                ]
+            },
+            "warnings": {
+                "yanked": [
+                    { "advisory": null, "package": { "name": "dusk-bytes", "version": "0.1.7" } }
+                ]
+            }
+        }"#;
+        let dependencies = [
+            dependency("bytecheck", "0.6.9", Some("MIT")),
+            dependency("dusk-bytes", "0.1.7", Some("MPL-2.0")),
+        ];
+
+        let advisories = parse_audit(json, &dependencies).expect("report parses");
+        assert_eq!(
+            advisories,
+            [
+                Advisory {
+                    kind: "vulnerability".to_string(),
+                    package: "bytecheck".to_string(),
+                    version: "0.6.9".to_string(),
+                    id: Some("RUSTSEC-2024-0001".to_string()),
+                    title: Some("Out-of-bounds read".to_string()),
+                    url: Some("https://example.com/advisory".to_string()),
+                },
+                Advisory {
+                    kind: "yanked".to_string(),
+                    package: "dusk-bytes".to_string(),
+                    version: "0.1.7".to_string(),
+                    id: None,
+                    title: None,
+                    url: None,
+                },
+            ]
+        );
+
+        let report = Report {
+            crate_name: "counter".to_string(),
+            dependencies: vec![
+                dependency("bytecheck", "0.6.9", Some("MIT")),
+                dependency("dusk-forge", "0.2.0", None),
+            ],
+            advisories: Some(advisories),
+        };
+        assert_eq!(
+            render(&report),
+            "bytecheck   0.6.9  MIT\n\
+             dusk-forge  0.2.0  unknown\n\
+             \n\
+             advisories:\n  \
+             vulnerability bytecheck@0.6.9 RUSTSEC-2024-0001: Out-of-bounds read\n  \
+             yanked dusk-bytes@0.1.7\n"
+        );
+    }
+}
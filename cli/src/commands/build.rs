@@ -1,39 +1,152 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
+#[cfg(feature = "schema")]
+use crate::build_runner::js_smoke;
+use crate::build_runner::timings::{self, Timings};
+use crate::build_runner::{BuildTarget, artifacts, panics, size_budget};
 use crate::cli::BuildArgs;
+#[cfg(feature = "schema")]
+use crate::data_driver_wasm::DataDriverWasm;
 use crate::error::Result;
+use crate::project::metadata::ProjectMetadata;
 use crate::project::{detect, metadata};
 use crate::{build_runner, toolchain, ui};
 
 pub fn run(args: BuildArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
+    let project = metadata::load(&args.project)?;
     detect::ensure_forge_project(&project.project_dir)?;
 
     toolchain::ensure_build(&project.project_dir, args.target.needs_rust_src())?;
 
+    let mut timings = Timings::start();
+    let verbose = args.project.verbose;
+    let features: Vec<_> = args.features.iter().map(String::as_str).collect();
+    let mut built = Vec::new();
     for target in args.target.expand() {
         ui::status(format!(
             "Building {} WASM ({})",
             target.label(),
             project.crate_name
         ));
+        let label = target.label();
 
-        let wasm_path = build_runner::build(&project, target, args.project.verbose)?;
-        let optimized =
-            build_runner::wasm_opt::optimize_if_available(&wasm_path, args.project.verbose)?;
+        let wasm_path = timings.time(label, "compile", || {
+            build_runner::build_with_features(&project, target, &features, verbose)
+        })?;
+        // Panic sites are read from the name section, which wasm-opt strips
+        if target == BuildTarget::Contract && args.deny_panics {
+            timings.time(label, "panic scan", || panics::check(&wasm_path))?;
+        }
+        // Only the contract is deployed, so only its size is budgeted
+        if target == BuildTarget::Contract {
+            timings.time(label, "function budgets", || {
+                size_budget::check_functions(&project, &wasm_path, verbose)
+            })?;
+        }
+        timings.time(label, "wasm-opt", || {
+            if args.keep_names {
+                build_runner::wasm_opt::optimize_with_names(&project, &wasm_path, verbose)
+            } else {
+                build_runner::wasm_opt::optimize(&project, &wasm_path, verbose)
+            }
+        })?;
+        if target == BuildTarget::Contract {
+            size_budget::check_size(&project, &wasm_path)?;
+        }
 
         let size = fs::metadata(&wasm_path)?.len();
-        if !optimized {
-            ui::warn("wasm-opt not found, skipping optimization");
-        }
 
         ui::success(format!(
             "{} wasm: {} ({})",
-            target.label(),
+            label,
             wasm_path.display(),
             ui::format_bytes(size)
         ));
+
+        if target == BuildTarget::DataDriver && !args.skip_js_smoke {
+            timings.time(label, "js smoke test", || smoke_test(&project, &wasm_path))?;
+        }
+        if target == BuildTarget::DataDriver
+            && let Some(schema_path) = timings.time(label, "schema", || write_schema(&wasm_path))?
+        {
+            built.push(("schema", schema_path));
+        }
+        built.push((label, wasm_path));
+    }
+
+    let built: Vec<_> = built
+        .iter()
+        .map(|(kind, path)| (*kind, path.as_path()))
+        .collect();
+    let manifest = timings.time("artifacts", "manifest", || {
+        artifacts::record(&project, &built)
+    })?;
+    ui::status(format!("Artifact manifest: {}", manifest.display()));
+
+    if args.timings {
+        let run = timings.finish(&project);
+        ui::status(format!("Build timings ({})", project.crate_name));
+        for row in run.breakdown() {
+            eprintln!("    {row}");
+        }
+        let (report, html) = timings::record(&project, run)?;
+        ui::status(format!(
+            "Timings report: {} ({})",
+            report.display(),
+            html.display()
+        ));
     }
 
     Ok(())
 }
+
+/// Smoke-test the JS entrypoints of a data-driver built with the
+/// `data-driver-js` feature.
+#[cfg(feature = "schema")]
+fn smoke_test(project: &ProjectMetadata, data_driver: &Path) -> Result<()> {
+    if detect::resolve_data_driver_feature(&project.project_dir)? != "data-driver-js" {
+        return Ok(());
+    }
+    let summary = js_smoke::check(data_driver)?;
+    ui::success(format!(
+        "data-driver JS exports round-tripped {} function input(s)",
+        summary.round_tripped
+    ));
+    if !summary.skipped.is_empty() {
+        ui::warn(format!(
+            "no input generated for {} (their types carry no layout in the schema)",
+            summary.skipped.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "schema"))]
+#[allow(clippy::unnecessary_wraps)]
+fn smoke_test(_project: &ProjectMetadata, _data_driver: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Write the schema embedded in a data-driver next to it, as
+/// `<name>.schema.json`. A data-driver whose schema cannot be read is left
+/// out of the manifest with a warning rather than failing the build.
+#[cfg(feature = "schema")]
+fn write_schema(data_driver: &Path) -> Result<Option<PathBuf>> {
+    let schema = match DataDriverWasm::load(data_driver).and_then(|mut d| d.get_schema_json()) {
+        Ok(schema) => schema,
+        Err(err) => {
+            ui::warn(format!("skipping schema artifact: {err}"));
+            return Ok(None);
+        }
+    };
+    let path = data_driver.with_extension("schema.json");
+    fs::write(&path, schema)?;
+    Ok(Some(path))
+}
+
+#[cfg(not(feature = "schema"))]
+#[allow(clippy::unnecessary_wraps)]
+fn write_schema(_data_driver: &Path) -> Result<Option<PathBuf>> {
+    Ok(None)
+}
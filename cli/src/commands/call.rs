@@ -4,31 +4,85 @@ use crate::error::Result;
 use crate::{
     build_runner::{self, BuildTarget},
     data_driver_wasm::DataDriverWasm,
-    project::{detect, metadata},
-    toolchain, ui,
+    error::CliError,
+    project::{config, detect, metadata},
+    random_input, toolchain, ui,
 };
 
 #[cfg(feature = "schema")]
 pub fn run(args: CallArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
-    detect::ensure_forge_project(&project.project_dir)?;
+    let network = match &args.network {
+        Some(name) => {
+            Some(config::load(&args.project.path, args.project.profile.as_deref())?.network(name)?)
+        }
+        None => None,
+    };
 
-    toolchain::ensure_build(&project.project_dir, false)?;
+    let wasm_path = match &args.data_driver {
+        Some(path) => path.clone(),
+        None => {
+            let project = metadata::load(&args.project)?;
+            detect::ensure_forge_project(&project.project_dir)?;
 
-    ui::status(format!(
-        "Building data-driver WASM for function '{}'",
-        args.function
-    ));
+            toolchain::ensure_build(&project.project_dir, false)?;
 
-    let wasm_path = build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
-    let optimized =
-        build_runner::wasm_opt::optimize_if_available(&wasm_path, args.project.verbose)?;
-    if !optimized {
-        ui::warn("wasm-opt not found, skipping optimization");
-    }
+            ui::status(format!(
+                "Building data-driver WASM for function '{}'",
+                args.function
+            ));
+
+            let wasm_path =
+                build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
+            build_runner::wasm_opt::optimize(&project, &wasm_path, args.project.verbose)?;
+            wasm_path
+        }
+    };
 
     let mut driver = DataDriverWasm::load(&wasm_path)?;
-    let encoded = driver.encode_input(&args.function, &args.input)?;
+
+    if let Some(hex) = &args.decode {
+        let payload = from_hex(hex)?;
+        let json = if args.event {
+            driver.decode_event(&args.function, &payload)?
+        } else {
+            driver.decode_output(&args.function, &payload)?
+        };
+
+        println!("{json}");
+        ui::success(if args.event {
+            "Event payload decoded"
+        } else {
+            "Call output decoded"
+        });
+        return Ok(());
+    }
+
+    let input = if args.random {
+        let schema = serde_json::from_str(&driver.get_schema_json()?)?;
+        let (input, seed) = random_input::generate(&schema, &args.function, args.seed)?;
+        ui::status(format!("Random input (seed {seed}): {input}"));
+        input.to_string()
+    } else {
+        args.input.clone()
+    };
+    if let Some(warning) = driver
+        .get_schema_json()
+        .ok()
+        .and_then(|schema| deprecation_warning(&schema, &args.function))
+    {
+        ui::warn(warning);
+    }
+
+    let encoded = match driver.encode_input(&args.function, &input) {
+        Ok(encoded) => encoded,
+        Err(err) => {
+            let named = driver
+                .get_schema_json()
+                .ok()
+                .and_then(|schema| named_args_error(&schema, &args.function, &input));
+            return Err(named.unwrap_or(err));
+        }
+    };
 
     if args.project.verbose {
         ui::status(format!(
@@ -38,16 +92,135 @@ pub fn run(args: CallArgs) -> Result<()> {
         ));
     }
 
+    let schema_json = if args.encode_only {
+        None
+    } else {
+        Some(driver.get_schema_json()?)
+    };
+    if let Some(schema_json) = schema_json
+        && let Some(item_type) = feed_type(&schema_json, &args.function)?
+    {
+        ui::status(format!(
+            "'{}' streams its output via abi::feed; decode each item as `{item_type}`",
+            args.function
+        ));
+    }
+
     println!("{}", to_hex_prefixed(&encoded));
+    if let Some(network) = network {
+        ui::status(format!("Target network: {network}"));
+    }
     ui::success("Call payload encoded");
     Ok(())
 }
 
 #[cfg(not(feature = "schema"))]
 pub fn run(_args: CallArgs) -> Result<()> {
-    Err(crate::error::CliError::Message(
-        "call command is disabled (build with --features schema)".to_string(),
-    ))
+    Err(crate::error::CliError::Disabled { command: "call" })
+}
+
+/// Item type fed by `function`, as declared in the schema's `feeds` field.
+#[cfg(feature = "schema")]
+fn feed_type(schema_json: &str, function: &str) -> Result<Option<String>> {
+    let schema: serde_json::Value = serde_json::from_str(schema_json)?;
+
+    let item_type = schema
+        .get("functions")
+        .and_then(serde_json::Value::as_array)
+        .and_then(|functions| functions.iter().find(|f| f["name"] == function))
+        .and_then(|f| f.get("feeds"))
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string);
+
+    Ok(item_type)
+}
+
+/// Warning for calls to `function` if the schema marks it deprecated, with
+/// its message and replacement.
+#[cfg(feature = "schema")]
+fn deprecation_warning(schema_json: &str, function: &str) -> Option<String> {
+    let schema: serde_json::Value = serde_json::from_str(schema_json).ok()?;
+    let deprecated = schema
+        .get("functions")?
+        .as_array()?
+        .iter()
+        .find(|f| f["name"] == function)?
+        .get("deprecated")
+        .filter(|deprecated| !deprecated.is_null())?;
+
+    let mut warning = format!("'{function}' is deprecated");
+    if let Some(message) = deprecated["message"].as_str().filter(|m| !m.is_empty()) {
+        warning.push_str(&format!(": {message}"));
+    }
+    if let Some(replacement) = deprecated["replacement"].as_str() {
+        warning.push_str(&format!(" (replaced by '{replacement}')"));
+    }
+    Some(warning)
+}
+
+/// Explain why a JSON object of named arguments was rejected: its keys must
+/// be exactly the parameter names of `function`, which takes several.
+#[cfg(feature = "schema")]
+fn named_args_error(schema_json: &str, function: &str, input: &str) -> Option<CliError> {
+    let serde_json::Value::Object(args) = serde_json::from_str(input).ok()? else {
+        return None;
+    };
+    let schema: serde_json::Value = serde_json::from_str(schema_json).ok()?;
+    let params: Vec<&str> = schema
+        .get("functions")?
+        .as_array()?
+        .iter()
+        .find(|f| f["name"] == function)?
+        .get("inputs")?
+        .as_array()?
+        .iter()
+        .filter_map(|param| param["name"].as_str())
+        .collect();
+    if params.len() < 2 {
+        return None;
+    }
+
+    let missing: Vec<_> = params
+        .iter()
+        .filter(|param| !args.contains_key(**param))
+        .copied()
+        .collect();
+    let unknown: Vec<_> = args
+        .keys()
+        .map(String::as_str)
+        .filter(|key| !params.contains(key))
+        .collect();
+    if missing.is_empty() && unknown.is_empty() {
+        return None;
+    }
+
+    let mut message = format!("'{function}' takes named arguments {}", params.join(", "));
+    if !missing.is_empty() {
+        message.push_str(&format!("; missing: {}", missing.join(", ")));
+    }
+    if !unknown.is_empty() {
+        message.push_str(&format!("; unknown: {}", unknown.join(", ")));
+    }
+    Some(CliError::InvalidInput(message))
+}
+
+/// Parse a hex payload, with or without a `0x` prefix.
+#[cfg(feature = "schema")]
+pub fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    let invalid = || CliError::InvalidInput(format!("invalid hex payload: '{hex}'"));
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            if pair.len() != 2 {
+                return Err(invalid());
+            }
+            u8::from_str_radix(pair, 16).map_err(|_| invalid())
+        })
+        .collect()
 }
 
 #[cfg(feature = "schema")]
@@ -62,3 +235,64 @@ fn to_hex_prefixed(bytes: &[u8]) -> String {
 
     out
 }
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_payloads_round_trip() {
+        let bytes = from_hex("0x2a00ff").expect("valid hex");
+        assert_eq!(bytes, [0x2a, 0x00, 0xff]);
+        assert_eq!(to_hex_prefixed(&bytes), "0x2a00ff");
+        assert_eq!(from_hex("2A00FF").expect("unprefixed hex"), bytes);
+
+        assert!(from_hex("0x2a0").is_err());
+        assert!(from_hex("zz").is_err());
+    }
+
+    #[test]
+    fn explains_mismatched_named_args() {
+        let schema = r#"{"functions": [
+            {"name": "transfer", "inputs": [
+                {"name": "from", "type": "u64"},
+                {"name": "to", "type": "u64"},
+                {"name": "amount", "type": "u64"}
+            ]},
+            {"name": "set", "inputs": [{"name": "config", "type": "Config"}]}
+        ]}"#;
+
+        let err = named_args_error(schema, "transfer", r#"{"from": 1, "too": 2, "amount": 3}"#)
+            .expect("mismatched keys");
+        assert_eq!(
+            err.to_string(),
+            "'transfer' takes named arguments from, to, amount; missing: to; \
+             unknown: too"
+        );
+
+        let exact = r#"{"from": 1, "to": 2, "amount": 3}"#;
+        assert!(named_args_error(schema, "transfer", exact).is_none());
+        assert!(named_args_error(schema, "transfer", "[1, 2, 3]").is_none());
+        assert!(named_args_error(schema, "set", r#"{"limit": 1}"#).is_none());
+    }
+
+    #[test]
+    fn warns_about_deprecated_functions() {
+        let schema = r#"{"functions": [
+            {"name": "transfer", "deprecated": {"message": "use transfer_v2", "replacement": "transfer_v2"}},
+            {"name": "legacy", "deprecated": {"message": "", "replacement": null}},
+            {"name": "transfer_v2", "deprecated": null}
+        ]}"#;
+
+        assert_eq!(
+            deprecation_warning(schema, "transfer").as_deref(),
+            Some("'transfer' is deprecated: use transfer_v2 (replaced by 'transfer_v2')")
+        );
+        assert_eq!(
+            deprecation_warning(schema, "legacy").as_deref(),
+            Some("'legacy' is deprecated")
+        );
+        assert!(deprecation_warning(schema, "transfer_v2").is_none());
+        assert!(deprecation_warning(schema, "missing").is_none());
+    }
+}
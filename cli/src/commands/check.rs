@@ -1,10 +1,11 @@
+use crate::build_runner::wasm_opt;
 use crate::cli::ProjectOptions;
 use crate::error::{CliError, Result};
 use crate::project::{detect, metadata};
 use crate::{toolchain, ui};
 
 pub fn run(args: ProjectOptions) -> Result<()> {
-    let project = metadata::load(&args.path)?;
+    let project = metadata::load(&args)?;
     let checks = detect::inspect_manifest(&project.project_dir)?;
     let toolchain = toolchain::inspect(&project.project_dir)?;
 
@@ -72,16 +73,23 @@ pub fn run(args: ProjectOptions) -> Result<()> {
     let rust_src_check = format!("rust-src component installed for {}", toolchain.channel);
     record(&rust_src_check, toolchain.rust_src, &mut failures);
 
-    if let Some(path) = toolchain.wasm_opt {
-        ui::success(format!("wasm-opt found at {}", path.display()));
-    } else {
-        ui::warn("wasm-opt not found (optional, but recommended for smaller binaries)");
+    match toolchain.wasm_opt {
+        Some(wasm_opt) if wasm_opt.is_outdated() => ui::warn(format!(
+            "{} at {} is older than binaryen version_{}",
+            wasm_opt.version.as_deref().unwrap_or("wasm-opt"),
+            wasm_opt.path.display(),
+            wasm_opt::RECOMMENDED_VERSION
+        )),
+        Some(wasm_opt) => ui::success(format!(
+            "{} found at {}",
+            wasm_opt.version.as_deref().unwrap_or("wasm-opt"),
+            wasm_opt.path.display()
+        )),
+        None => ui::warn("wasm-opt not found (optional, but recommended for smaller binaries)"),
     }
 
     if failures > 0 {
-        return Err(CliError::Message(format!(
-            "check failed with {failures} issue(s)"
-        )));
+        return Err(CliError::CheckFailed { failures });
     }
 
     ui::success("All checks passed");
@@ -6,7 +6,7 @@ use crate::project::{detect, metadata};
 use crate::ui;
 
 pub fn run(args: ProjectOptions) -> Result<()> {
-    let project = metadata::load(&args.path)?;
+    let project = metadata::load(&args)?;
     detect::ensure_forge_project(&project.project_dir)?;
 
     remove_if_exists(&project.contract_target_dir)?;
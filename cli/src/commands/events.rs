@@ -0,0 +1,208 @@
+#[cfg(feature = "schema")]
+use std::fs;
+#[cfg(feature = "schema")]
+use std::io::{self, BufWriter, Read, Write};
+#[cfg(feature = "schema")]
+use std::path::Path;
+
+#[cfg(feature = "schema")]
+use serde_json::{Map, Value};
+
+use crate::cli::{EventsArgs, EventsCommand, EventsDecodeArgs};
+use crate::error::Result;
+#[cfg(feature = "schema")]
+use crate::{
+    build_runner::{self, BuildTarget},
+    commands::call,
+    data_driver_wasm::DataDriverWasm,
+    error::CliError,
+    project::{detect, metadata},
+    toolchain, ui,
+};
+
+pub fn run(args: EventsArgs) -> Result<()> {
+    match args.command {
+        EventsCommand::Decode(args) => decode(args),
+    }
+}
+
+/// A raw event read from the input, with the rest of its record kept to be
+/// written back next to the decoded data.
+#[cfg(feature = "schema")]
+#[derive(Debug)]
+struct RawEvent {
+    record: Map<String, Value>,
+    topic: String,
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "schema")]
+fn decode(args: EventsDecodeArgs) -> Result<()> {
+    let events = parse_events(&read_input(args.file.as_deref())?)?;
+
+    let wasm_path = match &args.data_driver {
+        Some(path) => path.clone(),
+        None => {
+            let project = metadata::load(&args.project)?;
+            detect::ensure_forge_project(&project.project_dir)?;
+
+            toolchain::ensure_build(&project.project_dir, false)?;
+
+            ui::status("Building data-driver WASM to decode events");
+
+            let wasm_path =
+                build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
+            build_runner::wasm_opt::optimize(&project, &wasm_path, args.project.verbose)?;
+            wasm_path
+        }
+    };
+
+    let mut driver = DataDriverWasm::load(&wasm_path)?;
+    let total = events.len();
+    let mut failed = 0;
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for (index, event) in events.into_iter().enumerate() {
+        let RawEvent {
+            mut record,
+            topic,
+            data,
+        } = event;
+        let decoded = driver
+            .decode_event(&topic, &data)
+            .and_then(|json| Ok(serde_json::from_str::<Value>(&json)?));
+
+        match decoded {
+            Ok(decoded) => {
+                record.insert("data".to_string(), decoded);
+            }
+            Err(err) if args.keep_going => {
+                failed += 1;
+                record.insert("error".to_string(), Value::String(err.to_string()));
+            }
+            Err(err) => {
+                out.flush()?;
+                return Err(CliError::DataDriver(format!(
+                    "event {} (`{topic}`): {err}",
+                    index + 1
+                )));
+            }
+        }
+        writeln!(out, "{}", Value::Object(record))?;
+    }
+    out.flush()?;
+
+    if failed > 0 {
+        ui::warn(format!("{failed} of {total} events could not be decoded"));
+    } else {
+        ui::success(format!("Decoded {total} events"));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "schema"))]
+fn decode(_args: EventsDecodeArgs) -> Result<()> {
+    Err(crate::error::CliError::Disabled {
+        command: "events decode",
+    })
+}
+
+/// The input file, or stdin when it is omitted or `-`.
+#[cfg(feature = "schema")]
+fn read_input(file: Option<&Path>) -> Result<String> {
+    match file {
+        Some(path) if path != Path::new("-") => fs::read_to_string(path).map_err(|err| {
+            CliError::InvalidInput(format!("cannot read events {}: {err}", path.display()))
+        }),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// Events from a JSON array of records, or from one record per line
+/// (NDJSON, blank lines skipped).
+#[cfg(feature = "schema")]
+fn parse_events(input: &str) -> Result<Vec<RawEvent>> {
+    let records = if input.trim_start().starts_with('[') {
+        serde_json::from_str::<Vec<Value>>(input)
+            .map_err(|err| CliError::InvalidInput(format!("invalid events array: {err}")))?
+    } else {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|err| {
+                    CliError::InvalidInput(format!("invalid event on line {}: {err}", index + 1))
+                })
+            })
+            .collect::<Result<_>>()?
+    };
+
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            raw_event(record)
+                .map_err(|reason| CliError::InvalidInput(format!("event {}: {reason}", index + 1)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "schema")]
+fn raw_event(record: Value) -> std::result::Result<RawEvent, String> {
+    let Value::Object(record) = record else {
+        return Err("expected an object with `topic` and `data`".to_string());
+    };
+    let field = |name: &str| {
+        record
+            .get(name)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| format!("missing string field `{name}`"))
+    };
+    let topic = field("topic")?;
+    let data = call::from_hex(&field("data")?).map_err(|err| err.to_string())?;
+
+    Ok(RawEvent {
+        record,
+        topic,
+        data,
+    })
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_arrays_and_ndjson() {
+        let ndjson = "{\"topic\":\"updated\",\"data\":\"0x2a00\",\"block\":7}\n\n\
+                      {\"topic\":\"reset\",\"data\":\"\"}\n";
+        let events = parse_events(ndjson).expect("valid NDJSON");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].topic, "updated");
+        assert_eq!(events[0].data, [0x2a, 0x00]);
+        assert_eq!(events[0].record["block"], json!(7));
+        assert!(events[1].data.is_empty());
+
+        let array = r#"[{ "topic": "updated", "data": "2a00" }]"#;
+        let events = parse_events(array).expect("valid array");
+        assert_eq!(events[0].data, [0x2a, 0x00]);
+    }
+
+    #[test]
+    fn rejects_malformed_events() {
+        let err = parse_events("{\"topic\":\"updated\",\"data\":\"0x2a\"}\n{\"topic\":1}")
+            .expect_err("topic is not a string");
+        assert_eq!(err.to_string(), "event 2: missing string field `topic`");
+
+        let err = parse_events("{\"topic\":\"updated\"\n").expect_err("truncated line");
+        assert!(err.to_string().starts_with("invalid event on line 1"));
+    }
+}
@@ -7,7 +7,7 @@ use crate::toolchain::{self, WASM_TARGET};
 use crate::{build_runner, tools, ui};
 
 pub fn run(args: ExpandArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
+    let project = metadata::load(&args.project)?;
     detect::ensure_forge_project(&project.project_dir)?;
 
     if tools::find_in_path("cargo-expand").is_none() {
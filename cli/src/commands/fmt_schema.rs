@@ -0,0 +1,150 @@
+#[cfg(feature = "schema")]
+use std::fs;
+
+#[cfg(feature = "schema")]
+use serde_json::{Map, Value};
+
+use crate::cli::FmtSchemaArgs;
+use crate::error::Result;
+#[cfg(feature = "schema")]
+use crate::{
+    commands::{schema::build_schema, schema_changelog},
+    error::CliError,
+    project::{detect, metadata},
+    toolchain, ui,
+};
+
+/// Schema arrays whose order carries no meaning, with the keys their entries
+/// are sorted by. `state` and `inputs` are left alone: they follow the
+/// struct layout and the parameter order.
+#[cfg(feature = "schema")]
+const SORTED_ARRAYS: &[(&str, &[&str])] = &[
+    ("imports", &["name", "path"]),
+    ("functions", &["name"]),
+    ("events", &["topic", "data"]),
+];
+
+#[cfg(feature = "schema")]
+pub fn run(args: FmtSchemaArgs) -> Result<()> {
+    let project = metadata::load(&args.project)?;
+    detect::ensure_forge_project(&project.project_dir)?;
+
+    toolchain::ensure_build(&project.project_dir, false)?;
+
+    let schema = canonical(&build_schema(&project, args.project.verbose)?);
+    let formatted = format!("{}\n", serde_json::to_string_pretty(&schema)?);
+
+    let Some(path) = args.file else {
+        print!("{formatted}");
+        return Ok(());
+    };
+
+    if !args.check {
+        fs::write(&path, formatted)?;
+        ui::success(format!("Schema written to {}", path.display()));
+        return Ok(());
+    }
+
+    let committed = fs::read_to_string(&path).map_err(|err| {
+        CliError::InvalidInput(format!("cannot read schema {}: {err}", path.display()))
+    })?;
+    if committed == formatted {
+        ui::success(format!("{} is up to date", path.display()));
+        return Ok(());
+    }
+
+    let fix = format!(
+        "run `dusk-forge fmt-schema {}` to update it",
+        path.display()
+    );
+    match serde_json::from_str::<Value>(&committed) {
+        Ok(committed) if canonical(&committed) == schema => Err(CliError::Verification(format!(
+            "{} is not canonically formatted; {fix}",
+            path.display()
+        ))),
+        Ok(committed) => Err(CliError::Verification(format!(
+            "{} does not match the contract's schema; {fix}\n\n{}",
+            path.display(),
+            schema_changelog::changelog(&committed, &schema, &path.display().to_string())
+        ))),
+        Err(err) => Err(CliError::InvalidInput(format!(
+            "{} is not a schema: {err}",
+            path.display()
+        ))),
+    }
+}
+
+#[cfg(not(feature = "schema"))]
+pub fn run(_args: FmtSchemaArgs) -> Result<()> {
+    Err(crate::error::CliError::Disabled {
+        command: "fmt-schema",
+    })
+}
+
+/// The schema with sorted object keys and its unordered arrays sorted, so the
+/// same contract always yields the same JSON.
+#[cfg(feature = "schema")]
+fn canonical(schema: &Value) -> Value {
+    let mut schema = sort_keys(schema);
+    for (section, keys) in SORTED_ARRAYS {
+        if let Some(entries) = schema.get_mut(*section).and_then(Value::as_array_mut) {
+            entries.sort_by(|a, b| {
+                keys.iter()
+                    .map(|key| a[*key].to_string().cmp(&b[*key].to_string()))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+    }
+    schema
+}
+
+#[cfg(feature = "schema")]
+fn sort_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<_> = map.keys().collect();
+            keys.sort();
+            Value::Object(
+                keys.into_iter()
+                    .map(|key| (key.clone(), sort_keys(&map[key])))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.iter().map(sort_keys).collect()),
+        other => other.clone(),
+    }
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn sorts_keys_and_unordered_arrays() {
+        let schema = json!({
+            "name": "Counter",
+            "functions": [
+                { "name": "reset", "input": "()", "inputs": [] },
+                { "name": "add", "inputs": [{ "name": "b" }, { "name": "a" }], "input": "(u64, u64)" },
+            ],
+            "events": [
+                { "topic": "updated", "data": "u64" },
+                { "topic": "reset", "data": "()" },
+            ],
+            "state": [{ "name": "value" }, { "name": "owner" }],
+        });
+
+        let formatted = serde_json::to_string(&canonical(&schema)).unwrap();
+        assert_eq!(
+            formatted,
+            r#"{"events":[{"data":"()","topic":"reset"},{"data":"u64","topic":"updated"}],"#
+                .to_owned()
+                + r#""functions":[{"input":"(u64, u64)","inputs":[{"name":"b"},{"name":"a"}],"name":"add"},"#
+                + r#"{"input":"()","inputs":[],"name":"reset"}],"#
+                + r#""name":"Counter","state":[{"name":"value"},{"name":"owner"}]}"#
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::cli::MigrateGenArgs;
+use crate::error::Result;
+#[cfg(feature = "schema")]
+use crate::{
+    build_runner::{self, BuildTarget},
+    data_driver_wasm::DataDriverWasm,
+    error::CliError,
+    project::{detect, metadata},
+    toolchain, ui,
+};
+
+#[cfg(feature = "schema")]
+pub fn run(args: MigrateGenArgs) -> Result<()> {
+    let old_json = std::fs::read_to_string(&args.old_layout).map_err(|err| {
+        CliError::InvalidInput(format!(
+            "cannot read old layout {}: {err}",
+            args.old_layout.display()
+        ))
+    })?;
+    let old = layout(&serde_json::from_str(&old_json)?).ok_or_else(|| {
+        CliError::InvalidInput(format!(
+            "{} is not a state layout: expected a schema with a `state` array, or the array itself",
+            args.old_layout.display()
+        ))
+    })?;
+
+    let wasm_path = match &args.data_driver {
+        Some(path) => path.clone(),
+        None => {
+            let project = metadata::load(&args.project)?;
+            detect::ensure_forge_project(&project.project_dir)?;
+
+            toolchain::ensure_build(&project.project_dir, false)?;
+
+            ui::status("Building data-driver WASM for the current state layout");
+            let wasm_path =
+                build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
+            build_runner::wasm_opt::optimize(&project, &wasm_path, args.project.verbose)?;
+            wasm_path
+        }
+    };
+
+    let mut driver = DataDriverWasm::load(&wasm_path)?;
+    let schema: serde_json::Value = serde_json::from_str(&driver.get_schema_json()?)?;
+    let contract = schema["name"].as_str().unwrap_or("Contract");
+    let new = layout(&schema).ok_or_else(|| {
+        CliError::DataDriver(
+            "the data-driver schema has no `state` layout; rebuild with a current dusk-forge"
+                .to_string(),
+        )
+    })?;
+
+    let source = args.old_layout.display().to_string();
+    println!("{}", skeleton(contract, &old, &new, &source));
+
+    let todos = new.iter().filter(|f| !old.contains(f)).count()
+        + old
+            .iter()
+            .filter(|f| !new.iter().any(|n| n.name == f.name))
+            .count();
+    if todos == 0 {
+        ui::success("Migration skeleton generated; all fields carry over unchanged");
+    } else {
+        ui::success(format!(
+            "Migration skeleton generated with {todos} TODO(s) to resolve"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "schema"))]
+pub fn run(_args: MigrateGenArgs) -> Result<()> {
+    Err(crate::error::CliError::Disabled {
+        command: "migrate-gen",
+    })
+}
+
+/// A field of the contract state layout.
+#[cfg(feature = "schema")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+struct StateField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+/// The state layout of a schema (its `state` array) or of a bare array.
+#[cfg(feature = "schema")]
+fn layout(value: &serde_json::Value) -> Option<Vec<StateField>> {
+    let state = value.get("state").unwrap_or(value);
+    serde_json::from_value(state.clone()).ok()
+}
+
+/// Render the previous state as an `Old<Contract>` struct and a `migrate`
+/// function building the current state from it. Unchanged fields are moved
+/// over; added, retyped and removed fields are left as TODOs.
+#[cfg(feature = "schema")]
+fn skeleton(contract: &str, old: &[StateField], new: &[StateField], source: &str) -> String {
+    use std::fmt::Write;
+
+    let old_name = format!("Old{contract}");
+    let tuple = !old.is_empty() && old.iter().all(|f| f.name.parse::<usize>().is_ok());
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "/// `{contract}` state before the upgrade, from `{source}`."
+    );
+    if tuple {
+        let fields: Vec<_> = old.iter().map(|f| format!("pub {}", f.ty)).collect();
+        let _ = writeln!(out, "pub struct {old_name}({});", fields.join(", "));
+    } else {
+        let _ = writeln!(out, "pub struct {old_name} {{");
+        for field in old {
+            let _ = writeln!(out, "    pub {}: {},", field.name, field.ty);
+        }
+        out.push_str("}\n");
+    }
+
+    let _ = write!(
+        out,
+        "\n/// Build the upgraded `{contract}` state from the previous version's.\n\
+         pub fn migrate(old: {old_name}) -> {contract} {{\n    {contract} {{\n"
+    );
+    for field in new {
+        let name = &field.name;
+        match old.iter().find(|f| f.name == field.name) {
+            Some(prev) if prev.ty == field.ty => {
+                let _ = writeln!(out, "        {name}: old.{name},");
+            }
+            Some(prev) => {
+                let _ = writeln!(
+                    out,
+                    "        // TODO: `{name}` changed type from `{}` to `{}`.\n        \
+                     {name}: todo!(\"convert `old.{name}`\"),",
+                    prev.ty, field.ty
+                );
+            }
+            None => {
+                let _ = writeln!(
+                    out,
+                    "        // TODO: `{name}: {}` is a new field.\n        \
+                     {name}: todo!(\"initialize `{name}`\"),",
+                    field.ty
+                );
+            }
+        }
+    }
+    for field in old.iter().filter(|f| !new.iter().any(|n| n.name == f.name)) {
+        let _ = writeln!(
+            out,
+            "        // TODO: `{}: {}` was removed; carry `old.{}` over or drop it.",
+            field.name, field.ty, field.name
+        );
+    }
+    out.push_str("    }\n}");
+
+    out
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn field(name: &str, ty: &str) -> StateField {
+        StateField {
+            name: name.to_string(),
+            ty: ty.to_string(),
+        }
+    }
+
+    #[test]
+    fn reads_layout_from_schema_or_array() {
+        let schema = json!({
+            "name": "Counter",
+            "state": [{ "name": "value", "type": "u64" }],
+        });
+        let expected = vec![field("value", "u64")];
+
+        assert_eq!(layout(&schema), Some(expected.clone()));
+        assert_eq!(layout(&schema["state"]), Some(expected));
+        assert_eq!(layout(&json!({ "name": "Counter" })), None);
+    }
+
+    #[test]
+    fn maps_unchanged_fields_and_marks_the_rest() {
+        let old = [
+            field("owner", "PublicKey"),
+            field("value", "u64"),
+            field("legacy", "u32"),
+        ];
+        let new = [
+            field("owner", "Option < PublicKey >"),
+            field("value", "u64"),
+            field("paused", "bool"),
+        ];
+
+        let output = skeleton("Counter", &old, &new, "old.json");
+
+        assert_eq!(
+            output,
+            "\
+/// `Counter` state before the upgrade, from `old.json`.
+pub struct OldCounter {
+    pub owner: PublicKey,
+    pub value: u64,
+    pub legacy: u32,
+}
+
+/// Build the upgraded `Counter` state from the previous version's.
+pub fn migrate(old: OldCounter) -> Counter {
+    Counter {
+        // TODO: `owner` changed type from `PublicKey` to `Option < PublicKey >`.
+        owner: todo!(\"convert `old.owner`\"),
+        value: old.value,
+        // TODO: `paused: bool` is a new field.
+        paused: todo!(\"initialize `paused`\"),
+        // TODO: `legacy: u32` was removed; carry `old.legacy` over or drop it.
+    }
+}"
+        );
+    }
+
+    #[test]
+    fn renders_tuple_struct_layouts() {
+        let old = [field("0", "u64")];
+        let new = [field("0", "u64"), field("1", "bool")];
+
+        let output = skeleton("Counter", &old, &new, "old.json");
+
+        assert!(
+            output.contains("pub struct OldCounter(pub u64);"),
+            "{output}"
+        );
+        assert!(output.contains("        0: old.0,"), "{output}");
+    }
+}
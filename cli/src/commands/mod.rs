@@ -1,10 +1,19 @@
+pub mod audit;
 pub mod build;
 pub mod call;
 pub mod check;
 pub mod clean;
 pub mod completions;
+pub mod events;
 pub mod expand;
+pub mod fmt_schema;
+pub mod migrate_gen;
 pub mod new;
+pub mod plugin;
 pub mod schema;
+#[cfg(feature = "schema")]
+pub mod schema_changelog;
+pub mod script;
 pub mod test;
+pub mod test_report;
 pub mod verify;
@@ -5,7 +5,9 @@ use std::process::Command;
 use crate::cli::{NewArgs, TemplateChoice};
 use crate::error::{CliError, Result};
 use crate::template::embedded::TemplateKind;
-use crate::template::engine::{render_template, validate_contract_name};
+use crate::template::engine::{
+    TYPES_DIR, add_types_dependency, render_template, render_types, validate_contract_name,
+};
 use crate::{build_runner, toolchain, ui};
 
 pub fn run(args: NewArgs) -> Result<()> {
@@ -24,15 +26,19 @@ pub fn run(args: NewArgs) -> Result<()> {
     let template_kind = match args.template {
         TemplateChoice::Counter => TemplateKind::Counter,
         TemplateChoice::Empty => TemplateKind::Empty,
+        TemplateChoice::Multisig => TemplateKind::Multisig,
+        TemplateChoice::Token => TemplateKind::Token,
+        TemplateChoice::Nft => TemplateKind::Nft,
     };
 
     let rendered = render_template(template_kind, &parsed_name);
+    let cargo_toml = if args.lib_types {
+        add_types_dependency(&rendered.cargo_toml, &parsed_name)
+    } else {
+        rendered.cargo_toml
+    };
 
-    write_file(
-        &destination.join("Cargo.toml"),
-        &rendered.cargo_toml,
-        args.verbose,
-    )?;
+    write_file(&destination.join("Cargo.toml"), &cargo_toml, args.verbose)?;
     write_file(
         &destination.join("src/lib.rs"),
         &rendered.lib_rs,
@@ -59,6 +65,19 @@ pub fn run(args: NewArgs) -> Result<()> {
         args.verbose,
     )?;
 
+    if args.lib_types {
+        let types = render_types(&parsed_name);
+        let types_dir = destination.join(TYPES_DIR);
+        fs::create_dir_all(types_dir.join("src"))?;
+
+        write_file(
+            &types_dir.join("Cargo.toml"),
+            &types.cargo_toml,
+            args.verbose,
+        )?;
+        write_file(&types_dir.join("src/lib.rs"), &types.lib_rs, args.verbose)?;
+    }
+
     generate_lockfile(&destination, args.verbose)?;
 
     if !args.no_git {
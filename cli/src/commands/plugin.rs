@@ -0,0 +1,126 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+
+use crate::build_runner::{artifacts, registry};
+use crate::cli::ProjectOptions;
+use crate::error::{CliError, Result};
+use crate::project::metadata::ProjectMetadata;
+use crate::project::{detect, metadata};
+use crate::{tools, ui};
+
+/// Prefix of plugin executables: `dusk-forge explorer-upload` runs
+/// `dusk-forge-explorer-upload` from `PATH`.
+pub const PLUGIN_PREFIX: &str = "dusk-forge-";
+
+/// Environment variable holding the path of the running `dusk-forge`, so
+/// plugins can call back into it.
+pub const FORGE_ENV: &str = "DUSK_FORGE";
+
+/// Environment variable holding the [`Project`] JSON, set when the plugin is
+/// run from a contract project.
+pub const PROJECT_ENV: &str = "DUSK_FORGE_PROJECT";
+
+/// The project a plugin is run from, as passed in [`PROJECT_ENV`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Project<'a> {
+    crate_name: &'a str,
+    project_dir: &'a Path,
+    manifest_path: &'a Path,
+    profile: Option<&'a str>,
+    contract_wasm: &'a Path,
+    data_driver_wasm: &'a Path,
+    /// `forge-artifacts.json`, which exists once the project is built.
+    artifact_manifest: &'a Path,
+    registry: PathBuf,
+}
+
+impl<'a> From<&'a ProjectMetadata> for Project<'a> {
+    fn from(project: &'a ProjectMetadata) -> Self {
+        Self {
+            crate_name: &project.crate_name,
+            project_dir: &project.project_dir,
+            manifest_path: &project.manifest_path,
+            profile: project.profile.as_deref(),
+            contract_wasm: &project.contract_wasm_path,
+            data_driver_wasm: &project.data_driver_wasm_path,
+            artifact_manifest: &project.artifact_manifest_path,
+            registry: registry::path(project),
+        }
+    }
+}
+
+/// Run the plugin named by the first of `args` with the rest as its
+/// arguments, the way cargo runs `cargo-<name>` for unknown subcommands.
+pub fn run(args: Vec<OsString>) -> Result<()> {
+    let Some((name, plugin_args)) = args.split_first() else {
+        return Err(CliError::InvalidInput("missing command".to_string()));
+    };
+    let name = name.to_string_lossy();
+    let program = format!("{PLUGIN_PREFIX}{name}");
+    let Some(path) = tools::find_in_path(&program) else {
+        return Err(CliError::InvalidInput(format!(
+            "unknown command `{name}`: not a built-in command, and no `{program}` plugin on PATH"
+        )));
+    };
+
+    let mut cmd = Command::new(&path);
+    cmd.args(plugin_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .stdin(Stdio::inherit());
+    if let Ok(forge) = env::current_exe() {
+        cmd.env(FORGE_ENV, forge);
+    }
+    // Plugins run anywhere; inside a contract project they also get its paths
+    if let Some(project) = current_project() {
+        cmd.env(
+            PROJECT_ENV,
+            serde_json::to_string(&Project::from(&project))?,
+        )
+        .env(artifacts::MANIFEST_ENV, &project.artifact_manifest_path)
+        .env(registry::REGISTRY_ENV, registry::path(&project));
+    }
+
+    let status = cmd.status()?;
+    if !status.success() {
+        return Err(CliError::PassThrough {
+            program,
+            code: status.code().unwrap_or(1),
+        });
+    }
+    Ok(())
+}
+
+/// The contract project in the current directory, if it is one.
+fn current_project() -> Option<ProjectMetadata> {
+    let options = ProjectOptions {
+        path: PathBuf::from("."),
+        profile: None,
+        target_dir: None,
+        verbose: false,
+    };
+    detect::ensure_forge_project(&options.path).ok()?;
+    metadata::load(&options)
+        .inspect_err(|err| ui::warn(format!("plugin runs without project metadata: {err}")))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_plugin() {
+        let err = run(vec![OsString::from("no-such-forge-plugin")]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown command `no-such-forge-plugin`: not a built-in command, and no \
+             `dusk-forge-no-such-forge-plugin` plugin on PATH"
+        );
+    }
+}
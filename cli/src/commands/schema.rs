@@ -1,44 +1,430 @@
+#[cfg(feature = "schema")]
+use serde_json::Value;
+
 use crate::cli::SchemaArgs;
+#[cfg(feature = "schema")]
+use crate::cli::{SchemaCommand, SchemaFormat};
 use crate::error::Result;
 #[cfg(feature = "schema")]
 use crate::{
     build_runner::{self, BuildTarget},
+    commands::schema_changelog,
     data_driver_wasm::DataDriverWasm,
-    project::{detect, metadata},
+    error::CliError,
+    project::{
+        deps, detect,
+        metadata::{self, ProjectMetadata},
+    },
     toolchain, ui,
 };
 
 #[cfg(feature = "schema")]
 pub fn run(args: SchemaArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
+    if let Some(SchemaCommand::Changelog(changelog)) = args.command {
+        return schema_changelog::run(changelog);
+    }
+
+    let project = metadata::load(&args.project)?;
     detect::ensure_forge_project(&project.project_dir)?;
 
     toolchain::ensure_build(&project.project_dir, false)?;
 
-    ui::status("Building data-driver WASM");
-    let wasm_path = build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
-    let optimized =
-        build_runner::wasm_opt::optimize_if_available(&wasm_path, args.project.verbose)?;
-    if !optimized {
-        ui::warn("wasm-opt not found, skipping optimization");
+    let mut parsed = build_schema(&project, args.project.verbose)?;
+
+    if args.resolve_deps {
+        ui::status("Resolving import crates with cargo metadata");
+        let dependencies = deps::load(&project.manifest_path)?;
+        let unresolved = deps::version_imports(&mut parsed, &dependencies);
+        if !unresolved.is_empty() {
+            return Err(CliError::Config(format!(
+                "schema imports start with a crate that is not a dependency of {}: {}",
+                project.crate_name,
+                unresolved.join(", ")
+            )));
+        }
+    }
+
+    if args.events_only {
+        let topics = topic_table(&parsed);
+        println!("{}", render_topic_table(&topics, args.format, args.pretty)?);
+        return Ok(());
     }
+    if args.format == SchemaFormat::Csv {
+        return Err(CliError::InvalidInput(
+            "`--format csv` is only available with `--events-only`".to_string(),
+        ));
+    }
+
+    let selected = if args.index_plan {
+        index_plan(&parsed)
+    } else {
+        select(&parsed, args.function.as_deref(), args.event.as_deref())?
+    };
+    println!("{}", render(&selected, args.format, args.pretty)?);
+
+    Ok(())
+}
+
+/// Build the project's data-driver and read the schema it embeds.
+#[cfg(feature = "schema")]
+pub fn build_schema(project: &ProjectMetadata, verbose: bool) -> Result<Value> {
+    ui::status("Building data-driver WASM");
+    let wasm_path = build_runner::build(project, BuildTarget::DataDriver, verbose)?;
+    build_runner::wasm_opt::optimize(project, &wasm_path, verbose)?;
 
     let mut driver = DataDriverWasm::load(&wasm_path)?;
-    let schema_json = driver.get_schema_json()?;
-    let parsed: serde_json::Value = serde_json::from_str(&schema_json)?;
+    Ok(serde_json::from_str(&driver.get_schema_json()?)?)
+}
+
+/// Narrow the schema to a single function or event entry, if requested.
+#[cfg(feature = "schema")]
+fn select(schema: &Value, function: Option<&str>, event: Option<&str>) -> Result<Value> {
+    if let Some(name) = function {
+        return find_entry(schema, "functions", "function", name, &["name"]);
+    }
+    if let Some(topic) = event {
+        return find_entry(
+            schema,
+            "events",
+            "event",
+            topic,
+            &["topic", "value", "data"],
+        );
+    }
+    Ok(schema.clone())
+}
 
-    if args.pretty {
-        println!("{}", serde_json::to_string_pretty(&parsed)?);
+/// The events with indexed fields, under the topic string they are emitted
+/// with, and the fields to index.
+#[cfg(feature = "schema")]
+fn index_plan(schema: &Value) -> Value {
+    let events: Vec<_> = events(schema)
+        .filter(|e| e["indexed"].as_array().is_some_and(|f| !f.is_empty()))
+        .map(
+            |e| serde_json::json!({ "topic": topic(e), "data": e["data"], "fields": e["indexed"] }),
+        )
+        .collect();
+    serde_json::json!({ "contract": schema["name"], "events": events })
+}
+
+/// Columns of the topic table, in the order CSV and text tables print them.
+#[cfg(feature = "schema")]
+const TOPIC_COLUMNS: [&str; 6] = ["topic", "data", "version", "genesis", "indexed", "units"];
+
+/// Every event under the topic string it is emitted with, its data type and
+/// version, and the fields indexers and wallets need: the indexed ones and
+/// those with a display unit.
+#[cfg(feature = "schema")]
+fn topic_table(schema: &Value) -> Value {
+    let events: Vec<_> = events(schema)
+        .map(|e| {
+            let units: serde_json::Map<_, _> = e["units"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|u| Some((u["field"].as_str()?.to_string(), u["unit"].clone())))
+                .collect();
+            serde_json::json!({
+                "topic": topic(e),
+                "data": e["data"],
+                "version": e["version"],
+                "genesis": e["genesis"],
+                "indexed": e["indexed"],
+                "units": units,
+            })
+        })
+        .collect();
+    serde_json::json!({ "contract": schema["name"], "events": events })
+}
+
+#[cfg(feature = "schema")]
+fn events(schema: &Value) -> impl Iterator<Item = &Value> {
+    schema["events"].as_array().into_iter().flatten()
+}
+
+/// The topic string an event is emitted with, or the topic as written when
+/// the macro could not resolve it.
+#[cfg(feature = "schema")]
+fn topic(event: &Value) -> &Value {
+    if event["value"].is_null() {
+        &event["topic"]
     } else {
-        println!("{}", serde_json::to_string(&parsed)?);
+        &event["value"]
     }
+}
 
-    Ok(())
+#[cfg(feature = "schema")]
+fn find_entry(
+    schema: &Value,
+    section: &str,
+    kind: &str,
+    needle: &str,
+    keys: &[&str],
+) -> Result<Value> {
+    let entries = schema[section].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    entries
+        .iter()
+        .find(|entry| keys.iter().any(|key| entry[*key].as_str() == Some(needle)))
+        .cloned()
+        .ok_or_else(|| {
+            let available: Vec<_> = entries
+                .iter()
+                .filter_map(|entry| entry[keys[0]].as_str())
+                .collect();
+            CliError::InvalidInput(format!(
+                "{kind} '{needle}' not found in schema (available: {})",
+                available.join(", ")
+            ))
+        })
+}
+
+#[cfg(feature = "schema")]
+fn render(value: &Value, format: SchemaFormat, pretty: bool) -> Result<String> {
+    match format {
+        SchemaFormat::Json if pretty => Ok(serde_json::to_string_pretty(value)?),
+        SchemaFormat::Json => Ok(serde_json::to_string(value)?),
+        SchemaFormat::Toml => toml::to_string_pretty(&without_nulls(value.clone()))
+            .map(|toml| toml.trim_end().to_string())
+            .map_err(|err| CliError::Message(format!("cannot encode schema as TOML: {err}"))),
+        SchemaFormat::Table => Ok(render_table(value)),
+        SchemaFormat::Csv => Err(CliError::InvalidInput(
+            "`--format csv` is only available with `--events-only`".to_string(),
+        )),
+    }
+}
+
+/// Render the topic table, as CSV or a text table with one row per event.
+#[cfg(feature = "schema")]
+fn render_topic_table(topics: &Value, format: SchemaFormat, pretty: bool) -> Result<String> {
+    let rows: Vec<Vec<String>> = events(topics)
+        .map(|e| TOPIC_COLUMNS.map(|key| list_cell(&e[key])).to_vec())
+        .collect();
+    match format {
+        SchemaFormat::Csv => Ok(std::iter::once(TOPIC_COLUMNS.map(String::from).to_vec())
+            .chain(rows)
+            .map(|row| {
+                row.iter()
+                    .map(|c| csv_field(c))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        SchemaFormat::Table => Ok(format!(
+            "contract: {}\n\n{}",
+            cell(&topics["contract"]),
+            table(&TOPIC_COLUMNS, &rows)
+        )),
+        SchemaFormat::Json | SchemaFormat::Toml => render(topics, format, pretty),
+    }
+}
+
+/// A cell listing arrays as `a;b` and objects as `key=value;...`, so lists
+/// fit in one CSV field.
+#[cfg(feature = "schema")]
+fn list_cell(value: &Value) -> String {
+    match value {
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join(";"),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, v)| format!("{key}={}", cell(v)))
+            .collect::<Vec<_>>()
+            .join(";"),
+        other => cell(other),
+    }
+}
+
+/// Quote a CSV field when it holds a separator, quote or line break.
+#[cfg(feature = "schema")]
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// TOML has no null, so drop null object fields (e.g. `feeds` of functions
+/// that return directly).
+#[cfg(feature = "schema")]
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, without_nulls(v)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+        other => other,
+    }
+}
+
+#[cfg(feature = "schema")]
+fn render_table(value: &Value) -> String {
+    let Some(functions) = value["functions"].as_array() else {
+        // A single entry: one row per field.
+        let rows: Vec<_> = value
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(key, v)| vec![key.clone(), cell(v)])
+            .collect();
+        return table(&["field", "value"], &rows);
+    };
+
+    let function_rows: Vec<_> = functions
+        .iter()
+        .map(|f| {
+            ["name", "input", "output", "feeds"]
+                .map(|key| cell(&f[key]))
+                .to_vec()
+        })
+        .collect();
+    let event_rows: Vec<_> = value["events"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|e| ["topic", "value", "data"].map(|key| cell(&e[key])).to_vec())
+        .collect();
+
+    format!(
+        "contract: {}\n\nfunctions:\n{}\n\nevents:\n{}",
+        cell(&value["name"]),
+        table(&["name", "input", "output", "feeds"], &function_rows),
+        table(&["topic", "value", "data"], &event_rows)
+    )
+}
+
+#[cfg(feature = "schema")]
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Left-aligned columns separated by two spaces.
+#[cfg(feature = "schema")]
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let header: Vec<String> = headers.iter().map(ToString::to_string).collect();
+    std::iter::once(&header)
+        .chain(rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[cfg(not(feature = "schema"))]
 pub fn run(_args: SchemaArgs) -> Result<()> {
-    Err(crate::error::CliError::Message(
-        "schema command is disabled (build with --features schema)".to_string(),
-    ))
+    Err(crate::error::CliError::Disabled { command: "schema" })
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema() -> Value {
+        json!({
+            "name": "Counter",
+            "imports": [],
+            "functions": [
+                { "name": "counter", "doc": "", "input": "()", "output": "u64", "feeds": null },
+                { "name": "items", "doc": "", "input": "()", "output": "()", "feeds": "Item" },
+            ],
+            "events": [
+                { "topic": "events::Updated::TOPIC", "value": "updated", "data": "events::Updated", "indexed": [] },
+                { "topic": "events::Moved::TOPIC", "value": "moved", "data": "events::Moved", "indexed": ["to"] },
+            ],
+        })
+    }
+
+    #[test]
+    fn selects_function_and_event_entries() {
+        let schema = schema();
+
+        let function = select(&schema, Some("items"), None).expect("function exists");
+        assert_eq!(function["feeds"], "Item");
+
+        let by_data = select(&schema, None, Some("events::Updated")).expect("event exists");
+        let by_topic = select(&schema, None, Some("events::Updated::TOPIC")).expect("event exists");
+        let by_value = select(&schema, None, Some("updated")).expect("event exists");
+        assert_eq!(by_data, by_topic);
+        assert_eq!(by_data, by_value);
+
+        let err = select(&schema, Some("missing"), None).expect_err("unknown function");
+        assert!(
+            err.to_string().contains("available: counter, items"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn plans_indexed_events() {
+        assert_eq!(
+            index_plan(&schema()),
+            json!({
+                "contract": "Counter",
+                "events": [{ "topic": "moved", "data": "events::Moved", "fields": ["to"] }],
+            })
+        );
+    }
+
+    #[test]
+    fn builds_topic_table() {
+        let mut schema = schema();
+        schema["events"][1]["units"] = json!([{ "field": "fee", "unit": "lux" }]);
+
+        let topics = topic_table(&schema);
+        assert_eq!(topics["events"][0]["topic"], "updated");
+        assert_eq!(topics["events"][1]["units"], json!({ "fee": "lux" }));
+
+        let csv = render_topic_table(&topics, SchemaFormat::Csv, false).expect("CSV renders");
+        assert_eq!(
+            csv,
+            "topic,data,version,genesis,indexed,units\n\
+             updated,events::Updated,-,-,,\n\
+             moved,events::Moved,-,-,to,fee=lux"
+        );
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn renders_toml_without_nulls() {
+        let function = select(&schema(), Some("counter"), None).expect("function exists");
+        let toml = render(&function, SchemaFormat::Toml, false).expect("TOML renders");
+
+        assert!(toml.contains("name = \"counter\""), "{toml}");
+        assert!(!toml.contains("feeds"), "{toml}");
+    }
+
+    #[test]
+    fn renders_tables() {
+        let output = render(&schema(), SchemaFormat::Table, false).expect("table renders");
+
+        assert!(output.starts_with("contract: Counter"), "{output}");
+        assert!(output.contains("counter  ()     u64     -"), "{output}");
+        assert!(
+            output.contains("events::Updated::TOPIC  updated  events::Updated"),
+            "{output}"
+        );
+    }
 }
@@ -0,0 +1,227 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+
+use crate::cli::{ProjectOptions, SchemaChangelogArgs};
+use crate::commands::schema::build_schema;
+use crate::error::{CliError, Result};
+use crate::project::{detect, metadata};
+use crate::{toolchain, ui};
+
+pub fn run(args: SchemaChangelogArgs) -> Result<()> {
+    let project = metadata::load(&args.project)?;
+    detect::ensure_forge_project(&project.project_dir)?;
+
+    toolchain::ensure_build(&project.project_dir, false)?;
+
+    let current = build_schema(&project, args.project.verbose)?;
+    let previous = match &args.schema_file {
+        Some(path) => committed_schema(&project.project_dir, &args.since, path)?,
+        None => {
+            ui::status(format!("Rebuilding the project at {}", args.since));
+            let target_dir = project
+                .data_driver_target_dir
+                .with_file_name("schema-changelog");
+            rebuilt_schema(&args.project, &project.project_dir, &args.since, target_dir)?
+        }
+    };
+
+    println!("{}", changelog(&previous, &current, &args.since));
+    Ok(())
+}
+
+/// Read a schema file as committed at `rev`.
+fn committed_schema(project_dir: &Path, rev: &str, path: &Path) -> Result<Value> {
+    // `./` makes the path relative to the project rather than the repository.
+    let spec = format!("{rev}:./{}", path.display());
+    let json = git(project_dir, &["show", &spec])?;
+    serde_json::from_str(&json).map_err(|err| {
+        CliError::InvalidInput(format!(
+            "{} at {rev} is not a schema: {err}",
+            path.display()
+        ))
+    })
+}
+
+/// Check `rev` out into a temporary worktree and build its schema there,
+/// with separate target directories so the current artifacts are kept.
+fn rebuilt_schema(
+    options: &ProjectOptions,
+    project_dir: &Path,
+    rev: &str,
+    target_dir: PathBuf,
+) -> Result<Value> {
+    let prefix = git(project_dir, &["rev-parse", "--show-prefix"])?;
+    let worktree =
+        std::env::temp_dir().join(format!("dusk-forge-changelog-{}", std::process::id()));
+    let worktree_arg = worktree.display().to_string();
+    git(
+        project_dir,
+        &["worktree", "add", "--detach", "--quiet", &worktree_arg, rev],
+    )?;
+
+    let schema = (|| {
+        let options = ProjectOptions {
+            path: worktree.join(prefix.trim()),
+            profile: options.profile.clone(),
+            target_dir: Some(target_dir),
+            verbose: options.verbose,
+        };
+        let project = metadata::load(&options)?;
+        detect::ensure_forge_project(&project.project_dir)?;
+        build_schema(&project, options.verbose)
+    })();
+
+    if let Err(err) = git(
+        project_dir,
+        &["worktree", "remove", "--force", &worktree_arg],
+    ) {
+        ui::warn(format!("cannot remove worktree {worktree_arg}: {err}"));
+    }
+    schema
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    if !output.status.success() {
+        return Err(CliError::Git {
+            args: args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// A Markdown section listing the functions, events and state fields added,
+/// removed or changed between two schemas.
+pub fn changelog(old: &Value, new: &Value, since: &str) -> String {
+    let sections = [
+        (
+            "Functions",
+            diff(old, new, "functions", "name", &["input", "output", "feeds"]),
+        ),
+        ("Events", diff(old, new, "events", "topic", &["data"])),
+        ("State", diff(old, new, "state", "name", &["type"])),
+    ];
+
+    let mut out = format!("## ABI changes since {since}\n");
+    if sections.iter().all(|(_, lines)| lines.is_empty()) {
+        out.push_str("\nNo ABI changes.\n");
+    }
+    for (title, lines) in sections.iter().filter(|(_, lines)| !lines.is_empty()) {
+        let _ = write!(out, "\n### {title}\n\n");
+        for line in lines {
+            let _ = writeln!(out, "- {line}");
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// Changelog lines for the entries of `section`, matched by `key` and
+/// compared on `fields`.
+fn diff(old: &Value, new: &Value, section: &str, key: &str, fields: &[&str]) -> Vec<String> {
+    let entries = |schema: &Value| schema[section].as_array().cloned().unwrap_or_default();
+    let (old, new) = (entries(old), entries(new));
+    let find = |entries: &[Value], name: &Value| entries.iter().find(|e| e[key] == *name).cloned();
+
+    let mut lines = Vec::new();
+    for entry in &new {
+        match find(&old, &entry[key]) {
+            None => lines.push(format!("Added {}", describe(entry, key, fields))),
+            Some(prev) => {
+                let changes: Vec<_> = fields
+                    .iter()
+                    .filter(|field| prev[**field] != entry[**field])
+                    .map(|field| {
+                        format!(
+                            "{field} `{}` → `{}`",
+                            text(&prev[*field]),
+                            text(&entry[*field])
+                        )
+                    })
+                    .collect();
+                if !changes.is_empty() {
+                    lines.push(format!(
+                        "Changed `{}`: {}",
+                        text(&entry[key]),
+                        changes.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+    for entry in &old {
+        if find(&new, &entry[key]).is_none() {
+            lines.push(format!("Removed {}", describe(entry, key, fields)));
+        }
+    }
+    lines
+}
+
+fn describe(entry: &Value, key: &str, fields: &[&str]) -> String {
+    let details: Vec<_> = fields
+        .iter()
+        .filter(|field| !entry[**field].is_null())
+        .map(|field| format!("{field} `{}`", text(&entry[*field])))
+        .collect();
+    format!("`{}` ({})", text(&entry[key]), details.join(", "))
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema(functions: Value, events: Value) -> Value {
+        json!({ "name": "Counter", "functions": functions, "events": events })
+    }
+
+    #[test]
+    fn lists_added_removed_and_changed_entries() {
+        let old = schema(
+            json!([
+                { "name": "counter", "input": "()", "output": "u64", "feeds": null },
+                { "name": "reset", "input": "()", "output": "()", "feeds": null },
+            ]),
+            json!([{ "topic": "updated", "data": "u64" }]),
+        );
+        let new = schema(
+            json!([
+                { "name": "counter", "input": "()", "output": "u128", "feeds": null },
+                { "name": "add", "input": "u64", "output": "()", "feeds": null },
+            ]),
+            json!([{ "topic": "updated", "data": "u64" }]),
+        );
+
+        let changelog = changelog(&old, &new, "v0.1.0");
+        assert_eq!(
+            changelog,
+            "## ABI changes since v0.1.0\n\n\
+             ### Functions\n\n\
+             - Changed `counter`: output `u64` → `u128`\n\
+             - Added `add` (input `u64`, output `()`)\n\
+             - Removed `reset` (input `()`, output `()`)"
+        );
+    }
+
+    #[test]
+    fn reports_no_changes() {
+        let schema = schema(json!([]), json!([{ "topic": "updated", "data": "u64" }]));
+
+        assert_eq!(
+            changelog(&schema, &schema, "HEAD~1"),
+            "## ABI changes since HEAD~1\n\nNo ABI changes."
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use cargo_metadata::{MetadataCommand, Package, TargetKind};
+
+use crate::build_runner::{self, BuildTarget, artifacts, registry};
+use crate::cli::ScriptArgs;
+use crate::error::{CliError, Result};
+use crate::project::config::Network;
+use crate::project::metadata::ProjectMetadata;
+use crate::project::{detect, metadata};
+use crate::{toolchain, ui};
+
+/// Directory of the project's scripts.
+const SCRIPTS_DIR: &str = "scripts";
+
+/// Environment variable holding the name of the selected network.
+const NETWORK_ENV: &str = "DUSK_FORGE_NETWORK";
+
+/// Environment variable holding the RPC URL of the selected network.
+const RPC_ENV: &str = "DUSK_FORGE_RPC";
+
+/// Environment variable holding the chain ID of the selected network.
+const CHAIN_ID_ENV: &str = "DUSK_FORGE_CHAIN_ID";
+
+/// Environment variable holding the gas limit of the selected network.
+const GAS_LIMIT_ENV: &str = "DUSK_FORGE_GAS_LIMIT";
+
+/// Environment variable holding the gas price of the selected network.
+const GAS_PRICE_ENV: &str = "DUSK_FORGE_GAS_PRICE";
+
+/// Environment variable holding the path of the selected key file.
+const KEY_ENV: &str = "DUSK_FORGE_KEY";
+
+/// Cargo target kind a script is declared as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScriptKind {
+    Example,
+    Bin,
+}
+
+pub fn run(args: ScriptArgs) -> Result<()> {
+    let project = metadata::load(&args.project)?;
+    detect::ensure_forge_project(&project.project_dir)?;
+
+    let network = match &args.network {
+        Some(name) => Some(project.config.network(name)?),
+        None => None,
+    };
+    let key = args.key.as_deref().map(|key| key_path(&project, key));
+    if let Some(key) = &key
+        && !key.is_file()
+    {
+        return Err(CliError::Config(format!(
+            "key file not found: {}",
+            key.display()
+        )));
+    }
+
+    let (target, kind) = find_script(&project, &args.name)?;
+
+    toolchain::ensure_build(&project.project_dir, true)?;
+
+    let mut built = Vec::new();
+    for target in [BuildTarget::Contract, BuildTarget::DataDriver] {
+        ui::status(format!(
+            "Building {} WASM ({})",
+            target.label(),
+            project.crate_name
+        ));
+        let wasm_path = build_runner::build(&project, target, args.project.verbose)?;
+        build_runner::wasm_opt::optimize(&project, &wasm_path, args.project.verbose)?;
+        built.push((target, wasm_path));
+    }
+    let recorded: Vec<_> = built
+        .iter()
+        .map(|(target, path)| (target.label(), path.as_path()))
+        .collect();
+    let manifest = artifacts::record(&project, &recorded)?;
+
+    ui::status(format!("Running script '{}'", args.name));
+    if let Some(network) = &network {
+        ui::status(format!("Target network: {network}"));
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg(toolchain::cargo_toolchain_arg(&project.project_dir)?)
+        .arg("run")
+        .arg("--release")
+        .arg("--locked")
+        .arg("--manifest-path")
+        .arg(&project.manifest_path)
+        .arg(match kind {
+            ScriptKind::Example => "--example",
+            ScriptKind::Bin => "--bin",
+        })
+        .arg(&target)
+        .current_dir(&project.project_dir)
+        .envs(built.iter().map(|(target, path)| (target.wasm_env(), path)))
+        .env(artifacts::MANIFEST_ENV, &manifest)
+        .env(registry::REGISTRY_ENV, registry::path(&project))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .stdin(Stdio::inherit());
+
+    if let Some(network) = &network {
+        cmd.envs(network_env(network));
+    }
+    if let Some(key) = &key {
+        cmd.env(KEY_ENV, key);
+    }
+    if !args.script_args.is_empty() {
+        cmd.arg("--").args(&args.script_args);
+    }
+    build_runner::apply_local_forge_overrides(&mut cmd, args.project.verbose);
+
+    if args.project.verbose {
+        eprintln!("Running: {}", ui::format_command(&cmd));
+    }
+
+    let status = cmd.status()?;
+    if !status.success() {
+        return Err(CliError::PassThrough {
+            program: format!("script {}", args.name),
+            code: status.code().unwrap_or(1),
+        });
+    }
+
+    ui::success(format!("Script '{}' completed", args.name));
+    Ok(())
+}
+
+/// The key file named `key`: a `Forge.toml` key name or a path, relative to
+/// the project directory.
+fn key_path(project: &ProjectMetadata, key: &str) -> PathBuf {
+    let path = project
+        .config
+        .keys
+        .get(key)
+        .map_or_else(|| PathBuf::from(key), Clone::clone);
+    project.project_dir.join(path)
+}
+
+/// The cargo target compiled from `scripts/<name>.rs`, declared as an
+/// `[[example]]` or a `[[bin]]` of the project.
+fn find_script(project: &ProjectMetadata, name: &str) -> Result<(String, ScriptKind)> {
+    let scripts_dir = project.project_dir.join(SCRIPTS_DIR);
+    let source = scripts_dir.join(format!("{name}.rs"));
+    if !source.is_file() {
+        let available = list_scripts(&scripts_dir)?;
+        return Err(CliError::Config(format!(
+            "no script '{name}' in {} (available: {})",
+            scripts_dir.display(),
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        )));
+    }
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(&project.manifest_path)
+        .no_deps()
+        .exec()?;
+    let package = metadata
+        .packages
+        .iter()
+        .find(|package| package.manifest_path.as_std_path() == project.manifest_path)
+        .ok_or_else(|| {
+            CliError::Config(format!(
+                "unable to resolve package metadata for {}",
+                project.manifest_path.display()
+            ))
+        })?;
+
+    script_target(package, &fs::canonicalize(&source)?).ok_or_else(|| {
+        CliError::Config(format!(
+            "{} is not a target of {}; declare it with\n\n\
+             [[example]]\nname = \"{name}\"\npath = \"{SCRIPTS_DIR}/{name}.rs\"",
+            source.display(),
+            project.manifest_path.display()
+        ))
+    })
+}
+
+/// The example or binary target of `package` built from `source`.
+fn script_target(package: &Package, source: &Path) -> Option<(String, ScriptKind)> {
+    package.targets.iter().find_map(|target| {
+        if target.src_path.as_std_path() != source {
+            return None;
+        }
+        if target.kind.contains(&TargetKind::Example) {
+            Some((target.name.clone(), ScriptKind::Example))
+        } else if target.kind.contains(&TargetKind::Bin) {
+            Some((target.name.clone(), ScriptKind::Bin))
+        } else {
+            None
+        }
+    })
+}
+
+/// Names of the scripts in `dir`, sorted.
+fn list_scripts(dir: &Path) -> Result<Vec<String>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "rs")
+            && let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// The environment describing `network` to a script.
+fn network_env(network: &Network) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        (NETWORK_ENV, network.name.clone()),
+        (RPC_ENV, network.rpc.clone()),
+    ];
+    if let Some(chain_id) = network.chain_id {
+        env.push((CHAIN_ID_ENV, chain_id.to_string()));
+    }
+    if let Some(limit) = network.gas_limit {
+        env.push((GAS_LIMIT_ENV, limit.to_string()));
+    }
+    if let Some(price) = network.gas_price {
+        env.push((GAS_PRICE_ENV, price.to_string()));
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_env_skips_unset_settings() {
+        let network = Network {
+            name: "testnet".to_string(),
+            rpc: "https://testnet.nodes.dusk.network".to_string(),
+            chain_id: Some(2),
+            gas_limit: None,
+            gas_price: Some(1),
+            abi_version: None,
+        };
+
+        assert_eq!(
+            network_env(&network),
+            [
+                (NETWORK_ENV, "testnet".to_string()),
+                (RPC_ENV, "https://testnet.nodes.dusk.network".to_string()),
+                (CHAIN_ID_ENV, "2".to_string()),
+                (GAS_PRICE_ENV, "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_rust_scripts_sorted() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for file in ["transfer_ownership.rs", "deploy.rs", "README.md"] {
+            fs::write(dir.path().join(file), "").expect("write script");
+        }
+
+        let names = list_scripts(dir.path()).expect("list scripts");
+        assert_eq!(names, ["deploy", "transfer_ownership"]);
+
+        let missing = dir.path().join("missing");
+        assert!(list_scripts(&missing).expect("missing dir").is_empty());
+    }
+}
@@ -1,24 +1,64 @@
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
-use crate::build_runner::{self, BuildTarget};
+use crate::build_runner::{self, BuildTarget, artifacts, registry};
 use crate::cli::TestArgs;
+use crate::commands::test_report;
+#[cfg(feature = "schema")]
+use crate::data_driver_wasm::DataDriverWasm;
 use crate::error::{CliError, Result};
+use crate::project::metadata::ProjectMetadata;
 use crate::project::{detect, metadata};
 use crate::{toolchain, ui};
 
+/// Contract feature enabling `#[contract(metering)]` host-call counters.
+const METERING_FEATURE: &str = "metering";
+
+/// Contract feature enabling the `#[contract(invariant)]` checks, turned on
+/// for tests whenever the contract declares it.
+const INVARIANTS_FEATURE: &str = "invariants";
+
+/// Prefix of the per-host-call lines tests print in metered runs:
+/// `forge-metering: <host call> <count>`.
+const METERING_MARKER: &str = "forge-metering:";
+
+/// Environment variable holding the path of the encoded `init` arguments
+/// when tests run with `--init-args`.
+const INIT_ARGS_ENV: &str = "DUSK_FORGE_INIT_ARGS";
+
+/// File the encoded `init` arguments are written to, next to the artifact
+/// manifest.
+#[cfg(feature = "schema")]
+const INIT_ARGS_FILE: &str = "forge-init-args.rkyv";
+
+/// Host call -> count, per test.
+type MeteringReport = BTreeMap<String, BTreeMap<String, u64>>;
+
 pub fn run(args: TestArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
+    let project = metadata::load(&args.project)?;
     detect::ensure_forge_project(&project.project_dir)?;
 
     toolchain::ensure_build(&project.project_dir, true)?;
 
+    let manifest = detect::load_manifest(&project.project_dir)?;
+    let features = test_features(&manifest, args.metering);
+
     ui::status("Building contract WASM for tests");
-    let wasm_path = build_runner::build(&project, BuildTarget::Contract, args.project.verbose)?;
-    let optimized =
-        build_runner::wasm_opt::optimize_if_available(&wasm_path, args.project.verbose)?;
-    if !optimized {
-        ui::warn("wasm-opt not found, skipping optimization");
-    }
+    let wasm_path = build_runner::build_with_features(
+        &project,
+        BuildTarget::Contract,
+        &features,
+        args.project.verbose,
+    )?;
+    build_runner::wasm_opt::optimize(&project, &wasm_path, args.project.verbose)?;
+    let manifest = artifacts::record(&project, &[(BuildTarget::Contract.label(), &wasm_path)])?;
+
+    let init_args = match &args.init_args {
+        Some(path) => Some(encode_init_args(&project, path, args.project.verbose)?),
+        None => None,
+    };
 
     ui::status("Running cargo test --release");
     let mut cmd = Command::new("cargo");
@@ -28,25 +68,264 @@ pub fn run(args: TestArgs) -> Result<()> {
         .arg("--locked")
         .arg("--manifest-path")
         .arg(&project.manifest_path)
-        .args(&args.cargo_test_args)
         .current_dir(&project.project_dir)
-        .stdout(Stdio::inherit())
+        .env(BuildTarget::Contract.wasm_env(), &wasm_path)
+        .env(
+            BuildTarget::DataDriver.wasm_env(),
+            &project.data_driver_wasm_path,
+        )
+        .env(artifacts::MANIFEST_ENV, &manifest)
+        .env(registry::REGISTRY_ENV, registry::path(&project))
         .stderr(Stdio::inherit())
         .stdin(Stdio::inherit());
+
+    if let Some(init_args) = &init_args {
+        cmd.env(INIT_ARGS_ENV, init_args);
+    }
+
+    // Tests see the features the contract was built with, to skip checks
+    // that only hold for one build
+    if !features.is_empty() {
+        cmd.arg("--features").arg(features.join(","));
+    }
+    cmd.args(&args.cargo_test_args);
+
+    // Arguments for the test binaries. Metered runs go one test at a time
+    // with their output shown, so every counter line follows the name of the
+    // test that printed it
+    let mut libtest_args = Vec::new();
+    if args.metering {
+        libtest_args.push("--test-threads=1");
+    }
+    if args.metering || args.no_capture {
+        libtest_args.push("--nocapture");
+    }
+    if !libtest_args.is_empty() {
+        if !args.cargo_test_args.iter().any(|arg| arg == "--") {
+            cmd.arg("--");
+        }
+        cmd.args(libtest_args);
+    }
+
+    let capture = args.metering || args.report.is_some();
+    cmd.stdout(if capture {
+        Stdio::piped()
+    } else {
+        Stdio::inherit()
+    });
     build_runner::apply_local_forge_overrides(&mut cmd, args.project.verbose);
 
     if args.project.verbose {
         eprintln!("Running: {}", ui::format_command(&cmd));
     }
 
-    let status = cmd.status()?;
+    let mut child = cmd.spawn()?;
+    let mut lines = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            let line = line?;
+            println!("{line}");
+            lines.push(line);
+        }
+    }
+    let status = child.wait()?;
+
+    // Write the report whatever the outcome, failures are what CI wants
+    if let Some(format) = args.report {
+        let results = test_report::parse(lines.iter().map(String::as_str));
+        let path = args.report_path.clone().unwrap_or_else(|| {
+            project
+                .artifact_manifest_path
+                .with_file_name(test_report::file_name(format))
+        });
+        std::fs::write(
+            &path,
+            test_report::render(format, &project.crate_name, &results)?,
+        )?;
+        ui::status(format!(
+            "Wrote {} report for {} test(s) to {}",
+            format.name(),
+            results.len(),
+            path.display()
+        ));
+    }
+
     if !status.success() {
-        return Err(CliError::CommandFailed {
+        return Err(CliError::PassThrough {
             program: "cargo test".to_string(),
             code: status.code().unwrap_or(1),
         });
     }
 
+    if args.metering {
+        print_metering(&parse_metering(lines.iter().map(String::as_str)));
+    }
+
     ui::success("Tests completed");
     Ok(())
 }
+
+/// Extra contract features of a test build: `metering` when asked for, and
+/// `invariants` whenever the manifest declares it.
+fn test_features(manifest: &toml::Value, metering: bool) -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if metering {
+        features.push(METERING_FEATURE);
+    }
+    if detect::has_feature(manifest, INVARIANTS_FEATURE) {
+        features.push(INVARIANTS_FEATURE);
+    }
+    features
+}
+
+/// Encode the `init` arguments in the JSON file at `path` through the
+/// data-driver, and write them next to the artifact manifest for the tests
+/// to deploy with.
+#[cfg(feature = "schema")]
+fn encode_init_args(project: &ProjectMetadata, path: &Path, verbose: bool) -> Result<PathBuf> {
+    let json = std::fs::read_to_string(path).map_err(|e| {
+        CliError::InvalidInput(format!(
+            "cannot read init arguments {}: {e}",
+            path.display()
+        ))
+    })?;
+    serde_json::from_str::<serde_json::Value>(&json)
+        .map_err(|e| CliError::InvalidInput(format!("invalid JSON in {}: {e}", path.display())))?;
+
+    ui::status("Building data-driver WASM to encode init arguments");
+    let wasm_path = build_runner::build(project, BuildTarget::DataDriver, verbose)?;
+    build_runner::wasm_opt::optimize(project, &wasm_path, verbose)?;
+    let encoded = DataDriverWasm::load(&wasm_path)?.encode_input("init", &json)?;
+
+    let encoded_path = project
+        .artifact_manifest_path
+        .with_file_name(INIT_ARGS_FILE);
+    std::fs::write(&encoded_path, &encoded)?;
+    if verbose {
+        ui::status(format!(
+            "Encoded {} bytes of init arguments to {}",
+            encoded.len(),
+            encoded_path.display()
+        ));
+    }
+    Ok(encoded_path)
+}
+
+#[cfg(not(feature = "schema"))]
+fn encode_init_args(_project: &ProjectMetadata, _path: &Path, _verbose: bool) -> Result<PathBuf> {
+    Err(CliError::Disabled {
+        command: "test --init-args",
+    })
+}
+
+/// Collect `forge-metering:` lines from libtest output, attributing each to
+/// the test that was announced last.
+fn parse_metering<'a>(lines: impl IntoIterator<Item = &'a str>) -> MeteringReport {
+    let mut report = MeteringReport::new();
+    let mut current = None;
+
+    for line in lines {
+        // `test <name> ... ` is printed before the test's own output, which
+        // may continue on the same line
+        if let Some(rest) = line.strip_prefix("test ")
+            && let Some((name, _)) = rest.split_once(" ...")
+        {
+            current = Some(name.to_string());
+        }
+
+        let Some((_, counter)) = line.split_once(METERING_MARKER) else {
+            continue;
+        };
+        let mut parts = counter.split_whitespace();
+        let (Some(test), Some(host_call), Some(Ok(count))) = (
+            current.as_ref(),
+            parts.next(),
+            parts.next().map(str::parse::<u64>),
+        ) else {
+            continue;
+        };
+
+        *report
+            .entry(test.clone())
+            .or_default()
+            .entry(host_call.to_string())
+            .or_default() += count;
+    }
+
+    report
+}
+
+fn print_metering(report: &MeteringReport) {
+    if report.is_empty() {
+        ui::warn(format!(
+            "no `{METERING_MARKER}` lines found; print the counts returned by the \
+             contract's `__forge_host_calls` export from your tests"
+        ));
+        return;
+    }
+
+    ui::status("Host calls per test");
+    let width = report.keys().map(String::len).max().unwrap_or(0);
+    for (test, counts) in report {
+        let total: u64 = counts.values().sum();
+        let counts = counts
+            .iter()
+            .map(|(host_call, count)| format!("{host_call}={count}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!("  {test:<width$}  {total:>6}  {counts}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_metering_lines_per_test() {
+        let output = "\
+running 3 tests
+test tests::set_counter ... forge-metering: emit 1
+forge-metering: caller 2
+ok
+test tests::no_host_calls ... ok
+test tests::repeated ... forge-metering: emit 1
+forge-metering: emit 3
+ok
+
+test result: ok. 3 passed; 0 failed";
+
+        let report = parse_metering(output.lines());
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report["tests::set_counter"]["emit"], 1);
+        assert_eq!(report["tests::set_counter"]["caller"], 2);
+        assert_eq!(report["tests::repeated"]["emit"], 4);
+    }
+
+    #[test]
+    fn ignores_malformed_and_unattributed_lines() {
+        let output = "\
+forge-metering: emit 1
+test tests::a ... forge-metering: emit many
+forge-metering: emit
+ok";
+
+        assert!(parse_metering(output.lines()).is_empty());
+    }
+
+    #[test]
+    fn enables_invariants_when_declared() {
+        let declared: toml::Value = "[features]\ncontract = []\ninvariants = []"
+            .parse()
+            .expect("valid manifest");
+        let undeclared: toml::Value = "[features]\ncontract = []".parse().expect("valid manifest");
+
+        assert_eq!(test_features(&declared, false), [INVARIANTS_FEATURE]);
+        assert_eq!(
+            test_features(&declared, true),
+            [METERING_FEATURE, INVARIANTS_FEATURE]
+        );
+        assert!(test_features(&undeclared, false).is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde_json::json;
+
+use crate::cli::TestReportFormat;
+use crate::error::Result;
+
+/// How a test ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+impl Outcome {
+    fn parse(result: &str) -> Option<Self> {
+        match result.trim() {
+            "ok" => Some(Self::Passed),
+            "FAILED" => Some(Self::Failed),
+            result if result.starts_with("ignored") => Some(Self::Ignored),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Ignored => "ignored",
+        }
+    }
+}
+
+/// The result of one test, with the output libtest printed for it on
+/// failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    pub name: String,
+    pub outcome: Outcome,
+    pub output: String,
+}
+
+/// Default file name of a report, written to the base target directory.
+pub fn file_name(format: TestReportFormat) -> &'static str {
+    match format {
+        TestReportFormat::Junit => "forge-test-report.xml",
+        TestReportFormat::Json => "forge-test-report.json",
+    }
+}
+
+/// Collect the test results from libtest output, in the order the tests
+/// finished.
+///
+/// A test's result usually follows its name on the same line; with output
+/// shown it may come on a later line instead. Failure output is taken from
+/// the `---- <name> stdout ----` sections libtest prints after the run.
+pub fn parse<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<TestResult> {
+    let mut results = Vec::new();
+    let mut pending = None;
+    let mut outputs: BTreeMap<String, String> = BTreeMap::new();
+    let mut section = None;
+
+    for line in lines {
+        if let Some(rest) = line.strip_prefix("test ")
+            && let Some((name, result)) = rest.split_once(" ...")
+        {
+            section = None;
+            match Outcome::parse(result) {
+                Some(outcome) => results.push((name.to_string(), outcome)),
+                None => pending = Some(name.to_string()),
+            }
+            continue;
+        }
+
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        {
+            section = Some(name.to_string());
+            continue;
+        }
+        if line == "failures:" || line.starts_with("test result:") {
+            section = None;
+            continue;
+        }
+        if let Some(name) = &section {
+            let output = outputs.entry(name.clone()).or_default();
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        }
+
+        if let Some(outcome) = Outcome::parse(line)
+            && let Some(name) = pending.take()
+        {
+            results.push((name, outcome));
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|(name, outcome)| TestResult {
+            output: outputs
+                .get(&name)
+                .map_or_else(String::new, |output| output.trim_end().to_string()),
+            name,
+            outcome,
+        })
+        .collect()
+}
+
+/// Render the results of the tests of `crate_name` as a report.
+pub fn render(
+    format: TestReportFormat,
+    crate_name: &str,
+    results: &[TestResult],
+) -> Result<String> {
+    match format {
+        TestReportFormat::Junit => Ok(junit(crate_name, results)),
+        TestReportFormat::Json => {
+            let count = |outcome| results.iter().filter(|r| r.outcome == outcome).count();
+            let tests: Vec<_> = results
+                .iter()
+                .map(|result| {
+                    json!({
+                        "name": result.name,
+                        "outcome": result.outcome.name(),
+                        "output": result.output,
+                    })
+                })
+                .collect();
+            let report = json!({
+                "crate": crate_name,
+                "passed": count(Outcome::Passed),
+                "failed": count(Outcome::Failed),
+                "ignored": count(Outcome::Ignored),
+                "tests": tests,
+            });
+            Ok(serde_json::to_string_pretty(&report)? + "\n")
+        }
+    }
+}
+
+fn junit(crate_name: &str, results: &[TestResult]) -> String {
+    let count = |outcome| results.iter().filter(|r| r.outcome == outcome).count();
+    let (tests, failures, skipped) = (
+        results.len(),
+        count(Outcome::Failed),
+        count(Outcome::Ignored),
+    );
+    let suite = escape(crate_name);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{suite}\" tests=\"{tests}\" failures=\"{failures}\" skipped=\"{skipped}\">"
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{suite}\" tests=\"{tests}\" failures=\"{failures}\" skipped=\"{skipped}\">"
+    );
+    for result in results {
+        // `module::path::test` -> class `module::path`, name `test`
+        let (class, name) = result
+            .name
+            .rsplit_once("::")
+            .unwrap_or((crate_name, &result.name));
+        let _ = write!(
+            xml,
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            escape(class),
+            escape(name)
+        );
+        match result.outcome {
+            Outcome::Passed => xml.push_str("/>\n"),
+            Outcome::Ignored => xml.push_str(">\n      <skipped/>\n    </testcase>\n"),
+            Outcome::Failed => {
+                let _ = writeln!(
+                    xml,
+                    ">\n      <failure message=\"test failed\">{}</failure>\n    </testcase>",
+                    escape(&result.output)
+                );
+            }
+        }
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+running 4 tests
+test tests::deploys ... ok
+test tests::transfer_<all> ... FAILED
+test tests::slow ... ignored, needs a node
+test tests::noisy ... printed while running
+ok
+
+failures:
+
+---- tests::transfer_<all> stdout ----
+thread 'tests::transfer_<all>' panicked at tests/contract.rs:10:5:
+balance mismatch
+
+failures:
+    tests::transfer_<all>
+
+test result: FAILED. 2 passed; 1 failed; 1 ignored";
+
+    #[test]
+    fn parses_libtest_results() {
+        let results = parse(OUTPUT.lines());
+
+        let outcomes: Vec<_> = results
+            .iter()
+            .map(|r| (r.name.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                ("tests::deploys", Outcome::Passed),
+                ("tests::transfer_<all>", Outcome::Failed),
+                ("tests::slow", Outcome::Ignored),
+                ("tests::noisy", Outcome::Passed),
+            ]
+        );
+        assert!(results[0].output.is_empty());
+        assert_eq!(
+            results[1].output,
+            "thread 'tests::transfer_<all>' panicked at tests/contract.rs:10:5:\nbalance mismatch"
+        );
+    }
+
+    #[test]
+    fn renders_junit_and_json_reports() {
+        let results = parse(OUTPUT.lines());
+
+        let xml = render(TestReportFormat::Junit, "my-contract", &results).unwrap();
+        assert!(
+            xml.contains(
+                "<testsuite name=\"my-contract\" tests=\"4\" failures=\"1\" skipped=\"1\">"
+            )
+        );
+        assert!(xml.contains("<testcase classname=\"tests\" name=\"deploys\"/>"));
+        assert!(xml.contains("name=\"transfer_&lt;all&gt;\">"), "{xml}");
+        assert!(xml.contains("panicked at tests/contract.rs:10:5:\nbalance mismatch</failure>"));
+        assert!(xml.contains("<skipped/>"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(TestReportFormat::Json, "my-contract", &results).unwrap())
+                .unwrap();
+        assert_eq!(json["passed"], 2);
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["ignored"], 1);
+        assert_eq!(json["tests"][1]["outcome"], "failed");
+        assert_eq!(json["tests"][1]["name"], "tests::transfer_<all>");
+    }
+}
@@ -1,21 +1,29 @@
 #[cfg(feature = "schema")]
 use std::fs;
+#[cfg(feature = "schema")]
+use std::path::PathBuf;
 
 use crate::cli::VerifyArgs;
 use crate::error::Result;
 #[cfg(feature = "schema")]
 use crate::{
-    build_runner::{self, BuildTarget},
+    attestation::{self, Attestation, Provenance},
+    build_runner::{self, BuildTarget, names},
     data_driver_wasm::DataDriverWasm,
     error::CliError,
-    project::{detect, metadata},
+    project::{config::Network, detect, metadata},
     toolchain, ui,
 };
 
 #[cfg(feature = "schema")]
 pub fn run(args: VerifyArgs) -> Result<()> {
-    let project = metadata::load(&args.project.path)?;
+    let project = metadata::load(&args.project)?;
     detect::ensure_forge_project(&project.project_dir)?;
+    let network = args
+        .network
+        .as_deref()
+        .map(|name| project.config.network(name))
+        .transpose()?;
 
     let contract_wasm = if args.skip_build {
         project.contract_wasm_path.clone()
@@ -23,10 +31,7 @@ pub fn run(args: VerifyArgs) -> Result<()> {
         toolchain::ensure_build(&project.project_dir, true)?;
         ui::status("Building contract WASM for verification");
         let wasm = build_runner::build(&project, BuildTarget::Contract, args.project.verbose)?;
-        let optimized = build_runner::wasm_opt::optimize_if_available(&wasm, args.project.verbose)?;
-        if !optimized {
-            ui::warn("wasm-opt not found, skipping optimization");
-        }
+        build_runner::wasm_opt::optimize(&project, &wasm, args.project.verbose)?;
         wasm
     };
 
@@ -36,25 +41,22 @@ pub fn run(args: VerifyArgs) -> Result<()> {
         toolchain::ensure_build(&project.project_dir, false)?;
         ui::status("Building data-driver WASM for verification");
         let wasm = build_runner::build(&project, BuildTarget::DataDriver, args.project.verbose)?;
-        let optimized = build_runner::wasm_opt::optimize_if_available(&wasm, args.project.verbose)?;
-        if !optimized {
-            ui::warn("wasm-opt not found, skipping optimization");
-        }
+        build_runner::wasm_opt::optimize(&project, &wasm, args.project.verbose)?;
         wasm
     };
 
     if !contract_wasm.exists() {
-        return Err(CliError::Message(format!(
-            "contract WASM not found: {}",
-            contract_wasm.display()
-        )));
+        return Err(CliError::Build {
+            path: contract_wasm,
+            reason: "contract WASM not found".to_string(),
+        });
     }
 
     if !data_driver_wasm.exists() {
-        return Err(CliError::Message(format!(
-            "data-driver WASM not found: {}",
-            data_driver_wasm.display()
-        )));
+        return Err(CliError::Build {
+            path: data_driver_wasm,
+            reason: "data-driver WASM not found".to_string(),
+        });
     }
 
     DataDriverWasm::validate_module(&contract_wasm)?;
@@ -64,12 +66,12 @@ pub fn run(args: VerifyArgs) -> Result<()> {
     ui::success(format!("Valid WASM module: {}", data_driver_wasm.display()));
 
     let contract_bytes = fs::read(&contract_wasm)?;
-    let actual_hash = blake3::hash(&contract_bytes).to_hex().to_string();
+    let actual_hash = names::code_blake3(&contract_bytes);
 
     if let Some(expected) = args.expected_blake3 {
         let expected_normalized = expected.trim_start_matches("0x").to_ascii_lowercase();
         if actual_hash != expected_normalized {
-            return Err(CliError::Message(format!(
+            return Err(CliError::Verification(format!(
                 "BLAKE3 mismatch: expected {expected_normalized}, got {actual_hash}"
             )));
         }
@@ -83,37 +85,292 @@ pub fn run(args: VerifyArgs) -> Result<()> {
     let contract_name = schema
         .get("name")
         .and_then(serde_json::Value::as_str)
-        .ok_or_else(|| CliError::Message("schema is missing 'name'".to_string()))?;
+        .ok_or_else(|| CliError::Verification("schema is missing 'name'".to_string()))?;
 
     let function_count = schema
         .get("functions")
         .and_then(serde_json::Value::as_array)
         .map(std::vec::Vec::len)
-        .ok_or_else(|| CliError::Message("schema is missing 'functions' array".to_string()))?;
+        .ok_or_else(|| CliError::Verification("schema is missing 'functions' array".to_string()))?;
 
     ui::success(format!(
         "Schema loaded for {contract_name} with {function_count} function(s)"
     ));
 
     if function_count == 0 {
-        return Err(CliError::Message(
+        return Err(CliError::Verification(
             "schema contains zero functions".to_string(),
         ));
     }
 
+    if let Some(network) = &network {
+        let abi = schema.get("abi").and_then(serde_json::Value::as_str);
+        check_abi(abi, network)?;
+    }
+
+    let schema_hash = blake3::hash(schema_json.as_bytes()).to_hex().to_string();
+
+    if let (Some(path), Some(signer)) = (&args.artifact, &args.signer) {
+        check_attestation(
+            &project,
+            path,
+            signer,
+            network.as_ref(),
+            &actual_hash,
+            &schema_hash,
+        )?;
+    }
+
     println!("contract_wasm: {}", contract_wasm.display());
     println!("data_driver_wasm: {}", data_driver_wasm.display());
     println!("contract_blake3: {actual_hash}");
     println!("schema_contract: {contract_name}");
     println!("schema_functions: {function_count}");
+    println!("schema_blake3: {schema_hash}");
+
+    if let Some(key) = &args.attest {
+        let path = write_attestation(
+            &project,
+            key,
+            network.as_ref(),
+            &contract_wasm,
+            actual_hash,
+            schema_hash,
+        )?;
+        println!("attestation: {}", path.display());
+    }
 
     ui::success("Verification passed");
     Ok(())
 }
 
+/// Check a provenance attestation against the verified artifact hashes.
+///
+/// The signature is checked against the key recorded in the attestation, so
+/// anyone can produce a valid one; comparing that key with the trusted
+/// `signer` is what authenticates its origin.
+#[cfg(feature = "schema")]
+fn check_attestation(
+    project: &metadata::ProjectMetadata,
+    path: &std::path::Path,
+    signer: &str,
+    network: Option<&Network>,
+    wasm_hash: &str,
+    schema_hash: &str,
+) -> Result<()> {
+    let attestation = Attestation::read(path)?;
+    let provenance = attestation.verify()?;
+
+    let expected = signer.trim_start_matches("0x").to_ascii_lowercase();
+    if attestation.signer != expected {
+        return Err(CliError::Verification(format!(
+            "attestation signed by {}, expected {expected}",
+            attestation.signer
+        )));
+    }
+
+    if provenance.wasm_blake3 != wasm_hash {
+        return Err(CliError::Verification(format!(
+            "attested contract BLAKE3 {} does not match built artifact {wasm_hash}",
+            provenance.wasm_blake3
+        )));
+    }
+    if provenance.schema_blake3 != schema_hash {
+        return Err(CliError::Verification(format!(
+            "attested schema BLAKE3 {} does not match built schema {schema_hash}",
+            provenance.schema_blake3
+        )));
+    }
+
+    if let Some(network) = network {
+        check_network(provenance, network)?;
+    }
+
+    if provenance.dirty {
+        ui::warn("attestation was produced from a working tree with uncommitted changes");
+    }
+
+    let local_toolchain = toolchain::rustc_version(&project.project_dir)?;
+    if provenance.toolchain != local_toolchain {
+        ui::warn(format!(
+            "attestation was built with '{}', local toolchain is '{local_toolchain}'",
+            provenance.toolchain
+        ));
+    }
+
+    ui::success(format!(
+        "Attestation {} signed by {} ({})",
+        path.display(),
+        attestation.signer,
+        provenance.commit.as_deref().unwrap_or("no git commit")
+    ));
+    Ok(())
+}
+
+/// Check that an attestation targets `network`: by chain ID when both are
+/// known, by name otherwise.
+#[cfg(feature = "schema")]
+fn check_network(provenance: &Provenance, network: &Network) -> Result<()> {
+    let Some(attested) = &provenance.network else {
+        ui::warn(format!(
+            "attestation names no network; cannot confirm it targets {}",
+            network.name
+        ));
+        return Ok(());
+    };
+
+    let matches = match (provenance.chain_id, network.chain_id) {
+        (Some(attested_id), Some(id)) => attested_id == id,
+        _ => *attested == network.name,
+    };
+    if !matches {
+        return Err(CliError::Verification(format!(
+            "attestation targets network '{attested}', expected '{}'",
+            network.name
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the `dusk-core` version the contract was compiled against,
+/// recorded as the schema's `abi`, runs on `network`'s nodes.
+#[cfg(feature = "schema")]
+fn check_abi(abi: Option<&str>, network: &Network) -> Result<()> {
+    let Some(supported) = &network.abi_version else {
+        ui::warn(format!(
+            "network {} sets no abi-version; cannot check the contract's host ABI",
+            network.name
+        ));
+        return Ok(());
+    };
+    let Some(abi) = abi else {
+        ui::warn("schema records no ABI version; build with `dusk-forge build` to record it");
+        return Ok(());
+    };
+
+    match abi_compatible(abi, supported) {
+        Some(true) => {
+            ui::success(format!(
+                "Host ABI dusk-core {abi} is supported by {} (dusk-core {supported})",
+                network.name
+            ));
+            Ok(())
+        }
+        Some(false) => Err(CliError::Verification(format!(
+            "contract is compiled against dusk-core {abi}, but {} runs dusk-core {supported}; \
+             build it against a dusk-core version compatible with {supported} before deploying",
+            network.name
+        ))),
+        None => Err(CliError::Verification(format!(
+            "cannot compare ABI versions '{abi}' and '{supported}'"
+        ))),
+    }
+}
+
+/// Whether a contract built against `dusk-core` version `abi` runs on nodes
+/// at version `supported`: both must be semver-compatible, and the contract's
+/// no newer than the node's. `None` if either is not a `x.y.z` version.
+#[cfg(feature = "schema")]
+fn abi_compatible(abi: &str, supported: &str) -> Option<bool> {
+    let abi = parse_version(abi)?;
+    let supported = parse_version(supported)?;
+    let same_series = match (abi, supported) {
+        ((0, minor, _), (0, supported_minor, _)) => minor == supported_minor,
+        ((major, ..), (supported_major, ..)) => major == supported_major,
+    };
+    Some(same_series && abi <= supported)
+}
+
+/// `(major, minor, patch)` of a version, ignoring pre-release and build
+/// suffixes.
+#[cfg(feature = "schema")]
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(str::parse);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Some((major, minor, patch)),
+        _ => None,
+    }
+}
+
+/// Sign the provenance of the verified artifacts with `key` and write it
+/// next to the contract WASM.
+#[cfg(feature = "schema")]
+fn write_attestation(
+    project: &metadata::ProjectMetadata,
+    key: &str,
+    network: Option<&Network>,
+    contract_wasm: &std::path::Path,
+    wasm_blake3: String,
+    schema_blake3: String,
+) -> Result<PathBuf> {
+    let key_path = project
+        .config
+        .keys
+        .get(key)
+        .map_or_else(|| PathBuf::from(key), Clone::clone);
+    let signing_key = attestation::load_key(&project.project_dir.join(key_path))?;
+
+    let (commit, dirty) = build_runner::git_state(&project.project_dir);
+    if dirty {
+        ui::warn("working tree has uncommitted changes; the attestation records it as dirty");
+    }
+
+    let provenance = Provenance {
+        toolchain: toolchain::rustc_version(&project.project_dir)?,
+        commit,
+        dirty,
+        schema_blake3,
+        wasm_blake3,
+        network: network.map(|network| network.name.clone()),
+        chain_id: network.and_then(|network| network.chain_id),
+    };
+
+    let path = attestation::path_for(contract_wasm);
+    Attestation::sign(provenance, &signing_key)?.write(&path)?;
+    ui::success(format!("Wrote attestation {}", path.display()));
+    Ok(path)
+}
+
 #[cfg(not(feature = "schema"))]
 pub fn run(_args: VerifyArgs) -> Result<()> {
-    Err(crate::error::CliError::Message(
-        "verify command is disabled (build with --features schema)".to_string(),
-    ))
+    Err(crate::error::CliError::Disabled { command: "verify" })
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_abi_versions() {
+        assert_eq!(abi_compatible("1.3.0", "1.3.0"), Some(true));
+        assert_eq!(abi_compatible("1.2.4", "1.3.0"), Some(true));
+        assert_eq!(abi_compatible("1.4.0", "1.3.0"), Some(false));
+        assert_eq!(abi_compatible("1.3.0", "2.0.0"), Some(false));
+        assert_eq!(abi_compatible("0.2.1", "0.2.3"), Some(true));
+        assert_eq!(abi_compatible("0.1.0", "0.2.0"), Some(false));
+        assert_eq!(abi_compatible("1.0.0-rc.1", "1.0.0"), Some(true));
+        assert_eq!(abi_compatible("1.0", "1.0.0"), None);
+    }
+
+    #[test]
+    fn refuses_incompatible_network() {
+        let network = Network {
+            name: "testnet".to_string(),
+            rpc: "https://testnet.nodes.dusk.network".to_string(),
+            chain_id: Some(2),
+            gas_limit: None,
+            gas_price: None,
+            abi_version: Some("1.3.0".to_string()),
+        };
+
+        check_abi(Some("1.3.0"), &network).expect("same ABI");
+        check_abi(None, &network).expect("unrecorded ABI only warns");
+        let err = check_abi(Some("2.0.0"), &network).expect_err("newer major");
+        assert!(
+            err.to_string()
+                .contains("compiled against dusk-core 2.0.0, but testnet runs dusk-core 1.3.0"),
+            "{err}"
+        );
+    }
 }
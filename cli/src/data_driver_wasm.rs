@@ -24,11 +24,11 @@ impl DataDriverWasm {
         let instance = Instance::new(&mut store, &module, &[])?;
         let memory = instance
             .get_memory(&mut store, "memory")
-            .ok_or_else(|| CliError::Message("WASM export 'memory' not found".to_string()))?;
+            .ok_or_else(|| CliError::DataDriver("WASM export 'memory' not found".to_string()))?;
 
         let init = instance
             .get_typed_func::<(), ()>(&mut store, "init")
-            .map_err(|_| CliError::Message("WASM export 'init' not found".to_string()))?;
+            .map_err(|_| CliError::DataDriver("WASM export 'init' not found".to_string()))?;
         init.call(&mut store, ())?;
 
         Ok(Self {
@@ -47,54 +47,76 @@ impl DataDriverWasm {
         let get_schema = self
             .instance
             .get_typed_func::<(i32, i32), i32>(&mut self.store, "get_schema")
-            .map_err(|_| CliError::Message("WASM export 'get_schema' not found".to_string()))?;
+            .map_err(|_| CliError::DataDriver("WASM export 'get_schema' not found".to_string()))?;
 
         let code = get_schema.call(&mut self.store, (out_offset as i32, out_size as i32))?;
         if code != 0 {
             let detail = self
                 .read_last_error()
                 .unwrap_or_else(|| "unknown error".to_string());
-            return Err(CliError::Message(format!(
+            return Err(CliError::DataDriver(format!(
                 "get_schema failed with code {code}: {detail}"
             )));
         }
 
         let bytes = self.read_prefixed_bytes(out_offset)?;
         String::from_utf8(bytes)
-            .map_err(|err| CliError::Message(format!("schema output is not valid UTF-8: {err}")))
+            .map_err(|err| CliError::DataDriver(format!("schema output is not valid UTF-8: {err}")))
     }
 
     pub fn encode_input(&mut self, function: &str, json: &str) -> Result<Vec<u8>> {
-        let fn_name = function.as_bytes();
-        let json = json.as_bytes();
+        self.call_codec("encode_input_fn", function, json.as_bytes())
+    }
+
+    /// Decode a function's rkyv-encoded input to JSON.
+    pub fn decode_input(&mut self, function: &str, rkyv: &[u8]) -> Result<String> {
+        let json = self.call_codec("decode_input_fn", function, rkyv)?;
+        String::from_utf8(json)
+            .map_err(|err| CliError::DataDriver(format!("decoded input is not valid UTF-8: {err}")))
+    }
+
+    /// Decode a function's rkyv-encoded output to JSON.
+    pub fn decode_output(&mut self, function: &str, rkyv: &[u8]) -> Result<String> {
+        let json = self.call_codec("decode_output_fn", function, rkyv)?;
+        String::from_utf8(json).map_err(|err| {
+            CliError::DataDriver(format!("decoded output is not valid UTF-8: {err}"))
+        })
+    }
+
+    /// Decode an event's rkyv-encoded payload to JSON.
+    pub fn decode_event(&mut self, topic: &str, rkyv: &[u8]) -> Result<String> {
+        let json = self.call_codec("decode_event", topic, rkyv)?;
+        String::from_utf8(json)
+            .map_err(|err| CliError::DataDriver(format!("decoded event is not valid UTF-8: {err}")))
+    }
 
-        let fn_offset = 1024usize;
-        let json_offset = align_up(fn_offset + fn_name.len() + 16, 8);
-        let out_offset = align_up(json_offset + json.len() + 16, 8);
-        let out_size = (json.len() * 2).max(4096);
+    /// Call a data-driver codec export taking a name and a payload and
+    /// writing its length-prefixed result to an output buffer.
+    fn call_codec(&mut self, export: &str, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        let name = name.as_bytes();
+
+        let name_offset = 1024usize;
+        let payload_offset = align_up(name_offset + name.len() + 16, 8);
+        let out_offset = align_up(payload_offset + payload.len() + 16, 8);
+        let out_size = (payload.len() * 4).max(4096);
 
         self.ensure_memory_capacity((out_offset + out_size) as u64)?;
 
-        self.write_bytes(fn_offset, fn_name)?;
-        self.write_bytes(json_offset, json)?;
+        self.write_bytes(name_offset, name)?;
+        self.write_bytes(payload_offset, payload)?;
 
-        let encode_input_fn = self
+        let codec = self
             .instance
-            .get_typed_func::<(i32, i32, i32, i32, i32, i32), i32>(
-                &mut self.store,
-                "encode_input_fn",
-            )
-            .map_err(|_| {
-                CliError::Message("WASM export 'encode_input_fn' not found".to_string())
-            })?;
-
-        let code = encode_input_fn.call(
+            .get_typed_func::<(i32, i32, i32, i32, i32, i32), i32>(&mut self.store, export)
+            .map_err(|_| CliError::DataDriver(format!("WASM export '{export}' not found")))?;
+
+        let code = codec.call(
             &mut self.store,
             (
-                fn_offset as i32,
-                fn_name.len() as i32,
-                json_offset as i32,
-                json.len() as i32,
+                name_offset as i32,
+                name.len() as i32,
+                payload_offset as i32,
+                payload.len() as i32,
                 out_offset as i32,
                 out_size as i32,
             ),
@@ -104,8 +126,8 @@ impl DataDriverWasm {
             let detail = self
                 .read_last_error()
                 .unwrap_or_else(|| "unknown error".to_string());
-            return Err(CliError::Message(format!(
-                "encode_input_fn failed with code {code}: {detail}"
+            return Err(CliError::DataDriver(format!(
+                "{export} failed with code {code}: {detail}"
             )));
         }
 
@@ -157,7 +179,7 @@ impl DataDriverWasm {
         let mem = self.memory.data_mut(&mut self.store);
         let end = offset + data.len();
         if end > mem.len() {
-            return Err(CliError::Message(format!(
+            return Err(CliError::DataDriver(format!(
                 "WASM write out of bounds (offset={offset}, len={})",
                 data.len()
             )));
@@ -171,7 +193,7 @@ impl DataDriverWasm {
         let data = self.memory.data(&self.store);
 
         if offset + 4 > data.len() {
-            return Err(CliError::Message(
+            return Err(CliError::DataDriver(
                 "WASM output buffer out of bounds".to_string(),
             ));
         }
@@ -186,7 +208,7 @@ impl DataDriverWasm {
         let start = offset + 4;
         let end = start + len;
         if end > data.len() {
-            return Err(CliError::Message(
+            return Err(CliError::DataDriver(
                 "WASM output exceeds memory bounds".to_string(),
             ));
         }
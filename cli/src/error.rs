@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use serde_json::{Value, json};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, CliError>;
@@ -9,24 +10,57 @@ pub enum CliError {
     #[error("{0}")]
     Message(String),
 
+    #[error("{0}")]
+    InvalidInput(String),
+
     #[error("invalid contract name '{name}': {reason}")]
     InvalidContractName { name: String, reason: String },
 
     #[error("path already exists: {0}")]
     PathAlreadyExists(PathBuf),
 
+    #[cfg(not(feature = "schema"))]
+    #[error("{command} command is disabled (build with --features schema)")]
+    Disabled { command: &'static str },
+
     #[error("expected a Dusk Forge contract project at {0}")]
     NotAForgeProject(PathBuf),
 
+    #[error("{0}")]
+    Config(String),
+
+    #[error("check failed with {failures} issue(s)")]
+    CheckFailed { failures: usize },
+
     #[error("required tool not found: {tool}. {hint}")]
     MissingTool {
         tool: &'static str,
         hint: &'static str,
     },
 
+    #[error("{0}")]
+    Toolchain(String),
+
     #[error("command failed: {program} (exit code {code})")]
     CommandFailed { program: String, code: i32 },
 
+    /// A failed plugin, script or `cargo test` run, whose exit code
+    /// `dusk-forge` exits with, as cargo does for external subcommands.
+    #[error("{program} failed (exit code {code})")]
+    PassThrough { program: String, code: i32 },
+
+    #[error("git {args} failed: {stderr}")]
+    Git { args: String, stderr: String },
+
+    #[error("{}: {reason}", path.display())]
+    Build { path: PathBuf, reason: String },
+
+    #[error("{0}")]
+    Verification(String),
+
+    #[error("{0}")]
+    DataDriver(String),
+
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -43,3 +77,179 @@ pub enum CliError {
     #[error("wasm runtime error: {0}")]
     Wasm(#[from] wasmtime::Error),
 }
+
+/// Category of a [`CliError`], each with a stable exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Anything not covered below.
+    Other,
+    /// Invalid arguments or input files; shares clap's usage exit code.
+    Input,
+    /// The project, its manifest or `Forge.toml`.
+    Project,
+    /// Missing tools, toolchains, targets or components.
+    Toolchain,
+    /// A failed build step or an external command.
+    Build,
+    /// Artifacts not matching an expected hash, schema or attestation.
+    Verification,
+    /// Loading or calling the data-driver WASM.
+    DataDriver,
+    /// Reading or writing files.
+    Io,
+}
+
+impl ErrorKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Other => "other",
+            Self::Input => "input",
+            Self::Project => "project",
+            Self::Toolchain => "toolchain",
+            Self::Build => "build",
+            Self::Verification => "verification",
+            Self::DataDriver => "data-driver",
+            Self::Io => "io",
+        }
+    }
+
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::Other => 1,
+            Self::Input => 2,
+            Self::Project => 3,
+            Self::Toolchain => 4,
+            Self::Build => 5,
+            Self::Verification => 6,
+            Self::DataDriver => 7,
+            Self::Io => 8,
+        }
+    }
+}
+
+impl CliError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Message(_) => ErrorKind::Other,
+            Self::InvalidInput(_)
+            | Self::InvalidContractName { .. }
+            | Self::PathAlreadyExists(_)
+            | Self::Json(_) => ErrorKind::Input,
+            #[cfg(not(feature = "schema"))]
+            Self::Disabled { .. } => ErrorKind::Input,
+            Self::NotAForgeProject(_)
+            | Self::Config(_)
+            | Self::CheckFailed { .. }
+            | Self::CargoMetadata(_)
+            | Self::Toml(_) => ErrorKind::Project,
+            Self::MissingTool { .. } | Self::Toolchain(_) => ErrorKind::Toolchain,
+            Self::CommandFailed { .. }
+            | Self::PassThrough { .. }
+            | Self::Git { .. }
+            | Self::Build { .. } => ErrorKind::Build,
+            Self::Verification(_) => ErrorKind::Verification,
+            Self::DataDriver(_) => ErrorKind::DataDriver,
+            #[cfg(feature = "schema")]
+            Self::Wasm(_) => ErrorKind::DataDriver,
+            Self::Io(_) => ErrorKind::Io,
+        }
+    }
+
+    /// The exit code of `dusk-forge`: the failed command's own for
+    /// [`CliError::PassThrough`], that of the error's kind otherwise.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::PassThrough { code, .. } => *code,
+            _ => self.kind().exit_code(),
+        }
+    }
+
+    /// The error as a JSON object for `--error-format json`, with the
+    /// subcommand that failed and the path or external command involved.
+    pub fn to_json(&self, subcommand: &str) -> Value {
+        let kind = self.kind();
+        let mut error = json!({
+            "kind": kind.name(),
+            "exit_code": self.exit_code(),
+            "subcommand": subcommand,
+            "message": self.to_string(),
+        });
+
+        match self {
+            Self::PathAlreadyExists(path)
+            | Self::NotAForgeProject(path)
+            | Self::Build { path, .. } => {
+                error["path"] = json!(path);
+            }
+            Self::MissingTool { tool, .. } => error["command"] = json!(tool),
+            Self::CommandFailed { program, code } | Self::PassThrough { program, code } => {
+                error["command"] = json!(program);
+                error["command_exit_code"] = json!(code);
+            }
+            Self::Git { args, .. } => error["command"] = json!(format!("git {args}")),
+            _ => {}
+        }
+
+        json!({ "error": error })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_output_carries_kind_and_context() {
+        let err = CliError::Build {
+            path: PathBuf::from("target/contract/counter.wasm"),
+            reason: "uses features the Dusk VM does not allow".to_string(),
+        };
+
+        assert_eq!(err.kind().exit_code(), 5);
+        assert_eq!(
+            err.to_json("build"),
+            json!({
+                "error": {
+                    "kind": "build",
+                    "exit_code": 5,
+                    "subcommand": "build",
+                    "message": "target/contract/counter.wasm: uses features the Dusk VM does not allow",
+                    "path": "target/contract/counter.wasm",
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn exit_codes_tell_categories_apart() {
+        let toolchain = CliError::Toolchain("missing rust-src".to_string());
+        let io = CliError::Io(std::io::Error::other("disk full"));
+        let failed = CliError::CommandFailed {
+            program: "cargo build".to_string(),
+            code: 101,
+        };
+
+        assert_eq!(toolchain.kind(), ErrorKind::Toolchain);
+        assert_eq!(io.kind().exit_code(), 8);
+        assert_eq!(
+            failed.to_json("test")["error"]["command"],
+            json!("cargo build")
+        );
+    }
+
+    #[test]
+    fn pass_through_keeps_the_exit_code_of_the_command() {
+        let failed = CliError::PassThrough {
+            program: "dusk-forge-lint".to_string(),
+            code: 3,
+        };
+
+        assert_eq!(failed.kind(), ErrorKind::Build);
+        assert_eq!(failed.exit_code(), 3);
+        assert_eq!(failed.to_json("lint")["error"]["exit_code"], json!(3));
+        assert_eq!(
+            failed.to_json("lint")["error"]["command"],
+            json!("dusk-forge-lint")
+        );
+    }
+}
@@ -1,28 +1,37 @@
+#[cfg(feature = "schema")]
+mod attestation;
 mod build_runner;
 mod cli;
 mod commands;
 mod data_driver_wasm;
 mod error;
 mod project;
+#[cfg(feature = "schema")]
+mod random_input;
 mod template;
 mod toolchain;
 mod tools;
 mod ui;
 
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, ErrorFormat};
 use error::Result;
 
 fn main() {
-    if let Err(err) = run() {
-        ui::error(err.to_string());
-        std::process::exit(1);
+    let cli = Cli::parse();
+    let error_format = cli.error_format;
+    let subcommand = cli.command.name();
+
+    if let Err(err) = run(cli) {
+        match error_format {
+            ErrorFormat::Human => ui::error(err.to_string()),
+            ErrorFormat::Json => eprintln!("{}", err.to_json(subcommand)),
+        }
+        std::process::exit(err.exit_code());
     }
 }
 
-fn run() -> Result<()> {
-    let cli = Cli::parse();
-
+fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::New(args) => commands::new::run(args),
         Commands::Build(args) => commands::build::run(args),
@@ -33,6 +42,12 @@ fn run() -> Result<()> {
         Commands::Schema(args) => commands::schema::run(args),
         Commands::Call(args) => commands::call::run(args),
         Commands::Verify(args) => commands::verify::run(args),
+        Commands::Audit(args) => commands::audit::run(args),
+        Commands::MigrateGen(args) => commands::migrate_gen::run(args),
+        Commands::FmtSchema(args) => commands::fmt_schema::run(args),
+        Commands::Events(args) => commands::events::run(args),
+        Commands::Script(args) => commands::script::run(args),
         Commands::Completions(args) => commands::completions::run(args),
+        Commands::External(args) => commands::plugin::run(args),
     }
 }
@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs};
+
+use serde::Deserialize;
+use toml::{Table, Value};
+
+use crate::error::{CliError, Result};
+
+pub const CONFIG_FILE: &str = "Forge.toml";
+
+/// Networks selectable with `--network` without configuration, as
+/// `(name, rpc, chain id)`. A `[networks.<name>]` entry replaces them.
+const BUILTIN_NETWORKS: &[(&str, &str, u8)] = &[
+    ("localnet", "http://127.0.0.1:8080", 0),
+    ("devnet", "https://devnet.nodes.dusk.network", 3),
+    ("testnet", "https://testnet.nodes.dusk.network", 2),
+    ("mainnet", "https://nodes.dusk.network", 1),
+];
+
+/// Project-level settings from `Forge.toml`, with the selected profile
+/// applied.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ForgeConfig {
+    pub build: BuildConfig,
+    pub networks: BTreeMap<String, NetworkConfig>,
+    /// Key files by name, relative to the project directory.
+    pub keys: BTreeMap<String, PathBuf>,
+    pub gas: GasConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct BuildConfig {
+    /// Stack size linked into the contract WASM, in bytes.
+    pub stack_size: u32,
+    /// Run wasm-opt on built artifacts when it is installed.
+    pub wasm_opt: bool,
+    /// Base directory for the `contract` and `data-driver` target
+    /// directories, instead of the workspace `target/`.
+    pub target_dir: Option<PathBuf>,
+    /// Target directory of the contract build.
+    pub contract_target_dir: Option<PathBuf>,
+    /// Target directory of the data-driver build.
+    pub data_driver_target_dir: Option<PathBuf>,
+    /// Largest contract WASM the build accepts after wasm-opt, in bytes.
+    pub max_wasm_size: Option<u64>,
+    /// Largest body size of contract functions, in bytes, by function name
+    /// or by a `prefix*` pattern applying to each function it matches.
+    pub function_budgets: BTreeMap<String, u64>,
+    /// Dependencies the contract build may compile with their `std`
+    /// feature, by package name.
+    pub allow_std: Vec<String>,
+    /// Accept floating-point instructions in the contract WASM.
+    pub allow_floats: bool,
+    /// Largest linear memory the contract WASM may start with or grow to
+    /// by a constant amount, in 64 KiB pages.
+    pub max_memory_pages: Option<u64>,
+}
+
+impl Default for BuildConfig {
+    fn default() -> Self {
+        Self {
+            stack_size: 65_536,
+            wasm_opt: true,
+            target_dir: None,
+            contract_target_dir: None,
+            data_driver_target_dir: None,
+            max_wasm_size: None,
+            function_budgets: BTreeMap::new(),
+            allow_std: Vec::new(),
+            allow_floats: false,
+            max_memory_pages: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub rpc: String,
+    pub chain_id: Option<u8>,
+    /// Gas price on this network, overriding `[gas] price`.
+    pub gas_price: Option<u64>,
+    /// Version of `dusk-core` the network's nodes run, the newest host ABI
+    /// contracts deployed there may be compiled against.
+    pub abi_version: Option<String>,
+}
+
+/// A network selected with `--network`, with its gas settings resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    pub name: String,
+    pub rpc: String,
+    pub chain_id: Option<u8>,
+    pub gas_limit: Option<u64>,
+    pub gas_price: Option<u64>,
+    pub abi_version: Option<String>,
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}", self.name, self.rpc)?;
+        if let Some(chain_id) = self.chain_id {
+            write!(f, ", chain id {chain_id}")?;
+        }
+        if let Some(limit) = self.gas_limit {
+            write!(f, ", gas limit {limit}")?;
+        }
+        if let Some(price) = self.gas_price {
+            write!(f, ", gas price {price}")?;
+        }
+        if let Some(abi) = &self.abi_version {
+            write!(f, ", ABI {abi}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GasConfig {
+    pub limit: Option<u64>,
+    pub price: Option<u64>,
+}
+
+impl ForgeConfig {
+    /// Resolve the network named `name`: a `[networks.<name>]` entry, or one
+    /// of the built-in `localnet`, `devnet`, `testnet` and `mainnet`. The
+    /// network's gas price wins over `[gas] price`.
+    pub fn network(&self, name: &str) -> Result<Network> {
+        let builtin = BUILTIN_NETWORKS
+            .iter()
+            .find(|(builtin, ..)| *builtin == name);
+        let (rpc, chain_id, gas_price, abi_version) = match (self.networks.get(name), builtin) {
+            (Some(network), _) => (
+                network.rpc.clone(),
+                network.chain_id,
+                network.gas_price,
+                network.abi_version.clone(),
+            ),
+            (None, Some((_, rpc, chain_id))) => ((*rpc).to_string(), Some(*chain_id), None, None),
+            (None, None) => {
+                let mut available: Vec<_> = self.networks.keys().map(String::as_str).collect();
+                available.extend(
+                    BUILTIN_NETWORKS
+                        .iter()
+                        .map(|(builtin, ..)| *builtin)
+                        .filter(|builtin| !self.networks.contains_key(*builtin)),
+                );
+                return Err(CliError::Config(format!(
+                    "unknown network '{name}' (available: {})",
+                    available.join(", ")
+                )));
+            }
+        };
+
+        Ok(Network {
+            name: name.to_string(),
+            rpc,
+            chain_id,
+            gas_limit: self.gas.limit,
+            gas_price: gas_price.or(self.gas.price),
+            abi_version,
+        })
+    }
+}
+
+/// Load `Forge.toml` from the project directory and apply `profile`.
+///
+/// A missing file yields the defaults, unless a profile was requested.
+pub fn load(project_dir: &Path, profile: Option<&str>) -> Result<ForgeConfig> {
+    let path = project_dir.join(CONFIG_FILE);
+    if !path.is_file() {
+        return match profile {
+            Some(name) => Err(CliError::Config(format!(
+                "profile '{name}' requested but {} does not exist",
+                path.display()
+            ))),
+            None => Ok(ForgeConfig::default()),
+        };
+    }
+
+    let source = fs::read_to_string(&path)?;
+    parse(&source, profile)
+}
+
+fn parse(source: &str, profile: Option<&str>) -> Result<ForgeConfig> {
+    let mut root: Table = toml::from_str(source)?;
+    let profiles = match root.remove("profile") {
+        Some(Value::Table(profiles)) => profiles,
+        Some(_) => {
+            return Err(CliError::Config(format!(
+                "`profile` in {CONFIG_FILE} must be a table of profiles"
+            )));
+        }
+        None => Table::new(),
+    };
+
+    if let Some(name) = profile {
+        let Some(Value::Table(overrides)) = profiles.get(name) else {
+            let available: Vec<_> = profiles.keys().map(String::as_str).collect();
+            return Err(CliError::Config(format!(
+                "unknown profile '{name}' in {CONFIG_FILE} (available: {})",
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )));
+        };
+        merge(&mut root, overrides);
+    }
+
+    Ok(Value::Table(root).try_into()?)
+}
+
+/// Recursively overlay `overrides` onto `base`; nested tables are merged key
+/// by key, any other value replaces the base one.
+fn merge(base: &mut Table, overrides: &Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(key), value) {
+            (Some(Value::Table(base_table)), Value::Table(override_table)) => {
+                merge(base_table, override_table);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+[build]
+stack-size = 131072
+target-dir = "../../target/forge"
+max-wasm-size = 65536
+allow-std = ["getrandom"]
+allow-floats = true
+max-memory-pages = 256
+
+[build.function-budgets]
+"my_contract::Bridge::deposit" = 4096
+"serde_json::*" = 1024
+
+[networks.testnet]
+rpc = "https://testnet.nodes.dusk.network"
+chain-id = 2
+gas-price = 2
+abi-version = "1.3.0"
+
+[keys]
+deployer = "keys/deployer.key"
+
+[gas]
+limit = 500000
+price = 1
+
+[profile.dev.build]
+wasm-opt = false
+
+[profile.dev.networks.local]
+rpc = "http://127.0.0.1:8080"
+
+[profile.dev.gas]
+limit = 10000000
+"#;
+
+    #[test]
+    fn parses_base_settings() {
+        let config = parse(CONFIG, None).expect("config should parse");
+
+        assert_eq!(config.build.stack_size, 131_072);
+        assert!(config.build.wasm_opt);
+        assert_eq!(
+            config.build.target_dir,
+            Some(PathBuf::from("../../target/forge"))
+        );
+        assert_eq!(
+            config.networks["testnet"],
+            NetworkConfig {
+                rpc: "https://testnet.nodes.dusk.network".to_string(),
+                chain_id: Some(2),
+                gas_price: Some(2),
+                abi_version: Some("1.3.0".to_string()),
+            }
+        );
+        assert_eq!(config.build.max_wasm_size, Some(65_536));
+        assert_eq!(
+            config.build.function_budgets["my_contract::Bridge::deposit"],
+            4096
+        );
+        assert_eq!(config.build.function_budgets["serde_json::*"], 1024);
+        assert_eq!(config.build.allow_std, ["getrandom"]);
+        assert!(config.build.allow_floats);
+        assert_eq!(config.build.max_memory_pages, Some(256));
+        assert_eq!(config.keys["deployer"], PathBuf::from("keys/deployer.key"));
+        assert_eq!(config.gas.limit, Some(500_000));
+        assert!(!config.networks.contains_key("local"));
+    }
+
+    #[test]
+    fn profile_overrides_merge_into_base() {
+        let config = parse(CONFIG, Some("dev")).expect("config should parse");
+
+        assert_eq!(config.build.stack_size, 131_072);
+        assert!(!config.build.wasm_opt);
+        assert!(config.networks.contains_key("testnet"));
+        assert_eq!(config.networks["local"].rpc, "http://127.0.0.1:8080");
+        assert_eq!(config.gas.limit, Some(10_000_000));
+        assert_eq!(config.gas.price, Some(1));
+    }
+
+    #[test]
+    fn rejects_unknown_profile() {
+        let err = parse(CONFIG, Some("prod")).expect_err("unknown profile should fail");
+        let msg = err.to_string();
+
+        assert!(msg.contains("unknown profile 'prod'"), "{msg}");
+        assert!(msg.contains("available: dev"), "{msg}");
+    }
+
+    #[test]
+    fn resolves_configured_and_builtin_networks() {
+        let config = parse(CONFIG, Some("dev")).expect("config should parse");
+
+        let testnet = config.network("testnet").expect("configured network");
+        assert_eq!(testnet.chain_id, Some(2));
+        assert_eq!(testnet.gas_limit, Some(10_000_000));
+        assert_eq!(testnet.gas_price, Some(2));
+        assert_eq!(testnet.abi_version.as_deref(), Some("1.3.0"));
+
+        let local = config.network("local").expect("profile network");
+        assert_eq!(local.chain_id, None);
+        assert_eq!(local.gas_price, Some(1));
+
+        let mainnet = config.network("mainnet").expect("built-in network");
+        assert_eq!(mainnet.rpc, "https://nodes.dusk.network");
+        assert_eq!(mainnet.chain_id, Some(1));
+        assert_eq!(
+            mainnet.to_string(),
+            "mainnet (https://nodes.dusk.network, chain id 1, gas limit 10000000, gas price 1)"
+        );
+
+        let err = config.network("moon").expect_err("unknown network");
+        assert!(
+            err.to_string()
+                .contains("available: local, testnet, localnet, devnet, mainnet"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = parse("[build]\nstack = 1\n", None).expect_err("unknown key should fail");
+
+        assert!(err.to_string().contains("unknown field"), "{err}");
+    }
+
+    #[test]
+    fn defaults_without_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let config = load(dir.path(), None).expect("defaults should load");
+
+        assert_eq!(config.build.stack_size, 65_536);
+        assert!(config.build.wasm_opt);
+        assert!(load(dir.path(), Some("dev")).is_err());
+    }
+}
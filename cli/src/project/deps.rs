@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use cargo_metadata::camino::Utf8Path;
+use cargo_metadata::{Metadata, MetadataCommand};
+use serde_json::Value;
+
+use crate::error::{CliError, Result};
+use crate::project::metadata::select_package;
+
+/// Crates that are always in scope without a Cargo dependency.
+const BUILTIN_CRATES: &[&str] = &["core", "alloc", "std"];
+
+/// Path roots naming the contract crate itself rather than a dependency.
+const LOCAL_ROOTS: &[&str] = &["crate", "self", "super"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedCrate {
+    pub package: String,
+    pub version: String,
+}
+
+/// Dependency crates of a contract, keyed by the name used in `use` paths.
+pub type DependencyMap = BTreeMap<String, ResolvedCrate>;
+
+pub fn load(manifest_path: &Path) -> Result<DependencyMap> {
+    let metadata = MetadataCommand::new().manifest_path(manifest_path).exec()?;
+
+    dependency_map(&metadata, manifest_path)
+}
+
+fn dependency_map(metadata: &Metadata, manifest_path: &Path) -> Result<DependencyMap> {
+    // Only workspace members: the first package of the full graph may be
+    // any dependency
+    let root = Utf8Path::from_path(manifest_path)
+        .and_then(|path| select_package(metadata.workspace_packages(), path))
+        .ok_or_else(|| {
+            CliError::Config(format!(
+                "unable to resolve package metadata for {}",
+                manifest_path.display()
+            ))
+        })?;
+
+    let node = metadata
+        .resolve
+        .as_ref()
+        .and_then(|resolve| resolve.nodes.iter().find(|node| node.id == root.id))
+        .ok_or_else(|| {
+            CliError::Config(format!(
+                "cargo metadata returned no dependency graph for {}",
+                root.name
+            ))
+        })?;
+
+    let mut deps = DependencyMap::new();
+    deps.insert(
+        root.name.replace('-', "_"),
+        ResolvedCrate {
+            package: root.name.clone(),
+            version: root.version.to_string(),
+        },
+    );
+
+    for dep in &node.deps {
+        if let Some(pkg) = metadata.packages.iter().find(|pkg| pkg.id == dep.pkg) {
+            deps.insert(
+                dep.name.clone(),
+                ResolvedCrate {
+                    package: pkg.name.clone(),
+                    version: pkg.version.to_string(),
+                },
+            );
+        }
+    }
+
+    Ok(deps)
+}
+
+/// Add a `canonical` path, naming the package and version of the leading
+/// crate, to every schema import.
+///
+/// This only versions the crate: the item path after it is copied as
+/// written, not looked up in the crate. Paths rooted at `crate`, `self` or
+/// `super` are local to the contract and kept as they are, like those of the
+/// built-in crates.
+///
+/// Returns the import paths whose leading crate is not a dependency of the
+/// contract, so the caller can report them.
+pub fn version_imports(schema: &mut Value, deps: &DependencyMap) -> Vec<String> {
+    let mut unresolved = Vec::new();
+
+    let Some(imports) = schema.get_mut("imports").and_then(Value::as_array_mut) else {
+        return unresolved;
+    };
+
+    for import in imports {
+        let Some(path) = import.get("path").and_then(Value::as_str) else {
+            continue;
+        };
+
+        match versioned_path(path, deps) {
+            Some(canonical) => {
+                if let Some(object) = import.as_object_mut() {
+                    object.insert("canonical".to_string(), Value::String(canonical));
+                }
+            }
+            None => unresolved.push(path.to_string()),
+        }
+    }
+
+    unresolved
+}
+
+/// `path` with its leading crate replaced by `<package>@<version>`, or `None`
+/// if that crate is not a dependency.
+fn versioned_path(path: &str, deps: &DependencyMap) -> Option<String> {
+    let (krate, rest) = path.split_once("::").unwrap_or((path, ""));
+
+    if BUILTIN_CRATES.contains(&krate) || LOCAL_ROOTS.contains(&krate) {
+        return Some(path.to_string());
+    }
+
+    let resolved = deps.get(krate)?;
+    let mut canonical = format!("{}@{}", resolved.package, resolved.version);
+    if !rest.is_empty() {
+        canonical.push_str("::");
+        canonical.push_str(rest);
+    }
+
+    Some(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn deps() -> DependencyMap {
+        DependencyMap::from([
+            (
+                "types".to_string(),
+                ResolvedCrate {
+                    package: "types".to_string(),
+                    version: "0.1.0".to_string(),
+                },
+            ),
+            (
+                "dusk_core".to_string(),
+                ResolvedCrate {
+                    package: "dusk-core".to_string(),
+                    version: "1.6.0".to_string(),
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn versions_import_crates() {
+        let mut schema = json!({
+            "imports": [
+                { "name": "Item", "path": "types::Item" },
+                { "name": "PublicKey", "path": "dusk_core::signatures::bls::PublicKey" },
+                { "name": "BTreeMap", "path": "alloc::collections::BTreeMap" },
+            ]
+        });
+
+        let unresolved = version_imports(&mut schema, &deps());
+
+        assert!(
+            unresolved.is_empty(),
+            "unexpected unresolved: {unresolved:?}"
+        );
+        assert_eq!(schema["imports"][0]["canonical"], "types@0.1.0::Item");
+        assert_eq!(
+            schema["imports"][1]["canonical"],
+            "dusk-core@1.6.0::signatures::bls::PublicKey"
+        );
+        assert_eq!(
+            schema["imports"][2]["canonical"],
+            "alloc::collections::BTreeMap"
+        );
+    }
+
+    #[test]
+    fn keeps_local_import_paths() {
+        let mut schema = json!({
+            "imports": [
+                { "name": "Item", "path": "crate::types::Item" },
+                { "name": "Id", "path": "self::Id" },
+                { "name": "Key", "path": "super::Key" },
+            ]
+        });
+
+        let unresolved = version_imports(&mut schema, &deps());
+
+        assert!(
+            unresolved.is_empty(),
+            "unexpected unresolved: {unresolved:?}"
+        );
+        assert_eq!(schema["imports"][0]["canonical"], "crate::types::Item");
+        assert_eq!(schema["imports"][2]["canonical"], "super::Key");
+    }
+
+    #[test]
+    fn reports_imports_of_unknown_crates() {
+        let mut schema = json!({
+            "imports": [{ "name": "Thing", "path": "missing_crate::Thing" }]
+        });
+
+        let unresolved = version_imports(&mut schema, &deps());
+
+        assert_eq!(unresolved, vec!["missing_crate::Thing".to_string()]);
+        assert!(schema["imports"][0].get("canonical").is_none());
+    }
+}
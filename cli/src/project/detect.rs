@@ -46,7 +46,7 @@ pub fn load_manifest(project_dir: &Path) -> Result<Value> {
 pub fn resolve_data_driver_feature(project_dir: &Path) -> Result<&'static str> {
     let manifest = load_manifest(project_dir)?;
     preferred_data_driver_feature(&manifest).ok_or_else(|| {
-        CliError::Message(
+        CliError::Config(
             "project is missing a `data-driver` or `data-driver-js` feature".to_string(),
         )
     })
@@ -86,7 +86,7 @@ fn has_cdylib(manifest: &Value) -> bool {
         .is_some_and(|types| types.iter().any(|ty| ty.as_str() == Some("cdylib")))
 }
 
-fn has_feature(manifest: &Value, name: &str) -> bool {
+pub fn has_feature(manifest: &Value, name: &str) -> bool {
     manifest
         .get("features")
         .and_then(Value::as_table)
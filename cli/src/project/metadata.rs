@@ -1,27 +1,38 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use cargo_metadata::camino::Utf8Path;
 use cargo_metadata::{MetadataCommand, Package};
 
+use crate::build_runner::artifacts;
+use crate::cli::ProjectOptions;
 use crate::error::{CliError, Result};
+use crate::project::config::{self, BuildConfig, ForgeConfig};
 use crate::toolchain::WASM_TARGET;
 
 #[derive(Debug, Clone)]
 pub struct ProjectMetadata {
     pub project_dir: PathBuf,
     pub manifest_path: PathBuf,
+    /// The `Cargo.lock` of the project's workspace.
+    pub lockfile_path: PathBuf,
     pub crate_name: String,
     pub contract_target_dir: PathBuf,
     pub data_driver_target_dir: PathBuf,
     pub contract_wasm_path: PathBuf,
     pub data_driver_wasm_path: PathBuf,
+    /// Where builds record their artifacts (`forge-artifacts.json`).
+    pub artifact_manifest_path: PathBuf,
+    /// The `Forge.toml` profile applied to `config`, if any.
+    pub profile: Option<String>,
+    pub config: ForgeConfig,
 }
 
-pub fn load(project_dir: &Path) -> Result<ProjectMetadata> {
-    let project_dir = fs::canonicalize(project_dir)?;
+pub fn load(options: &ProjectOptions) -> Result<ProjectMetadata> {
+    let project_dir = fs::canonicalize(&options.path)?;
     let manifest_path = project_dir.join("Cargo.toml");
     if !manifest_path.exists() {
-        return Err(CliError::Message(format!(
+        return Err(CliError::Config(format!(
             "missing Cargo.toml at {}",
             manifest_path.display()
         )));
@@ -29,7 +40,7 @@ pub fn load(project_dir: &Path) -> Result<ProjectMetadata> {
 
     let manifest_utf8 = cargo_metadata::camino::Utf8PathBuf::from_path_buf(manifest_path.clone())
         .map_err(|_| {
-        CliError::Message(format!(
+        CliError::Config(format!(
             "manifest path contains invalid UTF-8: {}",
             manifest_path.display()
         ))
@@ -41,7 +52,7 @@ pub fn load(project_dir: &Path) -> Result<ProjectMetadata> {
         .exec()?;
 
     let package = select_package(&metadata.packages, &manifest_utf8).ok_or_else(|| {
-        CliError::Message(format!(
+        CliError::Config(format!(
             "unable to resolve package metadata for {}",
             manifest_path.display()
         ))
@@ -50,8 +61,14 @@ pub fn load(project_dir: &Path) -> Result<ProjectMetadata> {
     let crate_name = package.name.clone();
     let crate_name_snake = crate_name.replace('-', "_");
     let workspace_root = PathBuf::from(metadata.workspace_root.as_std_path());
-    let contract_target_dir = workspace_root.join("target/contract");
-    let data_driver_target_dir = workspace_root.join("target/data-driver");
+
+    let config = config::load(&project_dir, options.profile.as_deref())?;
+    let (target_dir, contract_target_dir, data_driver_target_dir) = target_dirs(
+        &workspace_root,
+        &project_dir,
+        &config.build,
+        options.target_dir.as_deref(),
+    )?;
 
     let contract_wasm_path = contract_target_dir
         .join(WASM_TARGET)
@@ -65,20 +82,110 @@ pub fn load(project_dir: &Path) -> Result<ProjectMetadata> {
     Ok(ProjectMetadata {
         project_dir,
         manifest_path,
+        lockfile_path: workspace_root.join("Cargo.lock"),
         crate_name,
         contract_target_dir,
         data_driver_target_dir,
         contract_wasm_path,
         data_driver_wasm_path,
+        artifact_manifest_path: target_dir.join(artifacts::MANIFEST_FILE),
+        profile: options.profile.clone(),
+        config,
     })
 }
 
-fn select_package<'a>(
-    packages: &'a [Package],
-    manifest_path: &cargo_metadata::camino::Utf8PathBuf,
+/// Resolve the base target directory and the contract and data-driver
+/// target directories.
+///
+/// `--target-dir` replaces the base directory outright. Otherwise the
+/// `Forge.toml` per-artifact directories win over its `target-dir`, which
+/// wins over the workspace `target/`. Config paths are relative to the
+/// project directory.
+fn target_dirs(
+    workspace_root: &Path,
+    project_dir: &Path,
+    build: &BuildConfig,
+    cli_target_dir: Option<&Path>,
+) -> Result<(PathBuf, PathBuf, PathBuf)> {
+    if let Some(dir) = cli_target_dir {
+        let base = std::path::absolute(dir)?;
+        return Ok((
+            base.clone(),
+            base.join("contract"),
+            base.join("data-driver"),
+        ));
+    }
+
+    let base = build.target_dir.as_ref().map_or_else(
+        || workspace_root.join("target"),
+        |dir| project_dir.join(dir),
+    );
+    let contract = build
+        .contract_target_dir
+        .as_ref()
+        .map_or_else(|| base.join("contract"), |dir| project_dir.join(dir));
+    let data_driver = build
+        .data_driver_target_dir
+        .as_ref()
+        .map_or_else(|| base.join("data-driver"), |dir| project_dir.join(dir));
+
+    Ok((base, contract, data_driver))
+}
+
+/// The package at `manifest_path`, or the first of `packages` if none is.
+pub(crate) fn select_package<'a>(
+    packages: impl IntoIterator<Item = &'a Package>,
+    manifest_path: &Utf8Path,
 ) -> Option<&'a Package> {
+    let mut packages = packages.into_iter().peekable();
+    let first = packages.peek().copied();
     packages
-        .iter()
-        .find(|pkg| pkg.manifest_path == *manifest_path)
-        .or_else(|| packages.first())
+        .find(|pkg| pkg.manifest_path == manifest_path)
+        .or(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_dirs_default_to_workspace_target() {
+        let (base, contract, data_driver) = target_dirs(
+            Path::new("/ws"),
+            Path::new("/ws/contracts/counter"),
+            &BuildConfig::default(),
+            None,
+        )
+        .expect("default dirs resolve");
+
+        assert_eq!(base, PathBuf::from("/ws/target"));
+        assert_eq!(contract, PathBuf::from("/ws/target/contract"));
+        assert_eq!(data_driver, PathBuf::from("/ws/target/data-driver"));
+    }
+
+    #[test]
+    fn target_dirs_follow_config_and_cli_overrides() {
+        let build = BuildConfig {
+            target_dir: Some(PathBuf::from("out")),
+            data_driver_target_dir: Some(PathBuf::from("../drivers")),
+            ..BuildConfig::default()
+        };
+        let project = Path::new("/ws/contracts/counter");
+
+        let (_, contract, data_driver) =
+            target_dirs(Path::new("/ws"), project, &build, None).expect("config dirs resolve");
+        assert_eq!(contract, project.join("out/contract"));
+        assert_eq!(data_driver, project.join("../drivers"));
+
+        let (base, contract, data_driver) = target_dirs(
+            Path::new("/ws"),
+            project,
+            &build,
+            Some(Path::new("/tmp/forge")),
+        )
+        .expect("cli dir resolves");
+        assert_eq!(base, PathBuf::from("/tmp/forge"));
+        assert_eq!(contract, PathBuf::from("/tmp/forge/contract"));
+        assert_eq!(data_driver, PathBuf::from("/tmp/forge/data-driver"));
+    }
 }
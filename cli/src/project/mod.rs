@@ -1,2 +1,4 @@
+pub mod config;
+pub mod deps;
 pub mod detect;
 pub mod metadata;
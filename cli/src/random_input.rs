@@ -0,0 +1,339 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value};
+
+use crate::error::{CliError, Result};
+
+/// Longest string, and largest collection, generated.
+const MAX_LEN: u64 = 16;
+const MAX_ITEMS: u64 = 4;
+
+/// A parameter type, parsed from its name in the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Ty {
+    /// A named type with its generic arguments, by its last path segment.
+    Named(String, Vec<Ty>),
+    Tuple(Vec<Ty>),
+    Array(Box<Ty>, u64),
+}
+
+/// A random JSON input for `function`, built from the parameter types in the
+/// schema, and the seed it was generated from. The same seed gives the same
+/// input.
+///
+/// Only primitives, strings and the standard containers (`Vec`, `Option`,
+/// `BTreeMap`, ...), tuples and arrays of them can be generated: the schema
+/// names the other types but carries no layout for them.
+pub fn generate(schema: &Value, function: &str, seed: Option<u64>) -> Result<(Value, u64)> {
+    let entry = schema
+        .get("functions")
+        .and_then(Value::as_array)
+        .and_then(|functions| functions.iter().find(|f| f["name"] == function))
+        .ok_or_else(|| {
+            CliError::InvalidInput(format!("function `{function}` is not in the schema"))
+        })?;
+    let types = entry
+        .get("inputs")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|param| param["type"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+    let mut rng = Rng(seed);
+    let mut values = types
+        .iter()
+        .map(|ty| {
+            let parsed = parse(ty).ok_or_else(|| {
+                CliError::InvalidInput(format!("cannot parse the parameter type `{ty}`"))
+            })?;
+            rng.value(&parsed).map_err(|unsupported| {
+                CliError::InvalidInput(format!(
+                    "cannot generate a random `{unsupported}` for `{function}`; \
+                     pass the input with --input"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let input = match values.len() {
+        0 => Value::Null,
+        1 => values.remove(0),
+        _ => Value::Array(values),
+    };
+    Ok((input, seed))
+}
+
+/// Parse a type name as the schema spells it, e.g. `Vec < (u64 , String) >`.
+fn parse(ty: &str) -> Option<Ty> {
+    let tokens = tokenize(ty);
+    let mut pos = 0;
+    let parsed = parse_ty(&tokens, &mut pos)?;
+    (pos == tokens.len()).then_some(parsed)
+}
+
+fn tokenize(ty: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = ty.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' || c == '\'' {
+            let mut word = c.to_string();
+            while let Some(&next) = chars.peek() {
+                if !(next.is_alphanumeric() || next == '_') {
+                    break;
+                }
+                word.push(next);
+                chars.next();
+            }
+            tokens.push(word);
+        } else if !c.is_whitespace() {
+            tokens.push(c.to_string());
+        }
+    }
+    tokens
+}
+
+fn parse_ty(tokens: &[String], pos: &mut usize) -> Option<Ty> {
+    let token = |pos: usize| tokens.get(pos).map(String::as_str);
+
+    // References and lifetimes do not change the encoding
+    while matches!(token(*pos), Some("&" | "mut")) || token(*pos)?.starts_with('\'') {
+        *pos += 1;
+    }
+
+    match token(*pos)? {
+        "(" => {
+            *pos += 1;
+            let mut items = Vec::new();
+            while token(*pos)? != ")" {
+                items.push(parse_ty(tokens, pos)?);
+                if token(*pos)? == "," {
+                    *pos += 1;
+                }
+            }
+            *pos += 1;
+            Some(Ty::Tuple(items))
+        }
+        "[" => {
+            *pos += 1;
+            let item = parse_ty(tokens, pos)?;
+            if token(*pos)? != ";" {
+                return None;
+            }
+            let len = token(*pos + 1)?.parse().ok()?;
+            if token(*pos + 2)? != "]" {
+                return None;
+            }
+            *pos += 3;
+            Some(Ty::Array(Box::new(item), len))
+        }
+        _ => {
+            // Keep the last segment of a path such as `alloc::string::String`
+            let mut name = token(*pos)?.to_string();
+            *pos += 1;
+            while token(*pos) == Some(":") && token(*pos + 1) == Some(":") {
+                name = token(*pos + 2)?.to_string();
+                *pos += 3;
+            }
+
+            let mut args = Vec::new();
+            if token(*pos) == Some("<") {
+                *pos += 1;
+                while token(*pos)? != ">" {
+                    if token(*pos)?.starts_with('\'') {
+                        *pos += 1;
+                    } else {
+                        args.push(parse_ty(tokens, pos)?);
+                    }
+                    if token(*pos)? == "," {
+                        *pos += 1;
+                    }
+                }
+                *pos += 1;
+            }
+            Some(Ty::Named(name, args))
+        }
+    }
+}
+
+/// SplitMix64, enough for exploratory inputs and reproducible from a seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// An unsigned integer of `bits` bits, one time in four at the edges of
+    /// its range, where overflow and boundary bugs live.
+    fn unsigned(&mut self, bits: u32) -> u64 {
+        let max = u64::MAX >> (64 - bits);
+        match self.below(8) {
+            0 => 0,
+            1 => max,
+            _ => self.next() & max,
+        }
+    }
+
+    fn signed(&mut self, bits: u32) -> i64 {
+        let shift = 64 - bits;
+        match self.below(8) {
+            0 => 0,
+            1 => i64::MIN >> shift,
+            2 => i64::MAX >> shift,
+            _ => ((self.next() << shift) as i64) >> shift,
+        }
+    }
+
+    fn string(&mut self) -> String {
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        (0..self.below(MAX_LEN + 1))
+            .map(|_| char::from(CHARS[self.below(CHARS.len() as u64) as usize]))
+            .collect()
+    }
+
+    /// A random value of `ty`, or the name of the type that cannot be
+    /// generated.
+    fn value(&mut self, ty: &Ty) -> std::result::Result<Value, String> {
+        let value = match ty {
+            Ty::Tuple(items) if items.is_empty() => Value::Null,
+            Ty::Tuple(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.value(item))
+                    .collect::<std::result::Result<_, _>>()?,
+            ),
+            Ty::Array(item, len) => Value::Array(
+                (0..*len)
+                    .map(|_| self.value(item))
+                    .collect::<std::result::Result<_, _>>()?,
+            ),
+            Ty::Named(name, args) => match (name.as_str(), args.as_slice()) {
+                ("bool", []) => Value::Bool(self.below(2) == 1),
+                ("u8", []) => self.unsigned(8).into(),
+                ("u16", []) => self.unsigned(16).into(),
+                ("u32", []) => self.unsigned(32).into(),
+                // Wider integers stay within `u64`, which JSON numbers carry
+                ("u64" | "usize" | "u128", []) => self.unsigned(64).into(),
+                ("i8", []) => self.signed(8).into(),
+                ("i16", []) => self.signed(16).into(),
+                ("i32", []) => self.signed(32).into(),
+                ("i64" | "isize" | "i128", []) => self.signed(64).into(),
+                ("String" | "str", []) => Value::String(self.string()),
+                ("char", []) => Value::String(char::from(b'a' + self.below(26) as u8).to_string()),
+                ("Option", [item]) => {
+                    if self.below(2) == 0 {
+                        Value::Null
+                    } else {
+                        self.value(item)?
+                    }
+                }
+                ("Box", [item]) => self.value(item)?,
+                ("Vec" | "VecDeque" | "BTreeSet", [item]) => Value::Array(
+                    (0..self.below(MAX_ITEMS + 1))
+                        .map(|_| self.value(item))
+                        .collect::<std::result::Result<_, _>>()?,
+                ),
+                ("BTreeMap", [key, item]) => {
+                    let mut map = Map::new();
+                    for _ in 0..self.below(MAX_ITEMS + 1) {
+                        let key = match self.value(key)? {
+                            Value::String(key) => key,
+                            key @ (Value::Number(_) | Value::Bool(_)) => key.to_string(),
+                            _ => return Err(display(ty)),
+                        };
+                        map.insert(key, self.value(item)?);
+                    }
+                    Value::Object(map)
+                }
+                _ => return Err(display(ty)),
+            },
+        };
+        Ok(value)
+    }
+}
+
+/// A parsed type written back in Rust syntax, for error messages.
+fn display(ty: &Ty) -> String {
+    let list = |items: &[Ty]| items.iter().map(display).collect::<Vec<_>>().join(", ");
+    match ty {
+        Ty::Named(name, args) if args.is_empty() => name.clone(),
+        Ty::Named(name, args) => format!("{name}<{}>", list(args)),
+        Ty::Tuple(items) => format!("({})", list(items)),
+        Ty::Array(item, len) => format!("[{}; {len}]", display(item)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_schema_type_names() {
+        assert_eq!(
+            parse("alloc :: vec :: Vec < (u64 , String) >"),
+            Some(Ty::Named(
+                "Vec".to_string(),
+                vec![Ty::Tuple(vec![
+                    Ty::Named("u64".to_string(), vec![]),
+                    Ty::Named("String".to_string(), vec![]),
+                ])]
+            ))
+        );
+        assert_eq!(
+            parse("[u8 ; 32]"),
+            Some(Ty::Array(Box::new(Ty::Named("u8".to_string(), vec![])), 32))
+        );
+        assert_eq!(parse("Vec < u8"), None);
+    }
+
+    #[test]
+    fn generates_reproducible_inputs() {
+        let schema = json!({
+            "functions": [
+                { "name": "set", "inputs": [{ "name": "value", "type": "u8" }] },
+                {
+                    "name": "add",
+                    "inputs": [
+                        { "name": "labels", "type": "Vec < String >" },
+                        { "name": "owner", "type": "Option < [u16 ; 2] >" },
+                    ],
+                },
+                { "name": "reset", "inputs": [] },
+                { "name": "transfer", "inputs": [{ "name": "to", "type": "PublicKey" }] },
+            ]
+        });
+
+        let (input, seed) = generate(&schema, "add", Some(7)).expect("supported types");
+        assert_eq!(seed, 7);
+        assert_eq!(generate(&schema, "add", Some(7)).unwrap().0, input);
+        let [labels, owner] = input.as_array().expect("two parameters").as_slice() else {
+            panic!("expected two parameters: {input}");
+        };
+        assert!(labels.as_array().is_some_and(|labels| labels.len() <= 4));
+        assert!(owner.is_null() || owner.as_array().is_some_and(|owner| owner.len() == 2));
+
+        let (value, _) = generate(&schema, "set", Some(1)).unwrap();
+        assert!(value.as_u64().is_some_and(|value| value <= 255));
+        assert_eq!(generate(&schema, "reset", None).unwrap().0, Value::Null);
+
+        let err = generate(&schema, "transfer", Some(1)).expect_err("no layout for PublicKey");
+        assert!(err.to_string().contains("`PublicKey`"), "{err}");
+        assert!(generate(&schema, "missing", Some(1)).is_err());
+    }
+}
@@ -2,6 +2,9 @@
 pub enum TemplateKind {
     Counter,
     Empty,
+    Multisig,
+    Token,
+    Nft,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +17,13 @@ pub struct TemplateFiles {
     pub makefile: &'static str,
 }
 
+/// Files of the companion `<name>-types` crate scaffolded by `new --lib-types`.
+#[derive(Debug, Clone, Copy)]
+pub struct TypesFiles {
+    pub cargo_toml: &'static str,
+    pub lib_rs: &'static str,
+}
+
 const COUNTER_CARGO_TOML: &str = include_str!("../../../contract-template/Cargo.toml");
 const COUNTER_LIB_RS: &str = include_str!("../../../contract-template/src/lib.rs");
 const COUNTER_TEST_RS: &str = include_str!("../../../contract-template/tests/contract.rs");
@@ -21,6 +31,21 @@ const COUNTER_RUST_TOOLCHAIN_TOML: &str = include_str!("../../../rust-toolchain.
 const COUNTER_GITIGNORE: &str = include_str!("../../../contract-template/.gitignore");
 const COUNTER_MAKEFILE: &str = include_str!("../../../contract-template/Makefile");
 
+const MULTISIG_CARGO_TOML: &str = include_str!("multisig/manifest.toml");
+const MULTISIG_LIB_RS: &str = include_str!("multisig/lib.rs");
+const MULTISIG_TEST_RS: &str = include_str!("multisig/contract.rs");
+
+const TOKEN_CARGO_TOML: &str = include_str!("token/manifest.toml");
+const TOKEN_LIB_RS: &str = include_str!("token/lib.rs");
+const TOKEN_TEST_RS: &str = include_str!("token/contract.rs");
+
+const NFT_CARGO_TOML: &str = include_str!("nft/manifest.toml");
+const NFT_LIB_RS: &str = include_str!("nft/lib.rs");
+const NFT_TEST_RS: &str = include_str!("nft/contract.rs");
+
+const TYPES_CARGO_TOML: &str = include_str!("types/manifest.toml");
+const TYPES_LIB_RS: &str = include_str!("types/lib.rs");
+
 const EMPTY_LIB_RS: &str = r#"//! Minimal contract template for `#[contract]`.
 
 #![no_std]
@@ -51,14 +76,27 @@ const EMPTY_TEST_RS: &str = r#"//! Contract deployment and integration tests.
 use dusk_core::abi::ContractId;
 use dusk_vm::{ContractData, VM};
 
-const CONTRACT_BYTECODE: &[u8] =
-    include_bytes!("../target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm");
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm"
+    ),
+};
 
 const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
 const CHAIN_ID: u8 = 1;
 const GAS_LIMIT: u64 = u64::MAX;
 const OWNER: [u8; 32] = [0; 32];
 
+fn contract_bytecode() -> Vec<u8> {
+    std::fs::read(CONTRACT_WASM).unwrap_or_else(|err| {
+        panic!("reading {CONTRACT_WASM} should succeed (build the contract first): {err}")
+    })
+}
+
 #[test]
 fn test_contract_deploys() {
     let vm = VM::ephemeral().expect("creating ephemeral VM should succeed");
@@ -66,7 +104,7 @@ fn test_contract_deploys() {
 
     let deployed_id = session
         .deploy(
-            CONTRACT_BYTECODE,
+            &contract_bytecode(),
             ContractData::builder()
                 .owner(OWNER)
                 .contract_id(CONTRACT_ID),
@@ -96,5 +134,36 @@ pub fn files(template: TemplateKind) -> TemplateFiles {
             gitignore: COUNTER_GITIGNORE,
             makefile: COUNTER_MAKEFILE,
         },
+        TemplateKind::Multisig => TemplateFiles {
+            cargo_toml: MULTISIG_CARGO_TOML,
+            lib_rs: MULTISIG_LIB_RS,
+            test_rs: MULTISIG_TEST_RS,
+            rust_toolchain_toml: COUNTER_RUST_TOOLCHAIN_TOML,
+            gitignore: COUNTER_GITIGNORE,
+            makefile: COUNTER_MAKEFILE,
+        },
+        TemplateKind::Token => TemplateFiles {
+            cargo_toml: TOKEN_CARGO_TOML,
+            lib_rs: TOKEN_LIB_RS,
+            test_rs: TOKEN_TEST_RS,
+            rust_toolchain_toml: COUNTER_RUST_TOOLCHAIN_TOML,
+            gitignore: COUNTER_GITIGNORE,
+            makefile: COUNTER_MAKEFILE,
+        },
+        TemplateKind::Nft => TemplateFiles {
+            cargo_toml: NFT_CARGO_TOML,
+            lib_rs: NFT_LIB_RS,
+            test_rs: NFT_TEST_RS,
+            rust_toolchain_toml: COUNTER_RUST_TOOLCHAIN_TOML,
+            gitignore: COUNTER_GITIGNORE,
+            makefile: COUNTER_MAKEFILE,
+        },
+    }
+}
+
+pub fn types_files() -> TypesFiles {
+    TypesFiles {
+        cargo_toml: TYPES_CARGO_TOML,
+        lib_rs: TYPES_LIB_RS,
     }
 }
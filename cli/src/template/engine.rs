@@ -1,4 +1,4 @@
-use super::embedded::{TemplateKind, files};
+use super::embedded::{TemplateKind, files, types_files};
 use crate::error::{CliError, Result};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,6 +18,15 @@ pub struct RenderedTemplate {
     pub makefile: String,
 }
 
+/// Directory of the companion types crate inside the project.
+pub const TYPES_DIR: &str = "types";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTypes {
+    pub cargo_toml: String,
+    pub lib_rs: String,
+}
+
 pub fn validate_contract_name(name: &str) -> Result<ContractName> {
     if name.is_empty() {
         return Err(CliError::InvalidContractName {
@@ -85,6 +94,37 @@ pub fn render_template(template: TemplateKind, name: &ContractName) -> RenderedT
     }
 }
 
+/// Render the companion `<name>-types` crate.
+pub fn render_types(name: &ContractName) -> RenderedTypes {
+    let template = types_files();
+
+    RenderedTypes {
+        cargo_toml: apply_common_replacements(template.cargo_toml, name),
+        lib_rs: apply_common_replacements(template.lib_rs, name),
+    }
+}
+
+/// Make a rendered contract manifest depend on the companion types crate,
+/// enabling its `serde` feature in data-driver builds.
+pub fn add_types_dependency(cargo_toml: &str, name: &ContractName) -> String {
+    let dependency = format!("{}-types = {{ path = \"{TYPES_DIR}\" }}", name.kebab);
+    let mut out = String::with_capacity(cargo_toml.len() + 3 * dependency.len());
+
+    for line in cargo_toml.lines() {
+        out.push_str(line);
+        out.push('\n');
+
+        if line.starts_with("dusk-forge = ") || line == "[dev-dependencies]" {
+            out.push_str(&dependency);
+            out.push('\n');
+        } else if line.trim() == "\"dusk-core/serde\"," {
+            out.push_str(&format!("  \"{}-types/serde\",\n", name.kebab));
+        }
+    }
+
+    out
+}
+
 fn apply_test_replacements(content: &str, name: &ContractName) -> String {
     let with_wasm_name =
         content.replace("YOUR_CONTRACT_NAME.wasm", &format!("{}.wasm", name.module));
@@ -180,7 +220,11 @@ mod tests {
         assert!(rendered.cargo_toml.contains("name = \"bridge-test\""));
         assert!(rendered.lib_rs.contains("mod bridge_test"));
         assert!(rendered.lib_rs.contains("pub struct BridgeTest"));
-        assert!(rendered.test_rs.contains("release/bridge_test.wasm"));
+        assert!(
+            rendered
+                .test_rs
+                .contains("option_env!(\"DUSK_FORGE_CONTRACT_WASM\")")
+        );
         assert!(!rendered.test_rs.contains("YOUR_CONTRACT_NAME"));
     }
 
@@ -194,6 +238,99 @@ mod tests {
         assert!(!rendered.lib_rs.contains("CountChanged"));
     }
 
+    #[test]
+    fn renders_multisig_template_replacements() {
+        let name = validate_contract_name("team-vault").expect("valid");
+        let rendered = render_template(TemplateKind::Multisig, &name);
+
+        assert!(rendered.cargo_toml.contains("name = \"team-vault\""));
+        assert!(rendered.cargo_toml.contains("rand = "));
+        assert!(rendered.lib_rs.contains("mod team_vault"));
+        assert!(rendered.lib_rs.contains("pub struct TeamVault"));
+        assert!(rendered.lib_rs.contains("impl Signers for TeamVault"));
+        assert!(rendered.lib_rs.contains("abi::self_id().to_bytes()"));
+        assert!(rendered.lib_rs.contains("abi::chain_id()"));
+        assert!(
+            rendered
+                .test_rs
+                .contains("option_env!(\"DUSK_FORGE_CONTRACT_WASM\")")
+        );
+        assert!(!rendered.lib_rs.contains("YOUR_"));
+        assert!(!rendered.test_rs.contains("YOUR_"));
+    }
+
+    #[test]
+    fn renders_token_template_replacements() {
+        let name = validate_contract_name("reward-token").expect("valid");
+        let rendered = render_template(TemplateKind::Token, &name);
+
+        assert!(rendered.cargo_toml.contains("name = \"reward-token\""));
+        assert!(rendered.cargo_toml.contains("dusk-forge-standards/abi"));
+        assert!(rendered.lib_rs.contains("mod reward_token"));
+        assert!(
+            rendered
+                .lib_rs
+                .contains("impl FungibleToken for RewardToken")
+        );
+        assert!(
+            rendered
+                .test_rs
+                .contains("use dusk_forge_standards::token::Account;")
+        );
+        assert!(!rendered.lib_rs.contains("YOUR_"));
+        assert!(!rendered.test_rs.contains("YOUR_"));
+    }
+
+    #[test]
+    fn renders_nft_template_replacements() {
+        let name = validate_contract_name("art-collection").expect("valid");
+        let rendered = render_template(TemplateKind::Nft, &name);
+
+        assert!(rendered.cargo_toml.contains("name = \"art-collection\""));
+        assert!(rendered.lib_rs.contains("mod art_collection"));
+        assert!(
+            rendered
+                .lib_rs
+                .contains("impl NonFungibleToken for ArtCollection")
+        );
+        assert!(
+            rendered
+                .lib_rs
+                .contains("#[contract(feeds = \"(TokenId, TokenMetadata)\")]")
+        );
+        assert!(!rendered.lib_rs.contains("YOUR_"));
+        assert!(!rendered.test_rs.contains("YOUR_"));
+    }
+
+    #[test]
+    fn renders_types_crate_and_dependency() {
+        let name = validate_contract_name("bridge-test").expect("valid");
+        let types = render_types(&name);
+
+        assert!(types.cargo_toml.contains("name = \"bridge-test-types\""));
+        assert!(
+            types
+                .cargo_toml
+                .contains("serde = [\"dep:serde\", \"dusk-core/serde\"]")
+        );
+        assert!(types.lib_rs.contains("#![no_std]"));
+        assert!(types.lib_rs.contains("#[archive_attr(derive(CheckBytes))]"));
+        assert!(!types.lib_rs.contains("YOUR_"));
+
+        for kind in [TemplateKind::Counter, TemplateKind::Token] {
+            let rendered = render_template(kind, &name);
+            let cargo_toml = add_types_dependency(&rendered.cargo_toml, &name);
+
+            assert_eq!(
+                cargo_toml
+                    .matches("bridge-test-types = { path = \"types\" }")
+                    .count(),
+                2
+            );
+            assert!(cargo_toml.contains("  \"bridge-test-types/serde\",\n"));
+        }
+    }
+
     #[test]
     fn replace_identifier_only_replaces_standalone_identifiers() {
         let rendered = replace_identifier(
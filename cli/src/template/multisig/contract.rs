@@ -0,0 +1,228 @@
+//! Contract deployment and integration tests.
+
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::{PublicKey, SecretKey, Signature};
+use dusk_vm::{ContractData, Session, VM};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm"
+    ),
+};
+const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
+const OTHER_CONTRACT_ID: ContractId = ContractId::from_bytes([2; 32]);
+const CHAIN_ID: u8 = 1;
+const GAS_LIMIT: u64 = u64::MAX;
+const OWNER: [u8; 32] = [0; 32];
+const THRESHOLD: u32 = 2;
+
+fn contract_bytecode() -> Vec<u8> {
+    std::fs::read(CONTRACT_WASM).unwrap_or_else(|err| {
+        panic!("reading {CONTRACT_WASM} should succeed (build the contract first): {err}")
+    })
+}
+
+struct TestHarness {
+    _vm: VM,
+    session: Session,
+    keys: Vec<SecretKey>,
+    /// Identifier the next proposal gets, which its signature must cover.
+    next_id: u64,
+}
+
+impl TestHarness {
+    fn deploy(&mut self, contract: ContractId) {
+        let signers: Vec<PublicKey> = self.keys.iter().map(PublicKey::from).collect();
+        let deployed_id = self
+            .session
+            .deploy(
+                &contract_bytecode(),
+                ContractData::builder()
+                    .owner(OWNER)
+                    .init_arg(&(signers, THRESHOLD))
+                    .contract_id(contract),
+                GAS_LIMIT,
+            )
+            .expect("deploying contract should succeed");
+
+        assert_eq!(deployed_id, contract);
+    }
+
+    fn public_key(&self, signer: usize) -> PublicKey {
+        PublicKey::from(&self.keys[signer])
+    }
+
+    fn propose(&mut self, signer: usize, description: &str) -> u64 {
+        let pk = self.public_key(signer);
+        let id = self.next_id;
+        self.next_id += 1;
+        let message = signed_message(b"propose", CONTRACT_ID, id, description.as_bytes());
+        let signature = self.keys[signer].sign(&message);
+        self.session
+            .call::<_, u64>(
+                CONTRACT_ID,
+                "propose",
+                &(pk, signature, description.to_string()),
+                GAS_LIMIT,
+            )
+            .expect("propose call should succeed")
+            .data
+    }
+
+    fn approve(&mut self, id: u64, signer: usize) {
+        let pk = self.public_key(signer);
+        let message = signed_message(b"approve", CONTRACT_ID, id, &[]);
+        let signature: Signature = self.keys[signer].sign(&message);
+        self.session
+            .call::<_, ()>(CONTRACT_ID, "approve", &(id, pk, signature), GAS_LIMIT)
+            .expect("approve call should succeed");
+    }
+
+    fn approvals(&mut self, id: u64) -> u32 {
+        self.session
+            .call::<_, u32>(CONTRACT_ID, "approvals", &id, GAS_LIMIT)
+            .expect("approvals call should succeed")
+            .data
+    }
+
+}
+
+/// Message the contract expects signed for `action` on proposal `id`.
+fn signed_message(action: &[u8], contract: ContractId, id: u64, payload: &[u8]) -> Vec<u8> {
+    let mut message = action.to_vec();
+    message.push(CHAIN_ID);
+    message.extend_from_slice(&contract.to_bytes());
+    message.extend_from_slice(&id.to_le_bytes());
+    message.extend_from_slice(payload);
+    message
+}
+
+fn deploy_multisig() -> TestHarness {
+    let vm = VM::ephemeral().expect("creating ephemeral VM should succeed");
+    let session = vm.genesis_session(CHAIN_ID);
+
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let keys: Vec<SecretKey> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let mut harness = TestHarness {
+        _vm: vm,
+        session,
+        keys,
+        next_id: 0,
+    };
+    harness.deploy(CONTRACT_ID);
+    harness
+}
+
+#[test]
+fn test_contract_deploys_with_signers() {
+    let mut harness = deploy_multisig();
+
+    let signers = harness
+        .session
+        .call::<_, Vec<PublicKey>>(CONTRACT_ID, "signers", &(), GAS_LIMIT)
+        .expect("signers call should succeed")
+        .data;
+    let threshold = harness
+        .session
+        .call::<_, u32>(CONTRACT_ID, "threshold", &(), GAS_LIMIT)
+        .expect("threshold call should succeed")
+        .data;
+
+    assert_eq!(signers.len(), 3);
+    assert_eq!(threshold, THRESHOLD);
+}
+
+#[test]
+fn test_proposal_executes_at_threshold() {
+    let mut harness = deploy_multisig();
+
+    let id = harness.propose(0, "upgrade the treasury");
+    assert_eq!(harness.approvals(id), 1);
+
+    // Below the threshold, execution is refused
+    harness
+        .session
+        .call::<_, ()>(CONTRACT_ID, "execute", &id, GAS_LIMIT)
+        .expect_err("execute below the threshold should fail");
+
+    // Approving twice does not count twice
+    harness.approve(id, 0);
+    assert_eq!(harness.approvals(id), 1);
+
+    harness.approve(id, 1);
+    assert_eq!(harness.approvals(id), 2);
+
+    harness
+        .session
+        .call::<_, ()>(CONTRACT_ID, "execute", &id, GAS_LIMIT)
+        .expect("execute at the threshold should succeed");
+
+    let (_, _, executed) = harness
+        .session
+        .call::<_, Option<(String, Vec<PublicKey>, bool)>>(CONTRACT_ID, "proposal", &id, GAS_LIMIT)
+        .expect("proposal call should succeed")
+        .data
+        .expect("proposal should exist");
+    assert!(executed);
+}
+
+#[test]
+fn test_approval_requires_valid_signature() {
+    let mut harness = deploy_multisig();
+    let id = harness.propose(0, "rotate keys");
+
+    // Signed by a different key than the one claimed
+    let pk = harness.public_key(1);
+    let forged = harness.keys[2].sign(&signed_message(b"approve", CONTRACT_ID, id, &[]));
+    harness
+        .session
+        .call::<_, ()>(CONTRACT_ID, "approve", &(id, pk, forged), GAS_LIMIT)
+        .expect_err("approval with a forged signature should fail");
+
+    assert_eq!(harness.approvals(id), 1);
+}
+
+#[test]
+fn test_approval_from_another_deployment_is_rejected() {
+    let mut harness = deploy_multisig();
+    harness.deploy(OTHER_CONTRACT_ID);
+    let id = harness.propose(0, "pay the auditors");
+
+    // A valid approval of the same proposal id on another contract
+    let pk = harness.public_key(1);
+    let message = signed_message(b"approve", OTHER_CONTRACT_ID, id, &[]);
+    let signature = harness.keys[1].sign(&message);
+
+    harness
+        .session
+        .call::<_, ()>(CONTRACT_ID, "approve", &(id, pk, signature), GAS_LIMIT)
+        .expect_err("approval signed for another contract should fail");
+    assert_eq!(harness.approvals(id), 1);
+}
+
+#[test]
+fn test_proposal_signature_cannot_be_replayed() {
+    let mut harness = deploy_multisig();
+
+    let pk = harness.public_key(0);
+    let description = "mint once".to_string();
+    let message = signed_message(b"propose", CONTRACT_ID, 0, description.as_bytes());
+    let signature = harness.keys[0].sign(&message);
+    let args = (pk, signature, description);
+
+    harness
+        .session
+        .call::<_, u64>(CONTRACT_ID, "propose", &args, GAS_LIMIT)
+        .expect("first propose call should succeed");
+    harness
+        .session
+        .call::<_, u64>(CONTRACT_ID, "propose", &args, GAS_LIMIT)
+        .expect_err("replaying the propose signature should fail");
+}
@@ -0,0 +1,180 @@
+//! Multisig contract template for `#[contract]`.
+//!
+//! A set of signers, fixed at deployment, approves proposals with BLS
+//! signatures. A proposal can be executed once the number of approvals
+//! reaches the threshold. Signed messages include the chain id, the
+//! contract id and the proposal id, so a signature is only valid for one
+//! proposal of one deployment. The template shows:
+//! - Multi-parameter `init`
+//! - Map-backed state with owned and borrowed returns
+//! - Signature checks through `abi::verify_bls`
+//! - Streaming proposals to the host with `abi::feed`
+//! - Exposing trait methods with `#[contract(expose = [...])]`
+
+#![no_std]
+#![cfg(target_family = "wasm")]
+
+#[cfg(not(any(feature = "contract", feature = "data-driver")))]
+compile_error!("Enable either 'contract' or 'data-driver' feature for WASM builds");
+
+extern crate alloc;
+
+/// Multisig contract with threshold approvals.
+#[dusk_forge::contract]
+mod YOUR_MODULE_NAME {
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use dusk_core::abi;
+    use dusk_core::signatures::bls::{PublicKey, Signature};
+
+    /// Read access to the signer set.
+    pub trait Signers {
+        /// Returns the public keys allowed to approve proposals.
+        fn signers(&self) -> Vec<PublicKey>;
+
+        /// Returns the number of approvals required to execute a proposal.
+        fn threshold(&self) -> u32;
+    }
+
+    /// Contract state.
+    pub struct YOUR_STRUCT_NAME {
+        /// Public keys allowed to propose and approve.
+        signers: Vec<PublicKey>,
+        /// Number of approvals required to execute a proposal.
+        threshold: u32,
+        /// Identifier of the next proposal.
+        next_id: u64,
+        /// Proposals by identifier: description, approving signers, and
+        /// whether the proposal was executed.
+        proposals: BTreeMap<u64, (String, Vec<PublicKey>, bool)>,
+    }
+
+    impl YOUR_STRUCT_NAME {
+        /// Initialize an empty multisig.
+        pub const fn new() -> Self {
+            Self {
+                signers: Vec::new(),
+                threshold: 0,
+                next_id: 0,
+                proposals: BTreeMap::new(),
+            }
+        }
+
+        /// Set the signers and the approval threshold at deployment.
+        #[contract(no_event)]
+        pub fn init(&mut self, signers: Vec<PublicKey>, threshold: u32) {
+            assert!(self.signers.is_empty(), "already initialized");
+            assert!(threshold > 0, "threshold must be positive");
+            assert!(
+                threshold as usize <= signers.len(),
+                "threshold exceeds the number of signers"
+            );
+            self.signers = signers;
+            self.threshold = threshold;
+        }
+
+        /// Create a proposal signed by one of the signers, counting as its
+        /// first approval. The signature covers the identifier the proposal
+        /// will get, which is returned.
+        pub fn propose(
+            &mut self,
+            signer: PublicKey,
+            signature: Signature,
+            description: String,
+        ) -> u64 {
+            let id = self.next_id;
+            let message = signed_message(b"propose", id, description.as_bytes());
+            self.authorize(signer, signature, message);
+
+            self.next_id += 1;
+            self.proposals
+                .insert(id, (description.clone(), alloc::vec![signer], false));
+
+            abi::emit("proposal_created", (id, description));
+            id
+        }
+
+        /// Approve a pending proposal. Approving twice has no effect.
+        pub fn approve(&mut self, id: u64, signer: PublicKey, signature: Signature) {
+            self.authorize(signer, signature, signed_message(b"approve", id, &[]));
+
+            let (_, approvals, executed) = self.proposals.get_mut(&id).expect("unknown proposal");
+            assert!(!*executed, "proposal already executed");
+            if !approvals.contains(&signer) {
+                approvals.push(signer);
+            }
+
+            abi::emit("proposal_approved", (id, signer));
+        }
+
+        /// Execute a proposal that reached the approval threshold.
+        pub fn execute(&mut self, id: u64) {
+            let threshold = self.threshold as usize;
+            let (_, approvals, executed) = self.proposals.get_mut(&id).expect("unknown proposal");
+            assert!(!*executed, "proposal already executed");
+            assert!(approvals.len() >= threshold, "not enough approvals");
+            *executed = true;
+
+            // Act on the proposal here, e.g. call another contract.
+
+            abi::emit("proposal_executed", id);
+        }
+
+        /// Get a proposal by identifier.
+        pub fn proposal(&self, id: u64) -> Option<(String, Vec<PublicKey>, bool)> {
+            self.proposals.get(&id).cloned()
+        }
+
+        /// Get the number of approvals of a proposal.
+        pub fn approvals(&self, id: u64) -> u32 {
+            self.proposals
+                .get(&id)
+                .map_or(0, |(_, approvals, _)| approvals.len() as u32)
+        }
+
+        /// Stream all proposals to the host.
+        #[contract(feeds = "(u64, (String, Vec<PublicKey>, bool))")]
+        pub fn proposals(&self) {
+            for (id, proposal) in &self.proposals {
+                abi::feed((*id, proposal.clone()));
+            }
+        }
+
+        /// Panic unless `signer` is a signer and `signature` signs `message`.
+        fn authorize(&self, signer: PublicKey, signature: Signature, message: Vec<u8>) {
+            assert!(self.signers.contains(&signer), "not a signer");
+            assert!(
+                abi::verify_bls(message, signer, signature),
+                "invalid signature"
+            );
+        }
+    }
+
+    #[contract(expose = [signers, threshold])]
+    impl Signers for YOUR_STRUCT_NAME {
+        fn signers(&self) -> Vec<PublicKey> {
+            self.signers.clone()
+        }
+
+        fn threshold(&self) -> u32 {
+            self.threshold
+        }
+    }
+
+    /// Message a signer signs to `action` proposal `id`: the action, the
+    /// chain id, this contract's id, the proposal id and `payload`.
+    ///
+    /// Binding the chain and contract ids keeps a signature from being
+    /// replayed on another deployment. Proposal ids are never reused, so
+    /// the id is the per-proposal nonce within this one.
+    fn signed_message(action: &[u8], id: u64, payload: &[u8]) -> Vec<u8> {
+        let mut message = action.to_vec();
+        message.push(abi::chain_id());
+        message.extend_from_slice(&abi::self_id().to_bytes());
+        message.extend_from_slice(&id.to_le_bytes());
+        message.extend_from_slice(payload);
+        message
+    }
+}
@@ -0,0 +1,119 @@
+//! Contract deployment and integration tests.
+
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::{PublicKey, SecretKey};
+use dusk_forge_standards::nft::{Account, TokenMetadata};
+use dusk_vm::{ContractData, Session, VM};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm"
+    ),
+};
+const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
+const CHAIN_ID: u8 = 1;
+const GAS_LIMIT: u64 = u64::MAX;
+const OWNER: [u8; 32] = [0; 32];
+
+fn contract_bytecode() -> Vec<u8> {
+    std::fs::read(CONTRACT_WASM).unwrap_or_else(|err| {
+        panic!("reading {CONTRACT_WASM} should succeed (build the contract first): {err}")
+    })
+}
+
+struct TestHarness {
+    _vm: VM,
+    session: Session,
+    minter: Account,
+}
+
+fn deploy_collection() -> TestHarness {
+    let vm = VM::ephemeral().expect("creating ephemeral VM should succeed");
+    let mut session = vm.genesis_session(CHAIN_ID);
+
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let minter = Account::from(PublicKey::from(&SecretKey::random(&mut rng)));
+
+    let deployed_id = session
+        .deploy(
+            &contract_bytecode(),
+            ContractData::builder()
+                .owner(OWNER)
+                .init_arg(&(
+                    String::from("Example Collection"),
+                    String::from("EXC"),
+                    minter,
+                ))
+                .contract_id(CONTRACT_ID),
+            GAS_LIMIT,
+        )
+        .expect("deploying contract should succeed");
+
+    assert_eq!(deployed_id, CONTRACT_ID);
+
+    TestHarness {
+        _vm: vm,
+        session,
+        minter,
+    }
+}
+
+#[test]
+fn test_contract_deploys_empty() {
+    let mut harness = deploy_collection();
+
+    let symbol = harness
+        .session
+        .call::<_, String>(CONTRACT_ID, "symbol", &(), GAS_LIMIT)
+        .expect("symbol call should succeed")
+        .data;
+    let total_supply = harness
+        .session
+        .call::<_, u64>(CONTRACT_ID, "total_supply", &(), GAS_LIMIT)
+        .expect("total_supply call should succeed")
+        .data;
+    let owner = harness
+        .session
+        .call::<_, Option<Account>>(CONTRACT_ID, "owner_of", &1u64, GAS_LIMIT)
+        .expect("owner_of call should succeed")
+        .data;
+
+    assert_eq!(symbol, "EXC");
+    assert_eq!(total_supply, 0);
+    assert_eq!(owner, None);
+}
+
+#[test]
+fn test_mint_requires_the_minter() {
+    let mut harness = deploy_collection();
+    let metadata = TokenMetadata {
+        name: String::from("First"),
+        uri: String::from("ipfs://first"),
+    };
+
+    // A direct session call has no calling contract or public sender to
+    // check against the minter; mints are sent in transactions through the
+    // transfer contract
+    harness
+        .session
+        .call::<_, ()>(
+            CONTRACT_ID,
+            "mint",
+            &(harness.minter, 1u64, metadata),
+            GAS_LIMIT,
+        )
+        .expect_err("mint without a sender should fail");
+
+    let balance = harness
+        .session
+        .call::<_, u64>(CONTRACT_ID, "balance_of", &harness.minter, GAS_LIMIT)
+        .expect("balance_of call should succeed")
+        .data;
+    assert_eq!(balance, 0);
+}
@@ -0,0 +1,142 @@
+//! Non-fungible token contract template for `#[contract]`.
+//!
+//! Implements the non-fungible token standard of `dusk-forge-standards`, so
+//! wallets, explorers and marketplaces can use the collection through the
+//! shared ABI. The template shows:
+//! - Exposing a standard trait with `#[contract(expose = [...])]`
+//! - Registering the events and the feed of the trait's default methods
+//! - Restricting minting to an account set in `init`
+
+#![no_std]
+#![cfg(target_family = "wasm")]
+
+#[cfg(not(any(feature = "contract", feature = "data-driver")))]
+compile_error!("Enable either 'contract' or 'data-driver' feature for WASM builds");
+
+extern crate alloc;
+
+/// Non-fungible token contract.
+#[dusk_forge::contract]
+mod YOUR_MODULE_NAME {
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+
+    use dusk_forge_standards::nft::{
+        Account, NonFungibleToken, TokenId, TokenMetadata, events,
+    };
+
+    /// Contract state.
+    pub struct YOUR_STRUCT_NAME {
+        /// Name of the collection.
+        name: String,
+        /// Symbol of the collection.
+        symbol: String,
+        /// Account allowed to mint, set at deployment.
+        minter: Option<Account>,
+        /// Owner of each token.
+        owners: BTreeMap<TokenId, Account>,
+        /// Account approved to transfer each token, besides its owner.
+        approvals: BTreeMap<TokenId, Account>,
+        /// Metadata of each token.
+        metadata: BTreeMap<TokenId, TokenMetadata>,
+    }
+
+    impl YOUR_STRUCT_NAME {
+        /// Initialize an empty collection.
+        pub const fn new() -> Self {
+            Self {
+                name: String::new(),
+                symbol: String::new(),
+                minter: None,
+                owners: BTreeMap::new(),
+                approvals: BTreeMap::new(),
+                metadata: BTreeMap::new(),
+            }
+        }
+
+        /// Set the collection name and symbol, and the account allowed to
+        /// mint, at deployment.
+        #[contract(no_event)]
+        pub fn init(&mut self, name: String, symbol: String, minter: Account) {
+            assert!(self.minter.is_none(), "already initialized");
+            self.name = name;
+            self.symbol = symbol;
+            self.minter = Some(minter);
+        }
+
+        /// Mint a new token to `receiver`. Only the minter may call it.
+        #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+        pub fn mint(&mut self, receiver: Account, token: TokenId, metadata: TokenMetadata) {
+            assert!(self.minter == Some(Account::caller()), "not the minter");
+            NonFungibleToken::mint(self, receiver, token, metadata);
+        }
+    }
+
+    #[contract(expose = [
+        name, symbol, total_supply, balance_of, owner_of, approved,
+        token_metadata, transfer, approve, tokens_of,
+    ])]
+    impl NonFungibleToken for YOUR_STRUCT_NAME {
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        fn owner_of(&self, token: TokenId) -> Option<Account> {
+            self.owners.get(&token).copied()
+        }
+
+        fn approved(&self, token: TokenId) -> Option<Account> {
+            self.approvals.get(&token).copied()
+        }
+
+        fn token_metadata(&self, token: TokenId) -> Option<TokenMetadata> {
+            self.metadata.get(&token).cloned()
+        }
+
+        fn token_ids(&self) -> impl Iterator<Item = TokenId> + '_ {
+            self.owners.keys().copied()
+        }
+
+        fn set_owner(&mut self, token: TokenId, owner: Option<Account>) {
+            match owner {
+                Some(owner) => self.owners.insert(token, owner),
+                None => self.owners.remove(&token),
+            };
+        }
+
+        fn set_approved(&mut self, token: TokenId, spender: Option<Account>) {
+            match spender {
+                Some(spender) => self.approvals.insert(token, spender),
+                None => self.approvals.remove(&token),
+            };
+        }
+
+        fn set_token_metadata(&mut self, token: TokenId, metadata: Option<TokenMetadata>) {
+            match metadata {
+                Some(metadata) => self.metadata.insert(token, metadata),
+                None => self.metadata.remove(&token),
+            };
+        }
+
+        /// Number of tokens in the collection.
+        fn total_supply(&self) -> u64 {
+            self.owners.len() as u64
+        }
+
+        /// Transfers a token owned by, or approved to, the caller.
+        #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+        fn transfer(&mut self, receiver: Account, token: TokenId) {}
+
+        /// Allows an account to transfer one of the caller's tokens.
+        #[contract(emits = [(events::Approval::TOPIC, events::Approval)])]
+        fn approve(&mut self, spender: Option<Account>, token: TokenId) {}
+
+        /// Streams the identifier and metadata of each token of an owner.
+        #[contract(feeds = "(TokenId, TokenMetadata)")]
+        fn tokens_of(&self, owner: Account) {}
+    }
+}
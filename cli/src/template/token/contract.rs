@@ -0,0 +1,128 @@
+//! Contract deployment and integration tests.
+
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::{PublicKey, SecretKey};
+use dusk_forge_standards::token::Account;
+use dusk_vm::{ContractData, Session, VM};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm"
+    ),
+};
+const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
+const CHAIN_ID: u8 = 1;
+const GAS_LIMIT: u64 = u64::MAX;
+const OWNER: [u8; 32] = [0; 32];
+const SUPPLY: u64 = 1_000_000;
+
+fn contract_bytecode() -> Vec<u8> {
+    std::fs::read(CONTRACT_WASM).unwrap_or_else(|err| {
+        panic!("reading {CONTRACT_WASM} should succeed (build the contract first): {err}")
+    })
+}
+
+struct TestHarness {
+    _vm: VM,
+    session: Session,
+    holder: Account,
+}
+
+impl TestHarness {
+    fn balance_of(&mut self, account: Account) -> u64 {
+        self.session
+            .call::<_, u64>(CONTRACT_ID, "balance_of", &account, GAS_LIMIT)
+            .expect("balance_of call should succeed")
+            .data
+    }
+}
+
+fn deploy_token() -> TestHarness {
+    let vm = VM::ephemeral().expect("creating ephemeral VM should succeed");
+    let mut session = vm.genesis_session(CHAIN_ID);
+
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let holder = Account::from(PublicKey::from(&SecretKey::random(&mut rng)));
+
+    let deployed_id = session
+        .deploy(
+            &contract_bytecode(),
+            ContractData::builder()
+                .owner(OWNER)
+                .init_arg(&(
+                    String::from("Example Token"),
+                    String::from("EXT"),
+                    holder,
+                    SUPPLY,
+                ))
+                .contract_id(CONTRACT_ID),
+            GAS_LIMIT,
+        )
+        .expect("deploying contract should succeed");
+
+    assert_eq!(deployed_id, CONTRACT_ID);
+
+    TestHarness {
+        _vm: vm,
+        session,
+        holder,
+    }
+}
+
+#[test]
+fn test_contract_deploys_with_metadata() {
+    let mut harness = deploy_token();
+
+    let symbol = harness
+        .session
+        .call::<_, String>(CONTRACT_ID, "symbol", &(), GAS_LIMIT)
+        .expect("symbol call should succeed")
+        .data;
+    let decimals = harness
+        .session
+        .call::<_, u8>(CONTRACT_ID, "decimals", &(), GAS_LIMIT)
+        .expect("decimals call should succeed")
+        .data;
+
+    assert_eq!(symbol, "EXT");
+    assert_eq!(decimals, 9);
+}
+
+#[test]
+fn test_initial_supply_is_minted_to_holder() {
+    let mut harness = deploy_token();
+
+    let total_supply = harness
+        .session
+        .call::<_, u64>(CONTRACT_ID, "total_supply", &(), GAS_LIMIT)
+        .expect("total_supply call should succeed")
+        .data;
+    let holder = harness.holder;
+
+    assert_eq!(total_supply, SUPPLY);
+    assert_eq!(harness.balance_of(holder), SUPPLY);
+    assert_eq!(harness.balance_of(Account::from(CONTRACT_ID)), 0);
+}
+
+#[test]
+fn test_transfer_requires_a_sender() {
+    let mut harness = deploy_token();
+    let receiver = Account::from(CONTRACT_ID);
+
+    // A direct session call has no calling contract or public sender to
+    // debit; transfers are sent in transactions through the transfer
+    // contract
+    harness
+        .session
+        .call::<_, ()>(CONTRACT_ID, "transfer", &(receiver, 1u64), GAS_LIMIT)
+        .expect_err("transfer without a sender should fail");
+
+    let holder = harness.holder;
+    assert_eq!(harness.balance_of(holder), SUPPLY);
+}
@@ -0,0 +1,137 @@
+//! Fungible token contract template for `#[contract]`.
+//!
+//! Implements the fungible token standard of `dusk-forge-standards`, so
+//! wallets, explorers and other contracts can use the token through the
+//! shared ABI. The template shows:
+//! - Exposing a standard trait with `#[contract(expose = [...])]`
+//! - Registering the events of the trait's default methods with `emits`
+//! - Minting the initial supply in `init`
+
+#![no_std]
+#![cfg(target_family = "wasm")]
+
+#[cfg(not(any(feature = "contract", feature = "data-driver")))]
+compile_error!("Enable either 'contract' or 'data-driver' feature for WASM builds");
+
+extern crate alloc;
+
+/// Fungible token contract.
+#[dusk_forge::contract]
+mod YOUR_MODULE_NAME {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use dusk_forge_standards::token::{Account, FungibleToken, events};
+
+    /// Number of decimals of a displayed amount, as for DUSK.
+    const DECIMALS: u8 = 9;
+
+    /// Contract state.
+    pub struct YOUR_STRUCT_NAME {
+        /// Name of the token.
+        name: String,
+        /// Symbol of the token.
+        symbol: String,
+        /// Number of tokens in existence.
+        total_supply: u64,
+        /// Balances by account. Accounts have no ordering, so they are kept
+        /// in a list; large holder sets call for a map keyed by the account
+        /// bytes.
+        balances: Vec<(Account, u64)>,
+        /// Allowances by owner and spender.
+        allowances: Vec<(Account, Account, u64)>,
+    }
+
+    impl YOUR_STRUCT_NAME {
+        /// Initialize an empty token.
+        pub const fn new() -> Self {
+            Self {
+                name: String::new(),
+                symbol: String::new(),
+                total_supply: 0,
+                balances: Vec::new(),
+                allowances: Vec::new(),
+            }
+        }
+
+        /// Set the token name and symbol and mint the initial supply to
+        /// `owner` at deployment.
+        #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+        pub fn init(&mut self, name: String, symbol: String, owner: Account, supply: u64) {
+            assert!(self.name.is_empty(), "already initialized");
+            self.name = name;
+            self.symbol = symbol;
+            self.mint(owner, supply);
+        }
+    }
+
+    #[contract(expose = [
+        name, symbol, decimals, total_supply, balance_of, allowance,
+        transfer, approve, transfer_from,
+    ])]
+    impl FungibleToken for YOUR_STRUCT_NAME {
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        fn decimals(&self) -> u8 {
+            DECIMALS
+        }
+
+        fn total_supply(&self) -> u64 {
+            self.total_supply
+        }
+
+        fn balance_of(&self, account: Account) -> u64 {
+            self.balances
+                .iter()
+                .find(|(holder, _)| *holder == account)
+                .map_or(0, |(_, balance)| *balance)
+        }
+
+        fn allowance(&self, owner: Account, spender: Account) -> u64 {
+            self.allowances
+                .iter()
+                .find(|(o, s, _)| *o == owner && *s == spender)
+                .map_or(0, |(_, _, value)| *value)
+        }
+
+        fn set_balance(&mut self, account: Account, value: u64) {
+            match self.balances.iter_mut().find(|(holder, _)| *holder == account) {
+                Some((_, balance)) => *balance = value,
+                None => self.balances.push((account, value)),
+            }
+        }
+
+        fn set_allowance(&mut self, owner: Account, spender: Account, value: u64) {
+            match self
+                .allowances
+                .iter_mut()
+                .find(|(o, s, _)| *o == owner && *s == spender)
+            {
+                Some((_, _, allowance)) => *allowance = value,
+                None => self.allowances.push((owner, spender, value)),
+            }
+        }
+
+        fn set_total_supply(&mut self, value: u64) {
+            self.total_supply = value;
+        }
+
+        /// Transfers tokens from the caller.
+        #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+        fn transfer(&mut self, receiver: Account, value: u64) {}
+
+        /// Allows a spender to transfer the caller's tokens.
+        #[contract(emits = [(events::Approval::TOPIC, events::Approval)])]
+        fn approve(&mut self, spender: Account, value: u64) {}
+
+        /// Transfers tokens on behalf of their owner.
+        #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+        fn transfer_from(&mut self, owner: Account, receiver: Account, value: u64) {}
+    }
+}
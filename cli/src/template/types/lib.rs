@@ -0,0 +1,19 @@
+//! Types shared by the YOUR_CONTRACT_NAME contract and its clients.
+
+#![no_std]
+#![deny(missing_docs)]
+
+use bytecheck::CheckBytes;
+use dusk_core as _;
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// An example record passed to and returned by the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record {
+    /// Identifier of the record.
+    pub id: u64,
+    /// Value held by the record.
+    pub value: u64,
+}
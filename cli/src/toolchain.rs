@@ -2,8 +2,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::build_runner::wasm_opt::{self, WasmOpt};
 use crate::error::{CliError, Result};
-use crate::tools;
 
 pub const WASM_TARGET: &str = "wasm32-unknown-unknown";
 
@@ -13,19 +13,19 @@ pub struct ToolchainStatus {
     pub installed: bool,
     pub wasm_target: bool,
     pub rust_src: bool,
-    pub wasm_opt: Option<PathBuf>,
+    pub wasm_opt: Option<WasmOpt>,
 }
 
 pub fn configured_channel(project_dir: &Path) -> Result<String> {
     let toolchain_file = resolve_toolchain_file(project_dir).ok_or_else(|| {
-        CliError::Message(format!(
+        CliError::Toolchain(format!(
             "missing rust-toolchain.toml (or rust-toolchain) in {} or its parents",
             project_dir.display()
         ))
     })?;
 
     read_toolchain_channel(&toolchain_file).ok_or_else(|| {
-        CliError::Message(format!(
+        CliError::Toolchain(format!(
             "unable to read toolchain channel from {}",
             toolchain_file.display()
         ))
@@ -53,7 +53,7 @@ pub fn inspect(project_dir: &Path) -> Result<ToolchainStatus> {
         "rust-src",
     );
 
-    let wasm_opt = tools::find_in_path("wasm-opt");
+    let wasm_opt = wasm_opt::find().cloned();
 
     Ok(ToolchainStatus {
         channel,
@@ -64,25 +64,39 @@ pub fn inspect(project_dir: &Path) -> Result<ToolchainStatus> {
     })
 }
 
+/// `rustc --version` of the project's configured toolchain.
+pub fn rustc_version(project_dir: &Path) -> Result<String> {
+    let channel = configured_channel(project_dir)?;
+    let output = Command::new("rustc")
+        .args([&format!("+{channel}"), "--version"])
+        .output()?;
+    if !output.status.success() {
+        return Err(CliError::Toolchain(format!(
+            "unable to query rustc version for toolchain '{channel}'"
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 pub fn ensure_build(project_dir: &Path, needs_rust_src: bool) -> Result<ToolchainStatus> {
     let status = inspect(project_dir)?;
 
     if !status.installed {
-        return Err(CliError::Message(format!(
+        return Err(CliError::Toolchain(format!(
             "missing Rust toolchain '{}'. Install with: rustup toolchain install {}",
             status.channel, status.channel
         )));
     }
 
     if !status.wasm_target {
-        return Err(CliError::Message(format!(
+        return Err(CliError::Toolchain(format!(
             "missing {WASM_TARGET} target for toolchain '{}'. Install with: rustup target add {WASM_TARGET} --toolchain {}",
             status.channel, status.channel
         )));
     }
 
     if needs_rust_src && !status.rust_src {
-        return Err(CliError::Message(format!(
+        return Err(CliError::Toolchain(format!(
             "missing rust-src component for toolchain '{}'. Install with: rustup component add rust-src --toolchain {}",
             status.channel, status.channel
         )));
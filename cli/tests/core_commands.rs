@@ -147,7 +147,8 @@ case "$subcmd" in
     crate_name="${{crate_name//-/_}}"
     wasm_path="${{CARGO_TARGET_DIR}}/wasm32-unknown-unknown/release/${{crate_name}}.wasm"
     mkdir -p "$(dirname "$wasm_path")"
-    printf '%s\n' "$feature" > "$wasm_path"
+    # Empty WASM module: passes the post-build determinism scan
+    printf '\000asm\001\000\000\000' > "$wasm_path"
     printf 'subcmd=build toolchain=%s feature=%s target_dir=%s manifest=%s\n' \
       "$toolchain" "$feature" "${{CARGO_TARGET_DIR-}}" "$manifest" >> {log_path}
     exit 0
@@ -272,8 +273,14 @@ fn build_creates_contract_and_data_driver_artifacts() {
     let (_tmp, project) = create_project();
     let tools = FakeTools::new();
 
+    // The fake data-driver is an empty module, without the JS exports
     cargo_bin_cmd!("dusk-forge")
-        .args(["build", "--path", project.to_str().expect("utf-8 path")])
+        .args([
+            "build",
+            "--path",
+            project.to_str().expect("utf-8 path"),
+            "--skip-js-smoke",
+        ])
         .env("PATH", tools.path())
         .assert()
         .success()
@@ -291,6 +298,22 @@ fn build_creates_contract_and_data_driver_artifacts() {
             .exists()
     );
 
+    let manifest: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(project.join("target/forge-artifacts.json")).expect("read manifest"),
+    )
+    .expect("manifest is JSON");
+    assert_eq!(manifest["crate-name"], "smoke-contract");
+    assert_eq!(manifest["cargo-profile"], "release");
+    for kind in ["contract", "data-driver"] {
+        let artifact = &manifest["artifacts"][kind];
+        assert!(
+            artifact["path"]
+                .as_str()
+                .is_some_and(|p| p.ends_with("smoke_contract.wasm"))
+        );
+        assert_eq!(artifact["blake3"].as_str().map(str::len), Some(64));
+    }
+
     let log = tools.log();
     assert!(log.contains("subcmd=build toolchain=+nightly-2024-07-30 feature=contract"));
     assert!(log.contains("subcmd=build toolchain=+nightly-2024-07-30 feature=data-driver-js"));
@@ -298,6 +321,26 @@ fn build_creates_contract_and_data_driver_artifacts() {
     assert!(log.contains("target/data-driver"));
 }
 
+#[test]
+fn build_rejects_data_driver_without_js_exports() {
+    let (_tmp, project) = create_project();
+    let tools = FakeTools::new();
+
+    cargo_bin_cmd!("dusk-forge")
+        .args([
+            "build",
+            "data-driver",
+            "--path",
+            project.to_str().expect("utf-8 path"),
+        ])
+        .env("PATH", tools.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is built with `data-driver-js` but does not export memory, init, get_schema",
+        ));
+}
+
 #[test]
 fn test_builds_contract_and_runs_cargo_test() {
     let (_tmp, project) = create_project();
@@ -327,3 +370,62 @@ fn test_builds_contract_and_runs_cargo_test() {
     assert!(log.contains("subcmd=test toolchain=+nightly-2024-07-30"));
     assert!(log.contains("--quiet"));
 }
+
+#[test]
+fn unknown_command_runs_plugin_with_project_metadata() {
+    let (_tmp, project) = create_project();
+    let tools = FakeTools::new();
+    write_executable(
+        &tools.bin_dir.join("dusk-forge-hello"),
+        "#!/usr/bin/env bash\n\
+         echo \"args=$*\"\n\
+         echo \"forge=${DUSK_FORGE:+set}\"\n\
+         echo \"artifacts=$DUSK_FORGE_ARTIFACTS\"\n\
+         echo \"project=$DUSK_FORGE_PROJECT\"\n",
+    );
+
+    let output = cargo_bin_cmd!("dusk-forge")
+        .args(["hello", "--flag", "value"])
+        .current_dir(&project)
+        .env("PATH", tools.path())
+        .output()
+        .expect("run plugin");
+    assert!(output.status.success(), "{output:?}");
+
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 output");
+    assert!(stdout.contains("args=--flag value"), "{stdout}");
+    assert!(stdout.contains("forge=set"), "{stdout}");
+    assert!(stdout.contains("artifacts=") && stdout.contains("forge-artifacts.json"));
+
+    let project_json = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("project="))
+        .expect("project line");
+    let metadata: serde_json::Value = serde_json::from_str(project_json).expect("project JSON");
+    assert_eq!(metadata["crate-name"], "smoke-contract");
+    assert!(
+        metadata["contract-wasm"]
+            .as_str()
+            .is_some_and(|path| path.ends_with("smoke_contract.wasm"))
+    );
+
+    write_executable(
+        &tools.bin_dir.join("dusk-forge-fail"),
+        "#!/usr/bin/env bash\nexit 3\n",
+    );
+    cargo_bin_cmd!("dusk-forge")
+        .arg("fail")
+        .current_dir(&project)
+        .env("PATH", tools.path())
+        .assert()
+        .code(3);
+
+    cargo_bin_cmd!("dusk-forge")
+        .arg("no-such-plugin")
+        .env("PATH", tools.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no `dusk-forge-no-such-plugin` plugin on PATH",
+        ));
+}
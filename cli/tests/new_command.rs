@@ -69,3 +69,85 @@ fn new_scaffolds_empty_template() {
     assert!(!lib.contains("CountChanged"));
     assert!(!test.contains("TODO"));
 }
+
+#[test]
+fn new_scaffolds_multisig_template() {
+    let tmp = tempdir().expect("tempdir");
+
+    cargo_bin_cmd!("dusk-forge")
+        .args([
+            "new",
+            "team-vault",
+            "--template",
+            "multisig",
+            "--no-git",
+            "--path",
+            tmp.path().to_str().expect("utf-8 path"),
+        ])
+        .assert()
+        .success();
+
+    let project = tmp.path().join("team-vault");
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).expect("read Cargo.toml");
+    let lib = fs::read_to_string(project.join("src/lib.rs")).expect("read lib.rs");
+    let test = fs::read_to_string(project.join("tests/contract.rs")).expect("read test file");
+    assert!(project.join("Cargo.lock").exists());
+
+    assert!(cargo.contains("name = \"team-vault\""));
+    assert!(lib.contains("mod team_vault"));
+    assert!(lib.contains("pub struct TeamVault"));
+    assert!(lib.contains("#[contract(expose = [signers, threshold])]"));
+    assert!(test.contains("option_env!(\"DUSK_FORGE_CONTRACT_WASM\")"));
+}
+
+#[test]
+fn new_scaffolds_token_template() {
+    let tmp = tempdir().expect("tempdir");
+
+    cargo_bin_cmd!("dusk-forge")
+        .args([
+            "new",
+            "reward-token",
+            "--template",
+            "token",
+            "--no-git",
+            "--path",
+            tmp.path().to_str().expect("utf-8 path"),
+        ])
+        .assert()
+        .success();
+
+    let project = tmp.path().join("reward-token");
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).expect("read Cargo.toml");
+    let lib = fs::read_to_string(project.join("src/lib.rs")).expect("read lib.rs");
+    assert!(project.join("Cargo.lock").exists());
+
+    assert!(cargo.contains("dusk-forge-standards = \"0.1.0\""));
+    assert!(lib.contains("mod reward_token"));
+    assert!(lib.contains("impl FungibleToken for RewardToken"));
+}
+
+#[test]
+fn new_scaffolds_nft_template() {
+    let tmp = tempdir().expect("tempdir");
+
+    cargo_bin_cmd!("dusk-forge")
+        .args([
+            "new",
+            "art-collection",
+            "--template",
+            "nft",
+            "--no-git",
+            "--path",
+            tmp.path().to_str().expect("utf-8 path"),
+        ])
+        .assert()
+        .success();
+
+    let project = tmp.path().join("art-collection");
+    let lib = fs::read_to_string(project.join("src/lib.rs")).expect("read lib.rs");
+    let test = fs::read_to_string(project.join("tests/contract.rs")).expect("read contract.rs");
+
+    assert!(lib.contains("impl NonFungibleToken for ArtCollection"));
+    assert!(test.contains("use dusk_forge_standards::nft::{Account, TokenMetadata};"));
+}
@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `#[contract_input]` expansion.
+//!
+//! Adds the rkyv/bytecheck/serde derive stack that types crossing the
+//! contract boundary need, plus `arbitrary::Arbitrary` for fuzzing, and
+//! rewrites field markers: `#[hex]` into the serde attribute encoding the
+//! field as a hex string, `#[arbitrary(..)]` into its feature-gated form.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Fields, Item};
+
+/// Expand `#[contract_input]` on a struct or enum.
+pub(crate) fn expand(attr: TokenStream2, mut item: Item) -> Result<TokenStream2, syn::Error> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[contract_input] takes no arguments",
+        ));
+    }
+
+    match &mut item {
+        Item::Struct(item_struct) => field_markers(&mut item_struct.fields),
+        Item::Enum(item_enum) => {
+            for variant in &mut item_enum.variants {
+                field_markers(&mut variant.fields);
+            }
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                item,
+                "#[contract_input] can only be applied to structs and enums",
+            ));
+        }
+    }
+
+    Ok(quote! {
+        #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+        #[archive_attr(derive(bytecheck::CheckBytes))]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        #item
+    })
+}
+
+/// Replace `#[hex]` on fields with the serde hex encoding attribute, and
+/// gate `#[arbitrary(..)]` behind the `arbitrary` feature: without the
+/// derive, the attribute would not be recognized.
+fn field_markers(fields: &mut Fields) {
+    for field in fields.iter_mut() {
+        let before = field.attrs.len();
+        field.attrs.retain(|attr| !is_hex_marker(attr));
+        if field.attrs.len() != before {
+            field.attrs.push(syn::parse_quote! {
+                #[cfg_attr(feature = "serde", serde(with = "dusk_forge::hex"))]
+            });
+        }
+
+        for attr in &mut field.attrs {
+            if let syn::Meta::List(list) = &attr.meta
+                && list.path.is_ident("arbitrary")
+            {
+                let tokens = &list.tokens;
+                *attr = syn::parse_quote! {
+                    #[cfg_attr(feature = "arbitrary", arbitrary(#tokens))]
+                };
+            }
+        }
+    }
+}
+
+fn is_hex_marker(attr: &Attribute) -> bool {
+    attr.path().is_ident("hex") && matches!(attr.meta, syn::Meta::Path(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normalize_tokens;
+
+    #[test]
+    fn test_expand_struct_with_hex_field() {
+        let item: Item = syn::parse_quote! {
+            /// A deposit.
+            #[derive(Debug, Clone)]
+            pub struct Deposit {
+                pub amount: u64,
+                #[hex]
+                pub memo: [u8; 32],
+            }
+        };
+
+        let output = normalize_tokens(&expand(TokenStream2::new(), item).unwrap());
+        let expected = normalize_tokens(&quote! {
+            #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+            #[archive_attr(derive(bytecheck::CheckBytes))]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+            /// A deposit.
+            #[derive(Debug, Clone)]
+            pub struct Deposit {
+                pub amount: u64,
+                #[cfg_attr(feature = "serde", serde(with = "dusk_forge::hex"))]
+                pub memo: [u8; 32],
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_expand_enum_variant_fields() {
+        let item: Item = syn::parse_quote! {
+            pub enum Payload {
+                Empty,
+                Raw(#[hex] Vec<u8>),
+            }
+        };
+
+        let output = normalize_tokens(&expand(TokenStream2::new(), item).unwrap());
+
+        assert!(
+            output.contains("Raw (# [cfg_attr (feature = \"serde\" , serde (with = \"dusk_forge::hex\"))] Vec < u8 >)"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_expand_gates_arbitrary_field_attributes() {
+        let item: Item = syn::parse_quote! {
+            pub struct Transfer {
+                #[arbitrary(with = dusk_forge::fuzz::public_key)]
+                pub to: PublicKey,
+                pub amount: u64,
+            }
+        };
+
+        let output = normalize_tokens(&expand(TokenStream2::new(), item).unwrap());
+
+        assert!(
+            output.contains(&normalize_tokens(&quote! {
+                #[cfg_attr(feature = "arbitrary", arbitrary(with = dusk_forge::fuzz::public_key))]
+                pub to: PublicKey,
+            })),
+            "{output}"
+        );
+        assert!(
+            output.contains(
+                "# [cfg_attr (feature = \"arbitrary\" , derive (arbitrary :: Arbitrary))]"
+            ),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_other_items_and_arguments() {
+        let item: Item = syn::parse_quote! { fn not_a_type() {} };
+        let Err(err) = expand(TokenStream2::new(), item) else {
+            panic!("expected error for a function");
+        };
+        assert!(err.to_string().contains("structs and enums"), "{err}");
+
+        let item: Item = syn::parse_quote! { pub struct Unit; };
+        let Err(err) = expand(quote! { hex }, item) else {
+            panic!("expected error for arguments");
+        };
+        assert!(err.to_string().contains("takes no arguments"), "{err}");
+    }
+}
@@ -14,21 +14,26 @@
 //! fully-qualified type paths resolved at extraction time.
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::resolve::TypeMap;
-use crate::{EventInfo, FunctionInfo};
+use crate::{EventInfo, FunctionInfo, generate};
 
-/// Generate the `data_driver` module at crate root level.
+/// Generate the `data_driver` module at crate root level. `schema` is the
+/// path to `CONTRACT_SCHEMA` from the module.
 pub(crate) fn module(
     type_map: &TypeMap,
     functions: &[FunctionInfo],
     events: &[EventInfo],
+    schema: &TokenStream2,
 ) -> TokenStream2 {
     let encode_input_arms = generate_encode_input_arms(functions, type_map);
     let decode_input_arms = generate_decode_input_arms(functions, type_map);
     let decode_output_arms = generate_decode_output_arms(functions, type_map);
     let decode_event_arms = generate_decode_event_arms(events, type_map);
+    let positional_args = positional_args_helper(functions);
+    let output_bytes = output_bytes_helper(functions);
+    let round_trip_tests = round_trip_tests(functions, events, type_map);
 
     quote! {
         /// Auto-generated data driver module.
@@ -101,20 +106,26 @@ pub(crate) fn module(
                 }
 
                 fn get_schema(&self) -> String {
-                    super::CONTRACT_SCHEMA.to_json()
+                    #schema.to_json()
                 }
             }
 
+            #positional_args
+
+            #output_bytes
+
             // WASM entrypoint for the data-driver
             #[cfg(target_family = "wasm")]
             dusk_data_driver::generate_wasm_entrypoint!(Driver);
+
+            #round_trip_tests
         }
     }
 }
 
 /// Get the resolved type path from the `type_map`, or return the original if
 /// not found.
-fn get_resolved_type(ty: &TokenStream2, type_map: &TypeMap) -> TokenStream2 {
+pub(crate) fn get_resolved_type(ty: &TokenStream2, type_map: &TypeMap) -> TokenStream2 {
     let key = ty.to_string();
     if let Some(resolved) = type_map.get(&key) {
         // Parse the resolved string back into tokens as a Type (not Path, since tuples
@@ -128,28 +139,87 @@ fn get_resolved_type(ty: &TokenStream2, type_map: &TypeMap) -> TokenStream2 {
 }
 
 /// Generate match arms for `encode_input_fn`.
+///
+/// `init` and functions with several parameters additionally accept their
+/// arguments as a JSON object keyed by parameter name
+/// (`{"from": ..., "to": ..., "amount": ...}`), converted to the positional
+/// form first.
 fn generate_encode_input_arms(functions: &[FunctionInfo], type_map: &TypeMap) -> Vec<TokenStream2> {
     functions
         .iter()
         .map(|f| {
             let name_str = f.name.to_string();
+            let cfg_attrs = generate::cfg_attrs(f);
             let input_type = get_resolved_type(&f.input_type, type_map);
-            quote! {
-                #name_str => dusk_data_driver::json_to_rkyv::<#input_type>(json)
+            if takes_named_args(f) {
+                let names = f.params.iter().map(|p| p.name.to_string());
+                quote! {
+                    #cfg_attrs #name_str => dusk_data_driver::json_to_rkyv::<#input_type>(
+                        &positional_args(json, &[#(#names),*])
+                    )
+                }
+            } else {
+                quote! {
+                    #cfg_attrs #name_str => dusk_data_driver::json_to_rkyv::<#input_type>(json)
+                }
             }
         })
         .collect()
 }
 
+/// Whether `f` accepts named arguments: it is the contract's `init` and takes
+/// arguments, or it takes several. A single parameter of another function
+/// keeps its plain JSON, which may itself be an object.
+fn takes_named_args(f: &FunctionInfo) -> bool {
+    f.params.len() > 1 || (f.name == "init" && !f.params.is_empty())
+}
+
+/// Generate the `positional_args` helper used by the encoding arms of
+/// functions taking named arguments.
+///
+/// An object with exactly the parameter names as keys is turned into the
+/// bare value (one parameter) or an array in parameter order (several); any
+/// other JSON is passed through unchanged.
+fn positional_args_helper(functions: &[FunctionInfo]) -> TokenStream2 {
+    if !functions.iter().any(takes_named_args) {
+        return quote! {};
+    }
+
+    quote! {
+        /// Convert named arguments (`{"owner": ...}`) to the positional JSON
+        /// the input type deserializes from.
+        fn positional_args(json: &str, names: &[&str]) -> String {
+            use alloc::string::ToString;
+
+            let Ok(dusk_data_driver::JsonValue::Object(mut args)) =
+                json.parse::<dusk_data_driver::JsonValue>()
+            else {
+                return String::from(json);
+            };
+            if args.len() != names.len() || !names.iter().all(|name| args.contains_key(*name)) {
+                return String::from(json);
+            }
+
+            let mut values: Vec<_> = names.iter().filter_map(|name| args.remove(*name)).collect();
+            if values.len() == 1 {
+                values.remove(0).to_string()
+            } else {
+                dusk_data_driver::JsonValue::Array(values).to_string()
+            }
+        }
+    }
+}
+
 /// Generate match arms for `decode_input_fn`.
 fn generate_decode_input_arms(functions: &[FunctionInfo], type_map: &TypeMap) -> Vec<TokenStream2> {
     functions
         .iter()
         .map(|f| {
             let name_str = f.name.to_string();
+            let cfg_attrs = generate::cfg_attrs(f);
             let input_type = get_resolved_type(&f.input_type, type_map);
             quote! {
-                #name_str => dusk_data_driver::rkyv_to_json::<#input_type>(rkyv)
+                #cfg_attrs #name_str => dusk_data_driver::rkyv_to_json::<#input_type>(rkyv)
             }
         })
         .collect()
@@ -168,6 +238,7 @@ fn generate_decode_output_arms(
         .iter()
         .map(|f| {
             let name_str = f.name.to_string();
+            let cfg_attrs = generate::cfg_attrs(f);
 
             // Use feed_type if present, otherwise use output_type
             let (decode_type, type_str) = if let Some(feed_type) = &f.feed_type {
@@ -184,73 +255,343 @@ fn generate_decode_output_arms(
 
             if type_str == "()" {
                 quote! {
-                    #name_str => Ok(dusk_data_driver::JsonValue::Null)
+                    #cfg_attrs #name_str => Ok(dusk_data_driver::JsonValue::Null)
                 }
             } else if type_str == "u64" {
                 quote! {
-                    #name_str => dusk_data_driver::rkyv_to_json_u64(rkyv)
+                    #cfg_attrs #name_str => dusk_data_driver::rkyv_to_json_u64(rkyv)
+                }
+            } else if let Some(decode) = decode_primitive(&type_str) {
+                quote! {
+                    #cfg_attrs #name_str => #decode
                 }
             } else {
                 quote! {
-                    #name_str => dusk_data_driver::rkyv_to_json::<#decode_type>(rkyv)
+                    #cfg_attrs #name_str => dusk_data_driver::rkyv_to_json::<#decode_type>(rkyv)
                 }
             }
         })
         .collect()
 }
 
+/// The type a function's output is decoded as: its fed type, if any.
+fn decoded_output(f: &FunctionInfo) -> &TokenStream2 {
+    f.feed_type.as_ref().unwrap_or(&f.output_type)
+}
+
+/// Generate the expression decoding an output of primitive type `type_str`
+/// straight from its archived bytes, or `None` for other types.
+///
+/// Integers up to 32 bits and `i64` decode to JSON numbers, `bool` to a JSON
+/// boolean and byte arrays (`[u8; N]`) to a lowercase hex string, rather than
+/// through the types' serde representation.
+fn decode_primitive(type_str: &str) -> Option<TokenStream2> {
+    let number = |ty: TokenStream2, size: usize| {
+        quote! {
+            output_bytes::<#size>(rkyv)
+                .map(|bytes| dusk_data_driver::JsonValue::from(#ty::from_le_bytes(bytes)))
+        }
+    };
+
+    let decode = match type_str {
+        "u8" => number(quote! { u8 }, 1),
+        "u16" => number(quote! { u16 }, 2),
+        "u32" => number(quote! { u32 }, 4),
+        "i8" => number(quote! { i8 }, 1),
+        "i16" => number(quote! { i16 }, 2),
+        "i32" => number(quote! { i32 }, 4),
+        "i64" => number(quote! { i64 }, 8),
+        "bool" => quote! {
+            match output_bytes::<1>(rkyv)? {
+                [byte @ (0 | 1)] => Ok(dusk_data_driver::JsonValue::Bool(byte == 1)),
+                [byte] => Err(dusk_data_driver::Error::Unsupported(
+                    alloc::format!("decode_output: invalid bool byte {byte}")
+                )),
+            }
+        },
+        _ => {
+            let size = byte_array_len(type_str)?;
+            quote! {
+                output_bytes::<#size>(rkyv).map(|bytes| {
+                    dusk_data_driver::JsonValue::String(
+                        bytes.iter().map(|byte| alloc::format!("{byte:02x}")).collect()
+                    )
+                })
+            }
+        }
+    };
+    Some(decode)
+}
+
+/// The length of a `[u8; N]` type with a literal length, or `None`.
+fn byte_array_len(type_str: &str) -> Option<usize> {
+    let syn::Type::Array(array) = syn::parse_str::<syn::Type>(type_str).ok()? else {
+        return None;
+    };
+    let syn::Type::Path(elem) = array.elem.as_ref() else {
+        return None;
+    };
+    if !elem.path.is_ident("u8") {
+        return None;
+    }
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(len),
+        ..
+    }) = &array.len
+    else {
+        return None;
+    };
+    len.base10_parse().ok()
+}
+
+/// Generate the `output_bytes` helper used by the primitive decoding arms.
+///
+/// rkyv archives a primitive root as its little-endian bytes at the end of
+/// the buffer, so the helper takes the last `N` bytes.
+fn output_bytes_helper(functions: &[FunctionInfo]) -> TokenStream2 {
+    if !functions
+        .iter()
+        .any(|f| decode_primitive(&decoded_output(f).to_string()).is_some())
+    {
+        return quote! {};
+    }
+
+    quote! {
+        /// The archived bytes of an `N`-byte primitive output.
+        fn output_bytes<const N: usize>(
+            rkyv: &[u8],
+        ) -> Result<[u8; N], dusk_data_driver::Error> {
+            rkyv.len()
+                .checked_sub(N)
+                .and_then(|start| rkyv[start..].try_into().ok())
+                .ok_or_else(|| {
+                    dusk_data_driver::Error::Unsupported(alloc::format!(
+                        "decode_output: expected {N} bytes, got {}",
+                        rkyv.len()
+                    ))
+                })
+        }
+    }
+}
+
 /// Generate match arms for `decode_event`.
 fn generate_decode_event_arms(events: &[EventInfo], type_map: &TypeMap) -> Vec<TokenStream2> {
     events
         .iter()
         .filter_map(|e| {
-            let topic_str = &e.topic;
+            let topic = event_topic(e, type_map)?;
             let data_type = get_resolved_type(&e.data_type, type_map);
+            Some(quote! {
+                #topic => dusk_data_driver::rkyv_to_json::<#data_type>(rkyv)
+            })
+        })
+        .collect()
+}
 
-            // Get the resolved topic path from the type_map
-            let resolved_topic = type_map
-                .get(topic_str)
-                .map_or(topic_str.clone(), Clone::clone);
-
-            // Try to parse the resolved topic as a path for constant resolution
-            if let Ok(topic_path) = syn::parse_str::<syn::Path>(&resolved_topic) {
-                // Skip variable references (single lowercase identifier)
-                if topic_path.segments.len() == 1 {
-                    let name = topic_path.segments[0].ident.to_string();
-                    if name.starts_with(char::is_lowercase) {
-                        return None;
-                    }
+/// Resolve an event topic to the tokens used to match on it.
+///
+/// Returns `None` for topics referencing a local variable (a single lowercase
+/// identifier), since those cannot be named outside the emitting method.
+pub(crate) fn event_topic(event: &EventInfo, type_map: &TypeMap) -> Option<TokenStream2> {
+    let topic_str = &event.topic;
+
+    // Get the resolved topic path from the type_map
+    let resolved_topic = type_map
+        .get(topic_str)
+        .map_or(topic_str.clone(), Clone::clone);
+
+    // Try to parse the resolved topic as a path for constant resolution
+    if let Ok(topic_path) = syn::parse_str::<syn::Path>(&resolved_topic) {
+        // Skip variable references (single lowercase identifier)
+        if topic_path.segments.len() == 1 {
+            let name = topic_path.segments[0].ident.to_string();
+            if name.starts_with(char::is_lowercase) {
+                return None;
+            }
+        }
+        Some(quote! { #topic_path })
+    } else {
+        Some(quote! { #resolved_topic })
+    }
+}
+
+/// Generate the round-trip test module for the data-driver.
+///
+/// Each function input, function output and event payload gets a test that
+/// takes sample values of the type, encodes them with rkyv, decodes them to
+/// JSON through the driver and encodes the JSON back, asserting the rkyv
+/// bytes match. This catches types whose serde representation does not
+/// survive the trip (e.g. fields serialized as hex but deserialized as
+/// numbers).
+///
+/// The samples come from `dusk_forge::fuzz::samples` for types implementing
+/// `Arbitrary`, so collections are filled and enum variants vary, and fall
+/// back to the `Default` value. Types with neither are skipped at compile
+/// time, as are unit, `u64` and byte array outputs, which the driver encodes
+/// specially. The module is only compiled for tests with the
+/// `data-driver-tests` feature enabled, which must let the crate build for
+/// the host, and expects `rkyv` and `dusk-forge` with its `arbitrary`
+/// feature to be available as dev-dependencies.
+fn round_trip_tests(
+    functions: &[FunctionInfo],
+    events: &[EventInfo],
+    type_map: &TypeMap,
+) -> TokenStream2 {
+    let input_tests = functions.iter().map(|f| input_round_trip_test(f, type_map));
+    let output_tests = functions
+        .iter()
+        .filter_map(|f| output_round_trip_test(f, type_map));
+    let event_tests = events
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| event_round_trip_test(i, e, type_map));
+
+    quote! {
+        #[cfg(all(test, feature = "data-driver-tests"))]
+        mod round_trip_tests {
+            use core::marker::PhantomData;
+
+            use dusk_data_driver::ConvertibleContract;
+
+            use super::*;
+
+            /// Type probe yielding sample values: arbitrary ones for types
+            /// implementing `Arbitrary`, else the `Default` value, else none.
+            /// Called as `(&&&Probe::<T>(PhantomData)).samples()`, so the
+            /// most specific applicable impl wins.
+            struct Probe<T>(PhantomData<T>);
+
+            trait ArbitrarySamples<T> {
+                fn samples(&self) -> Vec<T>;
+            }
+
+            impl<T> ArbitrarySamples<T> for &&Probe<T>
+            where
+                T: for<'a> dusk_forge::fuzz::Arbitrary<'a>,
+            {
+                fn samples(&self) -> Vec<T> {
+                    dusk_forge::fuzz::samples()
                 }
-                Some(quote! {
-                    #topic_path => dusk_data_driver::rkyv_to_json::<#data_type>(rkyv)
-                })
-            } else {
-                Some(quote! {
-                    #resolved_topic => dusk_data_driver::rkyv_to_json::<#data_type>(rkyv)
-                })
             }
-        })
-        .collect()
+
+            trait DefaultSamples<T> {
+                fn samples(&self) -> Vec<T>;
+            }
+
+            impl<T: Default> DefaultSamples<T> for &Probe<T> {
+                fn samples(&self) -> Vec<T> {
+                    core::iter::once(T::default()).collect()
+                }
+            }
+
+            trait NoSamples<T> {
+                fn samples(&self) -> Vec<T>;
+            }
+
+            impl<T> NoSamples<T> for Probe<T> {
+                fn samples(&self) -> Vec<T> {
+                    Vec::new()
+                }
+            }
+
+            fn encode<T>(value: &T) -> Vec<u8>
+            where
+                T: rkyv::Serialize<rkyv::ser::serializers::AllocSerializer<1024>>,
+            {
+                rkyv::to_bytes::<T, 1024>(value)
+                    .expect("value should serialize")
+                    .to_vec()
+            }
+
+            #(#input_tests)*
+            #(#output_tests)*
+            #(#event_tests)*
+        }
+    }
+}
+
+/// Generate the round-trip test for a function's input type.
+fn input_round_trip_test(f: &FunctionInfo, type_map: &TypeMap) -> TokenStream2 {
+    let name_str = f.name.to_string();
+    let test_name = format_ident!("input_{}", f.name);
+    let input_type = get_resolved_type(&f.input_type, type_map);
+    let cfg_attrs = generate::cfg_attrs(f);
+    quote! {
+        #[test]
+        #cfg_attrs
+        fn #test_name() {
+            for value in (&&&Probe::<#input_type>(PhantomData)).samples() {
+                let rkyv = encode(&value);
+                let json = Driver
+                    .decode_input_fn(#name_str, &rkyv)
+                    .expect("input should decode to JSON");
+                let encoded = Driver
+                    .encode_input_fn(#name_str, &json.to_string())
+                    .expect("input JSON should encode");
+                assert_eq!(rkyv, encoded, "input of `{}` did not round-trip: {json}", #name_str);
+            }
+        }
+    }
+}
+
+/// Generate the round-trip test for a function's output (or fed) type.
+fn output_round_trip_test(f: &FunctionInfo, type_map: &TypeMap) -> Option<TokenStream2> {
+    let output_type = decoded_output(f);
+    let type_str = output_type.to_string();
+    if type_str == "()" || type_str == "u64" || byte_array_len(&type_str).is_some() {
+        return None;
+    }
+
+    let name_str = f.name.to_string();
+    let test_name = format_ident!("output_{}", f.name);
+    let output_type = get_resolved_type(output_type, type_map);
+    let cfg_attrs = generate::cfg_attrs(f);
+    Some(quote! {
+        #[test]
+        #cfg_attrs
+        fn #test_name() {
+            for value in (&&&Probe::<#output_type>(PhantomData)).samples() {
+                let rkyv = encode(&value);
+                let json = Driver
+                    .decode_output_fn(#name_str, &rkyv)
+                    .expect("output should decode to JSON");
+                let encoded = dusk_data_driver::json_to_rkyv::<#output_type>(&json.to_string())
+                    .expect("output JSON should encode");
+                assert_eq!(rkyv, encoded, "output of `{}` did not round-trip: {json}", #name_str);
+            }
+        }
+    })
+}
+
+/// Generate the round-trip test for an event payload.
+fn event_round_trip_test(index: usize, e: &EventInfo, type_map: &TypeMap) -> Option<TokenStream2> {
+    let topic = event_topic(e, type_map)?;
+    let topic_str = &e.topic;
+    let test_name = format_ident!("event_{}", index);
+    let data_type = get_resolved_type(&e.data_type, type_map);
+    Some(quote! {
+        #[test]
+        fn #test_name() {
+            for value in (&&&Probe::<#data_type>(PhantomData)).samples() {
+                let rkyv = encode(&value);
+                let json = Driver
+                    .decode_event(#topic, &rkyv)
+                    .expect("event should decode to JSON");
+                let encoded = dusk_data_driver::json_to_rkyv::<#data_type>(&json.to_string())
+                    .expect("event JSON should encode");
+                assert_eq!(rkyv, encoded, "event `{}` did not round-trip: {json}", #topic_str);
+            }
+        }
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use quote::format_ident;
-
     use super::*;
-    use crate::Receiver;
-
-    /// Normalize token stream to a string with consistent whitespace for
-    /// comparison.
-    fn normalize_tokens(tokens: TokenStream2) -> String {
-        tokens
-            .to_string()
-            .split_whitespace()
-            .collect::<Vec<_>>()
-            .join(" ")
-    }
+    use crate::test_utils::normalize_tokens;
+    use crate::{ParameterInfo, Receiver};
 
     /// Create a basic `FunctionInfo` for testing.
     fn make_function(name: &str, input: TokenStream2, output: TokenStream2) -> FunctionInfo {
@@ -264,6 +605,15 @@ mod tests {
             receiver: Receiver::Ref,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         }
     }
 
@@ -287,7 +637,7 @@ mod tests {
         let ty = quote! { Address };
         let resolved = get_resolved_type(&ty, &type_map);
 
-        assert_eq!(normalize_tokens(resolved), "my_crate :: Address");
+        assert_eq!(normalize_tokens(&resolved), "my_crate :: Address");
     }
 
     #[test]
@@ -297,7 +647,7 @@ mod tests {
         let ty = quote! { u64 };
         let resolved = get_resolved_type(&ty, &type_map);
 
-        assert_eq!(normalize_tokens(resolved), "u64");
+        assert_eq!(normalize_tokens(&resolved), "u64");
     }
 
     #[test]
@@ -318,7 +668,7 @@ mod tests {
 
         let arms = generate_encode_input_arms(&functions, &type_map);
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
 
         assert!(
             arm_str.contains("\"store\""),
@@ -338,7 +688,7 @@ mod tests {
         let ty = quote! { Deposit };
         let resolved = get_resolved_type(&ty, &type_map);
 
-        assert_eq!(normalize_tokens(resolved), "my_crate :: Deposit");
+        assert_eq!(normalize_tokens(&resolved), "my_crate :: Deposit");
     }
 
     // =========================================================================
@@ -354,7 +704,7 @@ mod tests {
         let arms = generate_encode_input_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"init\""), "Should contain function name");
         assert!(arm_str.contains("json_to_rkyv"), "Should use json_to_rkyv");
         assert!(
@@ -371,7 +721,7 @@ mod tests {
         let arms = generate_encode_input_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"is_paused\""));
         assert!(arm_str.contains("json_to_rkyv :: < () >"));
     }
@@ -393,7 +743,7 @@ mod tests {
         let arms = generate_encode_input_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"transfer\""));
         assert!(arm_str.contains("json_to_rkyv"));
         // Verify the resolved tuple type is used
@@ -404,6 +754,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_input_init_accepts_named_args() {
+        let mut init = make_function("init", quote! { (Address, u64) }, quote! { () });
+        init.params = vec![
+            ParameterInfo {
+                name: format_ident!("owner"),
+                ty: quote! { Address },
+                is_ref: false,
+                is_mut_ref: false,
+                unit: None,
+            },
+            ParameterInfo {
+                name: format_ident!("threshold"),
+                ty: quote! { u64 },
+                is_ref: false,
+                is_mut_ref: false,
+                unit: None,
+            },
+        ];
+        let functions = vec![init];
+
+        let arms = generate_encode_input_arms(&functions, &HashMap::new());
+        let arm_str = normalize_tokens(&arms[0].clone());
+        assert!(
+            arm_str.contains("& positional_args (json , & [\"owner\" , \"threshold\"])"),
+            "init should convert named args: {arm_str}"
+        );
+
+        let helper = normalize_tokens(&positional_args_helper(&functions));
+        assert!(helper.contains("fn positional_args"));
+    }
+
+    #[test]
+    fn test_encode_input_multi_param_accepts_named_args() {
+        let mut transfer = make_function(
+            "transfer",
+            quote! { (Address, Address, u64) },
+            quote! { () },
+        );
+        transfer.params = ["from", "to", "amount"]
+            .into_iter()
+            .map(|name| ParameterInfo {
+                name: format_ident!("{name}"),
+                ty: quote! { u64 },
+                is_ref: false,
+                is_mut_ref: false,
+                unit: None,
+            })
+            .collect();
+        let functions = vec![transfer];
+
+        let arms = generate_encode_input_arms(&functions, &HashMap::new());
+        let arm_str = normalize_tokens(&arms[0].clone());
+        assert!(
+            arm_str.contains("& positional_args (json , & [\"from\" , \"to\" , \"amount\"])"),
+            "multi-parameter functions should convert named args: {arm_str}"
+        );
+        assert!(!positional_args_helper(&functions).is_empty());
+    }
+
+    #[test]
+    fn test_encode_input_positional_args_only_for_named_params() {
+        let mut set = make_function("set", quote! { Config }, quote! { () });
+        set.params = vec![ParameterInfo {
+            name: format_ident!("config"),
+            ty: quote! { Config },
+            is_ref: false,
+            is_mut_ref: false,
+            unit: None,
+        }];
+        let functions = vec![
+            make_function("init", quote! { () }, quote! { () }),
+            make_function("transfer", quote! { (Address, u64) }, quote! { () }),
+            set,
+        ];
+
+        let arms = generate_encode_input_arms(&functions, &HashMap::new());
+        assert!(
+            arms.iter()
+                .all(|arm| !normalize_tokens(&arm.clone()).contains("positional_args"))
+        );
+        assert!(positional_args_helper(&functions).is_empty());
+    }
+
     #[test]
     fn test_encode_input_multiple_functions() {
         let type_map = HashMap::new();
@@ -418,7 +852,7 @@ mod tests {
         assert_eq!(arms.len(), 3);
 
         // Verify each function is present in the generated arms
-        let all_arms: String = arms.iter().map(|a| normalize_tokens(a.clone())).collect();
+        let all_arms: String = arms.iter().map(|a| normalize_tokens(&a.clone())).collect();
         assert!(all_arms.contains("\"pause\""), "Should contain pause");
         assert!(all_arms.contains("\"unpause\""), "Should contain unpause");
         assert!(all_arms.contains("\"init\""), "Should contain init");
@@ -437,7 +871,7 @@ mod tests {
         let arms = generate_decode_input_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"deposit\""));
         assert!(arm_str.contains("rkyv_to_json"));
         assert!(arm_str.contains("my_crate :: Deposit"));
@@ -460,7 +894,7 @@ mod tests {
         let arms = generate_decode_input_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"transfer_with_fee\""));
         assert!(arm_str.contains("rkyv_to_json"));
         // Verify the resolved tuple type is used
@@ -488,7 +922,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"pause\""));
         assert!(arm_str.contains("Ok"));
         assert!(arm_str.contains("JsonValue :: Null"));
@@ -511,7 +945,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"finalization_period\""));
         assert!(arm_str.contains("rkyv_to_json_u64"));
         // Verify it does NOT use the generic rkyv_to_json::<u64>
@@ -529,12 +963,91 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"is_paused\""));
-        assert!(arm_str.contains("rkyv_to_json :: < bool >"));
-        // Verify it does NOT use the special handlers for unit or u64
-        assert!(!arm_str.contains("JsonValue :: Null"));
-        assert!(!arm_str.contains("rkyv_to_json_u64"));
+        assert!(arm_str.contains("output_bytes :: < 1 > (rkyv) ?"));
+        assert!(arm_str.contains("JsonValue :: Bool"));
+        // Verify it does NOT go through the serde representation
+        assert!(!arm_str.contains("rkyv_to_json"));
+    }
+
+    #[test]
+    fn test_decode_output_small_integers_decode_as_numbers() {
+        let type_map = HashMap::new();
+
+        for (ty, size) in [
+            (quote! { u8 }, 1),
+            (quote! { u16 }, 2),
+            (quote! { u32 }, 4),
+            (quote! { i8 }, 1),
+            (quote! { i16 }, 2),
+            (quote! { i32 }, 4),
+            (quote! { i64 }, 8),
+        ] {
+            let ty_str = ty.to_string();
+            let functions = vec![make_function("value", quote! { () }, ty)];
+            let arms = generate_decode_output_arms(&functions, &type_map);
+
+            let arm_str = normalize_tokens(&arms[0].clone());
+            assert!(
+                arm_str.contains(&format!("output_bytes :: < {size}usize > (rkyv)")),
+                "{ty_str}: {arm_str}"
+            );
+            assert!(
+                arm_str.contains(&format!(
+                    "JsonValue :: from ({ty_str} :: from_le_bytes (bytes))"
+                )),
+                "{ty_str}: {arm_str}"
+            );
+            assert!(!arm_str.contains("rkyv_to_json"), "{ty_str}: {arm_str}");
+        }
+    }
+
+    #[test]
+    fn test_decode_output_byte_array_as_hex() {
+        let type_map = HashMap::new();
+
+        let functions = vec![
+            make_function("root", quote! { () }, quote! { [u8; 32] }),
+            make_function("limbs", quote! { () }, quote! { [u64; 4] }),
+        ];
+        let arms = generate_decode_output_arms(&functions, &type_map);
+
+        let root = normalize_tokens(&arms[0].clone());
+        assert!(
+            root.contains("output_bytes :: < 32usize > (rkyv)"),
+            "{root}"
+        );
+        assert!(root.contains("JsonValue :: String"), "{root}");
+        assert!(root.contains("\"{byte:02x}\""), "{root}");
+
+        // Only arrays of bytes are hex encoded
+        let limbs = normalize_tokens(&arms[1].clone());
+        assert!(limbs.contains("rkyv_to_json :: < [u64 ; 4] >"), "{limbs}");
+    }
+
+    #[test]
+    fn test_output_bytes_helper_only_for_primitive_outputs() {
+        let primitive = vec![
+            make_function("counter", quote! { () }, quote! { u64 }),
+            make_function("is_paused", quote! { () }, quote! { bool }),
+        ];
+        let helper = normalize_tokens(&output_bytes_helper(&primitive));
+        assert!(helper.contains("fn output_bytes < const N : usize >"));
+
+        let fed = vec![make_function_with_feed(
+            "roots",
+            quote! { () },
+            quote! { () },
+            quote! { [u8; 32] },
+        )];
+        assert!(!output_bytes_helper(&fed).is_empty());
+
+        let other = vec![
+            make_function("counter", quote! { () }, quote! { u64 }),
+            make_function("item", quote! { () }, quote! { Option<Item> }),
+        ];
+        assert!(output_bytes_helper(&other).is_empty());
     }
 
     #[test]
@@ -553,7 +1066,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"pending_withdrawal\""));
         assert!(arm_str.contains("rkyv_to_json"));
         // Verify the resolved type is used
@@ -585,6 +1098,15 @@ mod tests {
             receiver: Receiver::Ref,
             trait_name: None,
             feed_type: Some(feed),
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         }
     }
 
@@ -606,7 +1128,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"pending_withdrawals\""));
         // Should use the feed type, not return JsonValue::Null
         assert!(
@@ -641,7 +1163,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"finalized_withdrawals\""));
         assert!(
             arm_str.contains("my_crate :: ItemId"),
@@ -655,12 +1177,12 @@ mod tests {
         let type_map = HashMap::new();
 
         // Function without feed_type should use output_type as before
-        let functions = vec![make_function("is_paused", quote! { () }, quote! { bool })];
+        let functions = vec![make_function("name", quote! { () }, quote! { String })];
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
-        assert!(arm_str.contains("rkyv_to_json :: < bool >"));
+        let arm_str = normalize_tokens(&arms[0].clone());
+        assert!(arm_str.contains("rkyv_to_json :: < String >"));
     }
 
     #[test]
@@ -677,7 +1199,7 @@ mod tests {
         let arms = generate_decode_output_arms(&functions, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("\"get_count\""));
         assert!(
             arm_str.contains("rkyv_to_json_u64"),
@@ -709,7 +1231,7 @@ mod tests {
         let arms = generate_decode_event_arms(&events, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         // Verify topic is resolved
         assert!(
             arm_str.contains("my_crate :: events :: PauseToggled :: PAUSED"),
@@ -733,7 +1255,7 @@ mod tests {
         let arms = generate_decode_event_arms(&events, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("events :: Paused"));
         assert!(arm_str.contains("rkyv_to_json :: < PauseEvent >"));
     }
@@ -758,7 +1280,7 @@ mod tests {
         let arms = generate_decode_event_arms(&events, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         assert!(arm_str.contains("PAUSED"));
         // Verify the data type is also included
         assert!(
@@ -778,7 +1300,7 @@ mod tests {
         let arms = generate_decode_event_arms(&events, &type_map);
 
         assert_eq!(arms.len(), 1);
-        let arm_str = normalize_tokens(arms[0].clone());
+        let arm_str = normalize_tokens(&arms[0].clone());
         // String literal topics are used directly in the match arm
         assert!(
             arm_str.contains("\"custom/event\""),
@@ -813,7 +1335,7 @@ mod tests {
         assert_eq!(arms.len(), 2);
 
         // Verify both events are present with correct resolved topics
-        let all_arms: String = arms.iter().map(|a| normalize_tokens(a.clone())).collect();
+        let all_arms: String = arms.iter().map(|a| normalize_tokens(&a.clone())).collect();
         assert!(
             all_arms.contains("my_crate :: events :: PauseToggled :: PAUSED"),
             "Should contain resolved PauseToggled topic"
@@ -843,8 +1365,13 @@ mod tests {
 
         let events = vec![make_event("PAUSED", quote! { PauseEvent })];
 
-        let output = module(&type_map, &functions, &events);
-        let output_str = normalize_tokens(output);
+        let output = module(
+            &type_map,
+            &functions,
+            &events,
+            &quote! { super::CONTRACT_SCHEMA },
+        );
+        let output_str = normalize_tokens(&output);
 
         // Verify module structure
         assert!(output_str.contains("pub mod data_driver"));
@@ -865,4 +1392,47 @@ mod tests {
         // Verify WASM entrypoint
         assert!(output_str.contains("generate_wasm_entrypoint"));
     }
+
+    // =========================================================================
+    // round_trip_tests tests
+    // =========================================================================
+
+    #[test]
+    fn test_round_trip_tests_cover_inputs_outputs_and_events() {
+        let mut type_map = HashMap::new();
+        type_map.insert("Deposit".to_string(), "my_crate::Deposit".to_string());
+
+        let mut feeder = make_function("deposits", quote! { () }, quote! { () });
+        feeder.feed_type = Some(quote! { Deposit });
+        let functions = vec![
+            make_function("deposit", quote! { Deposit }, quote! { () }),
+            make_function("balance", quote! { () }, quote! { u64 }),
+            feeder,
+        ];
+        let events = vec![
+            make_event("events::Deposited::TOPIC", quote! { Deposit }),
+            make_event("topic", quote! { u64 }),
+        ];
+
+        let output = normalize_tokens(&round_trip_tests(&functions, &events, &type_map));
+
+        assert!(output.contains("# [cfg (all (test , feature = \"data-driver-tests\"))]"));
+        for f in ["input_deposit", "input_balance", "input_deposits"] {
+            assert!(output.contains(&format!("fn {f} ()")), "{f}: {output}");
+        }
+        // Unit and `u64` outputs have driver-specific encodings.
+        assert!(!output.contains("fn output_deposit ()"));
+        assert!(!output.contains("fn output_balance ()"));
+        // Feeders round-trip their fed type.
+        assert!(output.contains("fn output_deposits ()"));
+        assert!(
+            output.contains("(&& & Probe :: < my_crate :: Deposit > (PhantomData)) . samples ()"),
+            "{output}"
+        );
+        assert!(output.contains("dusk_forge :: fuzz :: samples ()"));
+        // Variable topics cannot be named from the test module.
+        assert!(output.contains("fn event_0 ()"));
+        assert!(output.contains(". decode_event (events :: Deposited :: TOPIC , & rkyv)"));
+        assert!(!output.contains("fn event_1 ()"));
+    }
 }
@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compile errors with notes and suggested fixes.
+//!
+//! Structured rustc diagnostics (`proc_macro::Diagnostic`) are not available
+//! on stable, where a proc macro can only expand to `compile_error!`s. A
+//! [`Diagnostic`] is therefore lowered into a single `syn::Error` at the
+//! primary span, its notes and help appended to the message as `= note:` and
+//! `= help:` lines, the way rustc prints them. Suggestions are text: neither
+//! the compiler nor rust-analyzer can offer them as fixes to apply.
+
+use std::fmt::{Display, Write};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+/// A compile error being assembled from a primary message, notes and
+/// suggestions.
+pub(crate) struct Diagnostic {
+    tokens: TokenStream,
+    message: String,
+}
+
+impl Diagnostic {
+    /// Start a diagnostic with the primary `message` spanned to `tokens`.
+    pub(crate) fn spanned(tokens: impl ToTokens, message: impl Display) -> Self {
+        Self {
+            tokens: tokens.into_token_stream(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Add context, e.g. the method an offending receiver belongs to.
+    pub(crate) fn note(self, message: impl Display) -> Self {
+        self.push("note", message)
+    }
+
+    /// Suggest a fix without a verbatim replacement.
+    pub(crate) fn help(self, message: impl Display) -> Self {
+        self.push("help", message)
+    }
+
+    /// Suggest replacing the offending code with `replacement`.
+    pub(crate) fn suggestion(self, message: impl Display, replacement: impl Display) -> Self {
+        self.push("help", format!("{message}: `{replacement}`"))
+    }
+
+    fn push(mut self, label: &str, message: impl Display) -> Self {
+        let _ = write!(self.message, "\n= {label}: {message}");
+        self
+    }
+}
+
+impl From<Diagnostic> for syn::Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        syn::Error::new_spanned(diagnostic.tokens, diagnostic.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_is_one_error() {
+        let error: syn::Error = Diagnostic::spanned(quote! { self }, "cannot consume `self`")
+            .note("in exported method `transfer`")
+            .suggestion("borrow the contract state", "&self")
+            .help("or make the method private")
+            .into();
+
+        let messages: Vec<_> = error.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            ["cannot consume `self`\n\
+              = note: in exported method `transfer`\n\
+              = help: borrow the contract state: `&self`\n\
+              = help: or make the method private"]
+        );
+    }
+}
@@ -0,0 +1,624 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `#[derive(Event)]` expansion.
+//!
+//! Generates the topic consts events are emitted under. A struct gets a
+//! `TOPIC` const. An enum gets one const per variant, named after the variant
+//! in `SCREAMING_SNAKE_CASE`, so a single type can carry several topics
+//! (`PauseToggled::PAUSED` / `PauseToggled::UNPAUSED`), plus a `TOPICS` list
+//! and a `topic()` method returning the topic of a value's variant.
+//!
+//! Topics default to the `snake_case` name of the struct or variant and are
+//! set explicitly with `#[event(topic = "...")]`.
+//!
+//! Struct fields marked `#[event(indexed)]` are listed in an `INDEXED` const,
+//! which the contract schema reports so indexers know which fields to make
+//! searchable. Fields marked `#[event(unit = "lux")]` are listed with their
+//! display unit in a `UNITS` const, reported by the schema for wallets.
+//!
+//! A struct whose shape changed across contract upgrades is marked
+//! `#[event(version = N)]`: its topic gets an `@vN` suffix from version 2 on,
+//! and a `VERSION` const the contract schema reports. Earlier versions stay
+//! decodable when their structs are registered with
+//! `#[contract(past_events = [...])]`.
+//!
+//! Structs also get `from_receipt`, decoding the events emitted under their
+//! topic from a call receipt for tests. Its `for<'a> Self: Decode` bound is
+//! higher-ranked so it is only checked where the helper is called, leaving
+//! types that cannot be checked as archives free to derive `Event`.
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, LitInt, LitStr};
+
+use crate::validate;
+
+/// Expand `#[derive(Event)]` on a struct or enum.
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let items = match &input.data {
+        Data::Struct(data) => struct_items(input, &data.fields)?,
+        Data::Enum(data) => {
+            if topic_attr(&input.attrs)?.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "#[event(topic = \"...\")] on an enum goes on its variants",
+                ));
+            }
+            if let Some(version) = version_attr(&input.attrs)? {
+                return Err(syn::Error::new_spanned(
+                    version,
+                    "#[event(version = N)] is only supported on structs",
+                ));
+            }
+            if data.variants.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "#[derive(Event)] needs at least one variant",
+                ));
+            }
+
+            let mut consts = BTreeMap::from([("TOPICS".to_string(), None)]);
+            let mut topics = BTreeMap::new();
+            let mut defs = Vec::new();
+            let mut names = Vec::new();
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let name = &variant.ident;
+                reject_field_options(&variant.fields)?;
+                if let Some(version) = version_attr(&variant.attrs)? {
+                    return Err(syn::Error::new_spanned(
+                        version,
+                        "#[event(version = N)] is only supported on structs",
+                    ));
+                }
+                let topic = topic(&variant.attrs, &name.to_string())?;
+                let const_name = snake_case(&name.to_string()).to_uppercase();
+
+                if let Some(other) = consts.insert(const_name.clone(), Some(name)) {
+                    let clash = match other {
+                        Some(other) => format!("the topic const of variant `{other}`"),
+                        None => "the generated `TOPICS` const".to_string(),
+                    };
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        format!(
+                            "topic const `{const_name}` of variant `{name}` clashes with {clash}"
+                        ),
+                    ));
+                }
+                if let Some(other) = topics.insert(topic.value(), name) {
+                    return Err(syn::Error::new_spanned(
+                        &topic,
+                        format!(
+                            "topic \"{}\" is already used by variant `{other}`",
+                            topic.value()
+                        ),
+                    ));
+                }
+
+                let const_ident = format_ident!("{const_name}", span = name.span());
+                let doc = format!("Topic of [`{ident}::{name}`] events.");
+                let pattern = match &variant.fields {
+                    Fields::Named(_) => quote! { { .. } },
+                    Fields::Unnamed(_) => quote! { (..) },
+                    Fields::Unit => quote! {},
+                };
+
+                defs.push(quote! {
+                    #[doc = #doc]
+                    pub const #const_ident: &'static str = #topic;
+                });
+                arms.push(quote! { Self::#name #pattern => Self::#const_ident, });
+                names.push(const_ident);
+            }
+
+            quote! {
+                #(#defs)*
+
+                /// Topics of all variants, in declaration order.
+                pub const TOPICS: &'static [&'static str] = &[#(Self::#names),*];
+
+                /// Topic this event is emitted under.
+                #[must_use]
+                pub const fn topic(&self) -> &'static str {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "#[derive(Event)] can only be applied to structs and enums",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #items
+        }
+    })
+}
+
+/// The topic, version, indexed field and field unit consts of an event
+/// struct.
+fn struct_items(input: &DeriveInput, fields: &Fields) -> Result<TokenStream2, syn::Error> {
+    let ident = &input.ident;
+    let version = version_attr(&input.attrs)?;
+    let topic = versioned(topic(&input.attrs, &ident.to_string())?, version.as_ref())?;
+    let doc = format!("Topic of [`{ident}`] events.");
+    let FieldOptions { indexed, units } = field_options(fields)?;
+    let indexed = (!indexed.is_empty()).then(|| {
+        quote! {
+            /// Fields marked `#[event(indexed)]`.
+            pub const INDEXED: &'static [&'static str] = &[#(#indexed),*];
+        }
+    });
+    let units = (!units.is_empty()).then(|| {
+        let units = units.iter().map(|(field, unit)| {
+            quote! { dusk_forge::schema::FieldUnit { field: #field, unit: #unit } }
+        });
+        quote! {
+            /// Display units of the fields marked `#[event(unit = "...")]`.
+            pub const UNITS: &'static [dusk_forge::schema::FieldUnit] = &[#(#units),*];
+        }
+    });
+    let version = version.map(|version| {
+        quote! {
+            /// Version of the event's shape, from `#[event(version = N)]`.
+            pub const VERSION: u32 = #version;
+        }
+    });
+
+    let receipt_doc =
+        format!("The [`{ident}`] events recorded in a call receipt, in emission order.");
+
+    Ok(quote! {
+        #[doc = #doc]
+        pub const TOPIC: &'static str = #topic;
+        #version
+        #indexed
+        #units
+
+        #[doc = #receipt_doc]
+        ///
+        /// Takes a `dusk_vm::CallReceipt` (with the `vm` feature of
+        /// `dusk-forge`) or its `events`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if an event under the topic does not decode as this type.
+        #[must_use]
+        pub fn from_receipt(
+            receipt: &(impl dusk_forge::events::EventLog + ?::core::marker::Sized),
+        ) -> dusk_forge::events::Vec<Self>
+        where
+            for<'a> Self: dusk_forge::events::Decode,
+        {
+            dusk_forge::events::decode_all(receipt, Self::TOPIC)
+        }
+    })
+}
+
+/// Reject `#[event(...)]` on enum variant fields, which cannot be indexed or
+/// given units.
+fn reject_field_options(fields: &Fields) -> Result<(), syn::Error> {
+    match fields
+        .iter()
+        .find(|field| field.attrs.iter().any(|a| a.path().is_ident("event")))
+    {
+        Some(field) => Err(syn::Error::new_spanned(
+            field,
+            "#[event(indexed)] and #[event(unit = \"...\")] are only supported on struct fields",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The `#[event(...)]` options of a struct's fields.
+#[derive(Default)]
+struct FieldOptions {
+    /// Names of the fields marked `#[event(indexed)]`.
+    indexed: Vec<String>,
+    /// Names and units of the fields marked `#[event(unit = "...")]`.
+    units: Vec<(String, String)>,
+}
+
+/// Collect the `#[event(...)]` field options; tuple struct fields are named
+/// by index.
+fn field_options(fields: &Fields) -> Result<FieldOptions, syn::Error> {
+    let mut options = FieldOptions::default();
+    for (i, field) in fields.iter().enumerate() {
+        let name = field
+            .ident
+            .as_ref()
+            .map_or(i.to_string(), ToString::to_string);
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("event"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("indexed") {
+                    if !options.indexed.contains(&name) {
+                        options.indexed.push(name.clone());
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("unit") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    options.units.push((name.clone(), validate::unit(&lit)?));
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unknown event field option; expected `indexed` or `unit = \"...\"`",
+                    ))
+                }
+            })?;
+        }
+    }
+    Ok(options)
+}
+
+/// The topic set with `#[event(topic = "...")]`, or the `snake_case` form of
+/// `name`.
+fn topic(attrs: &[Attribute], name: &str) -> Result<LitStr, syn::Error> {
+    Ok(topic_attr(attrs)?
+        .unwrap_or_else(|| LitStr::new(&snake_case(name), proc_macro2::Span::call_site())))
+}
+
+/// `topic` with the `@vN` suffix of `version`, if it is 2 or more.
+fn versioned(topic: LitStr, version: Option<&LitInt>) -> Result<LitStr, syn::Error> {
+    match version {
+        Some(version) if version.base10_parse::<u32>()? > 1 => Ok(LitStr::new(
+            &format!("{}@v{}", topic.value(), version.base10_digits()),
+            topic.span(),
+        )),
+        _ => Ok(topic),
+    }
+}
+
+/// Parse `#[event(topic = "...")]` from `attrs`.
+fn topic_attr(attrs: &[Attribute]) -> Result<Option<LitStr>, syn::Error> {
+    Ok(options(attrs)?.0)
+}
+
+/// Parse `#[event(version = N)]` from `attrs`.
+fn version_attr(attrs: &[Attribute]) -> Result<Option<LitInt>, syn::Error> {
+    Ok(options(attrs)?.1)
+}
+
+/// Parse the `topic` and `version` options of the `#[event(...)]` attributes
+/// in `attrs`.
+fn options(attrs: &[Attribute]) -> Result<(Option<LitStr>, Option<LitInt>), syn::Error> {
+    let mut topic = None;
+    let mut version = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("event")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("topic") {
+                let value: LitStr = meta.value()?.parse()?;
+                if value.value().is_empty() {
+                    return Err(meta.error("event topics cannot be empty"));
+                }
+                topic = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("version") {
+                let value: LitInt = meta.value()?.parse()?;
+                if value.base10_parse::<u32>()? == 0 {
+                    return Err(meta.error("event versions start at 1"));
+                }
+                version = Some(value);
+                Ok(())
+            } else {
+                Err(meta.error("unknown event option; expected `topic = \"...\"` or `version = N`"))
+            }
+        })?;
+    }
+    Ok((topic, version))
+}
+
+/// Convert a `CamelCase` identifier to `snake_case`, keeping acronyms
+/// together (`HTTPRequest` → `http_request`).
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev != '_' && (prev.is_lowercase() || prev.is_ascii_digit() || next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normalize_tokens;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("Paused"), "paused");
+        assert_eq!(snake_case("OwnershipTransferred"), "ownership_transferred");
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("Stage2Done"), "stage2_done");
+        assert_eq!(snake_case("Already_Snake"), "already_snake");
+    }
+
+    #[test]
+    fn test_expand_struct() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct CounterReset();
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+        let expected = normalize_tokens(&quote! {
+            impl CounterReset {
+                #[doc = "Topic of [`CounterReset`] events."]
+                pub const TOPIC: &'static str = "counter_reset";
+
+                #[doc = "The [`CounterReset`] events recorded in a call receipt, in emission order."]
+                ///
+                /// Takes a `dusk_vm::CallReceipt` (with the `vm` feature of
+                /// `dusk-forge`) or its `events`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if an event under the topic does not decode as this type.
+                #[must_use]
+                pub fn from_receipt(
+                    receipt: &(impl dusk_forge::events::EventLog + ?::core::marker::Sized),
+                ) -> dusk_forge::events::Vec<Self>
+                where
+                    for<'a> Self: dusk_forge::events::Decode,
+                {
+                    dusk_forge::events::decode_all(receipt, Self::TOPIC)
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_expand_enum() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub enum PauseToggled {
+                Paused,
+                #[event(topic = "resumed")]
+                Unpaused { by: PublicKey },
+                Scheduled(u64),
+            }
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+        let expected = normalize_tokens(&quote! {
+            impl PauseToggled {
+                #[doc = "Topic of [`PauseToggled::Paused`] events."]
+                pub const PAUSED: &'static str = "paused";
+                #[doc = "Topic of [`PauseToggled::Unpaused`] events."]
+                pub const UNPAUSED: &'static str = "resumed";
+                #[doc = "Topic of [`PauseToggled::Scheduled`] events."]
+                pub const SCHEDULED: &'static str = "scheduled";
+
+                /// Topics of all variants, in declaration order.
+                pub const TOPICS: &'static [&'static str] =
+                    &[Self::PAUSED, Self::UNPAUSED, Self::SCHEDULED];
+
+                /// Topic this event is emitted under.
+                #[must_use]
+                pub const fn topic(&self) -> &'static str {
+                    match self {
+                        Self::Paused => Self::PAUSED,
+                        Self::Unpaused { .. } => Self::UNPAUSED,
+                        Self::Scheduled(..) => Self::SCHEDULED,
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_expand_versioned_struct() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[event(topic = "counter_updated", version = 2)]
+            pub struct CounterUpdated {
+                previous: u64,
+                new: u64,
+            }
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+        let expected = normalize_tokens(&quote! {
+            #[doc = "Topic of [`CounterUpdated`] events."]
+            pub const TOPIC: &'static str = "counter_updated@v2";
+            /// Version of the event's shape, from `#[event(version = N)]`.
+            pub const VERSION: u32 = 2;
+        });
+        assert!(output.contains(&expected), "{output}");
+        // Receipts are filtered by the versioned topic
+        assert!(output.contains("decode_all (receipt , Self :: TOPIC)"));
+
+        // The first version keeps the plain topic
+        let input: DeriveInput = syn::parse_quote! {
+            #[event(topic = "counter_updated", version = 1)]
+            pub struct CounterUpdatedV1(u64);
+        };
+        let output = normalize_tokens(&expand(&input).unwrap());
+        assert!(output.contains(r#"TOPIC : & 'static str = "counter_updated" ;"#));
+        assert!(output.contains("VERSION : u32 = 1 ;"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[event(version = 0)]
+            pub struct Reset;
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for version 0");
+        };
+        assert!(err.to_string().contains("start at 1"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            pub enum Toggled {
+                #[event(version = 2)]
+                On,
+            }
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for a versioned variant");
+        };
+        assert!(
+            err.to_string().contains("only supported on structs"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_expand_indexed_fields() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Transfer {
+                #[event(indexed)]
+                from: PublicKey,
+                #[event(indexed)]
+                to: PublicKey,
+                value: u64,
+            }
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+        let expected = normalize_tokens(&quote! {
+            /// Fields marked `#[event(indexed)]`.
+            pub const INDEXED: &'static [&'static str] = &["from", "to"];
+        });
+        assert!(output.contains(&expected), "{output}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Minted(#[event(indexed)] PublicKey, u64);
+        };
+        let output = normalize_tokens(&expand(&input).unwrap());
+        assert!(output.contains(r#"& ["0"]"#), "{output}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Burned(#[event(topic = "x")] u64);
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for an unknown field option");
+        };
+        assert!(err.to_string().contains("expected `indexed`"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            pub enum Moved {
+                Out { #[event(indexed)] to: PublicKey },
+            }
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for an indexed variant field");
+        };
+        assert!(
+            err.to_string().contains("only supported on struct fields"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_expand_field_units() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Deposited {
+                #[event(indexed, unit = "lux")]
+                amount: u64,
+                #[event(unit = "blocks")]
+                unlock_in: u64,
+            }
+        };
+        let output = normalize_tokens(&expand(&input).unwrap());
+        let expected = normalize_tokens(&quote! {
+            /// Display units of the fields marked `#[event(unit = "...")]`.
+            pub const UNITS: &'static [dusk_forge::schema::FieldUnit] = &[
+                dusk_forge::schema::FieldUnit { field: "amount", unit: "lux" },
+                dusk_forge::schema::FieldUnit { field: "unlock_in", unit: "blocks" }
+            ];
+        });
+        assert!(output.contains(&expected), "{output}");
+        assert!(output.contains(r#"& ["amount"]"#), "{output}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Deposited(#[event(unit = "satoshi")] u64);
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for an unknown unit");
+        };
+        assert!(err.to_string().contains("unknown unit `satoshi`"), "{err}");
+    }
+
+    #[test]
+    fn test_expand_rejects_duplicate_topics_and_consts() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Toggled {
+                On,
+                #[event(topic = "on")]
+                Off,
+            }
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for a duplicate topic");
+        };
+        assert!(
+            err.to_string().contains("already used by variant `On`"),
+            "{err}"
+        );
+
+        let input: DeriveInput = syn::parse_quote! {
+            enum Listed {
+                Topics,
+            }
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for a const clash");
+        };
+        assert!(err.to_string().contains("`TOPICS` const"), "{err}");
+    }
+
+    #[test]
+    fn test_expand_rejects_misplaced_options() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[event(topic = "toggled")]
+            enum Toggled {
+                On,
+            }
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for an enum-level topic");
+        };
+        assert!(err.to_string().contains("goes on its variants"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[event(name = "reset")]
+            struct Reset;
+        };
+        let Err(err) = expand(&input) else {
+            panic!("expected error for an unknown option");
+        };
+        assert!(err.to_string().contains("unknown event option"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            enum Never {}
+        };
+        assert!(expand(&input).is_err());
+    }
+}
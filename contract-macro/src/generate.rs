@@ -6,11 +6,20 @@
 
 //! Code generation functions for the contract macro.
 
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::{format_ident, quote};
-use syn::{ImplItem, ItemImpl};
-
-use crate::{EventInfo, FunctionInfo, ImportInfo, ParameterInfo, Receiver};
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Block, Expr, ExprLit, FnArg, ImplItem, Item, ItemImpl, Lit, LitStr, Macro, Member, Stmt, Token,
+};
+
+use crate::resolve::TypeMap;
+use crate::{
+    CalleeInfo, Cooldown, CooldownScope, Deprecation, EventInfo, FeedFallback, FunctionInfo,
+    ImportInfo, ParameterInfo, Receiver, StateField, data_driver,
+};
 
 /// Generate the argument expression for passing to the method.
 ///
@@ -27,11 +36,25 @@ fn generate_arg_expr(param: &ParameterInfo) -> TokenStream2 {
 }
 
 /// Generate the schema constant.
+///
+/// Each event records its `topic` as written in the contract (a const path
+/// such as `events::Transfer::TOPIC`, or a literal) and, as `value`, the
+/// string the topic resolves to, evaluated from the const at compile time.
+/// Events also found in `genesis`, those emitted by `init`, are flagged as
+/// genesis events. The `indexed` fields of an event are read from its data
+/// type (see [`indexed_fields`]). The `abi` version is read from
+/// `DUSK_FORGE_ABI_VERSION` when the contract is compiled. `callees` lists
+/// the `calls` interfaces with their contract ID newtypes.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn schema(
     contract_name: &str,
     imports: &[ImportInfo],
+    state: &[StateField],
     functions: &[FunctionInfo],
     events: &[EventInfo],
+    genesis: &[EventInfo],
+    callees: Option<&CalleeInfo>,
+    type_map: &TypeMap,
 ) -> TokenStream2 {
     let contract_name_lit = contract_name;
 
@@ -50,27 +73,23 @@ pub(crate) fn schema(
         })
         .collect();
 
-    let function_entries: Vec<_> = functions
-        .iter()
-        .map(|f| {
-            let name_str = f.name.to_string();
-            let doc = f.doc.as_deref().unwrap_or("");
-            let input = &f.input_type;
-            let output = &f.output_type;
-
-            // Convert type tokens to string for the schema
-            let input_str = input.to_string();
-            let output_str = output.to_string();
+    let state_entries = state.iter().map(|field| {
+        let name = &field.name;
+        let ty = field.ty.to_string();
+        let sealed = is_sealed(&field.ty);
 
-            quote! {
-                dusk_forge::schema::Function {
-                    name: #name_str,
-                    doc: #doc,
-                    input: #input_str,
-                    output: #output_str,
-                }
+        quote! {
+            dusk_forge::schema::Field {
+                name: #name,
+                ty: #ty,
+                sealed: #sealed,
             }
-        })
+        }
+    });
+
+    let function_entries: Vec<_> = functions
+        .iter()
+        .map(|f| function_entry(f, type_map))
         .collect();
 
     let event_entries: Vec<_> = events
@@ -82,39 +101,503 @@ pub(crate) fn schema(
             // Convert type tokens to string for the schema
             let data_str = data.to_string();
 
+            // Bare lowercase identifiers may be local variables, which have
+            // no value to resolve
+            let value = data_driver::event_topic(e, type_map)
+                .map_or_else(|| quote! { None }, |value| quote! { Some(#value) });
+            let genesis = genesis.iter().any(|g| g.topic == e.topic);
+            let indexed = indexed_fields(e, type_map);
+            let version = event_version(e, type_map);
+            let units = event_units(e, type_map);
+
             quote! {
                 dusk_forge::schema::Event {
                     topic: #topic,
+                    value: #value,
                     data: #data_str,
+                    genesis: #genesis,
+                    indexed: #indexed,
+                    version: #version,
+                    units: #units,
                 }
             }
         })
         .collect();
 
+    let callee_entries = callees
+        .into_iter()
+        .flat_map(|callees| &callees.interfaces)
+        .map(|(interface, id)| {
+            let id = id.to_string();
+            quote! {
+                dusk_forge::schema::Callee {
+                    id: #id,
+                    interface: #interface,
+                }
+            }
+        });
+
     quote! {
         /// Contract schema containing metadata about functions, events, and imports.
         pub const CONTRACT_SCHEMA: dusk_forge::schema::Contract = dusk_forge::schema::Contract {
             name: #contract_name_lit,
+            abi: option_env!("DUSK_FORGE_ABI_VERSION"),
             imports: &[#(#import_entries),*],
+            state: &[#(#state_entries),*],
             functions: &[#(#function_entries),*],
             events: &[#(#event_entries),*],
+            callees: &[#(#callee_entries),*],
         };
     }
 }
 
+/// The `#[event(indexed)]` fields of an event's data type.
+///
+/// `#[derive(Event)]` gives structs with indexed fields an inherent `INDEXED`
+/// const; any other type falls back to the empty one of
+/// `dusk_forge::events::Unindexed`. Only named types are looked up: tuples,
+/// primitives and variables emitted as data have no fields to index.
+fn indexed_fields(event: &EventInfo, type_map: &TypeMap) -> TokenStream2 {
+    let named = match syn::parse2::<syn::Type>(event.data_type.clone()) {
+        Ok(syn::Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident.to_string().starts_with(char::is_uppercase)),
+        _ => false,
+    };
+    if !named {
+        return quote! { &[] };
+    }
+
+    let data = data_driver::get_resolved_type(&event.data_type, type_map);
+    quote! {
+        {
+            use dusk_forge::events::Unindexed as _;
+            <#data>::INDEXED
+        }
+    }
+}
+
+/// The version of an event's data type.
+///
+/// `#[derive(Event)]` gives structs with `#[event(version = N)]` an inherent
+/// `VERSION` const; any other type falls back to the version 1 of
+/// `dusk_forge::events::Unversioned`. Only named types are looked up, as for
+/// [`indexed_fields`].
+fn event_version(event: &EventInfo, type_map: &TypeMap) -> TokenStream2 {
+    let named = match syn::parse2::<syn::Type>(event.data_type.clone()) {
+        Ok(syn::Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident.to_string().starts_with(char::is_uppercase)),
+        _ => false,
+    };
+    if !named {
+        return quote! { 1 };
+    }
+
+    let data = data_driver::get_resolved_type(&event.data_type, type_map);
+    quote! {
+        {
+            use dusk_forge::events::Unversioned as _;
+            <#data>::VERSION
+        }
+    }
+}
+
+/// The `#[event(unit = "...")]` fields of an event's data type.
+///
+/// `#[derive(Event)]` gives structs with such fields an inherent `UNITS`
+/// const; any other type falls back to the empty one of
+/// `dusk_forge::events::Unitless`. Only named types are looked up, as for
+/// [`indexed_fields`].
+fn event_units(event: &EventInfo, type_map: &TypeMap) -> TokenStream2 {
+    let named = match syn::parse2::<syn::Type>(event.data_type.clone()) {
+        Ok(syn::Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident.to_string().starts_with(char::is_uppercase)),
+        _ => false,
+    };
+    if !named {
+        return quote! { &[] };
+    }
+
+    let data = data_driver::get_resolved_type(&event.data_type, type_map);
+    quote! {
+        {
+            use dusk_forge::events::Unitless as _;
+            <#data>::UNITS
+        }
+    }
+}
+
+/// The format hint of a parameter type.
+///
+/// `#[derive(HexBytes)]` gives types an inherent `FORMAT` const; any other
+/// type falls back to the empty one of `dusk_forge::schema::Unformatted`.
+/// Only named types are looked up, as for [`indexed_fields`], through their
+/// resolved path since the schema lives at the crate root. Types from
+/// `alloc`, `core` and `std` never derive `HexBytes` and are skipped.
+fn format_hint(ty: &TokenStream2, type_map: &TypeMap) -> TokenStream2 {
+    let named = match syn::parse2::<syn::Type>(ty.clone()) {
+        Ok(syn::Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident.to_string().starts_with(char::is_uppercase)),
+        _ => false,
+    };
+    if !named {
+        return quote! { "" };
+    }
+
+    let resolved = data_driver::get_resolved_type(ty, type_map);
+    let root = resolved.to_string();
+    if ["alloc ::", "core ::", "std ::"]
+        .iter()
+        .any(|prefix| root.starts_with(prefix))
+    {
+        return quote! { "" };
+    }
+
+    quote! {
+        {
+            use dusk_forge::schema::Unformatted as _;
+            <#resolved>::FORMAT
+        }
+    }
+}
+
+/// Generate the schema entry of a single function.
+fn function_entry(f: &FunctionInfo, type_map: &TypeMap) -> TokenStream2 {
+    let name_str = f.name.to_string();
+    let doc = f.doc.as_deref().unwrap_or("");
+    let input = &f.input_type;
+    let output = &f.output_type;
+
+    // Convert type tokens to string for the schema
+    let input_str = input.to_string();
+    let output_str = output.to_string();
+    let inputs = f.params.iter().map(|p| {
+        let param_name = p.name.to_string();
+        let param_ty = p.ty.to_string();
+        let format = format_hint(&p.ty, type_map);
+        let unit = p.unit.as_deref().unwrap_or("");
+        quote! {
+            dusk_forge::schema::Parameter {
+                name: #param_name,
+                ty: #param_ty,
+                format: #format,
+                unit: #unit,
+            }
+        }
+    });
+    let feeds = if let Some(feed_type) = &f.feed_type {
+        let feed_str = feed_type.to_string();
+        quote! { Some(#feed_str) }
+    } else {
+        quote! { None }
+    };
+    let cooldown = f.cooldown.map_or_else(
+        || quote! { None },
+        |Cooldown { blocks, per }| {
+            let per = per.as_str();
+            quote! {
+                Some(dusk_forge::schema::Cooldown {
+                    blocks: #blocks,
+                    per: #per,
+                })
+            }
+        },
+    );
+
+    let when_not_paused = f.when_not_paused;
+    let internal = f.internal;
+    let errors = &f.errors;
+    let cfg_attrs = cfg_attrs(f);
+    let cfg = cfg_predicate(f).map_or_else(|| quote! { None }, |cfg| quote! { Some(#cfg) });
+    let deprecated = f.deprecated.as_ref().map_or_else(
+        || quote! { None },
+        |Deprecation {
+             message,
+             replacement,
+         }| {
+            let replacement = replacement.as_ref().map_or_else(
+                || quote! { None },
+                |replacement| quote! { Some(#replacement) },
+            );
+            quote! {
+                Some(dusk_forge::schema::Deprecation {
+                    message: #message,
+                    replacement: #replacement,
+                })
+            }
+        },
+    );
+
+    quote! {
+        #cfg_attrs
+        dusk_forge::schema::Function {
+            name: #name_str,
+            doc: #doc,
+            input: #input_str,
+            inputs: &[#(#inputs),*],
+            output: #output_str,
+            feeds: #feeds,
+            cooldown: #cooldown,
+            when_not_paused: #when_not_paused,
+            errors: &[#(#errors),*],
+            deprecated: #deprecated,
+            internal: #internal,
+            cfg: #cfg,
+        }
+    }
+}
+
+/// The `#[cfg(...)]` attributes of a method, repeated on every item generated
+/// for it so a feature-gated method is left out of the wrappers, the schema
+/// and the data-driver alike.
+pub(crate) fn cfg_attrs(f: &FunctionInfo) -> TokenStream2 {
+    let predicates = &f.cfg;
+    quote! { #(#[cfg(#predicates)])* }
+}
+
+/// The schema's `cfg` of a method: its predicate, or `all(...)` of several,
+/// or `None` for a method compiled unconditionally.
+fn cfg_predicate(f: &FunctionInfo) -> Option<String> {
+    match f.cfg.as_slice() {
+        [] => None,
+        [predicate] => Some(predicate.to_string()),
+        predicates => {
+            let predicates: Vec<_> = predicates.iter().map(ToString::to_string).collect();
+            Some(format!("all({})", predicates.join(", ")))
+        }
+    }
+}
+
 /// Generate the static `STATE` variable declaration.
 ///
-/// This creates a mutable static variable initialized via the contract's
-/// `new()` constructor:
+/// This creates a static `dusk_forge::state::ContractState` cell initialized
+/// via the contract's const `constructor`, `new()` unless renamed with
+/// `#[contract(constructor = "...")]`:
 ///
 /// ```ignore
-/// static mut STATE: ContractName = ContractName::new();
+/// static STATE: dusk_forge::state::ContractState<ContractName> =
+///     dusk_forge::state::ContractState::new(ContractName::new());
 /// ```
-pub(crate) fn state_static(contract_ident: &Ident) -> TokenStream2 {
+///
+/// The wrappers borrow it with `STATE.state()` / `STATE.state_mut()`, so no
+/// reference to a `static mut` is created.
+///
+/// `vis` is empty unless `STATE` is re-exported from a `generated` module.
+pub(crate) fn state_static(
+    contract_ident: &Ident,
+    constructor: &str,
+    vis: &TokenStream2,
+) -> TokenStream2 {
+    let constructor = format_ident!("{constructor}");
+    let doc = format!(" Static contract state initialized via `{constructor}()`.");
     quote! {
-        /// Static contract state initialized via `new()`.
+        #[doc = #doc]
         #[cfg(target_family = "wasm")]
-        static mut STATE: #contract_ident = #contract_ident::new();
+        #vis static STATE: dusk_forge::state::ContractState<#contract_ident> =
+            dusk_forge::state::ContractState::new(#contract_ident::#constructor());
+    }
+}
+
+/// Generate the getters of `#[contract(getters)]`: an impl block with a
+/// `pub fn <field>(&self) -> <Type>` returning a clone of each field.
+///
+/// ```ignore
+/// impl ContractName {
+///     /// Returns the `owner` field of the contract state.
+///     pub fn owner(&self) -> PublicKey {
+///         ::core::clone::Clone::clone(&self.owner)
+///     }
+/// }
+/// ```
+pub(crate) fn getters(contract_ident: &Ident, fields: &[&syn::Field]) -> Option<ItemImpl> {
+    if fields.is_empty() {
+        return None;
+    }
+
+    let methods = fields.iter().filter_map(|field| {
+        let name = field.ident.as_ref()?;
+        let ty = &field.ty;
+        let doc = format!(" Returns the `{name}` field of the contract state.");
+        Some(quote! {
+            #[doc = #doc]
+            pub fn #name(&self) -> #ty {
+                ::core::clone::Clone::clone(&self.#name)
+            }
+        })
+    });
+    let allows = lint_allows();
+    Some(syn::parse_quote! {
+        #allows
+        impl #contract_ident {
+            #(#methods)*
+        }
+    })
+}
+
+/// Generate hidden `include_str!`s of the interface files read by the macro
+/// (`implements`, `expose_extern` methods and `calls`).
+///
+/// The macro reads the files at expansion time; including them makes cargo
+/// rebuild the contract whenever an interface changes.
+pub(crate) fn interface_dependency<'a>(
+    paths: impl IntoIterator<Item = &'a LitStr>,
+) -> TokenStream2 {
+    let paths = paths.into_iter();
+
+    quote! {
+        #(const _: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #paths));)*
+    }
+}
+
+/// Contract-wide settings the exported wrappers apply on top of each
+/// function's own directives.
+#[derive(Default)]
+pub(crate) struct WrapperOptions<'a> {
+    /// Refuse calls while one of the contract's `dusk_forge::call!`s is in
+    /// progress.
+    pub(crate) reentrancy_guard: bool,
+    /// The state field of type `Pausable` checked by `when_not_paused`
+    /// methods.
+    pub(crate) pausable: Option<&'a Member>,
+    /// The `#[contract(invariant)]` methods checked after mutating calls.
+    pub(crate) invariants: &'a [Ident],
+}
+
+/// Decorate a wrapper's method call with the per-function directives.
+///
+/// - `feed_fallback` stream variants feed each entry of the returned
+///   collection, and limited direct variants assert the collection size.
+/// - Methods taking `&mut self` call the contract's `invariants` on the state
+///   they leave, in debug builds and builds with the contract's `invariants`
+///   feature.
+/// - `reinit_guard` counts calls in the `INIT_CALLS` static and refuses them
+///   past the limit.
+/// - `cooldown_blocks` refuses calls within the caller's window in the method's
+///   `COOLDOWN_<NAME>` static.
+/// - `when_not_paused` refuses calls while the contract's `pausable` state
+///   field is paused.
+/// - Every function records its name for the failure messages of
+///   `dusk_forge::revert!` and `ensure!`.
+/// - With `reentrancy_guard`, the call is refused while one of the contract's
+///   `dusk_forge::call!`s is in progress.
+fn wrapper_body(
+    f: &FunctionInfo,
+    method_call: TokenStream2,
+    options: &WrapperOptions<'_>,
+) -> TokenStream2 {
+    let fn_name = &f.name;
+
+    // `feed_fallback` pairs: stream the collection or cap its size
+    let body = match &f.feed_fallback {
+        Some(FeedFallback::Stream { .. }) => quote! {{
+            for entry in #method_call {
+                dusk_core::abi::feed(entry);
+            }
+        }},
+        Some(FeedFallback::Direct { limit: Some(limit) }) => {
+            let msg = format!(
+                "`{fn_name}` returned more than {limit} entries; call `{fn_name}_feed` instead"
+            );
+            quote! {{
+                let ret = #method_call;
+                assert!(ret.len() <= #limit, #msg);
+                ret
+            }}
+        }
+        _ => method_call,
+    };
+
+    // Mutating methods: check the contract's invariants on the new state
+    let invariants = options.invariants;
+    let body = if f.receiver == Receiver::RefMut && !invariants.is_empty() {
+        quote! {{
+            let ret = #body;
+            if cfg!(any(debug_assertions, feature = "invariants")) {
+                let state = STATE.state();
+                #(state.#invariants();)*
+            }
+            ret
+        }}
+    } else {
+        body
+    };
+
+    // Guarded `init`: count calls and refuse them past the limit
+    let body = match f.reinit_guard {
+        Some(limit) => {
+            let msg = if limit == 1 {
+                format!("`{fn_name}` can only be called once")
+            } else {
+                format!("`{fn_name}` can only be called {limit} times")
+            };
+            quote! {{
+                assert!(*INIT_CALLS.state() < #limit, #msg);
+                *INIT_CALLS.state_mut() += 1;
+                #body
+            }}
+        }
+        None => body,
+    };
+
+    // Rate-limited methods: refuse calls within the caller's window
+    let body = match f.cooldown {
+        Some(Cooldown { blocks, per }) => {
+            let source = cooldown_source(f);
+            let cooldowns = cooldown_ident(source);
+            let method = source.to_string();
+            let key = match per {
+                CooldownScope::Caller => quote! {
+                    dusk_forge::cooldown::caller_key(dusk_core::abi::public_sender(), #method)
+                },
+                CooldownScope::Global => quote! { () },
+            };
+            quote! {{
+                #cooldowns.state_mut().enter(#key, dusk_core::abi::block_height(), #blocks, #method);
+                #body
+            }}
+        }
+        None => body,
+    };
+
+    // Pausable methods: refuse calls while the contract is paused
+    let body = match options.pausable.filter(|_| f.when_not_paused) {
+        Some(field) => {
+            let method = cooldown_source(f).to_string();
+            quote! {{
+                STATE.state().#field.ensure_not_paused(#method);
+                #body
+            }}
+        }
+        None => body,
+    };
+
+    // Name the function in `revert!` / `ensure!` failure messages, including
+    // those raised by helpers the macro cannot see
+    let function = fn_name.to_string();
+    let body = quote! {{
+        let __revert_function = dusk_forge::revert::enter(#function);
+        #body
+    }};
+
+    // Contracts making calls: refuse to be re-entered by the callee
+    if options.reentrancy_guard {
+        quote! {{
+            dusk_forge::call::reject_reentry();
+            #body
+        }}
+    } else {
+        body
     }
 }
 
@@ -130,308 +613,2211 @@ pub(crate) fn state_static(contract_ident: &Ident) -> TokenStream2 {
 ///   fully-qualified syntax.
 /// - For associated functions (no self), calls the function on the contract
 ///   type.
-pub(crate) fn extern_wrappers(functions: &[FunctionInfo], contract_ident: &Ident) -> TokenStream2 {
-    let wrappers: Vec<_> = functions
-        .iter()
+/// - For `#[contract(feed_fallback)]` pairs, the `<name>_feed` wrapper calls
+///   the source method and feeds each entry of the returned collection, and a
+///   direct wrapper with a limit asserts the collection size before returning.
+/// - For an `init` with `#[contract(reinit_guard)]`, the wrapper counts calls
+///   in a hidden `INIT_CALLS` static and panics once the limit is reached.
+/// - For a method with `#[contract(cooldown_blocks = N)]`, the wrapper records
+///   the block height of each accepted call in a hidden `COOLDOWN_<NAME>`
+///   static and panics on calls within `N` blocks of the previous one.
+/// - For a method with `#[contract(when_not_paused)]`, the wrapper panics while
+///   the `pausable` field of STATE is paused.
+/// - For a method taking `&mut self`, the wrapper calls the contract's
+///   `#[contract(invariant)]` methods after it returns, in debug builds and
+///   builds with the contract's `invariants` feature.
+/// - Every wrapper records the method name, which prefixes the failure messages
+///   of `dusk_forge::revert!` and `ensure!`.
+/// - With `reentrancy_guard` (contracts using `dusk_forge::call!`), every
+///   wrapper panics if entered while an outgoing call is in progress.
+///
+/// Wrappers are emitted sorted by name, so reordering methods in the source
+/// leaves the exports and their functions in the WASM where they were.
+pub(crate) fn extern_wrappers(
+    functions: &[FunctionInfo],
+    contract_ident: &Ident,
+    options: &WrapperOptions<'_>,
+) -> TokenStream2 {
+    let wrappers: Vec<_> = by_name(functions)
+        .into_iter()
         .map(|f| {
             let fn_name = &f.name;
-            let input_type = &f.input_type;
-
-            // Build the closure parameter pattern and the method call arguments
-            let (closure_param, method_args) = match f.params.len() {
-                0 => {
-                    // No parameters: |(): ()|
-                    (quote! { (): () }, quote! {})
-                }
-                1 => {
-                    // Single parameter: |name: Type|
-                    let param = &f.params[0];
-                    let name = &param.name;
-                    let ty = &param.ty;
-                    let arg_expr = generate_arg_expr(param);
-                    (quote! { #name: #ty }, arg_expr)
-                }
-                _ => {
-                    // Multiple parameters: |(p1, p2, ...): (T1, T2, ...)|
-                    let names: Vec<_> = f.params.iter().map(|p| &p.name).collect();
-                    let arg_exprs: Vec<_> = f.params.iter().map(generate_arg_expr).collect();
-                    (
-                        quote! { (#(#names),*): #input_type },
-                        quote! { #(#arg_exprs),* },
-                    )
-                }
-            };
-
-            // Generate the method call based on whether it's a regular method,
-            // trait method, or associated function
-            let has_receiver = f.receiver != Receiver::None;
-            let method_call = match (&f.trait_name, has_receiver) {
-                // Trait method with default impl (empty body) - call via trait
-                (Some(trait_name), true) => {
-                    let trait_ident = format_ident!("{}", trait_name);
-                    let state_ref = if f.receiver == Receiver::RefMut {
-                        quote! { &mut STATE }
-                    } else {
-                        quote! { &STATE }
-                    };
-                    if f.returns_ref {
-                        quote! { #trait_ident::#fn_name(#state_ref, #method_args).clone() }
-                    } else {
-                        quote! { #trait_ident::#fn_name(#state_ref, #method_args) }
-                    }
-                }
-                // Trait associated function with default impl (no self)
-                (Some(trait_name), false) => {
-                    let trait_ident = format_ident!("{}", trait_name);
-                    if f.returns_ref {
-                        quote! { <#contract_ident as #trait_ident>::#fn_name(#method_args).clone() }
-                    } else {
-                        quote! { <#contract_ident as #trait_ident>::#fn_name(#method_args) }
-                    }
-                }
-                // Regular method - call on STATE
-                (None, true) => {
-                    if f.returns_ref {
-                        quote! { STATE.#fn_name(#method_args).clone() }
-                    } else {
-                        quote! { STATE.#fn_name(#method_args) }
-                    }
-                }
-                // Associated function (no self, no trait) - shouldn't happen but handle it
-                (None, false) => {
-                    if f.returns_ref {
-                        quote! { #contract_ident::#fn_name(#method_args).clone() }
-                    } else {
-                        quote! { #contract_ident::#fn_name(#method_args) }
-                    }
-                }
-            };
+            let call = wrap_call(f, contract_ident, options);
+            let cfg_attrs = cfg_attrs(f);
 
             quote! {
+                #cfg_attrs
                 #[unsafe(no_mangle)]
                 unsafe extern "C" fn #fn_name(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |#closure_param| #method_call)
+                    #call
                 }
             }
         })
         .collect();
 
+    let init_calls = init_calls_static(functions);
+    let cooldowns = cooldown_statics(functions);
+
     quote! {
         #[cfg(target_family = "wasm")]
         mod __contract_extern_wrappers {
             use super::*;
 
+            #init_calls
+
+            #cooldowns
+
             #(#wrappers)*
         }
     }
 }
 
-/// Strip #[contract(...)] attributes from the impl block and its methods.
-/// For trait impl blocks, also removes empty-body methods (they're just
-/// signature stubs for wrapper generation and should use the trait's default
-/// implementation).
-pub(crate) fn strip_contract_attributes(mut impl_block: ItemImpl) -> ItemImpl {
-    let is_trait_impl = impl_block.trait_.is_some();
-
-    // Strip from the impl block itself (e.g., #[contract(expose = [...])])
-    impl_block
-        .attrs
-        .retain(|attr| !attr.path().is_ident("contract"));
-
-    // Strip from methods (e.g., #[contract(no_event)], #[contract(feeds = "...")])
-    for item in &mut impl_block.items {
-        if let ImplItem::Fn(method) = item {
-            method
-                .attrs
-                .retain(|attr| !attr.path().is_ident("contract"));
+/// Generate extern "C" exports routed through a shared dispatch routine.
+///
+/// Used for `#[contract(compact_dispatch)]`. Each export is a thin thunk that
+/// forwards its function index to a single `#[inline(never)]` dispatch
+/// function. Its arms do not inline `wrap_call` per method: each passes the
+/// method's call closure, as a `fn(Input) -> Output` pointer, to the call
+/// glue of its signature, one non-inlined `wrap_call` per distinct pair of
+/// input and output types. Methods sharing a signature thus share the
+/// argument decoding and output serialization code, which [`extern_wrappers`]
+/// instantiates once per method.
+pub(crate) fn dispatch_wrappers(
+    functions: &[FunctionInfo],
+    contract_ident: &Ident,
+    options: &WrapperOptions<'_>,
+) -> TokenStream2 {
+    let sorted = by_name(functions);
+
+    // One call glue per signature, in order of first use
+    let mut signatures: Vec<(String, Vec<&FunctionInfo>)> = Vec::new();
+    for f in &sorted {
+        let key = signature_key(f);
+        match signatures.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, users)) => users.push(f),
+            None => signatures.push((key, vec![f])),
         }
     }
-
-    // For trait impls, remove empty-body methods so they use the default
-    // implementation
-    if is_trait_impl {
-        impl_block.items.retain(|item| {
-            if let ImplItem::Fn(method) = item {
-                !method.block.stmts.is_empty()
-            } else {
-                true
+    let glue_ident = |f: &FunctionInfo| {
+        let key = signature_key(f);
+        let index = signatures
+            .iter()
+            .position(|(k, _)| *k == key)
+            .expect("signature is collected");
+        format_ident!("__contract_call_{}", index)
+    };
+
+    let glue = signatures.iter().enumerate().map(|(index, (_, users))| {
+        let ident = format_ident!("__contract_call_{}", index);
+        let input_type = &users[0].input_type;
+        let output_type = &users[0].output_type;
+        // Compiled whenever one of its functions is
+        let cfg = if users.iter().all(|f| !f.cfg.is_empty()) {
+            let predicates = users.iter().map(|f| {
+                let predicates = &f.cfg;
+                quote! { all(#(#predicates),*) }
+            });
+            quote! { #[cfg(any(#(#predicates),*))] }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #cfg
+            #[inline(never)]
+            fn #ident(arg_len: u32, method: fn(#input_type) -> #output_type) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, method)
             }
-        });
-    }
-
-    impl_block
-}
+        }
+    });
+
+    let arms = sorted.iter().enumerate().map(|(index, f)| {
+        let index = u32::try_from(index).expect("function count fits in u32");
+        let closure = call_closure(f, contract_ident, options);
+        let glue = glue_ident(f);
+        let cfg_attrs = cfg_attrs(f);
+        quote! { #cfg_attrs #index => #glue(arg_len, #closure), }
+    });
+
+    let thunks = sorted.iter().enumerate().map(|(index, f)| {
+        let fn_name = &f.name;
+        let index = u32::try_from(index).expect("function count fits in u32");
+        let cfg_attrs = cfg_attrs(f);
+        quote! {
+            #cfg_attrs
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn #fn_name(arg_len: u32) -> u32 {
+                unsafe { __contract_dispatch(#index, arg_len) }
+            }
+        }
+    });
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{ParameterInfo, Receiver};
+    let init_calls = init_calls_static(functions);
+    let cooldowns = cooldown_statics(functions);
 
-    fn normalize_tokens(tokens: TokenStream2) -> String {
-        tokens
-            .to_string()
-            .split_whitespace()
-            .collect::<Vec<_>>()
-            .join(" ")
-    }
+    quote! {
+        #[cfg(target_family = "wasm")]
+        mod __contract_extern_wrappers {
+            use super::*;
 
-    #[test]
-    fn test_extern_wrapper_no_params() {
-        let contract_ident = format_ident!("MyContract");
-        let functions = vec![FunctionInfo {
-            name: format_ident!("is_paused"),
-            doc: Some("Returns pause state.".to_string()),
-            params: vec![],
-            input_type: quote! { () },
-            output_type: quote! { bool },
-            returns_ref: false,
-            receiver: Receiver::Ref,
-            trait_name: None,
-            feed_type: None,
-        }];
+            #init_calls
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
+            #cooldowns
 
-        let expected = normalize_tokens(quote! {
-            #[cfg(target_family = "wasm")]
-            mod __contract_extern_wrappers {
-                use super::*;
+            #(#glue)*
 
-                #[unsafe(no_mangle)]
-                unsafe extern "C" fn is_paused(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |(): ()| STATE.is_paused())
+            /// Shared call glue for every exported function, keyed by index.
+            #[inline(never)]
+            unsafe fn __contract_dispatch(index: u32, arg_len: u32) -> u32 {
+                match index {
+                    #(#arms)*
+                    _ => unreachable!(),
                 }
             }
-        });
 
-        assert_eq!(expected, output);
+            #(#thunks)*
+        }
     }
+}
 
-    #[test]
-    fn test_extern_wrapper_single_param() {
-        let contract_ident = format_ident!("MyContract");
-        let functions = vec![FunctionInfo {
-            name: format_ident!("init"),
-            doc: Some("Initialize.".to_string()),
-            params: vec![ParameterInfo {
-                name: format_ident!("owner"),
-                ty: quote! { Address },
-                is_ref: false,
-                is_mut_ref: false,
-            }],
-            input_type: quote! { Address },
-            output_type: quote! { () },
-            returns_ref: false,
-            receiver: Receiver::RefMut,
-            trait_name: None,
-            feed_type: None,
-        }];
+/// The input and output types of a function's wrapper, which decide the
+/// `wrap_call` instantiation it needs.
+fn signature_key(f: &FunctionInfo) -> String {
+    format!("{} -> {}", f.input_type, f.output_type)
+}
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
+/// Wrap generated `items` in the private module named by
+/// `#[contract(generated = "...")]`, gated by `cfg`, followed by the chosen
+/// `reexport`:
+///
+/// ```ignore
+/// mod forge {
+///     use super::*;
+///
+///     static STATE: ContractState<ContractName> = ContractState::new(ContractName::new());
+///     // ...
+/// }
+///
+/// use self::forge::STATE;
+/// ```
+pub(crate) fn generated_module(
+    module: &Ident,
+    cfg: &TokenStream2,
+    items: &TokenStream2,
+    reexport: Option<&TokenStream2>,
+) -> TokenStream2 {
+    quote! {
+        #cfg
+        mod #module {
+            use super::*;
 
-        let expected = normalize_tokens(quote! {
-            #[cfg(target_family = "wasm")]
-            mod __contract_extern_wrappers {
-                use super::*;
+            #items
+        }
 
-                #[unsafe(no_mangle)]
-                unsafe extern "C" fn init(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |owner: Address| STATE.init(owner))
-                }
-            }
-        });
+        #reexport
+    }
+}
 
-        assert_eq!(expected, output);
+/// The lints the generated items are exempt from.
+///
+/// Only the lints the expansion is known to trigger, all from
+/// `clippy::restriction`: `exhaustive_structs` on the data-driver's
+/// `Driver`, `impl_trait_in_params` on the `from_receipt` methods of
+/// `#[derive(Event)]` and `pub_use` on `reexport`ed items. The rest of the
+/// generated code is held to the lints of the contract, and the test
+/// contract's `test-expansion` target checks it against `clippy::pedantic`.
+pub(crate) fn lint_allows() -> TokenStream2 {
+    quote! {
+        #[allow(
+            clippy::exhaustive_structs,
+            clippy::impl_trait_in_params,
+            clippy::pub_use
+        )]
     }
+}
 
-    #[test]
-    fn test_extern_wrapper_multiple_params() {
-        let contract_ident = format_ident!("MyContract");
-        let functions = vec![FunctionInfo {
-            name: format_ident!("transfer"),
-            doc: Some("Transfer funds.".to_string()),
+/// Put [`lint_allows`] on each of the generated `items`.
+///
+/// Tokens that do not parse as items are returned unchanged.
+pub(crate) fn allow_lints(items: TokenStream2) -> TokenStream2 {
+    let Ok(file) = syn::parse2::<syn::File>(items.clone()) else {
+        return items;
+    };
+    let allows = lint_allows();
+    let items = file.items;
+    quote! { #(#allows #items)* }
+}
+
+/// Generate the `contract_schema` export.
+///
+/// Returns `CONTRACT_SCHEMA` serialized to JSON (as an rkyv `String`), so
+/// tooling can query a deployed contract's ABI without the data-driver.
+/// `schema` is the path to `CONTRACT_SCHEMA` from the module the export is
+/// generated in.
+pub(crate) fn schema_export(schema: &TokenStream2) -> TokenStream2 {
+    quote! {
+        #[cfg(target_family = "wasm")]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn contract_schema(arg_len: u32) -> u32 {
+            dusk_core::abi::wrap_call(arg_len, |(): ()| #schema.to_json())
+        }
+    }
+}
+
+/// Generate the `__forge_host_calls` export for `#[contract(metering)]`.
+///
+/// Returns and resets the host-call counts recorded since the previous call,
+/// as `Vec<(String, u64)>`. Only compiled with the contract's `metering`
+/// feature; empty when the option is not set.
+pub(crate) fn metering_export(metering: bool) -> TokenStream2 {
+    if !metering {
+        return TokenStream2::new();
+    }
+
+    quote! {
+        #[cfg(all(target_family = "wasm", feature = "metering"))]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn __forge_host_calls(arg_len: u32) -> u32 {
+            dusk_core::abi::wrap_call(arg_len, |(): ()| dusk_forge::metering::take())
+        }
+    }
+}
+
+/// Generate the `contract_metadata` export for `#[contract(metadata)]`.
+///
+/// Returns the contract's name, crate version, schema hash and source hash as
+/// a `dusk_forge::metadata::Metadata` tuple. The source hash is read from
+/// `DUSK_FORGE_SOURCE_HASH` when the contract is compiled. Empty when the
+/// option is not set. `schema` is the path to `CONTRACT_SCHEMA`, as for
+/// [`schema_export`].
+pub(crate) fn metadata_export(metadata: bool, schema: &TokenStream2) -> TokenStream2 {
+    if !metadata {
+        return TokenStream2::new();
+    }
+
+    quote! {
+        #[cfg(target_family = "wasm")]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn contract_metadata(arg_len: u32) -> u32 {
+            dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                dusk_forge::metadata::collect(
+                    &#schema,
+                    env!("CARGO_PKG_VERSION"),
+                    option_env!("DUSK_FORGE_SOURCE_HASH"),
+                )
+            })
+        }
+    }
+}
+
+/// Generate the `__storage_stats` export for `#[contract(storage_stats)]`.
+///
+/// Feeds a `dusk_forge::storage::FieldStats` for each field of the contract
+/// state, in declaration order. Empty when the option is not set.
+pub(crate) fn storage_stats_export(storage_stats: bool, state: &[StateField]) -> TokenStream2 {
+    if !storage_stats {
+        return TokenStream2::new();
+    }
+
+    let feeds = state.iter().map(|field| {
+        let name = &field.name;
+        let member: Member = syn::parse_str(name).expect("state field names are members");
+        quote! {
+            dusk_core::abi::feed((&dusk_forge::storage::Field(&state.#member)).stats(#name));
+        }
+    });
+
+    quote! {
+        #[cfg(target_family = "wasm")]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn __storage_stats(arg_len: u32) -> u32 {
+            use dusk_forge::storage::{Collection as _, Plain as _};
+
+            dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                let state = STATE.state();
+                #(#feeds)*
+            })
+        }
+    }
+}
+
+/// Parse a stored trait name, which may be a path such as
+/// `ownable::Ownable` for traits exposed from other modules or crates.
+fn trait_path(trait_name: &str) -> syn::Path {
+    syn::parse_str(trait_name).expect("trait names are validated paths")
+}
+
+/// Generate the `wrap_call` invocation for a single exported function.
+///
+/// Deserializes the input into the closure parameters, calls the method, and
+/// returns the serialized output length.
+fn wrap_call(
+    f: &FunctionInfo,
+    contract_ident: &Ident,
+    options: &WrapperOptions<'_>,
+) -> TokenStream2 {
+    let closure = call_closure(f, contract_ident, options);
+    quote! {
+        dusk_core::abi::wrap_call(arg_len, #closure)
+    }
+}
+
+/// Generate the closure a wrapper passes to `wrap_call`: it takes the
+/// deserialized input and calls the method.
+///
+/// The closure captures nothing, so it also coerces to the
+/// `fn(Input) -> Output` pointer the `compact_dispatch` call glue takes.
+fn call_closure(
+    f: &FunctionInfo,
+    contract_ident: &Ident,
+    options: &WrapperOptions<'_>,
+) -> TokenStream2 {
+    let fn_name = &f.name;
+    let input_type = &f.input_type;
+
+    // Build the closure parameter pattern and the method call arguments
+    let (closure_param, method_args) = match f.params.len() {
+        0 => {
+            // No parameters: |(): ()|
+            (quote! { (): () }, quote! {})
+        }
+        1 => {
+            // Single parameter: |name: Type|
+            let param = &f.params[0];
+            let name = &param.name;
+            let ty = &param.ty;
+            let arg_expr = generate_arg_expr(param);
+            (quote! { #name: #ty }, arg_expr)
+        }
+        _ => {
+            // Multiple parameters: |(p1, p2, ...): (T1, T2, ...)|
+            let names: Vec<_> = f.params.iter().map(|p| &p.name).collect();
+            let arg_exprs: Vec<_> = f.params.iter().map(generate_arg_expr).collect();
+            (
+                quote! { (#(#names),*): #input_type },
+                quote! { #(#arg_exprs),* },
+            )
+        }
+    };
+
+    // `<name>_feed` variants call the method whose collection they feed,
+    // aliases the method they were renamed to
+    let call_name = match (&f.feed_fallback, &f.alias_of) {
+        (Some(FeedFallback::Stream { source }), _) | (_, Some(source)) => source,
+        _ => fn_name,
+    };
+
+    // Borrow the state as the method's receiver does
+    let state_ref = if f.receiver == Receiver::RefMut {
+        quote! { STATE.state_mut() }
+    } else {
+        quote! { STATE.state() }
+    };
+
+    // Generate the method call based on whether it's a regular method,
+    // trait method, or associated function
+    let has_receiver = f.receiver != Receiver::None;
+    let method_call = match (&f.trait_name, has_receiver) {
+        // Trait method with default impl (empty body) - call via trait
+        (Some(trait_name), true) => {
+            let trait_ident = trait_path(trait_name);
+            if f.returns_ref {
+                quote! { #trait_ident::#call_name(#state_ref, #method_args).clone() }
+            } else {
+                quote! { #trait_ident::#call_name(#state_ref, #method_args) }
+            }
+        }
+        // Trait associated function with default impl (no self)
+        (Some(trait_name), false) => {
+            let trait_ident = trait_path(trait_name);
+            if f.returns_ref {
+                quote! { <#contract_ident as #trait_ident>::#call_name(#method_args).clone() }
+            } else {
+                quote! { <#contract_ident as #trait_ident>::#call_name(#method_args) }
+            }
+        }
+        // Regular method - call on STATE
+        (None, true) => {
+            if f.returns_ref {
+                quote! { #state_ref.#call_name(#method_args).clone() }
+            } else {
+                quote! { #state_ref.#call_name(#method_args) }
+            }
+        }
+        // Associated function (no self, no trait) - shouldn't happen but handle it
+        (None, false) => {
+            if f.returns_ref {
+                quote! { #contract_ident::#call_name(#method_args).clone() }
+            } else {
+                quote! { #contract_ident::#call_name(#method_args) }
+            }
+        }
+    };
+
+    let body = wrapper_body(f, method_call, options);
+
+    quote! { |#closure_param| #body }
+}
+
+/// The functions sorted by exported name, so the generated wrappers do not
+/// depend on the order methods are declared in.
+fn by_name(functions: &[FunctionInfo]) -> Vec<&FunctionInfo> {
+    let mut sorted: Vec<_> = functions.iter().collect();
+    sorted.sort_by_cached_key(|f| f.name.to_string());
+    sorted
+}
+
+/// Generate the hidden call counter backing `#[contract(reinit_guard)]`.
+///
+/// The counter is persisted with STATE, so it survives across calls, and
+/// held in a `ContractState` cell like it rather than a `static mut`.
+fn init_calls_static(functions: &[FunctionInfo]) -> Option<TokenStream2> {
+    functions.iter().any(|f| f.reinit_guard.is_some()).then(|| {
+        quote! {
+            /// Number of successful calls to the guarded `init`.
+            static INIT_CALLS: dusk_forge::state::ContractState<u32> =
+                dusk_forge::state::ContractState::new(0);
+        }
+    })
+}
+
+/// Generate the hidden call windows backing `#[contract(cooldown_blocks)]`.
+///
+/// One static per rate-limited method, shared with its `feed_fallback` stream
+/// variant and its alias, and persisted with STATE in a `ContractState` cell
+/// like it. Windows are keyed by the
+/// public sender's key bytes, or by `()` for `per = "global"`.
+fn cooldown_statics(functions: &[FunctionInfo]) -> TokenStream2 {
+    let statics = by_name(functions)
+        .into_iter()
+        .filter(|f| !matches!(f.feed_fallback, Some(FeedFallback::Stream { .. })))
+        .filter(|f| f.alias_of.is_none())
+        .filter_map(|f| {
+            let cooldown = f.cooldown?;
+            let ident = cooldown_ident(&f.name);
+            let doc = format!(
+                "Block height of the last accepted `{}` call, per caller.",
+                f.name
+            );
+            let key = match cooldown.per {
+                CooldownScope::Caller => quote! { dusk_forge::cooldown::CallerKey },
+                CooldownScope::Global => quote! { () },
+            };
+            let cfg_attrs = cfg_attrs(f);
+            Some(quote! {
+                #[doc = #doc]
+                #cfg_attrs
+                static #ident: dusk_forge::state::ContractState<
+                    dusk_forge::cooldown::Cooldowns<#key>
+                > = dusk_forge::state::ContractState::new(dusk_forge::cooldown::Cooldowns::new());
+            })
+        });
+
+    quote! { #(#statics)* }
+}
+
+/// The method whose window a wrapper checks: the source method for
+/// `feed_fallback` stream variants, the renamed method for aliases, the
+/// function itself otherwise.
+fn cooldown_source(f: &FunctionInfo) -> &Ident {
+    match (&f.feed_fallback, &f.alias_of) {
+        (Some(FeedFallback::Stream { source }), _) | (_, Some(source)) => source,
+        _ => &f.name,
+    }
+}
+
+/// Name of the static holding the call windows of `method`.
+fn cooldown_ident(method: &Ident) -> Ident {
+    format_ident!("COOLDOWN_{}", method.to_string().to_uppercase())
+}
+
+/// Strip #[contract(...)] attributes from the impl block and its methods.
+/// For trait impl blocks, also removes empty-body methods (they're just
+/// signature stubs for wrapper generation and should use the trait's default
+/// implementation).
+pub(crate) fn strip_contract_attributes(mut impl_block: ItemImpl) -> ItemImpl {
+    let is_trait_impl = impl_block.trait_.is_some();
+
+    // Strip from the impl block itself (e.g., #[contract(expose = [...])])
+    impl_block
+        .attrs
+        .retain(|attr| !attr.path().is_ident("contract"));
+
+    // Strip from methods (e.g., #[contract(no_event)], #[contract(feeds = "...")])
+    // and their parameters (#[contract(unit = "...")])
+    for item in &mut impl_block.items {
+        if let ImplItem::Fn(method) = item {
+            method
+                .attrs
+                .retain(|attr| !attr.path().is_ident("contract"));
+            for input in &mut method.sig.inputs {
+                if let FnArg::Typed(pat_type) = input {
+                    pat_type
+                        .attrs
+                        .retain(|attr| !attr.path().is_ident("contract"));
+                }
+            }
+        }
+    }
+
+    // For trait impls, remove empty-body methods so they use the default
+    // implementation
+    if is_trait_impl {
+        impl_block.items.retain(|item| {
+            if let ImplItem::Fn(method) = item {
+                !method.block.stmts.is_empty()
+            } else {
+                true
+            }
+        });
+    }
+
+    impl_block
+}
+
+/// Precede every host call in the impl block's methods with a
+/// `dusk_forge::metering::record` call, compiled only with the contract's
+/// `metering` feature.
+///
+/// Host calls are `abi::<name>(..)` calls (under any path ending in `abi`)
+/// and the `emit!` / `forward!` / `call!` macros, which expand to
+/// `abi::emit`, `abi::emit_raw` and `abi::call`.
+pub(crate) fn meter_host_calls(mut impl_block: ItemImpl) -> ItemImpl {
+    HostCallMeter.visit_item_impl_mut(&mut impl_block);
+    impl_block
+}
+
+/// Visitor inserting the counters for [`meter_host_calls`].
+struct HostCallMeter;
+
+impl VisitMut for HostCallMeter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        visit_mut::visit_expr_mut(self, expr);
+
+        let host_call = match expr {
+            Expr::Call(call) => match &*call.func {
+                Expr::Path(path) => abi_call_name(&path.path),
+                _ => None,
+            },
+            Expr::Macro(expr_macro) => macro_host_call(&expr_macro.mac).map(String::from),
+            _ => None,
+        };
+
+        if let Some(host_call) = host_call {
+            *expr = syn::parse_quote! {{
+                #[cfg(feature = "metering")]
+                dusk_forge::metering::record(#host_call);
+                #expr
+            }};
+        }
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        visit_mut::visit_block_mut(self, block);
+
+        // Statement-position macros (`emit!(..);`) are not expressions, so
+        // their counter goes in front of them
+        for stmt in std::mem::take(&mut block.stmts) {
+            if let Stmt::Macro(stmt_macro) = &stmt
+                && let Some(host_call) = macro_host_call(&stmt_macro.mac)
+            {
+                block.stmts.push(syn::parse_quote! {
+                    #[cfg(feature = "metering")]
+                    dusk_forge::metering::record(#host_call);
+                });
+            }
+            block.stmts.push(stmt);
+        }
+    }
+}
+
+/// Whether a state field type holds `dusk_forge::sealed::Sealed` values,
+/// itself or inside a container such as `BTreeMap<K, Sealed<V>>`. Sealed
+/// types are recognized by the last segment of their path.
+fn is_sealed(ty: &TokenStream2) -> bool {
+    struct Finder(bool);
+
+    impl<'ast> Visit<'ast> for Finder {
+        fn visit_type_path(&mut self, node: &'ast syn::TypePath) {
+            self.0 |= node
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Sealed");
+            syn::visit::visit_type_path(self, node);
+        }
+    }
+
+    let Ok(ty) = syn::parse2::<syn::Type>(ty.clone()) else {
+        return false;
+    };
+    let mut finder = Finder(false);
+    finder.visit_type(&ty);
+    finder.0
+}
+
+/// Host function called by `abi::<name>` paths.
+fn abi_call_name(path: &syn::Path) -> Option<String> {
+    let mut segments = path.segments.iter().rev();
+    let name = segments.next()?;
+    let module = segments.next()?;
+    (module.ident == "abi").then(|| name.ident.to_string())
+}
+
+/// Host function a forge event macro expands to.
+fn macro_host_call(mac: &Macro) -> Option<&'static str> {
+    match mac.path.segments.last()?.ident.to_string().as_str() {
+        "emit" => Some("emit"),
+        "forward" => Some("emit_raw"),
+        "call" => Some("call"),
+        _ => None,
+    }
+}
+
+/// Whether any item of the module calls other contracts through
+/// `dusk_forge::call!`.
+pub(crate) fn uses_call(items: &[Item]) -> bool {
+    struct Finder(bool);
+
+    impl<'ast> Visit<'ast> for Finder {
+        fn visit_macro(&mut self, mac: &'ast Macro) {
+            self.0 |= is_forge_call(mac);
+        }
+    }
+
+    let mut finder = Finder(false);
+    for item in items {
+        finder.visit_item(item);
+    }
+    finder.0
+}
+
+/// Generate the contract ID newtype of each `calls` interface, with
+/// conversions from and to `ContractId`, a hex `Display` and the
+/// `dusk_forge::call::Callee` impl letting `dusk_forge::call!` reach the
+/// interface's functions through it.
+pub(crate) fn callee_ids(callees: &CalleeInfo) -> Vec<Item> {
+    let allows = lint_allows();
+    callees
+        .interfaces
+        .iter()
+        .flat_map(|(path, id)| {
+            let doc = format!("ID of a contract implementing the `{path}` interface.");
+            let items: [Item; 5] = [
+                syn::parse_quote! {
+                    #allows
+                    #[doc = #doc]
+                    #[dusk_forge::contract_input]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                    pub struct #id(
+                        #[arbitrary(with = dusk_forge::fuzz::contract_id)]
+                        pub dusk_core::abi::ContractId,
+                    );
+                },
+                syn::parse_quote! {
+                    #allows
+                    impl From<dusk_core::abi::ContractId> for #id {
+                        fn from(id: dusk_core::abi::ContractId) -> Self {
+                            Self(id)
+                        }
+                    }
+                },
+                syn::parse_quote! {
+                    #allows
+                    impl From<#id> for dusk_core::abi::ContractId {
+                        fn from(id: #id) -> Self {
+                            id.0
+                        }
+                    }
+                },
+                syn::parse_quote! {
+                    #allows
+                    impl core::fmt::Display for #id {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                            f.write_str(&dusk_forge::hex::encode(&self.0.to_bytes()))
+                        }
+                    }
+                },
+                syn::parse_quote! {
+                    #allows
+                    impl dusk_forge::call::Callee<#id> for #id {
+                        fn contract_id(self) -> dusk_core::abi::ContractId {
+                            self.0
+                        }
+                    }
+                },
+            ];
+            items
+        })
+        .collect()
+}
+
+/// Fill in the argument and return types of `dusk_forge::call!(id, "fn",
+/// args)` invocations calling a function declared by the `calls` interfaces,
+/// and turn calls to undeclared functions into compile errors.
+///
+/// The contract ID is passed through `dusk_forge::call::Callee`, so it must be
+/// the ID newtype of the interface declaring the function or an untyped
+/// `ContractId`.
+///
+/// Calls that already name their types, or whose function name is not a
+/// string literal, are left as written.
+pub(crate) fn type_calls(mut item: Item, callees: &CalleeInfo) -> Item {
+    CallTyper(callees).visit_item_mut(&mut item);
+    item
+}
+
+/// Visitor rewriting the invocations for [`type_calls`].
+struct CallTyper<'a>(&'a CalleeInfo);
+
+impl VisitMut for CallTyper<'_> {
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        if !is_forge_call(mac) {
+            return;
+        }
+        // `; Args => Ret` given explicitly
+        let typed = mac
+            .tokens
+            .clone()
+            .into_iter()
+            .any(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ';'));
+        if typed {
+            return;
+        }
+        let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) else {
+            return;
+        };
+        let [contract, fn_name, args] = args.iter().collect::<Vec<_>>()[..] else {
+            return;
+        };
+        let Expr::Lit(ExprLit {
+            lit: Lit::Str(fn_lit),
+            ..
+        }) = fn_name
+        else {
+            return;
+        };
+
+        let Some((args_ty, ret_ty, id)) = self.0.functions.get(&fn_lit.value()) else {
+            let msg = format!(
+                "`{}` is not declared by the called interfaces ({}); \
+                 add its interface with `#[contract(calls = \"...\")]` or name the types \
+                 with `; Args => Ret`",
+                fn_lit.value(),
+                self.0
+                    .interfaces
+                    .iter()
+                    .map(|(path, _)| path.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let span = fn_lit.span();
+            mac.path = syn::parse_quote_spanned! {span=> ::core::compile_error };
+            mac.tokens = quote_spanned! {span=> #msg };
+            return;
+        };
+
+        mac.tokens = quote! {
+            dusk_forge::call::Callee::<#id>::contract_id(#contract), #fn_name, #args;
+            #args_ty => #ret_ty
+        };
+    }
+}
+
+/// Whether `mac` invokes `dusk_forge::call!`.
+fn is_forge_call(mac: &Macro) -> bool {
+    let segments: Vec<_> = mac
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    segments == ["dusk_forge", "call"]
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::{ToTokens, format_ident};
+
+    use super::*;
+    use crate::test_utils::normalize_tokens;
+    use crate::{ParameterInfo, Receiver};
+
+    #[test]
+    fn test_extern_wrapper_no_params() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("is_paused"),
+            doc: Some("Returns pause state.".to_string()),
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { bool },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn is_paused(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("is_paused");
+                        STATE.state().is_paused()
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_single_param() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("init"),
+            doc: Some("Initialize.".to_string()),
+            params: vec![ParameterInfo {
+                name: format_ident!("owner"),
+                ty: quote! { Address },
+                is_ref: false,
+                is_mut_ref: false,
+                unit: None,
+            }],
+            input_type: quote! { Address },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn init(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |owner: Address| {
+                        let __revert_function = dusk_forge::revert::enter("init");
+                        STATE.state_mut().init(owner)
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_multiple_params() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("transfer"),
+            doc: Some("Transfer funds.".to_string()),
+            params: vec![
+                ParameterInfo {
+                    name: format_ident!("to"),
+                    ty: quote! { Address },
+                    is_ref: false,
+                    is_mut_ref: false,
+                    unit: None,
+                },
+                ParameterInfo {
+                    name: format_ident!("amount"),
+                    ty: quote! { u64 },
+                    is_ref: false,
+                    is_mut_ref: false,
+                    unit: None,
+                },
+            ],
+            input_type: quote! { (Address, u64) },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn transfer(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(to, amount): (Address, u64)| {
+                        let __revert_function = dusk_forge::revert::enter("transfer");
+                        STATE.state_mut().transfer(to, amount)
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrappers_multiple_functions() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![
+            FunctionInfo {
+                name: format_ident!("pause"),
+                doc: None,
+                params: vec![],
+                input_type: quote! { () },
+                output_type: quote! { () },
+                returns_ref: false,
+                receiver: Receiver::RefMut,
+                trait_name: None,
+                feed_type: None,
+                feed_fallback: None,
+                reinit_guard: None,
+                cooldown: None,
+                when_not_paused: false,
+                errors: Vec::new(),
+                deprecated: None,
+                internal: false,
+                cfg: Vec::new(),
+                alias_of: None,
+            },
+            FunctionInfo {
+                name: format_ident!("unpause"),
+                doc: None,
+                params: vec![],
+                input_type: quote! { () },
+                output_type: quote! { () },
+                returns_ref: false,
+                receiver: Receiver::RefMut,
+                trait_name: None,
+                feed_type: None,
+                feed_fallback: None,
+                reinit_guard: None,
+                cooldown: None,
+                when_not_paused: false,
+                errors: Vec::new(),
+                deprecated: None,
+                internal: false,
+                cfg: Vec::new(),
+                alias_of: None,
+            },
+        ];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn pause(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("pause");
+                        STATE.state_mut().pause()
+                    })
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn unpause(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("unpause");
+                        STATE.state_mut().unpause()
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_returns_ref() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("get_data"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { LargeStruct },
+            returns_ref: true,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn get_data(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("get_data");
+                        STATE.state().get_data().clone()
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_ref_param() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("process"),
+            doc: None,
+            params: vec![ParameterInfo {
+                name: format_ident!("data"),
+                ty: quote! { LargeStruct },
+                is_ref: true,
+                is_mut_ref: false,
+                unit: None,
+            }],
+            input_type: quote! { LargeStruct },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn process(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |data: LargeStruct| {
+                        let __revert_function = dusk_forge::revert::enter("process");
+                        STATE.state_mut().process(&data)
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_mut_ref_param() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("modify"),
+            doc: None,
+            params: vec![ParameterInfo {
+                name: format_ident!("data"),
+                ty: quote! { Data },
+                is_ref: true,
+                is_mut_ref: true,
+                unit: None,
+            }],
+            input_type: quote! { Data },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn modify(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |data: Data| {
+                        let __revert_function = dusk_forge::revert::enter("modify");
+                        STATE.state_mut().modify(&mut data)
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_trait_path() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("owner"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { Owner },
+            returns_ref: true,
+            receiver: Receiver::Ref,
+            trait_name: Some("ownable::Ownable".to_string()),
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn owner(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("owner");
+                        ownable::Ownable::owner(STATE.state(),).clone()
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_feed_fallback_pair() {
+        let contract_ident = format_ident!("MyContract");
+        let direct = FunctionInfo {
+            name: format_ident!("items"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { Vec<Item> },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: Some(FeedFallback::Direct { limit: Some(8) }),
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        };
+        let stream = FunctionInfo {
+            name: format_ident!("items_feed"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: Some(quote! { Item }),
+            feed_fallback: Some(FeedFallback::Stream {
+                source: format_ident!("items"),
+            }),
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        };
+
+        let output = normalize_tokens(&extern_wrappers(
+            &[direct, stream],
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn items(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("items");
+                        {
+                            let ret = STATE.state().items();
+                            assert!(
+                                ret.len() <= 8usize,
+                                "`items` returned more than 8 entries; call `items_feed` instead"
+                            );
+                            ret
+                        }
+                    })
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn items_feed(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("items_feed");
+                        {
+                            for entry in STATE.state().items() {
+                                dusk_core::abi::feed(entry);
+                            }
+                        }
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_reinit_guard() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("init"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: Some(1),
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                /// Number of successful calls to the guarded `init`.
+                static INIT_CALLS: dusk_forge::state::ContractState<u32> =
+                    dusk_forge::state::ContractState::new(0);
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn init(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("init");
+                        {
+                            assert!(*INIT_CALLS.state() < 1u32, "`init` can only be called once");
+                            *INIT_CALLS.state_mut() += 1;
+                            STATE.state_mut().init()
+                        }
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_cooldown() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("claim"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { u64 },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: Some(Cooldown {
+                blocks: 100,
+                per: CooldownScope::Caller,
+            }),
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[doc = "Block height of the last accepted `claim` call, per caller."]
+                static COOLDOWN_CLAIM: dusk_forge::state::ContractState<
+                    dusk_forge::cooldown::Cooldowns<dusk_forge::cooldown::CallerKey>
+                > = dusk_forge::state::ContractState::new(dusk_forge::cooldown::Cooldowns::new());
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn claim(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("claim");
+                        {
+                            COOLDOWN_CLAIM.state_mut().enter(
+                                dusk_forge::cooldown::caller_key(
+                                    dusk_core::abi::public_sender(),
+                                    "claim"
+                                ),
+                                dusk_core::abi::block_height(),
+                                100u64,
+                                "claim"
+                            );
+                            STATE.state_mut().claim()
+                        }
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_cfg() {
+        let contract_ident = format_ident!("MyContract");
+        let functions = vec![FunctionInfo {
+            name: format_ident!("debug_dump"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { u64 },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: vec![
+                quote! { feature = "debug" },
+                quote! { not(feature = "lite") },
+            ],
+            alias_of: None,
+        }];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+        let expected = normalize_tokens(&quote! {
+            #[cfg(feature = "debug")]
+            #[cfg(not(feature = "lite"))]
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn debug_dump(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                    let __revert_function = dusk_forge::revert::enter("debug_dump");
+                    STATE.state().debug_dump()
+                })
+            }
+        });
+        assert!(output.contains(&expected), "{output}");
+
+        let output = normalize_tokens(&schema(
+            "MyContract",
+            &[],
+            &[],
+            &functions,
+            &[],
+            &[],
+            None,
+            &TypeMap::new(),
+        ));
+        assert!(
+            output.contains(&normalize_tokens(&quote! {
+                #[cfg(feature = "debug")]
+                #[cfg(not(feature = "lite"))]
+                dusk_forge::schema::Function
+            })),
+            "{output}"
+        );
+        assert!(
+            output.contains(&normalize_tokens(&quote! {
+                cfg: Some("all(feature = \"debug\", not (feature = \"lite\"))"),
+            })),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_extern_wrapper_invariants() {
+        let contract_ident = format_ident!("MyContract");
+        let function = |name: &str, receiver| FunctionInfo {
+            name: format_ident!("{name}"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        };
+        let functions = vec![
+            function("withdraw", Receiver::RefMut),
+            function("balance", Receiver::Ref),
+        ];
+        let invariants = [format_ident!("check_supply"), format_ident!("check_owner")];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions {
+                invariants: &invariants,
+                ..WrapperOptions::default()
+            },
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn balance(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("balance");
+                        STATE.state().balance()
+                    })
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn withdraw(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("withdraw");
+                        {
+                            let ret = STATE.state_mut().withdraw();
+                            if cfg!(any(debug_assertions, feature = "invariants")) {
+                                let state = STATE.state();
+                                state.check_supply();
+                                state.check_owner();
+                            }
+                            ret
+                        }
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_extern_wrapper_alias() {
+        let contract_ident = format_ident!("MyContract");
+        let function = |name: &str, alias_of: Option<&str>| FunctionInfo {
+            name: format_ident!("{name}"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { u64 },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: alias_of.map(|name| format_ident!("{name}")),
+        };
+        let functions = vec![
+            function("total", None),
+            function("get_total", Some("total")),
+        ];
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn get_total(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("get_total");
+                        STATE.state().total()
+                    })
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn total(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("total");
+                        STATE.state().total()
+                    })
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_dispatch_wrappers() {
+        let contract_ident = format_ident!("MyContract");
+        let getter = |name: &str| FunctionInfo {
+            name: format_ident!("{name}"),
+            doc: None,
+            params: vec![],
+            input_type: quote! { () },
+            output_type: quote! { u64 },
+            returns_ref: false,
+            receiver: Receiver::Ref,
+            trait_name: None,
+            feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        };
+        let setter = FunctionInfo {
+            name: format_ident!("set_value"),
+            params: vec![ParameterInfo {
+                name: format_ident!("value"),
+                ty: quote! { u64 },
+                is_ref: false,
+                is_mut_ref: false,
+                unit: None,
+            }],
+            input_type: quote! { u64 },
+            output_type: quote! { () },
+            receiver: Receiver::RefMut,
+            ..getter("set_value")
+        };
+        // Declared out of order: indices and exports follow the names
+        let functions = vec![setter, getter("get_value"), getter("get_total")];
+
+        let output = normalize_tokens(&dispatch_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            mod __contract_extern_wrappers {
+                use super::*;
+
+                #[inline(never)]
+                fn __contract_call_0(arg_len: u32, method: fn(()) -> u64) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, method)
+                }
+
+                #[inline(never)]
+                fn __contract_call_1(arg_len: u32, method: fn(u64) -> ()) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, method)
+                }
+
+                /// Shared call glue for every exported function, keyed by index.
+                #[inline(never)]
+                unsafe fn __contract_dispatch(index: u32, arg_len: u32) -> u32 {
+                    match index {
+                        0u32 => __contract_call_0(arg_len, |(): ()| {
+                            let __revert_function = dusk_forge::revert::enter("get_total");
+                            STATE.state().get_total()
+                        }),
+                        1u32 => __contract_call_0(arg_len, |(): ()| {
+                            let __revert_function = dusk_forge::revert::enter("get_value");
+                            STATE.state().get_value()
+                        }),
+                        2u32 => __contract_call_1(arg_len, |value: u64| {
+                            let __revert_function = dusk_forge::revert::enter("set_value");
+                            STATE.state_mut().set_value(value)
+                        }),
+                        _ => unreachable!(),
+                    }
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn get_total(arg_len: u32) -> u32 {
+                    unsafe { __contract_dispatch(0u32, arg_len) }
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn get_value(arg_len: u32) -> u32 {
+                    unsafe { __contract_dispatch(1u32, arg_len) }
+                }
+
+                #[unsafe(no_mangle)]
+                unsafe extern "C" fn set_value(arg_len: u32) -> u32 {
+                    unsafe { __contract_dispatch(2u32, arg_len) }
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+
+        // Three functions, two signatures: two `wrap_call` instantiations,
+        // against one per function without `compact_dispatch`
+        assert_eq!(output.matches("wrap_call").count(), 2);
+        let inlined = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
+        assert_eq!(inlined.matches("wrap_call").count(), 3);
+    }
+
+    #[test]
+    fn test_format_hint_resolves_imports() {
+        let type_map = TypeMap::from([
+            ("ItemId".to_string(), "types::ItemId".to_string()),
+            ("String".to_string(), "alloc::string::String".to_string()),
+        ]);
+
+        let output = normalize_tokens(&format_hint(&quote! { ItemId }, &type_map));
+        let expected = normalize_tokens(&quote! {
+            {
+                use dusk_forge::schema::Unformatted as _;
+                <types::ItemId>::FORMAT
+            }
+        });
+        assert_eq!(expected, output);
+
+        let output = normalize_tokens(&format_hint(&quote! { String }, &type_map));
+        assert_eq!(normalize_tokens(&quote! { "" }), output);
+    }
+
+    #[test]
+    fn test_schema_function_inputs() {
+        let functions = vec![FunctionInfo {
+            name: format_ident!("update"),
+            doc: None,
             params: vec![
                 ParameterInfo {
-                    name: format_ident!("to"),
-                    ty: quote! { Address },
+                    name: format_ident!("counter"),
+                    ty: quote! { u64 },
                     is_ref: false,
                     is_mut_ref: false,
+                    unit: Some("blocks".to_string()),
                 },
                 ParameterInfo {
-                    name: format_ident!("amount"),
-                    ty: quote! { u64 },
-                    is_ref: false,
+                    name: format_ident!("label"),
+                    ty: quote! { String },
+                    is_ref: true,
                     is_mut_ref: false,
+                    unit: None,
                 },
             ],
-            input_type: quote! { (Address, u64) },
+            input_type: quote! { (u64, String) },
             output_type: quote! { () },
             returns_ref: false,
             receiver: Receiver::RefMut,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: Some(Deprecation {
+                message: "use update_v2".to_string(),
+                replacement: Some("update_v2".to_string()),
+            }),
+            internal: true,
+            cfg: Vec::new(),
+            alias_of: None,
         }];
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
+        let output = normalize_tokens(&schema(
+            "MyContract",
+            &[],
+            &[],
+            &functions,
+            &[],
+            &[],
+            None,
+            &TypeMap::from([("String".to_string(), "alloc::string::String".to_string())]),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            dusk_forge::schema::Function {
+                name: "update",
+                doc: "",
+                input: "(u64 , String)",
+                inputs: &[
+                    dusk_forge::schema::Parameter {
+                        name: "counter",
+                        ty: "u64",
+                        format: "",
+                        unit: "blocks",
+                    },
+                    dusk_forge::schema::Parameter {
+                        name: "label",
+                        ty: "String",
+                        format: "",
+                        unit: "",
+                    }
+                ],
+                output: "()",
+                feeds: None,
+                cooldown: None,
+                when_not_paused: false,
+                errors: &[],
+                deprecated: Some(dusk_forge::schema::Deprecation {
+                    message: "use update_v2",
+                    replacement: Some("update_v2"),
+                }),
+                internal: true,
+                cfg: None,
+            }
+        });
+        assert!(output.contains(&expected), "{output}");
+    }
+
+    #[test]
+    fn test_schema_state_layout() {
+        let state = vec![
+            StateField {
+                name: "owner".to_string(),
+                ty: quote! { Option<PublicKey> },
+            },
+            StateField {
+                name: "counter".to_string(),
+                ty: quote! { u64 },
+            },
+            StateField {
+                name: "contacts".to_string(),
+                ty: quote! { BTreeMap<PublicKey, dusk_forge::sealed::Sealed<Contact>> },
+            },
+        ];
+
+        let output = normalize_tokens(&schema(
+            "MyContract",
+            &[],
+            &state,
+            &[],
+            &[],
+            &[],
+            None,
+            &TypeMap::new(),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            state: &[
+                dusk_forge::schema::Field { name: "owner", ty: "Option < PublicKey >", sealed: false, },
+                dusk_forge::schema::Field { name: "counter", ty: "u64", sealed: false, },
+                dusk_forge::schema::Field {
+                    name: "contacts",
+                    ty: "BTreeMap < PublicKey , dusk_forge :: sealed :: Sealed < Contact >>",
+                    sealed: true,
+                }
+            ],
+        });
+        assert!(output.contains(&expected), "{output}");
+
+        let expected = normalize_tokens(&quote! {
+            name: "MyContract",
+            abi: option_env!("DUSK_FORGE_ABI_VERSION"),
+        });
+        assert!(output.contains(&expected), "{output}");
+    }
+
+    #[test]
+    fn test_schema_event_topic_values() {
+        let events = vec![
+            EventInfo {
+                topic: "events::Transfer::TOPIC".to_string(),
+                data_type: quote! { events::Transfer },
+            },
+            EventInfo {
+                topic: "contract-paused".to_string(),
+                data_type: quote! { () },
+            },
+            EventInfo {
+                topic: "topic".to_string(),
+                data_type: quote! { u64 },
+            },
+        ];
+        let type_map = TypeMap::from([(
+            "events::Transfer::TOPIC".to_string(),
+            "types::events::Transfer::TOPIC".to_string(),
+        )]);
+
+        let genesis = [events[1].clone()];
+
+        let output = normalize_tokens(&schema(
+            "MyContract",
+            &[],
+            &[],
+            &[],
+            &events,
+            &genesis,
+            None,
+            &type_map,
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            events: &[
+                dusk_forge::schema::Event {
+                    topic: "events::Transfer::TOPIC",
+                    value: Some(types::events::Transfer::TOPIC),
+                    data: "events :: Transfer",
+                    genesis: false,
+                    indexed: {
+                        use dusk_forge::events::Unindexed as _;
+                        <events::Transfer>::INDEXED
+                    },
+                    version: {
+                        use dusk_forge::events::Unversioned as _;
+                        <events::Transfer>::VERSION
+                    },
+                    units: {
+                        use dusk_forge::events::Unitless as _;
+                        <events::Transfer>::UNITS
+                    },
+                },
+                dusk_forge::schema::Event {
+                    topic: "contract-paused",
+                    value: Some("contract-paused"),
+                    data: "()",
+                    genesis: true,
+                    indexed: &[],
+                    version: 1,
+                    units: &[],
+                },
+                dusk_forge::schema::Event {
+                    topic: "topic",
+                    value: None,
+                    data: "u64",
+                    genesis: false,
+                    indexed: &[],
+                    version: 1,
+                    units: &[],
+                }
+            ],
+        });
+        assert!(output.contains(&expected), "{output}");
+    }
+
+    #[test]
+    fn test_schema_export() {
+        let output = normalize_tokens(&schema_export(&quote! { super::CONTRACT_SCHEMA }));
 
-        let expected = normalize_tokens(quote! {
+        let expected = normalize_tokens(&quote! {
             #[cfg(target_family = "wasm")]
-            mod __contract_extern_wrappers {
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn contract_schema(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| super::CONTRACT_SCHEMA.to_json())
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_generated_module() {
+        let module = format_ident!("forge");
+        let items = quote! { static STATE: ContractState<MyContract> = ContractState::new(MyContract::new()); };
+        let reexport = quote! { use self::forge::STATE; };
+        let output = normalize_tokens(&generated_module(
+            &module,
+            &quote! { #[cfg(feature = "contract")] },
+            &items,
+            Some(&reexport),
+        ));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(feature = "contract")]
+            mod forge {
                 use super::*;
 
-                #[unsafe(no_mangle)]
-                unsafe extern "C" fn transfer(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |(to, amount): (Address, u64)| STATE.transfer(to, amount))
-                }
+                static STATE: ContractState<MyContract> = ContractState::new(MyContract::new());
             }
+
+            use self::forge::STATE;
         });
 
         assert_eq!(expected, output);
     }
 
     #[test]
-    fn test_extern_wrappers_multiple_functions() {
-        let contract_ident = format_ident!("MyContract");
-        let functions = vec![
-            FunctionInfo {
-                name: format_ident!("pause"),
-                doc: None,
-                params: vec![],
-                input_type: quote! { () },
-                output_type: quote! { () },
-                returns_ref: false,
-                receiver: Receiver::RefMut,
-                trait_name: None,
-                feed_type: None,
+    fn test_metering_export() {
+        let output = normalize_tokens(&metering_export(true));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(all(target_family = "wasm", feature = "metering"))]
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn __forge_host_calls(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| dusk_forge::metering::take())
+            }
+        });
+
+        assert_eq!(expected, output);
+
+        assert!(metering_export(false).is_empty());
+    }
+
+    #[test]
+    fn test_metadata_export() {
+        let output = normalize_tokens(&metadata_export(true, &quote! { super::CONTRACT_SCHEMA }));
+
+        let expected = normalize_tokens(&quote! {
+            #[cfg(target_family = "wasm")]
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn contract_metadata(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                    dusk_forge::metadata::collect(
+                        &super::CONTRACT_SCHEMA,
+                        env!("CARGO_PKG_VERSION"),
+                        option_env!("DUSK_FORGE_SOURCE_HASH"),
+                    )
+                })
+            }
+        });
+
+        assert_eq!(expected, output);
+
+        assert!(metadata_export(false, &quote! { super::CONTRACT_SCHEMA }).is_empty());
+    }
+
+    #[test]
+    fn test_storage_stats_export() {
+        let state = [
+            StateField {
+                name: "owner".to_string(),
+                ty: quote! { PublicKey },
             },
-            FunctionInfo {
-                name: format_ident!("unpause"),
-                doc: None,
-                params: vec![],
-                input_type: quote! { () },
-                output_type: quote! { () },
-                returns_ref: false,
-                receiver: Receiver::RefMut,
-                trait_name: None,
-                feed_type: None,
+            StateField {
+                name: "balances".to_string(),
+                ty: quote! { BTreeMap<PublicKey, u64> },
             },
         ];
+        let output = normalize_tokens(&storage_stats_export(true, &state));
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
-
-        let expected = normalize_tokens(quote! {
+        let expected = normalize_tokens(&quote! {
             #[cfg(target_family = "wasm")]
-            mod __contract_extern_wrappers {
-                use super::*;
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn __storage_stats(arg_len: u32) -> u32 {
+                use dusk_forge::storage::{Collection as _, Plain as _};
+
+                dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                    let state = STATE.state();
+                    dusk_core::abi::feed((&dusk_forge::storage::Field(&state.owner)).stats("owner"));
+                    dusk_core::abi::feed((&dusk_forge::storage::Field(&state.balances)).stats("balances"));
+                })
+            }
+        });
 
-                #[unsafe(no_mangle)]
-                unsafe extern "C" fn pause(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |(): ()| STATE.pause())
+        assert_eq!(expected, output);
+
+        // Tuple struct fields are accessed by index
+        let tuple = [StateField {
+            name: "0".to_string(),
+            ty: quote! { u64 },
+        }];
+        let output = normalize_tokens(&storage_stats_export(true, &tuple));
+        assert!(
+            output.contains("Field (& state . 0)) . stats (\"0\")"),
+            "{output}"
+        );
+
+        assert!(storage_stats_export(false, &state).is_empty());
+    }
+
+    #[test]
+    fn test_meter_host_calls() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn set(&mut self, value: u64) {
+                    self.value = value;
+                    abi::emit("set", value);
+                    dusk_forge::emit!(events::Reset);
                 }
 
-                #[unsafe(no_mangle)]
-                unsafe extern "C" fn unpause(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |(): ()| STATE.unpause())
+                pub fn caller(&self) -> Option<PublicKey> {
+                    dusk_core::abi::caller()
+                }
+
+                pub fn value(&self) -> u64 {
+                    helpers::value(self.value)
+                }
+            }
+        };
+
+        let instrumented = meter_host_calls(impl_block);
+        let output = normalize_tokens(&quote! { #instrumented });
+        let expected = normalize_tokens(&quote! {
+            impl MyContract {
+                pub fn set(&mut self, value: u64) {
+                    self.value = value;
+                    {
+                        #[cfg(feature = "metering")]
+                        dusk_forge::metering::record("emit");
+                        abi::emit("set", value)
+                    };
+                    #[cfg(feature = "metering")]
+                    dusk_forge::metering::record("emit");
+                    dusk_forge::emit!(events::Reset);
+                }
+
+                pub fn caller(&self) -> Option<PublicKey> {
+                    {
+                        #[cfg(feature = "metering")]
+                        dusk_forge::metering::record("caller");
+                        dusk_core::abi::caller()
+                    }
+                }
+
+                pub fn value(&self) -> u64 {
+                    helpers::value(self.value)
                 }
             }
         });
@@ -439,31 +2825,188 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    fn token_callees() -> CalleeInfo {
+        CalleeInfo {
+            interfaces: vec![("token.forge-abi".to_string(), format_ident!("TokenId"))],
+            functions: [
+                (
+                    "balance_of".to_string(),
+                    (
+                        quote! { PublicKey },
+                        quote! { u64 },
+                        format_ident!("TokenId"),
+                    ),
+                ),
+                (
+                    "transfer".to_string(),
+                    (
+                        quote! { (PublicKey, u64) },
+                        quote! { () },
+                        format_ident!("TokenId"),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
     #[test]
-    fn test_extern_wrapper_returns_ref() {
+    fn test_type_calls() {
+        let item: Item = syn::parse_quote! {
+            impl Vault {
+                pub fn sweep(&mut self, to: PublicKey) {
+                    let balance = dusk_forge::call!(TOKEN, "balance_of", &self.account).unwrap();
+                    dusk_forge::call!(TOKEN, "transfer", &(to, balance)).unwrap();
+                    dusk_forge::call!(TOKEN, "transfer", &(to, 0); (PublicKey, u64) => ()).unwrap();
+                }
+            }
+        };
+
+        let output = normalize_tokens(&type_calls(item, &token_callees()).into_token_stream());
+        let expected = normalize_tokens(&quote! {
+            impl Vault {
+                pub fn sweep(&mut self, to: PublicKey) {
+                    let balance = dusk_forge::call!(
+                        dusk_forge::call::Callee::<TokenId>::contract_id(TOKEN), "balance_of", &self.account;
+                        PublicKey => u64
+                    ).unwrap();
+                    dusk_forge::call!(
+                        dusk_forge::call::Callee::<TokenId>::contract_id(TOKEN), "transfer", &(to, balance);
+                        (PublicKey, u64) => ()
+                    ).unwrap();
+                    dusk_forge::call!(TOKEN, "transfer", &(to, 0); (PublicKey, u64) => ()).unwrap();
+                }
+            }
+        });
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_callee_ids() {
+        let ids = callee_ids(&token_callees());
+        let output = normalize_tokens(&quote! { #(#ids)* });
+        let allows = lint_allows();
+
+        let expected = normalize_tokens(&quote! {
+            #allows
+            #[doc = "ID of a contract implementing the `token.forge-abi` interface."]
+            #[dusk_forge::contract_input]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct TokenId(
+                #[arbitrary(with = dusk_forge::fuzz::contract_id)]
+                pub dusk_core::abi::ContractId,
+            );
+        });
+        assert!(output.starts_with(&expected), "{output}");
+
+        let expected = normalize_tokens(&quote! {
+            #allows
+            impl dusk_forge::call::Callee<TokenId> for TokenId {
+                fn contract_id(self) -> dusk_core::abi::ContractId {
+                    self.0
+                }
+            }
+        });
+        assert!(output.ends_with(&expected), "{output}");
+        assert_eq!(ids.len(), 5);
+
+        let output = normalize_tokens(&schema(
+            "Vault",
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            Some(&token_callees()),
+            &TypeMap::new(),
+        ));
+        let expected = normalize_tokens(&quote! {
+            callees: &[dusk_forge::schema::Callee { id: "TokenId", interface: "token.forge-abi", }],
+        });
+        assert!(output.contains(&expected), "{output}");
+    }
+
+    #[test]
+    fn test_type_calls_undeclared_function() {
+        let item: Item = syn::parse_quote! {
+            fn burn() {
+                dusk_forge::call!(TOKEN, "burn", &()).unwrap();
+            }
+        };
+
+        let output = normalize_tokens(&type_calls(item, &token_callees()).into_token_stream());
+        assert!(
+            output.contains(":: core :: compile_error ! (\"`burn` is not declared by the called interfaces (token.forge-abi)"),
+            "undeclared call should become a compile error: {output}"
+        );
+    }
+
+    #[test]
+    fn test_uses_call() {
+        let with_call: Vec<Item> = vec![syn::parse_quote! {
+            fn ping() {
+                let _ = dusk_forge::call!(PEER, "ping", &());
+            }
+        }];
+        let without_call: Vec<Item> = vec![syn::parse_quote! {
+            fn ping() {
+                let _ = dusk_core::abi::call::<_, ()>(PEER, "ping", &());
+            }
+        }];
+
+        assert!(uses_call(&with_call));
+        assert!(!uses_call(&without_call));
+    }
+
+    #[test]
+    fn test_extern_wrapper_reentrancy_guard() {
         let contract_ident = format_ident!("MyContract");
         let functions = vec![FunctionInfo {
-            name: format_ident!("get_data"),
+            name: format_ident!("sweep"),
             doc: None,
             params: vec![],
             input_type: quote! { () },
-            output_type: quote! { LargeStruct },
-            returns_ref: true,
-            receiver: Receiver::Ref,
+            output_type: quote! { () },
+            returns_ref: false,
+            receiver: Receiver::RefMut,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         }];
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions {
+                reentrancy_guard: true,
+                ..WrapperOptions::default()
+            },
+        ));
 
-        let expected = normalize_tokens(quote! {
+        let expected = normalize_tokens(&quote! {
             #[cfg(target_family = "wasm")]
             mod __contract_extern_wrappers {
                 use super::*;
 
                 #[unsafe(no_mangle)]
-                unsafe extern "C" fn get_data(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |(): ()| STATE.get_data().clone())
+                unsafe extern "C" fn sweep(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        dusk_forge::call::reject_reentry();
+                        {
+                            let __revert_function = dusk_forge::revert::enter("sweep");
+                            STATE.state_mut().sweep()
+                        }
+                    })
                 }
             }
         });
@@ -472,35 +3015,53 @@ mod tests {
     }
 
     #[test]
-    fn test_extern_wrapper_ref_param() {
+    fn test_extern_wrapper_when_not_paused() {
         let contract_ident = format_ident!("MyContract");
         let functions = vec![FunctionInfo {
-            name: format_ident!("process"),
+            name: format_ident!("deposit"),
             doc: None,
-            params: vec![ParameterInfo {
-                name: format_ident!("data"),
-                ty: quote! { LargeStruct },
-                is_ref: true,
-                is_mut_ref: false,
-            }],
-            input_type: quote! { LargeStruct },
+            params: vec![],
+            input_type: quote! { () },
             output_type: quote! { () },
             returns_ref: false,
             receiver: Receiver::RefMut,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: true,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         }];
+        let pausable: Member = syn::parse_quote! { pause };
+
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions {
+                pausable: Some(&pausable),
+                ..WrapperOptions::default()
+            },
+        ));
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
-
-        let expected = normalize_tokens(quote! {
+        let expected = normalize_tokens(&quote! {
             #[cfg(target_family = "wasm")]
             mod __contract_extern_wrappers {
                 use super::*;
 
                 #[unsafe(no_mangle)]
-                unsafe extern "C" fn process(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |data: LargeStruct| STATE.process(&data))
+                unsafe extern "C" fn deposit(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("deposit");
+                        {
+                            STATE.state().pause.ensure_not_paused("deposit");
+                            STATE.state_mut().deposit()
+                        }
+                    })
                 }
             }
         });
@@ -509,53 +3070,161 @@ mod tests {
     }
 
     #[test]
-    fn test_extern_wrapper_mut_ref_param() {
+    fn test_extern_wrapper_revert_context() {
         let contract_ident = format_ident!("MyContract");
         let functions = vec![FunctionInfo {
-            name: format_ident!("modify"),
+            name: format_ident!("transfer_ownership"),
             doc: None,
-            params: vec![ParameterInfo {
-                name: format_ident!("data"),
-                ty: quote! { Data },
-                is_ref: true,
-                is_mut_ref: true,
-            }],
-            input_type: quote! { Data },
+            params: vec![],
+            input_type: quote! { () },
             output_type: quote! { () },
             returns_ref: false,
             receiver: Receiver::RefMut,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: vec!["NOT_OWNER".to_string()],
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         }];
 
-        let output = normalize_tokens(extern_wrappers(&functions, &contract_ident));
+        let output = normalize_tokens(&extern_wrappers(
+            &functions,
+            &contract_ident,
+            &WrapperOptions::default(),
+        ));
 
-        let expected = normalize_tokens(quote! {
+        let expected = normalize_tokens(&quote! {
             #[cfg(target_family = "wasm")]
             mod __contract_extern_wrappers {
                 use super::*;
 
                 #[unsafe(no_mangle)]
-                unsafe extern "C" fn modify(arg_len: u32) -> u32 {
-                    dusk_core::abi::wrap_call(arg_len, |data: Data| STATE.modify(&mut data))
+                unsafe extern "C" fn transfer_ownership(arg_len: u32) -> u32 {
+                    dusk_core::abi::wrap_call(arg_len, |(): ()| {
+                        let __revert_function = dusk_forge::revert::enter("transfer_ownership");
+                        STATE.state_mut().transfer_ownership()
+                    })
                 }
             }
         });
 
         assert_eq!(expected, output);
+
+        let schema = normalize_tokens(&function_entry(&functions[0], &TypeMap::new()));
+        assert!(schema.contains(r#"errors : & ["NOT_OWNER"]"#), "{schema}");
+    }
+
+    #[test]
+    fn test_interface_dependency() {
+        let path: LitStr = syn::parse_quote! { "token.forge-abi" };
+
+        let output = normalize_tokens(&interface_dependency(Some(&path)));
+        let expected = normalize_tokens(&quote! {
+            const _: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "token.forge-abi"));
+        });
+        assert_eq!(expected, output);
+
+        assert!(interface_dependency(None).is_empty());
     }
 
     #[test]
     fn test_state_static() {
         let contract_ident = format_ident!("MyContract");
-        let output = normalize_tokens(state_static(&contract_ident));
+        let output = normalize_tokens(&state_static(&contract_ident, "new", &quote! {}));
+
+        let expected = normalize_tokens(&quote! {
+            #[doc = " Static contract state initialized via `new()`."]
+            #[cfg(target_family = "wasm")]
+            static STATE: dusk_forge::state::ContractState<MyContract> =
+                dusk_forge::state::ContractState::new(MyContract::new());
+        });
+
+        assert_eq!(expected, output);
 
-        let expected = normalize_tokens(quote! {
-            /// Static contract state initialized via `new()`.
+        let output = normalize_tokens(&state_static(&contract_ident, "empty", &quote! {}));
+        let expected = normalize_tokens(&quote! {
+            #[doc = " Static contract state initialized via `empty()`."]
             #[cfg(target_family = "wasm")]
-            static mut STATE: MyContract = MyContract::new();
+            static STATE: dusk_forge::state::ContractState<MyContract> =
+                dusk_forge::state::ContractState::new(MyContract::empty());
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_getters() {
+        let contract_ident = format_ident!("MyContract");
+        let fields: syn::FieldsNamed = syn::parse_quote! {
+            { owner: PublicKey, finalization_period: u64 }
+        };
+        let fields: Vec<_> = fields.named.iter().collect();
+        let getters_impl = getters(&contract_ident, &fields);
+        let output = normalize_tokens(&quote! { #getters_impl });
+        let allows = lint_allows();
+
+        let expected = normalize_tokens(&quote! {
+            #allows
+            impl MyContract {
+                #[doc = " Returns the `owner` field of the contract state."]
+                pub fn owner(&self) -> PublicKey {
+                    ::core::clone::Clone::clone(&self.owner)
+                }
+                #[doc = " Returns the `finalization_period` field of the contract state."]
+                pub fn finalization_period(&self) -> u64 {
+                    ::core::clone::Clone::clone(&self.finalization_period)
+                }
+            }
         });
 
         assert_eq!(expected, output);
+        assert!(getters(&contract_ident, &[]).is_none());
+    }
+
+    #[test]
+    fn test_allow_lints() {
+        let allows = lint_allows();
+        let items = quote! {
+            #[cfg(target_family = "wasm")]
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn counter(arg_len: u32) -> u32 {
+                0
+            }
+
+            mod generated {
+                use super::*;
+            }
+
+            compile_error!("unreachable");
+        };
+        let output = normalize_tokens(&allow_lints(items));
+
+        let expected = normalize_tokens(&quote! {
+            #allows
+            #[cfg(target_family = "wasm")]
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn counter(arg_len: u32) -> u32 {
+                0
+            }
+
+            #allows
+            mod generated {
+                use super::*;
+            }
+
+            #allows
+            compile_error!("unreachable");
+        });
+        assert_eq!(expected, output);
+
+        let output = normalize_tokens(&allows);
+        for lint in ["clippy :: pedantic", "clippy :: all", "missing_docs"] {
+            assert!(!output.contains(lint), "{output}");
+        }
     }
 }
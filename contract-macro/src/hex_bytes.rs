@@ -0,0 +1,253 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `#[derive(HexBytes)]` expansion.
+//!
+//! Implements, for a newtype over a byte array such as
+//! `struct WithdrawalId([u8; 32])`:
+//! - the rkyv traits, archiving the value as its byte array;
+//! - serde as a hex string through `dusk_forge::hex`, behind the crate's
+//!   `serde` feature;
+//! - `Display` and `FromStr` as a hex string;
+//! - `arbitrary::Arbitrary` from arbitrary bytes, behind the crate's
+//!   `arbitrary` feature;
+//! - a `FORMAT` const, read by the schema as the format hint of parameters of
+//!   the type.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, GenericParam, Generics, Member, Type};
+
+/// Expand `#[derive(HexBytes)]` on a byte array newtype.
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let (member, bytes) = byte_array_field(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Wrap the bytes, from inside the type's impls and from the impl on its
+    // byte array
+    let construct = match &member {
+        Member::Named(field) => quote! { |bytes| Self { #field: bytes } },
+        Member::Unnamed(_) => quote! { Self },
+    };
+    let archived = match &member {
+        Member::Named(field) => quote! { #name { #field: *self } },
+        Member::Unnamed(_) => quote! { #name(*self) },
+    };
+
+    let serializer = with_param(
+        &input.generics,
+        syn::parse_quote! { __S: rkyv::Fallible + ?Sized },
+    );
+    let deserializer = with_param(
+        &input.generics,
+        syn::parse_quote! { __D: rkyv::Fallible + ?Sized },
+    );
+    let serde_de = with_param(&input.generics, syn::parse_quote! { 'de });
+    let arbitrary = with_param(&input.generics, syn::parse_quote! { '__a });
+    let (serializer, ..) = serializer.split_for_impl();
+    let (deserializer, ..) = deserializer.split_for_impl();
+    let (serde_de, ..) = serde_de.split_for_impl();
+    let (arbitrary, ..) = arbitrary.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Schema format hint of the type: a hex string.
+            pub const FORMAT: &'static str = "hex";
+        }
+
+        impl #impl_generics rkyv::Archive for #name #ty_generics #where_clause {
+            type Archived = <#bytes as rkyv::Archive>::Archived;
+            type Resolver = <#bytes as rkyv::Archive>::Resolver;
+
+            #[inline]
+            unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+                unsafe { rkyv::Archive::resolve(&self.#member, pos, resolver, out) }
+            }
+        }
+
+        impl #serializer rkyv::Serialize<__S> for #name #ty_generics #where_clause {
+            #[inline]
+            fn serialize(&self, serializer: &mut __S) -> ::core::result::Result<Self::Resolver, __S::Error> {
+                rkyv::Serialize::<__S>::serialize(&self.#member, serializer)
+            }
+        }
+
+        impl #deserializer rkyv::Deserialize<#name #ty_generics, __D> for #bytes #where_clause {
+            #[inline]
+            fn deserialize(&self, _: &mut __D) -> ::core::result::Result<#name #ty_generics, __D::Error> {
+                ::core::result::Result::Ok(#archived)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl #impl_generics serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                dusk_forge::hex::serialize(&self.#member, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl #serde_de serde::Deserialize<'de> for #name #ty_generics #where_clause {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                dusk_forge::hex::deserialize(deserializer).map(#construct)
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(&dusk_forge::hex::encode(&self.#member))
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = dusk_forge::hex::HexError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let bytes = dusk_forge::hex::decode(s)?;
+                let len = bytes.len();
+                <#bytes>::try_from(bytes)
+                    .map(#construct)
+                    .map_err(|_| dusk_forge::hex::HexError::Length(len))
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl #arbitrary arbitrary::Arbitrary<'__a> for #name #ty_generics #where_clause {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'__a>) -> arbitrary::Result<Self> {
+                <#bytes as arbitrary::Arbitrary<'__a>>::arbitrary(u).map(#construct)
+            }
+
+            fn size_hint(depth: usize) -> (usize, ::core::option::Option<usize>) {
+                <#bytes as arbitrary::Arbitrary<'__a>>::size_hint(depth)
+            }
+        }
+    })
+}
+
+/// The single field of a byte array newtype and its array type.
+fn byte_array_field(input: &DeriveInput) -> Result<(Member, &Type), syn::Error> {
+    let error = || {
+        syn::Error::new_spanned(
+            input,
+            "#[derive(HexBytes)] requires a newtype over a byte array, \
+             like `struct WithdrawalId([u8; 32]);`",
+        )
+    };
+
+    let Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+    let mut fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter(),
+        Fields::Unnamed(fields) => fields.unnamed.iter(),
+        Fields::Unit => return Err(error()),
+    };
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(error());
+    };
+
+    let is_byte_array = matches!(&field.ty, Type::Array(array)
+        if matches!(&*array.elem, Type::Path(path) if path.path.is_ident("u8")));
+    if !is_byte_array {
+        return Err(error());
+    }
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((member, &field.ty))
+}
+
+/// `generics` with `param` added, lifetimes first as Rust requires.
+fn with_param(generics: &Generics, param: GenericParam) -> Generics {
+    let mut generics = generics.clone();
+    match param {
+        GenericParam::Lifetime(_) => generics.params.insert(0, param),
+        _ => generics.params.push(param),
+    }
+    generics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normalize_tokens;
+
+    #[test]
+    fn test_expand_tuple_newtype() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct WithdrawalId([u8; 32]);
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+
+        assert!(output.contains(&normalize_tokens(&quote! {
+            impl WithdrawalId {
+                #[doc = r" Schema format hint of the type: a hex string."]
+                pub const FORMAT: &'static str = "hex";
+            }
+        })));
+        assert!(output.contains("type Archived = < [u8 ; 32] as rkyv :: Archive > :: Archived ;"));
+        assert!(output.contains(
+            "impl < __D : rkyv :: Fallible + ? Sized > rkyv :: Deserialize < WithdrawalId , __D > \
+             for [u8 ; 32]"
+        ));
+        assert!(output.contains("Ok (WithdrawalId (* self))"));
+        assert!(output.contains("dusk_forge :: hex :: deserialize (deserializer) . map (Self)"));
+        assert!(
+            output
+                .contains("# [cfg (feature = \"serde\")] impl serde :: Serialize for WithdrawalId")
+        );
+        assert!(output.contains("impl :: core :: str :: FromStr for WithdrawalId"));
+        assert!(output.contains(
+            "# [cfg (feature = \"arbitrary\")] impl < '__a > arbitrary :: Arbitrary < '__a > \
+             for WithdrawalId"
+        ));
+    }
+
+    #[test]
+    fn test_expand_const_generic_named_newtype() {
+        let input: DeriveInput = syn::parse_quote! {
+            pub struct Bytes<const N: usize> {
+                bytes: [u8; N],
+            }
+        };
+
+        let output = normalize_tokens(&expand(&input).unwrap());
+
+        assert!(output.contains(
+            "impl < const N : usize , __S : rkyv :: Fallible + ? Sized > rkyv :: Serialize < __S > \
+             for Bytes < N >"
+        ));
+        assert!(output.contains(
+            "impl < 'de , const N : usize > serde :: Deserialize < 'de > for Bytes < N >"
+        ));
+        assert!(output.contains("| bytes | Self { bytes : bytes }"));
+        assert!(output.contains("Ok (Bytes { bytes : * self })"));
+        assert!(
+            output.contains("rkyv :: Archive :: resolve (& self . bytes , pos , resolver , out)")
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_non_byte_arrays() {
+        for input in [
+            quote! { struct Id(u64); },
+            quote! { struct Id([u16; 4]); },
+            quote! { struct Id([u8; 4], u8); },
+            quote! { enum Id { A([u8; 4]) } },
+        ] {
+            let input: DeriveInput = syn::parse2(input).unwrap();
+            let err = expand(&input).unwrap_err();
+            assert!(
+                err.to_string().contains("newtype over a byte array"),
+                "{err}"
+            );
+        }
+    }
+}
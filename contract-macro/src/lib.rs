@@ -37,16 +37,25 @@
 #![deny(clippy::pedantic)]
 #![warn(missing_debug_implementations, unreachable_pub, rustdoc::all)]
 
+mod contract_input;
 mod data_driver;
+mod diagnostic;
+mod event;
 mod generate;
+mod hex_bytes;
+mod mock;
 mod parse;
 mod resolve;
+#[cfg(test)]
+mod test_utils;
 mod validate;
 
+use std::collections::BTreeMap;
+
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{Item, ItemImpl, ItemMod, Type, parse_macro_input};
+use syn::{DeriveInput, ImplItemFn, Item, ItemImpl, ItemMod, LitStr, Type, parse_macro_input};
 
 // ============================================================================
 // IR Data Structures
@@ -73,6 +82,7 @@ enum Receiver {
 }
 
 /// Information about a function parameter.
+#[derive(Clone)]
 struct ParameterInfo {
     /// The parameter name.
     name: Ident,
@@ -83,6 +93,17 @@ struct ParameterInfo {
     is_ref: bool,
     /// Whether the parameter is a mutable reference.
     is_mut_ref: bool,
+    /// Display unit from `#[contract(unit = "...")]`, recorded in the
+    /// schema.
+    unit: Option<String>,
+}
+
+/// A field of the contract struct, recorded in the schema's state layout.
+struct StateField {
+    /// The field name, or its index in a tuple struct.
+    name: String,
+    /// The field type.
+    ty: TokenStream2,
 }
 
 /// Information about a contract function extracted from the impl block.
@@ -108,6 +129,90 @@ struct FunctionInfo {
     /// `#[contract(feeds = "Type")]`). When present, the data-driver uses
     /// this type for `decode_output_fn` instead of `output_type`.
     feed_type: Option<TokenStream2>,
+    /// Role of the function in a `#[contract(feed_fallback)]` pair, if any.
+    feed_fallback: Option<FeedFallback>,
+    /// Maximum number of successful calls for a guarded `init` (from
+    /// `#[contract(reinit_guard)]` / `#[contract(reinit_guard = N)]`).
+    reinit_guard: Option<u32>,
+    /// Call window of a rate-limited method (from
+    /// `#[contract(cooldown_blocks = N, per = "...")]`).
+    cooldown: Option<Cooldown>,
+    /// Whether the wrapper refuses calls while the contract's `Pausable`
+    /// field is paused (from `#[contract(when_not_paused)]`).
+    when_not_paused: bool,
+    /// Error codes of the `dusk_forge::revert!` / `ensure!` invocations in
+    /// the method body, listed in the schema.
+    errors: Vec<String>,
+    /// Deprecation notice of the function (from
+    /// `#[contract(deprecated = "...")]`).
+    deprecated: Option<Deprecation>,
+    /// Whether the schema marks the function for explorers and wallets to
+    /// hide by default (from `#[contract(internal)]`).
+    internal: bool,
+    /// Predicates of the method's `#[cfg(...)]` attributes, repeated on its
+    /// wrapper, data-driver arms and schema entry so a feature-gated method
+    /// is exported exactly when it is compiled.
+    cfg: Vec<TokenStream2>,
+    /// For the alias export of a renamed method, the method it forwards to
+    /// (from `#[contract(alias = "...")]` on that method).
+    alias_of: Option<Ident>,
+}
+
+/// Deprecation of a method with `#[contract(deprecated = "...")]`.
+#[derive(Clone)]
+struct Deprecation {
+    /// Why the method is deprecated, or what to use instead.
+    message: String,
+    /// The exported function replacing it (from `replacement = "..."`).
+    replacement: Option<String>,
+}
+
+/// Call window of a method with `#[contract(cooldown_blocks = N)]`.
+#[derive(Clone, Copy)]
+struct Cooldown {
+    /// Minimum number of blocks between accepted calls.
+    blocks: u64,
+    /// Whom the window applies to.
+    per: CooldownScope,
+}
+
+/// Whom a cooldown window applies to (from `per = "..."`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CooldownScope {
+    /// Each public sender has its own window (`per = "caller"`, the default).
+    Caller,
+    /// One window shared by all callers (`per = "global"`).
+    Global,
+}
+
+impl CooldownScope {
+    /// The `per` value selecting this scope, as recorded in the schema.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Caller => "caller",
+            Self::Global => "global",
+        }
+    }
+}
+
+/// Role of a function generated for `#[contract(feed_fallback)]`.
+///
+/// A collection-returning method marked with the directive is exported twice:
+/// once as-is and once as a `<name>_feed` variant that streams each entry.
+#[derive(Clone)]
+enum FeedFallback {
+    /// The direct-return wrapper, optionally capped at a number of entries.
+    Direct {
+        /// Maximum number of entries returned directly (from
+        /// `feed_fallback = N`).
+        limit: Option<usize>,
+    },
+    /// The `<name>_feed` variant, feeding the collection returned by
+    /// `source`.
+    Stream {
+        /// The method whose returned collection is fed.
+        source: Ident,
+    },
 }
 
 /// Information about an event extracted from `abi::emit()` calls.
@@ -139,6 +244,126 @@ struct TraitImplInfo<'a> {
     expose_list: Vec<String>,
 }
 
+/// Options passed to the `#[contract(...)]` attribute on the module.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct ContractOptions {
+    /// Route every export through one shared dispatch routine (from
+    /// `#[contract(compact_dispatch)]`).
+    compact_dispatch: bool,
+    /// Count host calls behind the contract's `metering` feature (from
+    /// `#[contract(metering)]`).
+    metering: bool,
+    /// Export `contract_metadata` (from `#[contract(metadata)]`).
+    metadata: bool,
+    /// Export `__storage_stats` (from `#[contract(storage_stats)]`).
+    storage_stats: bool,
+    /// Reject unchecked `+`, `-` and `*` in the contract's methods (from
+    /// `#[contract(deny_unchecked_math)]`).
+    deny_unchecked_math: bool,
+    /// Interface file the contract must conform to, relative to the crate
+    /// root (from `#[contract(implements = "token.forge-abi")]`).
+    implements: Option<LitStr>,
+    /// Traits implemented outside the contract module whose methods are
+    /// exposed through re-declared signatures (from
+    /// `#[contract(expose_extern = "...", methods = ...)]`).
+    expose_extern: Vec<ExternTraitInfo>,
+    /// Interface files of the contracts called through `dusk_forge::call!`,
+    /// typing those calls (from `#[contract(calls = "token.forge-abi")]`).
+    calls: Vec<LitStr>,
+    /// Modules the contract may glob-import, with the names they export
+    /// (from `#[contract(prelude = "...", names = [...])]`).
+    preludes: Vec<PreludeInfo>,
+    /// The `const fn` initializing `STATE`, if not `new` (from
+    /// `#[contract(constructor = "...")]`).
+    constructor: Option<Ident>,
+    /// Private module holding the generated items, instead of the contract
+    /// module and the crate root (from `#[contract(generated = "...")]`).
+    generated: Option<Ident>,
+    /// Generated items re-exported from the `generated` module (from
+    /// `#[contract(reexport = [...])]`).
+    reexports: Vec<Ident>,
+    /// Event types of earlier versions, no longer emitted but still decoded
+    /// (from `#[contract(past_events = [...])]`).
+    past_events: Vec<syn::Path>,
+}
+
+impl ContractOptions {
+    /// Name of the const constructor initializing `STATE`.
+    fn constructor_name(&self) -> String {
+        self.constructor
+            .as_ref()
+            .map_or_else(|| "new".to_string(), ToString::to_string)
+    }
+
+    /// Path to `CONTRACT_SCHEMA` from a module at crate root level.
+    fn schema_path(&self) -> TokenStream2 {
+        if let Some(generated) = &self.generated {
+            quote! { super::#generated::CONTRACT_SCHEMA }
+        } else {
+            quote! { super::CONTRACT_SCHEMA }
+        }
+    }
+}
+
+/// A glob-importable module declared with
+/// `#[contract(prelude = "...", names = [...])]`.
+struct PreludeInfo {
+    /// Path of the module, as written in its `use` statement.
+    path: String,
+    /// The names the module exports.
+    names: Vec<String>,
+}
+
+/// Functions of the contracts called through `dusk_forge::call!`, read from
+/// the interfaces named by `#[contract(calls = "...")]`.
+struct CalleeInfo {
+    /// The interface files, as written, with the contract ID newtype
+    /// generated for each.
+    interfaces: Vec<(String, Ident)>,
+    /// Argument and return type of each declared function, and the ID
+    /// newtype of its interface, by name. The first interface declaring a
+    /// name wins.
+    functions: BTreeMap<String, (TokenStream2, TokenStream2, Ident)>,
+}
+
+/// A trait implemented for the contract outside the `#[contract]` module.
+struct ExternTraitInfo {
+    /// Path of the trait, as written in `expose_extern = "..."`.
+    path: LitStr,
+    /// Signatures of the methods to expose.
+    methods: ExternMethods,
+}
+
+/// Where the signatures of an extern trait's exposed methods come from.
+enum ExternMethods {
+    /// Declared inline (`methods = [fn owner(&self) -> Owner, ...]`), as
+    /// stubs with empty bodies.
+    Declared(Vec<ImplItemFn>),
+    /// Read from an interface file (`methods = "ownable.forge-abi"`).
+    Interface(LitStr),
+}
+
+/// A contract interface loaded from a `.forge-abi` file.
+struct InterfaceInfo {
+    /// The declared functions.
+    functions: Vec<InterfaceFunction>,
+    /// The declared events.
+    events: Vec<EventInfo>,
+}
+
+/// A function signature declared in a `.forge-abi` interface.
+struct InterfaceFunction {
+    /// The function name.
+    name: Ident,
+    /// The receiver type (`&self`, `&mut self`, or none).
+    receiver: Receiver,
+    /// Function parameters.
+    params: Vec<ParameterInfo>,
+    /// The output type (dereferenced if declared as a reference).
+    output_type: TokenStream2,
+}
+
 /// Validated contract module data extracted during parsing.
 struct ContractData<'a> {
     /// Imported types.
@@ -147,10 +372,15 @@ struct ContractData<'a> {
     contract_name: String,
     /// The contract struct identifier.
     contract_ident: Ident,
+    /// Fields of the contract struct, in declaration order.
+    state: Vec<StateField>,
     /// Inherent impl blocks for the contract.
     impl_blocks: Vec<&'a ItemImpl>,
     /// Trait implementations with `#[contract(expose = [...])]` attributes.
     trait_impls: Vec<TraitImplInfo<'a>>,
+    /// State fields to generate getters for (from `#[contract(getters)]` on
+    /// the contract struct).
+    getters: Vec<&'a syn::Field>,
 }
 
 // ============================================================================
@@ -177,10 +407,26 @@ struct ContractData<'a> {
 /// - A public method is async
 /// - A public method consumes `self` instead of borrowing it
 /// - A public method uses `impl Trait` in parameters or return type
+/// - A public method returns a `&mut` reference
+/// - A public method's name collides with a reserved WASM export or a generated
+///   item
+/// - Two exported methods, inherent or from trait impls, have the same name
+/// - The attribute is given an unknown option
+/// - The contract does not match the interface named by `implements`
+/// - With `deny_unchecked_math`, a contract method uses `+`, `-` or `*`
+///   (including `+=`, `-=` and `*=`) instead of checked, saturating or wrapping
+///   arithmetic
+/// - An `expose_extern` trait has no `methods`, or a declared method fails the
+///   trait method checks
 #[proc_macro_attribute]
-pub fn contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
     let module = parse_macro_input!(item as ItemMod);
 
+    let options = match parse::contract_options(attr.into()) {
+        Ok(options) => options,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
     // Module must have content (not just a declaration)
     let Some((_, items)) = &module.content else {
         return syn::Error::new_spanned(&module, "#[contract] requires a module with content")
@@ -188,8 +434,11 @@ pub fn contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
             .into();
     };
 
+    // The const constructor initializing STATE
+    let constructor = options.constructor_name();
+
     // Validate and extract contract data
-    let data = match parse::contract_data(&module, items) {
+    let data = match parse::contract_data(&module, items, &options.preludes, &constructor) {
         Ok(data) => data,
         Err(e) => return e.to_compile_error().into(),
     };
@@ -198,72 +447,103 @@ pub fn contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
         imports,
         contract_name,
         contract_ident,
-        impl_blocks,
+        state,
+        mut impl_blocks,
         trait_impls,
+        getters,
     } = data;
 
-    // Extract functions and events from all inherent impl blocks
-    let mut functions = Vec::new();
-    let mut events = Vec::new();
-
-    for impl_block in &impl_blocks {
-        match parse::public_methods(impl_block) {
-            Ok(methods) => functions.extend(methods),
-            Err(e) => return e.to_compile_error().into(),
+    // Export the generated getters like hand-written methods
+    let getters = generate::getters(&contract_ident, &getters);
+    impl_blocks.extend(&getters);
+
+    // Reject unchecked arithmetic, if requested
+    if options.deny_unchecked_math {
+        let blocks = impl_blocks
+            .iter()
+            .copied()
+            .chain(trait_impls.iter().map(|t| t.impl_block));
+        if let Err(e) = validate::checked_math(blocks) {
+            return e.to_compile_error().into();
         }
-        events.extend(parse::emit_calls(impl_block));
-        // Include events from method-level #[contract(emits = [...])] attributes
-        events.extend(parse::inherent_method_emits(impl_block));
     }
 
-    // Extract functions and events from trait impl blocks with expose lists
-    for trait_impl in &trait_impls {
-        match parse::trait_methods(trait_impl) {
-            Ok(trait_functions) => functions.extend(trait_functions),
-            Err(e) => return e.to_compile_error().into(),
-        }
-        events.extend(parse::emit_calls(trait_impl.impl_block));
-        // Include events from method-level #[contract(emits = [...])] attributes
-        events.extend(parse::trait_method_emits(trait_impl));
+    let (functions, events) = match functions_and_events(&impl_blocks, &trait_impls, &options) {
+        Ok(collected) => collected,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // Deduplicate events by topic — first-seen wins — and register the
+    // event of a `U64Config` state field
+    let events = parse::config_events(&state, parse::dedup_events_by_topic(events));
+
+    // Events a deployment can emit, flagged as genesis events in the schema
+    let genesis = parse::init_events(&impl_blocks);
+
+    // Reject colliding exports, dangling deprecations and interface mismatches
+    if let Err(e) = validate_exports(&functions, &events, &options) {
+        return e.to_compile_error().into();
     }
 
-    // Deduplicate events by topic — first-seen wins.
-    let events = parse::dedup_events_by_topic(events);
+    // The interfaces of the contracts called through `dusk_forge::call!`
+    let callees = match parse::callees(&options.calls) {
+        Ok(callees) => callees,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-    // Generate schema
-    let schema = generate::schema(&contract_name, &imports, &functions, &events);
+    // Build resolved type map for the schema's topic values and data_driver
+    let type_map = resolve::build_type_map(&imports, &functions, &events);
 
-    // Generate static STATE variable
-    let state_static = generate::state_static(&contract_ident);
+    // Generate schema
+    let schema = generate::schema(
+        &contract_name,
+        &imports,
+        &state,
+        &functions,
+        &events,
+        &genesis,
+        callees.as_ref(),
+        &type_map,
+    );
 
     // Generate extern "C" wrappers
-    let externs = generate::extern_wrappers(&functions, &contract_ident);
+    let externs = wrappers(
+        items,
+        &impl_blocks,
+        &functions,
+        &contract_ident,
+        &state,
+        &options,
+    );
+    let externs = match externs {
+        Ok(externs) => externs,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-    // Build resolved type map for data_driver
-    let type_map = resolve::build_type_map(&imports, &functions, &events);
+    // Generate STATE, the wrappers and the schema, metering and metadata
+    // exports of the contract module
+    let exports = contract_exports(&options, &contract_ident, &constructor, &state, &externs);
+
+    // Rebuild when an interface file changes
+    let interface_dep = generate::interface_dependency(interface_files(&options));
 
     // Generate data_driver module at crate root level (outside contract module)
-    let data_driver = data_driver::module(&type_map, &functions, &events);
+    let data_driver = data_driver::module(&type_map, &functions, &events, &options.schema_path());
 
     // Rebuild the module with stripped contract attributes on methods
     let mod_vis = &module.vis;
     let mod_name = &module.ident;
     let mod_attrs = &module.attrs;
 
-    let new_items: Vec<_> = items
-        .iter()
-        .map(|item| {
-            if let Item::Impl(impl_block) = item
-                && let Type::Path(type_path) = &*impl_block.self_ty
-                && type_path.path.is_ident(&contract_name)
-            {
-                // Strip #[contract(...)] attributes from both inherent and trait impl blocks
-                Item::Impl(generate::strip_contract_attributes(impl_block.clone()))
-            } else {
-                item.clone()
-            }
-        })
-        .collect();
+    let new_items = module_items(items, &contract_name, getters, callees.as_ref(), &options);
+
+    let (schema, generated) = generated_items(&options, &schema, &exports);
+
+    // Allow the few lints the generated code is known to trigger
+    let schema = generate::allow_lints(schema);
+    let interface_dep = generate::allow_lints(interface_dep);
+    let generated = generate::allow_lints(generated);
+    let data_driver = generate::allow_lints(data_driver);
 
     // Output:
     // - Contract schema at crate root (always available)
@@ -276,17 +556,16 @@ pub fn contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
         #[cfg(all(feature = "contract", feature = "data-driver"))]
         compile_error!("Features 'contract' and 'data-driver' are mutually exclusive");
 
-        #[cfg(any(feature = "contract", feature = "data-driver"))]
         #schema
 
+        #interface_dep
+
         #[cfg(not(feature = "data-driver"))]
         #(#mod_attrs)*
         #mod_vis mod #mod_name {
             #(#new_items)*
 
-            #state_static
-
-            #externs
+            #generated
         }
 
         #data_driver
@@ -294,3 +573,415 @@ pub fn contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     output.into()
 }
+
+/// Validate the exported functions: reject exports that would collide with
+/// reserved or generated symbols and deprecations pointing at functions the
+/// contract does not export, and check conformance with the declared
+/// interface, if any.
+fn validate_exports(
+    functions: &[FunctionInfo],
+    events: &[EventInfo],
+    options: &ContractOptions,
+) -> Result<(), syn::Error> {
+    validate::export_names(functions)?;
+    validate::replacements(functions)?;
+    if let Some(path) = &options.implements {
+        let interface = parse::interface(path)?;
+        validate::implements(&interface, path, functions, events)?;
+    }
+    Ok(())
+}
+
+/// Generate the items added to the contract module: `STATE`, the extern
+/// wrappers and the `contract_schema`, metering, metadata and storage
+/// statistics exports.
+fn contract_exports(
+    options: &ContractOptions,
+    contract_ident: &Ident,
+    constructor: &str,
+    state: &[StateField],
+    externs: &TokenStream2,
+) -> TokenStream2 {
+    // A re-exported STATE must be visible from the contract module
+    let state_vis = if options.reexports.iter().any(|name| name == "STATE") {
+        quote! { pub(super) }
+    } else {
+        quote! {}
+    };
+    let state_static = generate::state_static(contract_ident, constructor, &state_vis);
+
+    // The exports sit one module deeper in the `generated` module
+    let schema = options.schema_path();
+    let schema = if options.generated.is_some() {
+        quote! { super::#schema }
+    } else {
+        schema
+    };
+
+    // Expose the schema from the contract WASM itself
+    let schema_export = generate::schema_export(&schema);
+
+    // Report host-call counts from metered builds
+    let metering_export = generate::metering_export(options.metering);
+
+    // Let deployed contracts describe themselves
+    let metadata_export = generate::metadata_export(options.metadata, &schema);
+
+    // Let operators watch the state grow
+    let storage_stats_export = generate::storage_stats_export(options.storage_stats, state);
+
+    quote! {
+        #state_static
+
+        #externs
+
+        #schema_export
+
+        #metering_export
+
+        #metadata_export
+
+        #storage_stats_export
+    }
+}
+
+/// Place the schema at crate root and the items generated in the contract
+/// module, each in the private module of `#[contract(generated = "...")]` if
+/// set, followed by the re-exports chosen with `reexport`.
+fn generated_items(
+    options: &ContractOptions,
+    schema: &TokenStream2,
+    items: &TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    let schema_cfg = quote! { #[cfg(any(feature = "contract", feature = "data-driver"))] };
+    let Some(generated) = &options.generated else {
+        return (quote! { #schema_cfg #schema }, items.clone());
+    };
+
+    let reexported = |item: &str| options.reexports.iter().any(|name| name == item);
+    let schema_reexport = reexported("CONTRACT_SCHEMA").then(|| {
+        quote! {
+            #schema_cfg
+            pub use self::#generated::CONTRACT_SCHEMA;
+        }
+    });
+    let state_reexport = reexported("STATE").then(|| {
+        quote! {
+            #[cfg(target_family = "wasm")]
+            use self::#generated::STATE;
+        }
+    });
+
+    (
+        generate::generated_module(generated, &schema_cfg, schema, schema_reexport.as_ref()),
+        generate::generated_module(generated, &quote! {}, items, state_reexport.as_ref()),
+    )
+}
+
+/// Generate the contract's extern "C" wrappers, routed through a shared
+/// dispatcher with `compact_dispatch`. Wrappers refuse re-entry while one of
+/// the contract's `dusk_forge::call!`s is in progress, check the `Pausable`
+/// field for `when_not_paused` methods, and run the `#[contract(invariant)]`
+/// methods of the inherent impl blocks after mutating calls in debug builds
+/// and builds with the contract's `invariants` feature.
+fn wrappers(
+    items: &[Item],
+    impl_blocks: &[&ItemImpl],
+    functions: &[FunctionInfo],
+    contract_ident: &Ident,
+    state: &[StateField],
+    options: &ContractOptions,
+) -> Result<TokenStream2, syn::Error> {
+    let pausable = pausable_field(state, functions)?;
+    let invariants = parse::invariants(impl_blocks)?;
+    let wrapper_options = generate::WrapperOptions {
+        reentrancy_guard: generate::uses_call(items),
+        pausable: pausable.as_ref(),
+        invariants: &invariants,
+    };
+    Ok(if options.compact_dispatch {
+        generate::dispatch_wrappers(functions, contract_ident, &wrapper_options)
+    } else {
+        generate::extern_wrappers(functions, contract_ident, &wrapper_options)
+    })
+}
+
+/// The state field of type `Pausable` that `#[contract(when_not_paused)]`
+/// wrappers check, if any method carries the directive.
+///
+/// # Errors
+///
+/// Returns an error if a method carries the directive and the state has no
+/// `Pausable` field, or more than one.
+fn pausable_field(
+    state: &[StateField],
+    functions: &[FunctionInfo],
+) -> Result<Option<syn::Member>, syn::Error> {
+    let Some(guarded) = functions.iter().find(|f| f.when_not_paused) else {
+        return Ok(None);
+    };
+
+    let is_pausable = |field: &&StateField| {
+        syn::parse2::<syn::TypePath>(field.ty.clone()).is_ok_and(|ty| {
+            ty.path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "Pausable")
+        })
+    };
+    let mut fields = state.iter().filter(is_pausable);
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        let found = state.iter().filter(is_pausable).count();
+        let msg = if found == 0 {
+            format!(
+                "`{}` is `#[contract(when_not_paused)]`, but the contract state has no \
+                 `dusk_forge::pause::Pausable` field",
+                guarded.name
+            )
+        } else {
+            format!(
+                "the contract state has {found} `Pausable` fields; \
+                 `#[contract(when_not_paused)]` needs exactly one"
+            )
+        };
+        return Err(syn::Error::new_spanned(&guarded.name, msg));
+    };
+
+    Ok(Some(match field.name.parse::<u32>() {
+        Ok(index) => syn::Member::Unnamed(syn::Index {
+            index,
+            span: proc_macro2::Span::call_site(),
+        }),
+        Err(_) => syn::Member::Named(Ident::new(&field.name, proc_macro2::Span::call_site())),
+    }))
+}
+
+/// Collect the exported functions and the emitted events of the contract's
+/// inherent impl blocks, exposed trait impls and extern traits.
+fn functions_and_events(
+    impl_blocks: &[&ItemImpl],
+    trait_impls: &[TraitImplInfo],
+    options: &ContractOptions,
+) -> Result<(Vec<FunctionInfo>, Vec<EventInfo>), syn::Error> {
+    let constructor = &options.constructor_name();
+    let mut functions = Vec::new();
+    let mut events = Vec::new();
+
+    // Extract functions and events from all inherent impl blocks
+    for impl_block in impl_blocks {
+        functions.extend(parse::public_methods(impl_block, constructor)?);
+        events.extend(parse::emit_calls(impl_block));
+        // Include events from method-level #[contract(emits = [...])] attributes
+        events.extend(parse::inherent_method_emits(impl_block, constructor));
+    }
+
+    // Extract functions and events from trait impl blocks with expose lists
+    for trait_impl in trait_impls {
+        functions.extend(parse::trait_methods(trait_impl)?);
+        events.extend(parse::emit_calls(trait_impl.impl_block));
+        // Include events from method-level #[contract(emits = [...])] attributes
+        events.extend(parse::trait_method_emits(trait_impl));
+    }
+
+    // Extract functions and declared events of traits implemented elsewhere
+    for extern_trait in &options.expose_extern {
+        let (extern_functions, extern_events) = parse::extern_trait_methods(extern_trait)?;
+        functions.extend(extern_functions);
+        events.extend(extern_events);
+    }
+
+    // Register earlier versions of events, so the data-driver decodes them
+    events.extend(parse::past_events(&options.past_events));
+
+    Ok((functions, events))
+}
+
+/// The interface files read for `implements`, `expose_extern` methods and
+/// `calls`.
+fn interface_files(options: &ContractOptions) -> impl Iterator<Item = &LitStr> {
+    let extern_files = options
+        .expose_extern
+        .iter()
+        .filter_map(|extern_trait| match &extern_trait.methods {
+            ExternMethods::Interface(path) => Some(path),
+            ExternMethods::Declared(_) => None,
+        });
+    options
+        .implements
+        .iter()
+        .chain(extern_files)
+        .chain(&options.calls)
+}
+
+/// Rebuild the module items, stripping `#[contract(...)]` attributes from the
+/// contract struct and the contract's inherent and trait impl blocks,
+/// counting their host calls with `metering`, typing `dusk_forge::call!`s
+/// against the `calls` interfaces, and adding the generated getters and the
+/// contract ID newtypes of the called interfaces.
+fn module_items(
+    items: &[Item],
+    contract_name: &str,
+    getters: Option<ItemImpl>,
+    callees: Option<&CalleeInfo>,
+    options: &ContractOptions,
+) -> Vec<Item> {
+    let items = items.iter().map(|item| {
+        let item = if let Item::Impl(impl_block) = item
+            && let Type::Path(type_path) = &*impl_block.self_ty
+            && type_path.path.is_ident(contract_name)
+        {
+            let impl_block = generate::strip_contract_attributes(impl_block.clone());
+            if options.metering {
+                Item::Impl(generate::meter_host_calls(impl_block))
+            } else {
+                Item::Impl(impl_block)
+            }
+        } else if let Item::Struct(struct_) = item
+            && struct_.ident == contract_name
+        {
+            let mut struct_ = struct_.clone();
+            struct_
+                .attrs
+                .retain(|attr| !attr.path().is_ident("contract"));
+            Item::Struct(struct_)
+        } else {
+            item.clone()
+        };
+
+        match callees {
+            Some(callees) => generate::type_calls(item, callees),
+            None => item,
+        }
+    });
+    let ids = callees.map(generate::callee_ids).unwrap_or_default();
+    items.chain(getters.map(Item::Impl)).chain(ids).collect()
+}
+
+/// Attribute for types passed to or returned from contract functions.
+///
+/// Adds the derives every type crossing the contract boundary needs:
+///
+/// ```ignore
+/// #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+/// #[archive_attr(derive(bytecheck::CheckBytes))]
+/// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// ```
+///
+/// It also derives `arbitrary::Arbitrary` behind the crate's `arbitrary`
+/// feature, for fuzzing and property tests.
+///
+/// Fields marked `#[hex]` are (de)serialized by serde as hex strings through
+/// `dusk_forge::hex`. `#[arbitrary(..)]` field attributes are kept only when
+/// the `arbitrary` feature is enabled, so fields of types without an
+/// `Arbitrary` impl can name a generator such as
+/// `#[arbitrary(with = dusk_forge::fuzz::public_key)]`. The crate must
+/// depend on `rkyv` and `bytecheck`, on `serde` behind a `serde` feature, and
+/// on `arbitrary` behind an `arbitrary` feature.
+///
+/// # Errors
+///
+/// Returns a compile error if applied to anything but a struct or enum, or
+/// if given arguments.
+#[proc_macro_attribute]
+pub fn contract_input(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as Item);
+
+    match contract_input::expand(attr.into(), item) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Generate a mock of the contract declared by a `.forge-abi` interface,
+/// for integration tests.
+///
+/// Invoked at the root of a contract crate, with the types named by the
+/// interface in scope, it exports every function of the interface. Calls
+/// are recorded and answered with the response programmed through the
+/// `__mock_<name>` export, and `__mock_calls_<name>` returns the inputs
+/// received so far:
+///
+/// ```ignore
+/// use dusk_forge_standards::token::Account;
+///
+/// dusk_forge::mock_contract!("token.forge-abi");
+/// ```
+///
+/// Functions returning `()` answer without being programmed; the output
+/// types of the others must be `Clone`.
+///
+/// # Errors
+///
+/// Returns a compile error if the interface cannot be read or parsed.
+#[proc_macro]
+pub fn mock_contract(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+
+    match mock::expand(&path) {
+        Ok(output) => generate::allow_lints(output).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive the topic consts of an event type.
+///
+/// On a struct, generates a `TOPIC` const. On an enum, generates one const
+/// per variant, named after the variant in `SCREAMING_SNAKE_CASE`, a
+/// `TOPICS` list and a `topic()` method returning the topic of a value:
+///
+/// ```ignore
+/// #[derive(dusk_forge::Event)]
+/// pub enum PauseToggled {
+///     Paused,
+///     #[event(topic = "resumed")]
+///     Unpaused,
+/// }
+///
+/// abi::emit(PauseToggled::PAUSED, PauseToggled::Paused);
+/// ```
+///
+/// Topics default to the `snake_case` name of the struct or variant.
+///
+/// # Errors
+///
+/// Returns a compile error if applied to a union or an empty enum, or if two
+/// variants share a topic or a topic const name.
+#[proc_macro_derive(Event, attributes(event))]
+pub fn derive_event(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    match event::expand(&input) {
+        Ok(output) => generate::allow_lints(output).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive hex encodings for a newtype over a byte array.
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, dusk_forge::HexBytes)]
+/// pub struct WithdrawalId(pub [u8; 32]);
+/// ```
+///
+/// Implements the rkyv traits, archiving the value as its byte array;
+/// serde as a hex string, behind the crate's `serde` feature; `Display` and
+/// `FromStr` as a hex string, `FromStr` accepting a `0x` prefix; and a
+/// `FORMAT` const, which the schema records as the `format` of parameters
+/// of the type; and `arbitrary::Arbitrary` from arbitrary bytes, behind the
+/// crate's `arbitrary` feature. The crate must depend on `rkyv`, on `serde`
+/// behind a `serde` feature, and on `arbitrary` behind an `arbitrary`
+/// feature.
+///
+/// # Errors
+///
+/// Returns a compile error unless applied to a struct with a single
+/// `[u8; N]` field.
+#[proc_macro_derive(HexBytes)]
+pub fn derive_hex_bytes(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    match hex_bytes::expand(&input) {
+        Ok(output) => generate::allow_lints(output).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `mock_contract!` expansion.
+//!
+//! Reads a `.forge-abi` interface and generates a contract exporting each of
+//! its functions, backed by a `dusk_forge::mock::Mock` static recording the
+//! calls and answering with the programmed response, plus the
+//! `__mock_<name>` and `__mock_calls_<name>` exports driving it. The statics
+//! are `dusk_forge::state::ContractState` cells rather than `static mut`s.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::LitStr;
+
+use crate::{generate, parse};
+
+/// Expand `mock_contract!("<file>.forge-abi")`.
+pub(crate) fn expand(path: &LitStr) -> Result<TokenStream2, syn::Error> {
+    let Some(callees) = parse::callees(core::slice::from_ref(path))? else {
+        unreachable!("one interface is given");
+    };
+
+    let mocks = callees.functions.iter().map(|(name, (input, output, _))| {
+        let fn_name = format_ident!("{name}");
+        let set_name = format_ident!("__mock_{name}");
+        let calls_name = format_ident!("__mock_calls_{name}");
+        let ident = format_ident!("MOCK_{}", name.to_uppercase());
+        let doc = format!("Programmed response and recorded calls of `{name}`.");
+
+        // Functions returning nothing answer without being programmed
+        let init = if output.to_string() == "()" {
+            quote! { dusk_forge::mock::Mock::responding(()) }
+        } else {
+            quote! { dusk_forge::mock::Mock::new() }
+        };
+
+        quote! {
+            #[doc = #doc]
+            static #ident: dusk_forge::state::ContractState<dusk_forge::mock::Mock<#input, #output>> =
+                dusk_forge::state::ContractState::new(#init);
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn #fn_name(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |input: #input| {
+                    #ident.state_mut().call(input, #name)
+                })
+            }
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn #set_name(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |response: #output| {
+                    #ident.state_mut().respond(response)
+                })
+            }
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn #calls_name(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| #ident.state_mut().take_calls())
+            }
+        }
+    });
+
+    let interface_dep = generate::interface_dependency([path]);
+
+    Ok(quote! {
+        #interface_dep
+
+        #[cfg(target_family = "wasm")]
+        mod __contract_mock {
+            use super::*;
+
+            #(#mocks)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normalize_tokens;
+
+    #[test]
+    fn test_expand_mocks_interface_functions() {
+        let path: LitStr = syn::parse_quote! { "../tests/test-contract/counter.forge-abi" };
+
+        let output = normalize_tokens(&expand(&path).unwrap());
+
+        let counter = normalize_tokens(&quote! {
+            #[doc = "Programmed response and recorded calls of `counter`."]
+            static MOCK_COUNTER: dusk_forge::state::ContractState<dusk_forge::mock::Mock<(), u64>> =
+                dusk_forge::state::ContractState::new(dusk_forge::mock::Mock::new());
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn counter(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |input: ()| {
+                    MOCK_COUNTER.state_mut().call(input, "counter")
+                })
+            }
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn __mock_counter(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |response: u64| {
+                    MOCK_COUNTER.state_mut().respond(response)
+                })
+            }
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn __mock_calls_counter(arg_len: u32) -> u32 {
+                dusk_core::abi::wrap_call(arg_len, |(): ()| MOCK_COUNTER.state_mut().take_calls())
+            }
+        });
+        assert!(output.contains(&counter), "{output}");
+        assert!(output.contains(
+            "static MOCK_SET_COUNTER : dusk_forge :: state :: ContractState < dusk_forge :: \
+             mock :: Mock < u64 , () >> = dusk_forge :: state :: ContractState :: new \
+             (dusk_forge :: mock :: Mock :: responding (())) ;"
+        ));
+        assert!(output.contains("unsafe extern \"C\" fn __mock_calls_reset_counter"));
+        assert!(output.contains("mod __contract_mock"));
+    }
+
+    #[test]
+    fn test_expand_rejects_missing_interface() {
+        let path = LitStr::new("missing.forge-abi", proc_macro2::Span::call_site());
+        assert!(expand(&path).is_err());
+    }
+}
@@ -6,12 +6,18 @@
 
 //! Parsers for the `#[contract(...)]` directive on impls and methods.
 //!
-//! These are four ad-hoc parsers (`expose`, `emits`, `feeds`, `no_event`),
-//! collected here pending consolidation into a single typed parser.
+//! These are eleven ad-hoc parsers (`expose`, `emits`, `forwards`, `feeds`,
+//! `feed_fallback`, `reinit_guard`, `cooldown_blocks`, `when_not_paused`,
+//! `deprecated`, `alias`, `no_event`), collected here pending consolidation
+//! into a single typed parser, plus `getters` on the contract struct, `unit`
+//! on parameters and the `#[cfg(...)]` predicates of methods.
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::Attribute;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitInt, LitStr, Token};
+
+use crate::{Cooldown, CooldownScope, Deprecation, FeedFallback, validate};
 
 /// Check if method has `#[contract(no_event)]` attribute to suppress the emit
 /// validation.
@@ -27,6 +33,29 @@ pub(super) fn event_suppressed(attrs: &[Attribute]) -> bool {
     })
 }
 
+/// Extract the display unit from a `#[contract(unit = "lux")]` attribute on
+/// a parameter.
+///
+/// Returns `Ok(None)` if the parameter has no `#[contract]` attribute, and an
+/// error for any other option or an unknown unit.
+pub(super) fn unit(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut unit = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("contract")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("unit") {
+                let lit: LitStr = meta.value()?.parse()?;
+                unit = Some(validate::unit(&lit)?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown parameter option; expected `unit = \"...\"`"))
+            }
+        })?;
+    }
+
+    Ok(unit)
+}
+
 /// Extract the `feeds` type from a `#[contract(feeds = "Type")]` attribute.
 ///
 /// This attribute specifies the type fed via `abi::feed()` for streaming
@@ -81,6 +110,402 @@ pub(super) fn extract_feeds_attribute(attrs: &[Attribute]) -> Option<TokenStream
     None
 }
 
+/// Extract the `feed_fallback` directive from a `#[contract(...)]` attribute.
+///
+/// Accepts both `#[contract(feed_fallback)]` and
+/// `#[contract(feed_fallback = N)]`, where `N` caps the number of entries the
+/// direct-return wrapper may return before pointing callers at the `_feed`
+/// variant.
+///
+/// Returns `Ok(None)` if the directive is absent, and an error if the limit is
+/// not an integer literal.
+pub(super) fn feed_fallback(attrs: &[Attribute]) -> Result<Option<FeedFallback>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("contract") {
+            continue;
+        }
+
+        let Ok(meta) = attr.meta.require_list() else {
+            continue;
+        };
+
+        // Parse: feed_fallback [= N]
+        let tokens = meta.tokens.clone();
+        let mut iter = tokens.into_iter();
+
+        // Look for "feed_fallback"
+        let Some(proc_macro2::TokenTree::Ident(ident)) = iter.next() else {
+            continue;
+        };
+        if ident != "feed_fallback" {
+            continue;
+        }
+
+        // Optional "= N"
+        let Some(proc_macro2::TokenTree::Punct(punct)) = iter.next() else {
+            return Ok(Some(FeedFallback::Direct { limit: None }));
+        };
+        if punct.as_char() != '=' {
+            return Ok(Some(FeedFallback::Direct { limit: None }));
+        }
+
+        let limit = match iter.next() {
+            Some(proc_macro2::TokenTree::Literal(lit)) => lit.to_string().parse::<usize>().ok(),
+            _ => None,
+        };
+        let Some(limit) = limit else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`feed_fallback` limit must be an integer literal; \
+                 use `#[contract(feed_fallback = 64)]` or omit the limit",
+            ));
+        };
+
+        return Ok(Some(FeedFallback::Direct { limit: Some(limit) }));
+    }
+
+    Ok(None)
+}
+
+/// Extract the `reinit_guard` directive from a `#[contract(...)]` attribute.
+///
+/// `#[contract(reinit_guard)]` allows a single call; `#[contract(reinit_guard =
+/// N)]` allows up to `N` calls for contracts that re-initialize on upgrade.
+///
+/// Returns `Ok(None)` if the directive is absent, and an error if the count is
+/// not a positive integer literal.
+pub(super) fn reinit_guard(attrs: &[Attribute]) -> Result<Option<u32>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("contract") {
+            continue;
+        }
+
+        let Ok(meta) = attr.meta.require_list() else {
+            continue;
+        };
+
+        // Parse: reinit_guard [= N]
+        let tokens = meta.tokens.clone();
+        let mut iter = tokens.into_iter();
+
+        // Look for "reinit_guard"
+        let Some(proc_macro2::TokenTree::Ident(ident)) = iter.next() else {
+            continue;
+        };
+        if ident != "reinit_guard" {
+            continue;
+        }
+
+        // Optional "= N"
+        let Some(proc_macro2::TokenTree::Punct(punct)) = iter.next() else {
+            return Ok(Some(1));
+        };
+        if punct.as_char() != '=' {
+            return Ok(Some(1));
+        }
+
+        let count = match iter.next() {
+            Some(proc_macro2::TokenTree::Literal(lit)) => lit.to_string().parse::<u32>().ok(),
+            _ => None,
+        };
+        let Some(count @ 1..) = count else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`reinit_guard` count must be a positive integer literal; \
+                 use `#[contract(reinit_guard = 2)]` or omit the count to allow a single call",
+            ));
+        };
+
+        return Ok(Some(count));
+    }
+
+    Ok(None)
+}
+
+/// Extract the `cooldown_blocks` directive from a `#[contract(...)]`
+/// attribute.
+///
+/// `#[contract(cooldown_blocks = N)]` rejects a caller's calls within `N`
+/// blocks of their previous accepted call; `per = "global"` shares a single
+/// window between all callers instead of the default `per = "caller"`.
+///
+/// Returns `Ok(None)` if the directive is absent, and an error if the block
+/// count is not a positive integer literal or an option is unknown.
+pub(super) fn cooldown(attrs: &[Attribute]) -> Result<Option<Cooldown>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("contract") {
+            continue;
+        }
+
+        let Ok(meta) = attr.meta.require_list() else {
+            continue;
+        };
+
+        // Parse: cooldown_blocks = N [, per = "caller" | "global"]
+        let has_cooldown = meta.tokens.clone().into_iter().any(
+            |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "cooldown_blocks"),
+        );
+        if !has_cooldown {
+            continue;
+        }
+
+        let mut blocks = 0;
+        let mut per = CooldownScope::Caller;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("cooldown_blocks") {
+                let lit: LitInt = meta.value()?.parse()?;
+                blocks = lit.base10_parse::<u64>().ok().filter(|&n| n > 0).ok_or_else(|| {
+                    syn::Error::new(
+                        lit.span(),
+                        "`cooldown_blocks` must be a positive integer literal; \
+                         use `#[contract(cooldown_blocks = 100)]`",
+                    )
+                })?;
+                Ok(())
+            } else if meta.path.is_ident("per") {
+                let lit: LitStr = meta.value()?.parse()?;
+                per = match lit.value().as_str() {
+                    "caller" => CooldownScope::Caller,
+                    "global" => CooldownScope::Global,
+                    other => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "unknown cooldown scope \"{other}\"; expected \"caller\" or \"global\""
+                            ),
+                        ));
+                    }
+                };
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown cooldown option; expected `cooldown_blocks = N` and optionally \
+                     `per = \"caller\"` or `per = \"global\"`",
+                ))
+            }
+        })?;
+
+        return Ok(Some(Cooldown { blocks, per }));
+    }
+
+    Ok(None)
+}
+
+/// Check if a method has the `#[contract(when_not_paused)]` directive.
+pub(super) fn when_not_paused(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("contract")
+            && attr.meta.require_list().is_ok_and(|meta| {
+                meta.tokens.clone().into_iter().any(|token| {
+                    matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "when_not_paused")
+                })
+            })
+    })
+}
+
+/// Check if a method has the `#[contract(internal)]` directive, marking it
+/// for explorers and wallets to hide by default.
+pub(super) fn internal(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("contract")
+            && attr.meta.require_list().is_ok_and(|meta| {
+                meta.tokens.clone().into_iter().any(|token| {
+                    matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "internal")
+                })
+            })
+    })
+}
+
+/// Check if a method has the `#[contract(invariant)]` directive, making it a
+/// check run after every mutating export instead of an export itself.
+pub(super) fn invariant(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("contract")
+            && attr.meta.require_list().is_ok_and(|meta| {
+                meta.tokens.clone().into_iter().any(|token| {
+                    matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "invariant")
+                })
+            })
+    })
+}
+
+/// The predicates of a method's `#[cfg(...)]` attributes, in order (empty
+/// if it has none).
+pub(super) fn cfg_predicates(attrs: &[Attribute]) -> Vec<TokenStream2> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .map(|meta| meta.tokens.clone())
+        .collect()
+}
+
+/// Extract the `deprecated` directive from a `#[contract(...)]` attribute.
+///
+/// `#[contract(deprecated = "use transfer_v2")]` marks the method deprecated
+/// in the schema with the given message; `replacement = "transfer_v2"` names
+/// the function replacing it.
+///
+/// Returns `Ok(None)` if the directive is absent, and an error if an option
+/// is unknown.
+pub(super) fn deprecated(attrs: &[Attribute]) -> Result<Option<Deprecation>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("contract") {
+            continue;
+        }
+
+        let Ok(meta) = attr.meta.require_list() else {
+            continue;
+        };
+
+        // Parse: deprecated = "message" [, replacement = "name"]
+        let has_deprecated = meta.tokens.clone().into_iter().any(
+            |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "deprecated"),
+        );
+        if !has_deprecated {
+            continue;
+        }
+
+        let mut message = String::new();
+        let mut replacement = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("deprecated") {
+                let lit: LitStr = meta.value()?.parse()?;
+                message = lit.value();
+                Ok(())
+            } else if meta.path.is_ident("replacement") {
+                let lit: LitStr = meta.value()?.parse()?;
+                replacement = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown deprecation option; expected `deprecated = \"...\"` and optionally \
+                     `replacement = \"...\"`",
+                ))
+            }
+        })?;
+
+        return Ok(Some(Deprecation {
+            message,
+            replacement,
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Extract the `alias` directive from a `#[contract(...)]` attribute.
+///
+/// `#[contract(alias = "old_name")]` on a renamed method keeps it exported
+/// under its previous name too, so clients calling `old_name` keep working.
+///
+/// Returns `Ok(None)` if the directive is absent, and an error if the alias
+/// is not an identifier or the attribute has other options.
+pub(super) fn alias(attrs: &[Attribute]) -> Result<Option<Ident>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("contract") {
+            continue;
+        }
+
+        let Ok(meta) = attr.meta.require_list() else {
+            continue;
+        };
+
+        // Parse: alias = "old_name"
+        let has_alias =
+            meta.tokens.clone().into_iter().any(
+                |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "alias"),
+            );
+        if !has_alias {
+            continue;
+        }
+
+        let mut alias = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("alias") {
+                let lit: LitStr = meta.value()?.parse()?;
+                let ident = lit.parse::<Ident>().map_err(|_| {
+                    syn::Error::new(
+                        lit.span(),
+                        format!("alias `{}` is not a function name", lit.value()),
+                    )
+                })?;
+                alias = Some(ident);
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown alias option; expected `alias = \"...\"` in its own \
+                     `#[contract(...)]` attribute",
+                ))
+            }
+        })?;
+
+        return Ok(alias);
+    }
+
+    Ok(None)
+}
+
+/// Fields selected by `#[contract(getters ...)]` on the contract struct.
+pub(super) struct Getters {
+    /// The listed fields of `getters = [...]`, or `None` for every field.
+    pub(super) fields: Option<Vec<Ident>>,
+    /// The fields listed by `exclude = [...]`.
+    pub(super) exclude: Vec<Ident>,
+}
+
+/// Extract the `getters` directive from a `#[contract(...)]` attribute on the
+/// contract struct.
+///
+/// `#[contract(getters)]` selects every field and `#[contract(getters = [a,
+/// b])]` only the listed ones; `exclude = [c]` leaves fields out of either.
+///
+/// Returns `Ok(None)` if there is no `#[contract(...)]` attribute, and an
+/// error for other options or an `exclude` without `getters`.
+pub(super) fn getters(attrs: &[Attribute]) -> Result<Option<Getters>, syn::Error> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("contract")) else {
+        return Ok(None);
+    };
+
+    let mut getters = false;
+    let mut fields = None;
+    let mut exclude = Vec::new();
+    attr.parse_nested_meta(|meta| {
+        let list = |meta: syn::meta::ParseNestedMeta| -> Result<Vec<Ident>, syn::Error> {
+            let value = meta.value()?;
+            let content;
+            syn::bracketed!(content in value);
+            let idents = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            Ok(idents.into_iter().collect())
+        };
+        if meta.path.is_ident("getters") {
+            getters = true;
+            if meta.input.peek(Token![=]) {
+                fields = Some(list(meta)?);
+            }
+            Ok(())
+        } else if meta.path.is_ident("exclude") {
+            exclude = list(meta)?;
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unknown #[contract] option on the contract struct; expected `getters`, \
+                 `getters = [...]` and optionally `exclude = [...]`",
+            ))
+        }
+    })?;
+
+    if !getters {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`#[contract(...)]` on the contract struct needs `getters`; \
+             `exclude` only leaves fields out of it",
+        ));
+    }
+
+    Ok(Some(Getters { fields, exclude }))
+}
+
 /// Extract the `expose = [method1, method2, ...]` list from a
 /// `#[contract(...)]` attribute.
 ///
@@ -150,6 +575,22 @@ pub(super) fn expose_list(attrs: &[Attribute]) -> Option<Vec<String>> {
 ///   events::OwnershipTransferred)`
 /// - String literal: `("my_topic", MyEventType)`
 pub(super) fn emits_list(attrs: &[Attribute]) -> Option<Vec<(String, TokenStream2)>> {
+    event_list(attrs, "emits")
+}
+
+/// Extract the `forwards = [(topic, Type), ...]` list from a
+/// `#[contract(...)]` attribute.
+///
+/// Declares the events a method re-emits from other contracts via
+/// `dusk_forge::events::forward!`, whose raw payloads carry no type
+/// information. Uses the same tuple format as [`emits_list`].
+pub(super) fn forwards_list(attrs: &[Attribute]) -> Option<Vec<(String, TokenStream2)>> {
+    event_list(attrs, "forwards")
+}
+
+/// Extract a `<key> = [(topic, Type), ...]` list from a `#[contract(...)]`
+/// attribute.
+fn event_list(attrs: &[Attribute], key: &str) -> Option<Vec<(String, TokenStream2)>> {
     for attr in attrs {
         if !attr.path().is_ident("contract") {
             continue;
@@ -159,17 +600,17 @@ pub(super) fn emits_list(attrs: &[Attribute]) -> Option<Vec<(String, TokenStream
             continue;
         };
 
-        // Parse the token stream to find emits = [...]
+        // Parse the token stream to find <key> = [...]
         let tokens = meta.tokens.clone();
         let mut iter = tokens.into_iter().peekable();
 
-        // Look through all tokens for "emits"
+        // Look through all tokens for the key
         while let Some(token) = iter.next() {
             let proc_macro2::TokenTree::Ident(ident) = token else {
                 continue;
             };
 
-            if ident != "emits" {
+            if ident != key {
                 continue;
             }
 
@@ -346,4 +787,242 @@ mod tests {
         let expose_list = expose_list(&impl_block.attrs);
         assert!(expose_list.is_none());
     }
+
+    #[test]
+    fn test_feed_fallback_without_limit() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(feed_fallback)]
+            pub fn entries(&self) -> Vec<u64> { self.entries.clone() }
+        };
+        let Ok(Some(FeedFallback::Direct { limit })) = feed_fallback(&method.attrs) else {
+            panic!("expected feed_fallback directive");
+        };
+        assert_eq!(limit, None);
+    }
+
+    #[test]
+    fn test_feed_fallback_with_limit() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(feed_fallback = 64)]
+            pub fn entries(&self) -> Vec<u64> { self.entries.clone() }
+        };
+        let Ok(Some(FeedFallback::Direct { limit })) = feed_fallback(&method.attrs) else {
+            panic!("expected feed_fallback directive");
+        };
+        assert_eq!(limit, Some(64));
+    }
+
+    #[test]
+    fn test_feed_fallback_invalid_limit() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(feed_fallback = "many")]
+            pub fn entries(&self) -> Vec<u64> { self.entries.clone() }
+        };
+        let Err(err) = feed_fallback(&method.attrs) else {
+            panic!("expected error for non-integer limit");
+        };
+        assert!(err.to_string().contains("integer literal"));
+    }
+
+    #[test]
+    fn test_feed_fallback_absent() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(feeds = "u64")]
+            pub fn entries(&self) {}
+        };
+        assert!(matches!(feed_fallback(&method.attrs), Ok(None)));
+    }
+
+    #[test]
+    fn test_reinit_guard_default_single_call() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard)]
+            pub fn init(&mut self) {}
+        };
+        assert!(matches!(reinit_guard(&method.attrs), Ok(Some(1))));
+    }
+
+    #[test]
+    fn test_reinit_guard_with_count() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard = 3)]
+            pub fn init(&mut self) {}
+        };
+        assert!(matches!(reinit_guard(&method.attrs), Ok(Some(3))));
+    }
+
+    #[test]
+    fn test_reinit_guard_zero_count_rejected() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard = 0)]
+            pub fn init(&mut self) {}
+        };
+        let Err(err) = reinit_guard(&method.attrs) else {
+            panic!("expected error for zero count");
+        };
+        assert!(err.to_string().contains("positive integer"));
+    }
+
+    #[test]
+    fn test_when_not_paused() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(when_not_paused)]
+            pub fn deposit(&mut self) {}
+        };
+        assert!(when_not_paused(&method.attrs));
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(no_event)]
+            pub fn deposit(&mut self) {}
+        };
+        assert!(!when_not_paused(&method.attrs));
+    }
+
+    #[test]
+    fn test_internal() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(internal, no_event)]
+            pub fn sweep_dust(&mut self) {}
+        };
+        assert!(internal(&method.attrs));
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(deprecated = "internal")]
+            pub fn sweep_dust(&mut self) {}
+        };
+        assert!(!internal(&method.attrs));
+    }
+
+    #[test]
+    fn test_cooldown_per_caller_by_default() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(cooldown_blocks = 100)]
+            pub fn claim(&mut self) {}
+        };
+        let window = cooldown(&method.attrs).unwrap().unwrap();
+        assert_eq!(window.blocks, 100);
+        assert!(window.per == CooldownScope::Caller);
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(cooldown_blocks = 10, per = "global")]
+            pub fn claim(&mut self) {}
+        };
+        let window = cooldown(&method.attrs).unwrap().unwrap();
+        assert!(window.per == CooldownScope::Global);
+    }
+
+    #[test]
+    fn test_cooldown_rejects_invalid_options() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(cooldown_blocks = 0)]
+            pub fn claim(&mut self) {}
+        };
+        let Err(err) = cooldown(&method.attrs) else {
+            panic!("expected error for a zero window");
+        };
+        assert!(err.to_string().contains("positive integer"), "{err}");
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(cooldown_blocks = 10, per = "block")]
+            pub fn claim(&mut self) {}
+        };
+        let Err(err) = cooldown(&method.attrs) else {
+            panic!("expected error for an unknown scope");
+        };
+        assert!(err.to_string().contains("unknown cooldown scope"), "{err}");
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard)]
+            pub fn init(&mut self) {}
+        };
+        assert!(cooldown(&method.attrs).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_alias() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(alias = "transfer_v1")]
+            pub fn transfer(&mut self) {}
+        };
+        let alias = super::alias(&method.attrs).unwrap().unwrap();
+        assert_eq!(alias, "transfer_v1");
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(alias = "not a name")]
+            pub fn transfer(&mut self) {}
+        };
+        let Err(err) = super::alias(&method.attrs) else {
+            panic!("expected error for an alias that is not an identifier");
+        };
+        assert!(err.to_string().contains("is not a function name"), "{err}");
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(alias = "transfer_v1", internal)]
+            pub fn transfer(&mut self) {}
+        };
+        assert!(super::alias(&method.attrs).is_err());
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(internal)]
+            pub fn transfer(&mut self) {}
+        };
+        assert!(super::alias(&method.attrs).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_deprecated_with_replacement() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(deprecated = "use transfer_v2", replacement = "transfer_v2")]
+            pub fn transfer(&mut self, to: PublicKey, value: u64) {}
+        };
+        let deprecation = deprecated(&method.attrs).unwrap().unwrap();
+        assert_eq!(deprecation.message, "use transfer_v2");
+        assert_eq!(deprecation.replacement.as_deref(), Some("transfer_v2"));
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(deprecated = "no longer maintained")]
+            pub fn legacy(&self) -> u64 { 0 }
+        };
+        let deprecation = deprecated(&method.attrs).unwrap().unwrap();
+        assert!(deprecation.replacement.is_none());
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(deprecated = "old", since = "0.3")]
+            pub fn legacy(&self) -> u64 { 0 }
+        };
+        let Err(err) = deprecated(&method.attrs) else {
+            panic!("expected error for an unknown option");
+        };
+        assert!(
+            err.to_string().contains("unknown deprecation option"),
+            "{err}"
+        );
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[contract(when_not_paused)]
+            pub fn claim(&mut self) {}
+        };
+        assert!(deprecated(&method.attrs).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cfg_predicates() {
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[cfg(feature = "debug")]
+            #[contract(internal)]
+            #[cfg(not(test))]
+            pub fn dump(&self) -> u64 { 0 }
+        };
+        let predicates: Vec<_> = cfg_predicates(&method.attrs)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(predicates, ["feature = \"debug\"", "not (test)"]);
+
+        let method: syn::ImplItemFn = syn::parse_quote! {
+            #[cfg_attr(feature = "debug", inline)]
+            pub fn dump(&self) -> u64 { 0 }
+        };
+        assert!(cfg_predicates(&method.attrs).is_empty());
+    }
 }
@@ -4,9 +4,10 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-//! Event extraction from impl blocks: `abi::emit()` call-site discovery,
-//! `abi::feed()` call-site discovery, and `#[contract(emits = [...])]`
-//! attribute collection.
+//! Event extraction from impl blocks: `abi::emit()` / `emit!()` call-site
+//! discovery, `abi::feed()` call-site discovery, and
+//! `#[contract(emits = [...])]` / `#[contract(forwards = [...])]` attribute
+//! collection.
 
 use std::collections::HashSet;
 
@@ -14,110 +15,184 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::visit::Visit;
 use syn::{
-    Attribute, Expr, ExprCall, ExprLit, ExprPath, ImplItem, ImplItemFn, ItemImpl, Lit, Visibility,
+    Attribute, Expr, ExprCall, ExprLit, ExprPath, ImplItem, ImplItemFn, ItemImpl, Lit, Macro, Path,
+    PathSegment, Type, Visibility,
 };
 
 use crate::parse::directives;
-use crate::{EventInfo, TraitImplInfo};
+use crate::{EventInfo, StateField, TraitImplInfo};
 
-/// Visitor to find `abi::emit()` calls within function bodies.
+/// Visitor to find `abi::emit()` calls, `emit!()` invocations and
+/// `abi::feed()` calls within function bodies.
 struct EmitVisitor {
     /// Collected events.
     events: Vec<EventInfo>,
+    /// The expressions passed to `abi::feed()` calls.
+    feeds: Vec<Expr>,
+    /// Whether a `forward!()` invocation was found.
+    forwards: bool,
+    /// Whether a `batch!()` or `flush!()` invocation was found.
+    batches: bool,
 }
 
 impl EmitVisitor {
     /// Create a new empty visitor.
     fn new() -> Self {
-        Self { events: Vec::new() }
+        Self {
+            events: Vec::new(),
+            feeds: Vec::new(),
+            forwards: false,
+            batches: false,
+        }
     }
 }
 
 impl<'ast> Visit<'ast> for EmitVisitor {
     fn visit_expr_call(&mut self, node: &'ast ExprCall) {
-        // Check if this is an abi::emit() call
         if let Expr::Path(ExprPath { path, .. }) = &*node.func {
             let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
 
-            // Match abi::emit or just emit
-            let is_emit = matches!(
-                segments
-                    .iter()
-                    .map(String::as_str)
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                ["abi", "emit"] | ["emit"]
-            );
-
-            if is_emit && node.args.len() >= 2 {
-                // First arg is the topic - can be a string literal or a const path
-                let topic = topic_from_expr(node.args.first().unwrap());
-
-                if let Some(topic) = topic {
-                    // Second arg is the event data - extract its type
-                    let data_expr = &node.args[1];
-                    let data_type = type_from_expr(data_expr);
-
-                    self.events.push(EventInfo { topic, data_type });
+            match segments
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                // Match abi::emit or just emit
+                ["abi", "emit"] | ["emit"] if node.args.len() >= 2 => {
+                    // First arg is the topic - can be a string literal or a const path
+                    let topic = topic_from_expr(node.args.first().unwrap());
+
+                    if let Some(topic) = topic {
+                        // Second arg is the event data - extract its type
+                        let data_expr = &node.args[1];
+                        let data_type = type_from_expr(data_expr);
+
+                        self.events.push(EventInfo { topic, data_type });
+                    }
+                }
+                // Match abi::feed or just feed
+                ["abi", "feed"] | ["feed"] if !node.args.is_empty() => {
+                    self.feeds.push(node.args[0].clone());
                 }
+                _ => {}
             }
         }
 
         // Continue visiting nested expressions
         syn::visit::visit_expr_call(self, node);
     }
-}
 
-/// Visitor to detect `abi::feed()` calls within function bodies.
-struct FeedVisitor {
-    /// The expressions passed to `abi::feed()` calls, as strings.
-    feed_exprs: Vec<String>,
-}
-
-impl FeedVisitor {
-    /// Create a new visitor.
-    fn new() -> Self {
-        Self {
-            feed_exprs: Vec::new(),
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if let Some(event) = emit_macro_event(node) {
+            self.events.push(event);
+        } else if is_forward_macro(node) {
+            self.forwards = true;
+        } else if is_batch_macro(node) {
+            self.batches = true;
         }
+
+        syn::visit::visit_macro(self, node);
     }
 }
 
-impl<'ast> Visit<'ast> for FeedVisitor {
-    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
-        // Check if this is an abi::feed() call
-        if let Expr::Path(ExprPath { path, .. }) = &*node.func {
-            let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+/// Extract the event from an `emit!(EventType { .. })` invocation.
+///
+/// The `emit!` macro takes its topic from the `TOPIC` associated const of the
+/// event type, so the topic is always `<event path>::TOPIC`. Matches both
+/// `emit!` and `dusk_forge::emit!`; returns `None` for any other macro or if
+/// the body is not a struct, tuple-struct or unit-struct expression.
+fn emit_macro_event(mac: &Macro) -> Option<EventInfo> {
+    let segments: Vec<_> = mac
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    let is_emit = matches!(
+        segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice(),
+        ["emit"] | ["dusk_forge", "emit"]
+    );
+    if !is_emit {
+        return None;
+    }
 
-            // Match abi::feed or just feed
-            let is_feed = matches!(
-                segments
-                    .iter()
-                    .map(String::as_str)
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                ["abi", "feed"] | ["feed"]
-            );
-
-            if is_feed && !node.args.is_empty() {
-                // Capture the expression being fed
-                let expr = &node.args[0];
-                let expr_str = quote!(#expr).to_string();
-                self.feed_exprs.push(expr_str);
-            }
-        }
+    let event_path = match mac.parse_body::<Expr>().ok()? {
+        Expr::Struct(s) => s.path,
+        Expr::Call(call) => match *call.func {
+            Expr::Path(path) => path.path,
+            _ => return None,
+        },
+        Expr::Path(path) => path.path,
+        _ => return None,
+    };
+
+    let path_str = event_path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+
+    Some(EventInfo {
+        topic: format!("{path_str}::TOPIC"),
+        data_type: quote! { #event_path },
+    })
+}
 
-        // Continue visiting nested expressions
-        syn::visit::visit_expr_call(self, node);
-    }
+/// Whether a macro invocation is `dusk_forge::events::forward!`.
+///
+/// Forwarded payloads are raw bytes, so the event type cannot be inferred
+/// from the call; it is declared with `#[contract(forwards = [...])]`.
+fn is_forward_macro(mac: &Macro) -> bool {
+    let segments: Vec<_> = mac
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    matches!(
+        segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice(),
+        ["forward"] | ["events", "forward"] | ["dusk_forge", "events", "forward"]
+    )
 }
 
-/// Check if a method body contains `abi::feed()` calls.
-/// Returns the expressions being fed (empty if no feed calls).
-pub(super) fn get_feed_exprs(method: &ImplItemFn) -> Vec<String> {
-    let mut visitor = FeedVisitor::new();
+/// Whether a macro invocation is `dusk_forge::events::batch!` or
+/// `dusk_forge::events::flush!`.
+///
+/// Batches are emitted under the topic of a `Batcher` value, so the envelope
+/// is declared with `#[contract(emits = [...])]`.
+fn is_batch_macro(mac: &Macro) -> bool {
+    let segments: Vec<_> = mac
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    matches!(
+        segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice(),
+        ["events", "batch" | "flush"] | ["dusk_forge", "events", "batch" | "flush"]
+    )
+}
+
+/// The expressions passed to `abi::feed()` calls in a method body (empty if
+/// there are none).
+pub(super) fn get_feed_exprs(method: &ImplItemFn) -> Vec<Expr> {
+    let mut visitor = EmitVisitor::new();
     visitor.visit_block(&method.block);
-    visitor.feed_exprs
+    visitor.feeds
 }
 
 /// Check if a type string looks like a tuple (starts with `(` and contains
@@ -129,33 +204,150 @@ fn looks_like_tuple(s: &str) -> bool {
 
 /// Validate that the `feeds` attribute type matches the fed expressions.
 /// Returns an error message if there's a mismatch, None if OK.
+///
+/// The expression must be a tuple exactly when the declared type is. Where
+/// [`fed_type`] can tell the type of the expression, that type must also
+/// agree with the declared one (see [`types_agree`]), since the data-driver
+/// decodes the fed items as the declared type.
 pub(super) fn validate_feed_type_match(
-    feed_type_str: &str,
-    feed_exprs: &[String],
+    feed_type: &TokenStream2,
+    feed_exprs: &[Expr],
 ) -> Option<String> {
-    if feed_exprs.is_empty() {
-        return None;
-    }
+    // Check the first fed expression (there is only one call site per function)
+    let expr = feed_exprs.first()?;
+    let expr_str = quote!(#expr).to_string();
+    let feed_type_str = feed_type.to_string();
 
-    let feeds_is_tuple = looks_like_tuple(feed_type_str);
-
-    // Check the first fed expression (they should all be the same type in practice)
-    let expr = &feed_exprs[0];
-    let expr_is_tuple = looks_like_tuple(expr);
+    let feeds_is_tuple = looks_like_tuple(&feed_type_str);
+    let expr_is_tuple = looks_like_tuple(&expr_str);
 
     if feeds_is_tuple && !expr_is_tuple {
-        Some(format!(
-            "feeds attribute specifies tuple type `{feed_type_str}` but expression `{expr}` doesn't look like a tuple"
-        ))
+        return Some(format!(
+            "feeds attribute specifies tuple type `{feed_type_str}` but expression `{expr_str}` doesn't look like a tuple"
+        ));
     } else if !feeds_is_tuple && expr_is_tuple {
+        return Some(format!(
+            "feeds attribute specifies non-tuple type `{feed_type_str}` but expression `{expr_str}` looks like a tuple"
+        ));
+    }
+
+    let declared = syn::parse2::<Type>(feed_type.clone()).ok()?;
+    let fed = fed_type(expr)?;
+    if types_agree(&declared, &fed) {
+        None
+    } else {
+        let fed = quote!(#fed).to_string();
         Some(format!(
-            "feeds attribute specifies non-tuple type `{feed_type_str}` but expression `{expr}` looks like a tuple"
+            "feeds attribute specifies `{feed_type_str}` but expression `{expr_str}` is a `{fed}`; \
+             the data-driver would decode the fed items as the wrong type"
         ))
-    } else {
-        None
     }
 }
 
+/// The type of a fed expression, where it shows in the expression itself:
+/// suffixed and boolean literals, casts, struct and tuple-struct
+/// constructors, and tuples of those. Tuple elements of unknown type are
+/// `_`. `None` for anything else, such as variables, field accesses and
+/// function calls.
+fn fed_type(expr: &Expr) -> Option<Type> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => {
+            let suffix = match lit {
+                Lit::Int(int) => int.suffix(),
+                Lit::Float(float) => float.suffix(),
+                Lit::Bool(_) => "bool",
+                _ => "",
+            };
+            syn::parse_str(suffix).ok()
+        }
+        Expr::Cast(cast) => Some((*cast.ty).clone()),
+        Expr::Paren(paren) => fed_type(&paren.expr),
+        Expr::Group(group) => fed_type(&group.expr),
+        Expr::Struct(s) => syn::parse2(event_type(&s.path)).ok(),
+        Expr::Call(call) => {
+            let Expr::Path(path) = &*call.func else {
+                return None;
+            };
+            // Only constructors: capitalized, and not the prelude's variants
+            let last = path.path.segments.last()?.ident.to_string();
+            if !last.starts_with(char::is_uppercase)
+                || matches!(last.as_str(), "Some" | "Ok" | "Err")
+            {
+                return None;
+            }
+            syn::parse2(event_type(&path.path)).ok()
+        }
+        Expr::Tuple(tuple) if !tuple.elems.is_empty() => {
+            let mut elems: syn::punctuated::Punctuated<Type, syn::Token![,]> = tuple
+                .elems
+                .iter()
+                .map(|elem| fed_type(elem).unwrap_or_else(|| syn::parse_quote! { _ }))
+                .collect();
+            if tuple.elems.trailing_punct() {
+                elems.push_punct(syn::parse_quote! { , });
+            }
+            Some(syn::parse_quote! { (#elems) })
+        }
+        _ => None,
+    }
+}
+
+/// Whether the declared feed type `declared` agrees with the type `fed`
+/// inferred from the fed expression.
+///
+/// Tuples agree element-wise, and named types by their last path segment,
+/// since the declared type is written with the contract's imports. A
+/// primitive fed where a non-primitive is declared agrees, as the declared
+/// type may be an alias of it; so does anything not inferred.
+fn types_agree(declared: &Type, fed: &Type) -> bool {
+    match (declared, fed) {
+        (Type::Paren(paren), _) => types_agree(&paren.elem, fed),
+        (Type::Group(group), _) => types_agree(&group.elem, fed),
+        (Type::Tuple(declared), Type::Tuple(fed)) => {
+            declared.elems.len() == fed.elems.len()
+                && declared
+                    .elems
+                    .iter()
+                    .zip(&fed.elems)
+                    .all(|(declared, fed)| types_agree(declared, fed))
+        }
+        (Type::Path(declared), Type::Path(fed)) => {
+            let (Some(declared), Some(fed)) =
+                (declared.path.segments.last(), fed.path.segments.last())
+            else {
+                return true;
+            };
+            declared.ident == fed.ident
+                || (is_primitive(&fed.ident.to_string())
+                    && !is_primitive(&declared.ident.to_string()))
+        }
+        (Type::Tuple(_), Type::Path(_)) | (Type::Path(_), Type::Tuple(_)) => false,
+        _ => true,
+    }
+}
+
+/// Whether `name` is a primitive scalar type.
+fn is_primitive(name: &str) -> bool {
+    matches!(
+        name,
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "char"
+    )
+}
+
 /// Deduplicate a list of events by topic, keeping the first occurrence.
 ///
 /// Two events sharing a topic but registering structurally different data
@@ -219,29 +411,43 @@ fn emit_variable_topic_warning(_name: &str) {}
 pub(super) fn type_from_expr(expr: &Expr) -> TokenStream2 {
     match expr {
         // Handle struct instantiation: events::PauseToggled { ... } or PauseToggled { ... }
-        Expr::Struct(s) => {
-            let path = &s.path;
-            quote! { #path }
-        }
+        Expr::Struct(s) => event_type(&s.path),
         // Handle unit struct or tuple struct: events::PauseToggled() or PauseToggled()
         Expr::Call(call) => {
             if let Expr::Path(path) = &*call.func {
-                let p = &path.path;
-                quote! { #p }
+                event_type(&path.path)
             } else {
                 quote! { () }
             }
         }
         // Handle path expressions: events::PauseToggled
-        Expr::Path(path) => {
-            let p = &path.path;
-            quote! { #p }
-        }
+        Expr::Path(path) => event_type(&path.path),
         // Fallback - unknown type
         _ => quote! { () },
     }
 }
 
+/// The type of an event constructed through `path`.
+///
+/// A path whose last two segments are both capitalized, such as
+/// `events::PauseToggled::Paused`, names an enum variant (see
+/// `#[derive(Event)]`), so the event type is the enum.
+fn event_type(path: &Path) -> TokenStream2 {
+    let capitalized = |s: &PathSegment| s.ident.to_string().starts_with(char::is_uppercase);
+    let mut segments = path.segments.iter().rev();
+    if let (Some(last), Some(parent)) = (segments.next(), segments.next())
+        && capitalized(last)
+        && capitalized(parent)
+        && parent.ident != "Self"
+    {
+        let mut enum_path = path.clone();
+        enum_path.segments.pop();
+        enum_path.segments.pop_punct();
+        return quote! { #enum_path };
+    }
+    quote! { #path }
+}
+
 /// Extract all `abi::emit()` calls from an impl block.
 ///
 /// Events are deduplicated by topic, keeping only the first occurrence.
@@ -252,26 +458,40 @@ pub(crate) fn emit_calls(impl_block: &ItemImpl) -> Vec<EventInfo> {
     dedup_events_by_topic(visitor.events)
 }
 
-/// Check if a method body contains any `abi::emit()` call.
+/// Check if a method body contains any `abi::emit()` call, `emit!()`,
+/// `forward!()`, `batch!()` or `flush!()` invocation.
 pub(super) fn method_has_emit_call(method: &ImplItemFn) -> bool {
     let mut visitor = EmitVisitor::new();
     visitor.visit_block(&method.block);
-    !visitor.events.is_empty()
+    !visitor.events.is_empty() || visitor.forwards || visitor.batches
+}
+
+/// Check if a method body contains a `forward!()` invocation.
+pub(super) fn method_has_forward_call(method: &ImplItemFn) -> bool {
+    let mut visitor = EmitVisitor::new();
+    visitor.visit_block(&method.block);
+    visitor.forwards
+}
+
+/// Check if a method body contains a `batch!()` or `flush!()` invocation.
+pub(super) fn method_has_batch_call(method: &ImplItemFn) -> bool {
+    let mut visitor = EmitVisitor::new();
+    visitor.visit_block(&method.block);
+    visitor.batches
 }
 
-/// Extract events from a method's `#[contract(emits = [...])]` attribute.
+/// Extract events from a method's `#[contract(emits = [...])]` and
+/// `#[contract(forwards = [...])]` attributes.
 ///
 /// Returns the events registered on this specific method, or an empty vec if
 /// none.
 pub(super) fn method_emits(attrs: &[Attribute]) -> Vec<EventInfo> {
     directives::emits_list(attrs)
-        .map(|events| {
-            events
-                .into_iter()
-                .map(|(topic, data_type)| EventInfo { topic, data_type })
-                .collect()
-        })
-        .unwrap_or_default()
+        .into_iter()
+        .chain(directives::forwards_list(attrs))
+        .flatten()
+        .map(|(topic, data_type)| EventInfo { topic, data_type })
+        .collect()
 }
 
 /// Collect events from method-level `#[contract(emits = [...])]` attributes
@@ -306,26 +526,108 @@ pub(crate) fn trait_method_emits(trait_impl: &TraitImplInfo) -> Vec<EventInfo> {
 /// Extract events from method-level `#[contract(emits = [...])]` attributes in
 /// an inherent impl block.
 ///
-/// Only public methods (excluding `new`) are checked, matching the set of
-/// methods exposed as contract functions by
+/// Only public methods (excluding the constructors) are checked, matching
+/// the set of methods exposed as contract functions by
 /// [`super::functions::public_methods`].
-pub(crate) fn inherent_method_emits(impl_block: &ItemImpl) -> Vec<EventInfo> {
+pub(crate) fn inherent_method_emits(impl_block: &ItemImpl, constructor: &str) -> Vec<EventInfo> {
     impl_method_emits(impl_block, |method| {
-        matches!(method.vis, Visibility::Public(_)) && method.sig.ident != "new"
+        matches!(method.vis, Visibility::Public(_))
+            && !crate::validate::is_constructor(method, constructor)
     })
 }
 
+/// Extract the events emitted by the contract's `init`, from its body and its
+/// `#[contract(emits = [...])]` attribute.
+///
+/// These are the events a deployment can produce, flagged as `genesis` in the
+/// schema.
+pub(crate) fn init_events(impl_blocks: &[&ItemImpl]) -> Vec<EventInfo> {
+    let mut events = Vec::new();
+    for impl_block in impl_blocks {
+        for item in &impl_block.items {
+            if let ImplItem::Fn(method) = item
+                && method.sig.ident == "init"
+            {
+                let mut visitor = EmitVisitor::new();
+                visitor.visit_block(&method.block);
+                events.extend(visitor.events);
+                events.extend(method_emits(&method.attrs));
+            }
+        }
+    }
+    dedup_events_by_topic(events)
+}
+
+/// Register the `ConfigChanged` event of a `dusk_forge::config::U64Config`
+/// state field.
+///
+/// The contract emits the event returned by `U64Config::set`, whose type the
+/// emit visitor cannot infer from the call, so events emitted under a
+/// `ConfigChanged::TOPIC` topic are replaced by the event under its full
+/// path. Events are returned unchanged if the state has no `U64Config`
+/// field.
+pub(crate) fn config_events(state: &[StateField], events: Vec<EventInfo>) -> Vec<EventInfo> {
+    let has_config = state.iter().any(|field| {
+        syn::parse2::<syn::TypePath>(field.ty.clone()).is_ok_and(|ty| {
+            ty.path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "U64Config")
+        })
+    });
+    if !has_config {
+        return events;
+    }
+
+    let config_changed = || EventInfo {
+        topic: "dusk_forge::config::ConfigChanged::TOPIC".to_string(),
+        data_type: quote! { dusk_forge::config::ConfigChanged },
+    };
+    let mut registered = false;
+    let mut events: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            if event.topic == "ConfigChanged::TOPIC"
+                || event.topic.ends_with("::ConfigChanged::TOPIC")
+            {
+                registered = true;
+                config_changed()
+            } else {
+                event
+            }
+        })
+        .collect();
+    if !registered {
+        events.push(config_changed());
+    }
+    dedup_events_by_topic(events)
+}
+
+/// Events of the types named by `#[contract(past_events = [...])]`: earlier
+/// versions of the contract's events, no longer emitted, registered under
+/// their `TOPIC` const so the data-driver still decodes them.
+pub(crate) fn past_events(paths: &[syn::Path]) -> Vec<EventInfo> {
+    paths
+        .iter()
+        .map(|path| {
+            let path_str = path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+            EventInfo {
+                topic: format!("{path_str}::TOPIC"),
+                data_type: quote! { #path },
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn normalize_tokens(tokens: TokenStream2) -> String {
-        tokens
-            .to_string()
-            .split_whitespace()
-            .collect::<Vec<_>>()
-            .join(" ")
-    }
+    use crate::test_utils::normalize_tokens;
 
     // =========================================================================
     // EmitVisitor tests
@@ -366,6 +668,42 @@ mod tests {
         assert_eq!(visitor.events[0].topic, "events::PauseToggled::PAUSED");
     }
 
+    #[test]
+    fn test_emit_visitor_enum_variant_event() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn pause(&mut self) {
+                    abi::emit(events::PauseToggled::PAUSED, events::PauseToggled::Paused);
+                    abi::emit(events::PauseToggled::UNPAUSED, events::PauseToggled::Unpaused { by });
+                    abi::emit(Scheduled::TOPIC, Scheduled(at));
+                }
+            }
+        };
+
+        let mut visitor = EmitVisitor::new();
+        visitor.visit_item_impl(&impl_block);
+
+        let types: Vec<_> = visitor
+            .events
+            .iter()
+            .map(|e| (e.topic.as_str(), e.data_type.to_string()))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                (
+                    "events::PauseToggled::PAUSED",
+                    "events :: PauseToggled".to_string()
+                ),
+                (
+                    "events::PauseToggled::UNPAUSED",
+                    "events :: PauseToggled".to_string()
+                ),
+                ("Scheduled::TOPIC", "Scheduled".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_emit_visitor_multiple_emits() {
         let impl_block: ItemImpl = syn::parse_quote! {
@@ -473,6 +811,48 @@ mod tests {
         assert_eq!(visitor.events.len(), 2);
     }
 
+    #[test]
+    fn test_emit_visitor_finds_emit_macro() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn set(&mut self, value: u64) {
+                    dusk_forge::emit!(events::CounterUpdated { previous: 0, new: value });
+                }
+                pub fn reset(&mut self) {
+                    emit!(events::CounterReset());
+                }
+            }
+        };
+
+        let mut visitor = EmitVisitor::new();
+        visitor.visit_item_impl(&impl_block);
+
+        assert_eq!(visitor.events.len(), 2);
+        assert_eq!(visitor.events[0].topic, "events::CounterUpdated::TOPIC");
+        assert_eq!(
+            normalize_tokens(&visitor.events[0].data_type.clone()),
+            "events :: CounterUpdated"
+        );
+        assert_eq!(visitor.events[1].topic, "events::CounterReset::TOPIC");
+    }
+
+    #[test]
+    fn test_emit_visitor_ignores_other_macros() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn check(&self) {
+                    assert!(self.ready);
+                    other::emit!(Event {});
+                }
+            }
+        };
+
+        let mut visitor = EmitVisitor::new();
+        visitor.visit_item_impl(&impl_block);
+
+        assert_eq!(visitor.events.len(), 0);
+    }
+
     // =========================================================================
     // dedup_events_by_topic tests
     //
@@ -556,7 +936,7 @@ mod tests {
             }
         };
 
-        let collected = inherent_method_emits(&impl_block);
+        let collected = inherent_method_emits(&impl_block, "new");
         assert_eq!(
             collected.len(),
             2,
@@ -648,7 +1028,7 @@ mod tests {
         );
         assert_eq!(events[0].topic, "shared");
         assert_eq!(
-            normalize_tokens(events[0].data_type.clone()),
+            normalize_tokens(&events[0].data_type.clone()),
             "FirstEvent",
             "first-seen data type wins; the colliding entry is dropped silently"
         );
@@ -719,8 +1099,146 @@ mod tests {
                 pub fn new() -> Self { Self }
             }
         };
-        let events = inherent_method_emits(&impl_block);
+        let events = inherent_method_emits(&impl_block, "new");
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].topic, "Resolved::TOPIC");
     }
+
+    #[test]
+    fn test_forward_macro_counts_as_emit_and_declares_events() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(forwards = [(Transferred::TOPIC, Transferred), ("minted", Minted)])]
+                pub fn relay(&mut self, raw: Vec<u8>) {
+                    dusk_forge::events::forward!(Transferred::TOPIC, raw);
+                }
+            }
+        };
+        let ImplItem::Fn(method) = &impl_block.items[0] else {
+            panic!("expected a method");
+        };
+
+        assert!(method_has_emit_call(method));
+        assert!(method_has_forward_call(method));
+
+        let topics: Vec<_> = inherent_method_emits(&impl_block, "new")
+            .into_iter()
+            .map(|e| e.topic)
+            .collect();
+        assert_eq!(topics, ["Transferred::TOPIC", "minted"]);
+    }
+
+    #[test]
+    fn test_batch_macros_count_as_emit() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(emits = [(WITHDRAWALS, dusk_forge::events::Batch<Withdrawn>)])]
+                pub fn withdraw_all(&mut self, amounts: Vec<u64>) {
+                    let mut batcher = dusk_forge::events::Batcher::new(WITHDRAWALS);
+                    for amount in amounts {
+                        events::batch!(batcher, Withdrawn { amount });
+                    }
+                    dusk_forge::events::flush!(batcher);
+                }
+            }
+        };
+        let ImplItem::Fn(method) = &impl_block.items[0] else {
+            panic!("expected a method");
+        };
+
+        assert!(method_has_emit_call(method));
+        assert!(method_has_batch_call(method));
+        assert!(!method_has_forward_call(method));
+
+        let events = inherent_method_emits(&impl_block, "new");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic, "WITHDRAWALS");
+    }
+
+    #[test]
+    fn test_init_events_collects_deployment_events() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(emits = [(Configured::TOPIC, Configured)])]
+                pub fn init(&mut self, owner: PublicKey) {
+                    self.owner = Some(owner);
+                    dusk_forge::emit!(Deployed { owner });
+                    helpers::configure(self);
+                }
+
+                pub fn transfer(&mut self, to: PublicKey) {
+                    abi::emit("transferred", to);
+                }
+            }
+        };
+
+        let topics: Vec<_> = init_events(&[&impl_block])
+            .into_iter()
+            .map(|e| e.topic)
+            .collect();
+        assert_eq!(topics, ["Deployed::TOPIC", "Configured::TOPIC"]);
+    }
+
+    #[test]
+    fn test_config_events_registers_config_changed() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn set_config(&mut self, key: String, value: u64) {
+                    abi::emit(ConfigChanged::TOPIC, self.config.set(&key, value));
+                    abi::emit("updated", Updated { key });
+                }
+            }
+        };
+        let state = |ty: TokenStream2| {
+            vec![StateField {
+                name: "config".to_string(),
+                ty,
+            }]
+        };
+
+        let events = config_events(
+            &state(quote! { dusk_forge::config::U64Config }),
+            emit_calls(&impl_block),
+        );
+        let events: Vec<_> = events
+            .iter()
+            .map(|e| (e.topic.as_str(), normalize_tokens(&e.data_type.clone())))
+            .collect();
+        assert_eq!(
+            events,
+            [
+                (
+                    "dusk_forge::config::ConfigChanged::TOPIC",
+                    "dusk_forge :: config :: ConfigChanged".to_string()
+                ),
+                ("updated", "Updated".to_string()),
+            ]
+        );
+
+        // Registered even if the contract does not emit it (yet)
+        let events = config_events(&state(quote! { U64Config }), Vec::new());
+        assert_eq!(events[0].topic, "dusk_forge::config::ConfigChanged::TOPIC");
+
+        // Left alone without a `U64Config` field
+        let events = config_events(&state(quote! { u64 }), emit_calls(&impl_block));
+        assert_eq!(events[0].topic, "ConfigChanged::TOPIC");
+    }
+
+    #[test]
+    fn test_past_events_registered_under_their_topic() {
+        let paths: Vec<syn::Path> = vec![
+            syn::parse_quote! { events::CounterUpdatedV1 },
+            syn::parse_quote! { CounterResetV1 },
+        ];
+
+        let events = past_events(&paths);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].topic, "events::CounterUpdatedV1::TOPIC");
+        assert_eq!(
+            normalize_tokens(&events[0].data_type.clone()),
+            "events :: CounterUpdatedV1"
+        );
+        assert_eq!(events[1].topic, "CounterResetV1::TOPIC");
+    }
 }
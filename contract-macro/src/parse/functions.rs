@@ -9,13 +9,18 @@
 
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
 use syn::{
-    Attribute, Expr, ExprLit, FnArg, ImplItem, ImplItemFn, ItemImpl, Lit, Pat, ReturnType, Type,
-    Visibility,
+    Attribute, Expr, ExprLit, FnArg, ImplItem, ImplItemFn, ItemImpl, Lit, Macro, Pat, ReturnType,
+    Signature, Token, Type, Visibility,
 };
 
-use crate::parse::{directives, events};
-use crate::{FunctionInfo, ParameterInfo, Receiver, TraitImplInfo, validate};
+use crate::parse::{directives, events, interface};
+use crate::{
+    Deprecation, EventInfo, ExternMethods, ExternTraitInfo, FeedFallback, FunctionInfo,
+    ParameterInfo, Receiver, TraitImplInfo, validate,
+};
 
 /// Check if a method body is empty (just `{}`).
 ///
@@ -25,9 +30,58 @@ pub(super) fn has_empty_body(method: &ImplItemFn) -> bool {
     method.block.stmts.is_empty()
 }
 
+/// Error codes of the `revert!` / `ensure!` invocations in a method body,
+/// in order of first use.
+///
+/// The macros only accept string-literal codes, so each code is known here
+/// and can be listed in the schema.
+pub(super) fn revert_codes(method: &ImplItemFn) -> Vec<String> {
+    struct Codes(Vec<String>);
+
+    impl<'ast> Visit<'ast> for Codes {
+        fn visit_macro(&mut self, mac: &'ast Macro) {
+            let segments: Vec<_> = mac
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            // Position of the code among the macro arguments
+            let position = match segments
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                ["revert"] | ["dusk_forge", "revert"] => 0,
+                ["ensure"] | ["dusk_forge", "ensure"] => 1,
+                _ => return,
+            };
+            let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            else {
+                return;
+            };
+            if let Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(code),
+                ..
+            })) = args.iter().nth(position)
+            {
+                let code = code.value();
+                if !self.0.contains(&code) {
+                    self.0.push(code);
+                }
+            }
+        }
+    }
+
+    let mut codes = Codes(Vec::new());
+    codes.visit_block(&method.block);
+    codes.0
+}
+
 /// Extract the receiver type from a method signature.
-pub(super) fn extract_receiver(method: &ImplItemFn) -> Receiver {
-    if let Some(FnArg::Receiver(receiver)) = method.sig.inputs.first() {
+pub(super) fn extract_receiver(sig: &Signature) -> Receiver {
+    if let Some(FnArg::Receiver(receiver)) = sig.inputs.first() {
         if receiver.mutability.is_some() {
             Receiver::RefMut
         } else {
@@ -68,8 +122,8 @@ pub(super) fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
 /// 1. There is at most one `abi::feed()` call site in the function
 /// 2. If `abi::feed()` is used, the `#[contract(feeds = "Type")]` attribute is
 ///    present
-/// 3. If present, the feeds type matches the fed expression (tuple vs
-///    non-tuple)
+/// 3. If present, the feeds type matches the fed expression: tuple vs
+///    non-tuple, and the type itself where the expression shows it
 ///
 /// Returns an error if validation fails.
 fn validate_feeds(
@@ -82,7 +136,10 @@ fn validate_feeds(
     // Check for multiple feed call sites
     if feed_exprs.len() > 1 {
         // Deduplicate to show unique expressions
-        let mut unique_exprs: Vec<_> = feed_exprs.clone();
+        let mut unique_exprs: Vec<_> = feed_exprs
+            .iter()
+            .map(|expr| quote!(#expr).to_string())
+            .collect();
         unique_exprs.sort();
         unique_exprs.dedup();
 
@@ -98,17 +155,17 @@ fn validate_feeds(
 
     if let Some(ft) = &feed_type {
         // Has feeds attribute - validate it matches the expressions
-        if let Some(mismatch_msg) = events::validate_feed_type_match(&ft.to_string(), &feed_exprs) {
+        if let Some(mismatch_msg) = events::validate_feed_type_match(ft, &feed_exprs) {
             return Err(syn::Error::new_spanned(&method.sig, mismatch_msg));
         }
-    } else if !feed_exprs.is_empty() {
+    } else if let Some(expr) = feed_exprs.first() {
         // Uses abi::feed() but missing feeds attribute
         return Err(syn::Error::new_spanned(
             &method.sig,
             format!(
                 "method `{name}` uses `abi::feed()` but is missing `#[contract(feeds = \"Type\")]` attribute; \
                  feeds: `{}`",
-                feed_exprs[0]
+                quote!(#expr)
             ),
         ));
     }
@@ -116,6 +173,163 @@ fn validate_feeds(
     Ok(())
 }
 
+/// Extract the entry type of a collection return type.
+///
+/// Recognizes `Vec<T>`, `VecDeque<T>` and `BTreeSet<T>` (entry `T`) and
+/// `BTreeMap<K, V>` (entry `(K, V)`), matching on the last path segment so
+/// both `Vec<T>` and `alloc::vec::Vec<T>` are accepted.
+///
+/// Returns `None` if the type is not one of the supported collections.
+fn collection_item_type(output_type: &TokenStream2) -> Option<TokenStream2> {
+    let Ok(Type::Path(type_path)) = syn::parse2::<Type>(output_type.clone()) else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let types: Vec<_> = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect();
+
+    match (segment.ident.to_string().as_str(), types.as_slice()) {
+        ("Vec" | "VecDeque" | "BTreeSet", [item]) => Some(quote! { #item }),
+        ("BTreeMap", [key, value]) => Some(quote! { (#key, #value) }),
+        _ => None,
+    }
+}
+
+/// Apply a `#[contract(feed_fallback)]` directive to an extracted function.
+///
+/// Marks `function` as the direct-return half of the pair and builds the
+/// `<name>_feed` variant, which takes the same input and feeds each entry of
+/// the returned collection via `abi::feed()`.
+///
+/// Returns `Ok(None)` if the method has no `feed_fallback` directive.
+fn feed_fallback_variant(
+    method: &ImplItemFn,
+    function: &mut FunctionInfo,
+) -> Result<Option<FunctionInfo>, syn::Error> {
+    let Some(fallback) = directives::feed_fallback(&method.attrs)? else {
+        return Ok(None);
+    };
+    let name = &function.name;
+
+    if function.feed_type.is_some() {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "method `{name}` cannot combine `feed_fallback` with `feeds`; \
+                 `feed_fallback` generates the streaming variant `{name}_feed` itself"
+            ),
+        ));
+    }
+
+    let Some(item_type) = collection_item_type(&function.output_type) else {
+        return Err(syn::Error::new_spanned(
+            &method.sig.output,
+            format!(
+                "method `{name}` uses `#[contract(feed_fallback)]` but returns `{}`; \
+                 return a `Vec`, `VecDeque`, `BTreeSet` or `BTreeMap` instead",
+                function.output_type
+            ),
+        ));
+    };
+
+    function.feed_fallback = Some(fallback);
+
+    Ok(Some(FunctionInfo {
+        name: format_ident!("{}_feed", name),
+        doc: Some(format!(
+            "Feeds the entries returned by `{name}` via `abi::feed()`."
+        )),
+        params: function.params.clone(),
+        input_type: function.input_type.clone(),
+        output_type: quote! { () },
+        returns_ref: function.returns_ref,
+        receiver: function.receiver,
+        trait_name: function.trait_name.clone(),
+        feed_type: Some(item_type),
+        feed_fallback: Some(FeedFallback::Stream {
+            source: name.clone(),
+        }),
+        reinit_guard: None,
+        cooldown: function.cooldown,
+        when_not_paused: function.when_not_paused,
+        errors: function.errors.clone(),
+        deprecated: function.deprecated.clone(),
+        internal: function.internal,
+        cfg: function.cfg.clone(),
+        alias_of: None,
+    }))
+}
+
+/// Build the alias export of a method with `#[contract(alias = "...")]`.
+///
+/// The alias takes the same input and returns the same output, forwarding
+/// to the method under its new name, and is deprecated in the schema with the
+/// method as its replacement.
+///
+/// Returns `Ok(None)` if the method has no `alias` directive, and an error if
+/// the alias repeats the method's name or the method also has
+/// `feed_fallback`.
+fn alias_variant(
+    method: &ImplItemFn,
+    function: &FunctionInfo,
+) -> Result<Option<FunctionInfo>, syn::Error> {
+    let Some(alias) = directives::alias(&method.attrs)? else {
+        return Ok(None);
+    };
+    let name = &function.name;
+
+    if alias == *name {
+        return Err(syn::Error::new(
+            alias.span(),
+            format!("method `{name}` cannot be its own alias"),
+        ));
+    }
+    if function.feed_fallback.is_some() {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "method `{name}` cannot combine `alias` with `feed_fallback`; \
+                 rename the collection method without an alias"
+            ),
+        ));
+    }
+
+    Ok(Some(FunctionInfo {
+        name: alias,
+        doc: Some(format!(
+            "Previous name of `{name}`, kept for older clients."
+        )),
+        params: function.params.clone(),
+        input_type: function.input_type.clone(),
+        output_type: function.output_type.clone(),
+        returns_ref: function.returns_ref,
+        receiver: function.receiver,
+        trait_name: function.trait_name.clone(),
+        feed_type: function.feed_type.clone(),
+        feed_fallback: None,
+        reinit_guard: function.reinit_guard,
+        cooldown: function.cooldown,
+        when_not_paused: function.when_not_paused,
+        errors: function.errors.clone(),
+        deprecated: Some(Deprecation {
+            message: format!("renamed to `{name}`"),
+            replacement: Some(name.to_string()),
+        }),
+        internal: function.internal,
+        cfg: function.cfg.clone(),
+        alias_of: Some(name.clone()),
+    }))
+}
+
 /// Extract methods from a trait impl block based on the expose list.
 ///
 /// Only methods whose names appear in the `expose_list` will be extracted.
@@ -142,7 +356,7 @@ pub(crate) fn trait_methods(trait_impl: &TraitImplInfo) -> Result<Vec<FunctionIn
             let name = method.sig.ident.clone();
             let doc = extract_doc_comment(&method.attrs);
             let feed_type = directives::extract_feeds_attribute(&method.attrs);
-            let receiver = extract_receiver(method);
+            let receiver = extract_receiver(&method.sig);
 
             // Check for method-level emits attribute
             let method_events = events::method_emits(&method.attrs);
@@ -162,13 +376,23 @@ pub(crate) fn trait_methods(trait_impl: &TraitImplInfo) -> Result<Vec<FunctionIn
             // (only check non-empty bodies since empty bodies delegate to trait defaults)
             if !is_default_impl {
                 validate_feeds(method, &name, feed_type.as_ref())?;
+                validate::forwarded_events(
+                    method,
+                    events::method_has_forward_call(method),
+                    directives::forwards_list(&method.attrs).is_some(),
+                )?;
+                validate::batched_events(
+                    method,
+                    events::method_has_batch_call(method),
+                    directives::emits_list(&method.attrs).is_some(),
+                )?;
             }
 
             // Validate that mutating methods emit events
             validate::method_emits_event(method, has_emit_call, suppressed, has_method_emits)?;
 
             // Extract parameters (name and type)
-            let params = parameters(method);
+            let params = parameters(&method.sig)?;
 
             // Extract input type (parameters after self)
             let input_type = input_type(&params);
@@ -183,7 +407,7 @@ pub(crate) fn trait_methods(trait_impl: &TraitImplInfo) -> Result<Vec<FunctionIn
                 None
             };
 
-            functions.push(FunctionInfo {
+            let mut function = FunctionInfo {
                 name,
                 doc,
                 params,
@@ -193,7 +417,22 @@ pub(crate) fn trait_methods(trait_impl: &TraitImplInfo) -> Result<Vec<FunctionIn
                 receiver,
                 trait_name,
                 feed_type,
-            });
+                feed_fallback: None,
+                reinit_guard: None,
+                cooldown: None,
+                when_not_paused: false,
+                errors: revert_codes(method),
+                deprecated: directives::deprecated(&method.attrs)?,
+                internal: directives::internal(&method.attrs),
+                cfg: directives::cfg_predicates(&method.attrs),
+                alias_of: None,
+            };
+            let feed_variant = feed_fallback_variant(method, &mut function)?;
+            let alias = alias_variant(method, &function)?;
+
+            functions.push(function);
+            functions.extend(feed_variant);
+            functions.extend(alias);
         }
     }
 
@@ -214,14 +453,88 @@ pub(crate) fn trait_methods(trait_impl: &TraitImplInfo) -> Result<Vec<FunctionIn
     Ok(functions)
 }
 
+/// Extract functions from a trait implemented for the contract outside the
+/// `#[contract]` module, exposed through
+/// `#[contract(expose_extern = "...", methods = ...)]`.
+///
+/// The declared signatures are handled like trait stubs with empty bodies:
+/// the wrappers call the method through the trait path, and since the
+/// implementation is not visible, mutating methods must declare their events
+/// with `#[contract(emits = [...])]` (or the interface file's `event` lines)
+/// or opt out with `#[contract(no_event)]`.
+///
+/// Returns the functions together with the events the declarations register.
+pub(crate) fn extern_trait_methods(
+    extern_trait: &ExternTraitInfo,
+) -> Result<(Vec<FunctionInfo>, Vec<EventInfo>), syn::Error> {
+    let trait_name = extern_trait.path.value();
+    let (stubs, mut declared_events) = match &extern_trait.methods {
+        ExternMethods::Declared(stubs) => (stubs.clone(), Vec::new()),
+        ExternMethods::Interface(path) => interface::stubs(path)?,
+    };
+    // Events listed in an interface file cover every method it declares
+    let has_interface_events = !declared_events.is_empty();
+
+    let mut functions = Vec::new();
+    for stub in &stubs {
+        validate::trait_method(stub, &trait_name, true)?;
+
+        let method_events = events::method_emits(&stub.attrs);
+        let has_method_emits = !method_events.is_empty();
+        validate::method_emits_event(
+            stub,
+            has_method_emits || has_interface_events,
+            directives::event_suppressed(&stub.attrs),
+            has_method_emits,
+        )?;
+        declared_events.extend(method_events);
+
+        let params = parameters(&stub.sig)?;
+        let input_type = input_type(&params);
+        let (output_type, returns_ref) = output_type(&stub.sig.output);
+
+        let mut function = FunctionInfo {
+            name: stub.sig.ident.clone(),
+            doc: extract_doc_comment(&stub.attrs),
+            params,
+            input_type,
+            output_type,
+            returns_ref,
+            receiver: extract_receiver(&stub.sig),
+            trait_name: Some(trait_name.clone()),
+            feed_type: directives::extract_feeds_attribute(&stub.attrs),
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
+        };
+        let feed_variant = feed_fallback_variant(stub, &mut function)?;
+        let alias = alias_variant(stub, &function)?;
+
+        functions.push(function);
+        functions.extend(feed_variant);
+        functions.extend(alias);
+    }
+
+    Ok((functions, declared_events))
+}
+
 /// Extract public methods from an impl block.
 ///
-/// Note: The `new` method is skipped because it's a special constructor
-/// used only for initializing the static STATE variable.
+/// Note: The `constructor` initializing the static STATE variable is
+/// skipped, and so is `new` (see [`validate::is_constructor`]).
 ///
 /// Returns an error if a method uses `abi::feed()` but lacks the
 /// `#[contract(feeds = "Type")]` attribute.
-pub(crate) fn public_methods(impl_block: &ItemImpl) -> Result<Vec<FunctionInfo>, syn::Error> {
+pub(crate) fn public_methods(
+    impl_block: &ItemImpl,
+    constructor: &str,
+) -> Result<Vec<FunctionInfo>, syn::Error> {
     let mut functions = Vec::new();
 
     for item in &impl_block.items {
@@ -231,27 +544,57 @@ pub(crate) fn public_methods(impl_block: &ItemImpl) -> Result<Vec<FunctionInfo>,
                 continue;
             }
 
-            // Skip the `new` constructor - it's not exported
-            if method.sig.ident == "new" {
+            // Skip the constructors - they're not exported
+            if validate::is_constructor(method, constructor) {
+                continue;
+            }
+
+            // Skip invariants - the wrappers call them instead
+            if directives::invariant(&method.attrs) {
                 continue;
             }
 
             let name = method.sig.ident.clone();
             let doc = extract_doc_comment(&method.attrs);
             let feed_type = directives::extract_feeds_attribute(&method.attrs);
-            let receiver = extract_receiver(method);
+            let receiver = extract_receiver(&method.sig);
             let has_emit_call = events::method_has_emit_call(method);
             let suppressed = directives::event_suppressed(&method.attrs);
             let has_method_emits = !events::method_emits(&method.attrs).is_empty();
+            let reinit_guard = directives::reinit_guard(&method.attrs)?;
+            let cooldown = directives::cooldown(&method.attrs)?;
+            let when_not_paused = directives::when_not_paused(&method.attrs);
+            let deprecated = directives::deprecated(&method.attrs)?;
+            let internal = directives::internal(&method.attrs);
+            let errors = revert_codes(method);
 
             // Validate feed-related attributes
             validate_feeds(method, &name, feed_type.as_ref())?;
 
+            // Validate that forwarded events are declared
+            validate::forwarded_events(
+                method,
+                events::method_has_forward_call(method),
+                directives::forwards_list(&method.attrs).is_some(),
+            )?;
+
+            // Validate that batched events are declared
+            validate::batched_events(
+                method,
+                events::method_has_batch_call(method),
+                directives::emits_list(&method.attrs).is_some(),
+            )?;
+
+            // Validate that only `init` carries the re-initialization guard
+            if reinit_guard.is_some() {
+                validate::reinit_guard(method)?;
+            }
+
             // Validate that mutating methods emit events
             validate::method_emits_event(method, has_emit_call, suppressed, has_method_emits)?;
 
             // Extract parameters (name and type)
-            let params = parameters(method);
+            let params = parameters(&method.sig)?;
 
             // Extract input type (parameters after self)
             let input_type = input_type(&params);
@@ -259,7 +602,7 @@ pub(crate) fn public_methods(impl_block: &ItemImpl) -> Result<Vec<FunctionInfo>,
             // Extract output type (dereferenced if it's a reference)
             let (output_type, returns_ref) = output_type(&method.sig.output);
 
-            functions.push(FunctionInfo {
+            let mut function = FunctionInfo {
                 name,
                 doc,
                 params,
@@ -269,21 +612,55 @@ pub(crate) fn public_methods(impl_block: &ItemImpl) -> Result<Vec<FunctionInfo>,
                 receiver,
                 trait_name: None, // Not a trait method
                 feed_type,
-            });
+                feed_fallback: None,
+                reinit_guard,
+                cooldown,
+                when_not_paused,
+                errors,
+                deprecated,
+                internal,
+                cfg: directives::cfg_predicates(&method.attrs),
+                alias_of: None,
+            };
+            let feed_variant = feed_fallback_variant(method, &mut function)?;
+            let alias = alias_variant(method, &function)?;
+
+            functions.push(function);
+            functions.extend(feed_variant);
+            functions.extend(alias);
         }
     }
 
     Ok(functions)
 }
 
-/// Extract parameter names and types from a method (excluding self).
+/// The `#[contract(invariant)]` methods of the contract's inherent impl
+/// blocks, which the wrappers of mutating exports call on the new state.
+///
+/// Returns an error if an invariant is not a `fn name(&self)`.
+pub(crate) fn invariants(impl_blocks: &[&ItemImpl]) -> Result<Vec<Ident>, syn::Error> {
+    let mut invariants = Vec::new();
+    for impl_block in impl_blocks {
+        for item in &impl_block.items {
+            if let ImplItem::Fn(method) = item
+                && directives::invariant(&method.attrs)
+            {
+                validate::invariant(method)?;
+                invariants.push(method.sig.ident.clone());
+            }
+        }
+    }
+    Ok(invariants)
+}
+
+/// Extract parameter names and types from a method signature (excluding
+/// self).
 ///
 /// For reference parameters (`&T` or `&mut T`), extracts the inner type
-/// and marks them accordingly for wrapper generation.
-fn parameters(method: &ImplItemFn) -> Vec<ParameterInfo> {
-    method
-        .sig
-        .inputs
+/// and marks them accordingly for wrapper generation. Returns an error if a
+/// parameter's `#[contract(...)]` attribute is invalid.
+pub(super) fn parameters(sig: &Signature) -> Result<Vec<ParameterInfo>, syn::Error> {
+    sig.inputs
         .iter()
         .filter_map(|arg| {
             if let FnArg::Typed(pat_type) = arg {
@@ -305,12 +682,18 @@ fn parameters(method: &ImplItemFn) -> Vec<ParameterInfo> {
                     (quote! { #t }, false, false)
                 };
 
-                Some(ParameterInfo {
+                let unit = match directives::unit(&pat_type.attrs) {
+                    Ok(unit) => unit,
+                    Err(e) => return Some(Err(e)),
+                };
+
+                Some(Ok(ParameterInfo {
                     name,
                     ty,
                     is_ref,
                     is_mut_ref,
-                })
+                    unit,
+                }))
             } else {
                 None // Skip self parameters
             }
@@ -319,7 +702,7 @@ fn parameters(method: &ImplItemFn) -> Vec<ParameterInfo> {
 }
 
 /// Build the input type from extracted parameters.
-fn input_type(params: &[ParameterInfo]) -> TokenStream2 {
+pub(super) fn input_type(params: &[ParameterInfo]) -> TokenStream2 {
     match params.len() {
         0 => quote! { () },
         1 => {
@@ -338,7 +721,7 @@ fn input_type(params: &[ParameterInfo]) -> TokenStream2 {
 ///
 /// If the return type is a reference (`&T` or `&mut T`), returns the inner type
 /// and `true`. Otherwise returns the type as-is and `false`.
-fn output_type(ret: &ReturnType) -> (TokenStream2, bool) {
+pub(super) fn output_type(ret: &ReturnType) -> (TokenStream2, bool) {
     match ret {
         ReturnType::Default => (quote! { () }, false),
         ReturnType::Type(_, ty) => {
@@ -356,14 +739,7 @@ fn output_type(ret: &ReturnType) -> (TokenStream2, bool) {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn normalize_tokens(tokens: TokenStream2) -> String {
-        tokens
-            .to_string()
-            .split_whitespace()
-            .collect::<Vec<_>>()
-            .join(" ")
-    }
+    use crate::test_utils::normalize_tokens;
 
     // ========================================================================
     // output_type tests
@@ -373,7 +749,7 @@ mod tests {
     fn test_output_type_value() {
         let ret: ReturnType = syn::parse_quote! { -> u64 };
         let (ty, returns_ref) = output_type(&ret);
-        assert_eq!(normalize_tokens(ty), "u64");
+        assert_eq!(normalize_tokens(&ty), "u64");
         assert!(!returns_ref);
     }
 
@@ -381,7 +757,7 @@ mod tests {
     fn test_output_type_ref() {
         let ret: ReturnType = syn::parse_quote! { -> &LargeStruct };
         let (ty, returns_ref) = output_type(&ret);
-        assert_eq!(normalize_tokens(ty), "LargeStruct");
+        assert_eq!(normalize_tokens(&ty), "LargeStruct");
         assert!(returns_ref);
     }
 
@@ -389,7 +765,7 @@ mod tests {
     fn test_output_type_mut_ref() {
         let ret: ReturnType = syn::parse_quote! { -> &mut Data };
         let (ty, returns_ref) = output_type(&ret);
-        assert_eq!(normalize_tokens(ty), "Data");
+        assert_eq!(normalize_tokens(&ty), "Data");
         assert!(returns_ref);
     }
 
@@ -402,10 +778,10 @@ mod tests {
         let method: ImplItemFn = syn::parse_quote! {
             pub fn process(&self, data: &LargeStruct) {}
         };
-        let params = parameters(&method);
+        let params = parameters(&method.sig).unwrap();
         assert_eq!(params.len(), 1);
         assert_eq!(params[0].name.to_string(), "data");
-        assert_eq!(normalize_tokens(params[0].ty.clone()), "LargeStruct");
+        assert_eq!(normalize_tokens(&params[0].ty.clone()), "LargeStruct");
         assert!(params[0].is_ref);
         assert!(!params[0].is_mut_ref);
     }
@@ -415,18 +791,59 @@ mod tests {
         let method: ImplItemFn = syn::parse_quote! {
             pub fn modify(&mut self, data: &mut Data) {}
         };
-        let params = parameters(&method);
+        let params = parameters(&method.sig).unwrap();
         assert_eq!(params.len(), 1);
         assert_eq!(params[0].name.to_string(), "data");
-        assert_eq!(normalize_tokens(params[0].ty.clone()), "Data");
+        assert_eq!(normalize_tokens(&params[0].ty.clone()), "Data");
         assert!(params[0].is_ref);
         assert!(params[0].is_mut_ref);
     }
 
+    #[test]
+    fn test_parameters_unit() {
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn lock(&mut self, #[contract(unit = "lux")] amount: u64, period: u64) {}
+        };
+        let params = parameters(&method.sig).unwrap();
+        assert_eq!(params[0].unit.as_deref(), Some("lux"));
+        assert_eq!(params[1].unit, None);
+
+        for method in [
+            syn::parse_quote! { pub fn lock(&mut self, #[contract(unit = "gwei")] amount: u64) {} },
+            syn::parse_quote! { pub fn lock(&mut self, #[contract(feeds = "u64")] amount: u64) {} },
+        ] {
+            let method: ImplItemFn = method;
+            let Err(err) = parameters(&method.sig) else {
+                panic!("expected error for an invalid parameter option");
+            };
+            assert!(
+                err.to_string().contains("unknown unit `gwei`")
+                    || err.to_string().contains("expected `unit"),
+                "{err}"
+            );
+        }
+    }
+
     // ========================================================================
     // trait_methods / public_methods tests
     // ========================================================================
 
+    #[test]
+    fn test_revert_codes() {
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn transfer_ownership(&mut self, new_owner: PublicKey) {
+                dusk_forge::ensure!(self.is_owner(), "NOT_OWNER", "caller not owner");
+                if new_owner == self.owner {
+                    revert!("SAME_OWNER", "{} already owns the contract", new_owner);
+                }
+                ensure!(self.is_owner(), "NOT_OWNER", "caller not owner");
+                assert!(self.active, "inactive");
+            }
+        };
+
+        assert_eq!(revert_codes(&method), ["NOT_OWNER", "SAME_OWNER"]);
+    }
+
     #[test]
     fn test_trait_methods_success() {
         let impl_block: ItemImpl = syn::parse_quote! {
@@ -483,7 +900,7 @@ mod tests {
                 }
             }
         };
-        let functions = match public_methods(&impl_block) {
+        let functions = match public_methods(&impl_block, "new") {
             Ok(functions) => functions,
             Err(err) => panic!("expected success, got: {err}"),
         };
@@ -491,6 +908,68 @@ mod tests {
         assert_eq!(functions[0].name.to_string(), "resolve");
     }
 
+    #[test]
+    fn test_invariants_are_not_exported() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn total(&self) -> u64 { self.total }
+
+                #[contract(invariant)]
+                pub fn check_total(&self) {
+                    assert!(self.total <= self.cap, "total exceeds cap");
+                }
+            }
+        };
+        let functions = public_methods(&impl_block, "new").expect("methods parse");
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name.to_string(), "total");
+
+        let found = invariants(&[&impl_block]).expect("invariants parse");
+        assert_eq!(found, ["check_total"]);
+
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(invariant)]
+                fn check_total(&mut self) {}
+            }
+        };
+        assert!(invariants(&[&impl_block]).is_err());
+    }
+
+    #[test]
+    fn test_alias_export() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(alias = "get_total")]
+                pub fn total(&self, round: u64) -> u64 { self.total }
+            }
+        };
+        let functions = public_methods(&impl_block, "new").expect("methods parse");
+        assert_eq!(functions.len(), 2);
+
+        let alias = &functions[1];
+        assert_eq!(alias.name.to_string(), "get_total");
+        assert_eq!(alias.alias_of.as_ref().unwrap().to_string(), "total");
+        assert_eq!(
+            alias.input_type.to_string(),
+            functions[0].input_type.to_string()
+        );
+        let deprecation = alias.deprecated.as_ref().unwrap();
+        assert_eq!(deprecation.message, "renamed to `total`");
+        assert_eq!(deprecation.replacement.as_deref(), Some("total"));
+
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(alias = "total")]
+                pub fn total(&self) -> u64 { self.total }
+            }
+        };
+        let Err(err) = public_methods(&impl_block, "new") else {
+            panic!("expected error for a method aliased to itself");
+        };
+        assert!(err.to_string().contains("cannot be its own alias"), "{err}");
+    }
+
     #[test]
     fn test_public_methods_delegating_without_emits_errors() {
         // Same shape without the `emits` attribute must still fail the strict
@@ -502,7 +981,7 @@ mod tests {
                 }
             }
         };
-        let Err(err) = public_methods(&impl_block) else {
+        let Err(err) = public_methods(&impl_block, "new") else {
             panic!("expected error for delegating method without emits");
         };
         assert!(err.to_string().contains("emits no events"));
@@ -529,6 +1008,71 @@ mod tests {
         assert!(err.to_string().contains("not found"));
     }
 
+    // ========================================================================
+    // extern_trait_methods tests
+    // ========================================================================
+
+    fn extern_trait(methods: Vec<ImplItemFn>) -> ExternTraitInfo {
+        ExternTraitInfo {
+            path: syn::parse_quote!("ownable::Ownable"),
+            methods: ExternMethods::Declared(methods),
+        }
+    }
+
+    #[test]
+    fn test_extern_trait_methods_success() {
+        let extern_trait = extern_trait(vec![
+            syn::parse_quote! {
+                /// Returns the owner.
+                fn owner(&self) -> &Owner {}
+            },
+            syn::parse_quote! {
+                #[contract(emits = [(events::OwnershipTransferred::TOPIC, events::OwnershipTransferred)])]
+                fn transfer_ownership(&mut self, new_owner: Owner) {}
+            },
+        ]);
+
+        let (functions, events) = extern_trait_methods(&extern_trait).unwrap();
+
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[0].name.to_string(), "owner");
+        assert_eq!(functions[0].doc.as_deref(), Some("Returns the owner."));
+        assert!(functions[0].returns_ref);
+        assert_eq!(functions[0].trait_name.as_deref(), Some("ownable::Ownable"));
+        assert_eq!(functions[1].input_type.to_string(), "Owner");
+        assert!(functions[1].receiver == Receiver::RefMut);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic, "events::OwnershipTransferred::TOPIC");
+    }
+
+    #[test]
+    fn test_extern_trait_methods_require_events() {
+        let extern_trait = extern_trait(vec![syn::parse_quote! {
+            fn transfer_ownership(&mut self, new_owner: Owner) {}
+        }]);
+
+        let Err(err) = extern_trait_methods(&extern_trait) else {
+            panic!("expected error for mutating method without events");
+        };
+        assert!(err.to_string().contains("emits no events"));
+    }
+
+    #[test]
+    fn test_extern_trait_methods_validate_signatures() {
+        let extern_trait = extern_trait(vec![syn::parse_quote! {
+            fn owner<T>(&self) -> T {}
+        }]);
+
+        let Err(err) = extern_trait_methods(&extern_trait) else {
+            panic!("expected error for generic method");
+        };
+        assert!(
+            err.to_string().contains("ownable::Ownable::owner"),
+            "unexpected error: {err}"
+        );
+    }
+
     // ========================================================================
     // Feed validation tests
     // ========================================================================
@@ -763,6 +1307,163 @@ mod tests {
         assert!(msg.contains("tuple"), "error should mention 'tuple': {msg}");
     }
 
+    #[test]
+    fn test_validate_feeds_inferred_type_mismatch() {
+        // The constructor shows the fed type, which is not the declared one
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn stream_items(&self) {
+                for (id, value) in &self.items {
+                    abi::feed((*id, Item { value: *value }));
+                }
+            }
+        };
+        let name = format_ident!("stream_items");
+        let feed_type: TokenStream2 = quote! { (ItemId, types::Entry) };
+        let result = validate_feeds(&method, &name, Some(&feed_type));
+
+        let Err(err) = result else {
+            panic!("expected error for mismatched fed type");
+        };
+        let msg = err.to_string();
+        assert!(msg.contains("is a `(_ , Item)`"), "{msg}");
+        assert!(msg.contains("wrong type"), "{msg}");
+
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn stream_count(&self) {
+                abi::feed(self.count as u32);
+            }
+        };
+        let feed_type: TokenStream2 = quote! { u64 };
+        assert!(validate_feeds(&method, &name, Some(&feed_type)).is_err());
+    }
+
+    #[test]
+    fn test_validate_feeds_inferred_type_agrees() {
+        let name = format_ident!("stream_items");
+        for (method, feed_type) in [
+            (
+                syn::parse_quote! {
+                    pub fn stream_items(&self) {
+                        abi::feed((ItemId(1), Item { value: 2 }));
+                    }
+                },
+                quote! { (types::ItemId, types::Item) },
+            ),
+            // The declared type may alias the primitive
+            (
+                syn::parse_quote! {
+                    pub fn stream_items(&self) {
+                        abi::feed(1u64);
+                    }
+                },
+                quote! { ItemId },
+            ),
+            // Enum variants feed the enum
+            (
+                syn::parse_quote! {
+                    pub fn stream_items(&self) {
+                        abi::feed(Status::Active(1));
+                    }
+                },
+                quote! { Status },
+            ),
+            // Calls that are not constructors are not inferred
+            (
+                syn::parse_quote! {
+                    pub fn stream_items(&self) {
+                        abi::feed(Some(self.item()));
+                    }
+                },
+                quote! { Option<Item> },
+            ),
+        ] {
+            let method: ImplItemFn = method;
+            let result = validate_feeds(&method, &name, Some(&feed_type));
+            assert!(result.is_ok(), "{feed_type}: {:?}", result.err());
+        }
+    }
+
+    // ========================================================================
+    // feed_fallback tests
+    // ========================================================================
+
+    #[test]
+    fn test_collection_item_type_vec() {
+        let item = collection_item_type(&quote! { Vec<ItemId> });
+        assert_eq!(normalize_tokens(&item.unwrap()), "ItemId");
+    }
+
+    #[test]
+    fn test_collection_item_type_btree_map() {
+        let item = collection_item_type(&quote! { alloc::collections::BTreeMap<ItemId, Item> });
+        assert_eq!(normalize_tokens(&item.unwrap()), "(ItemId , Item)");
+    }
+
+    #[test]
+    fn test_collection_item_type_not_collection() {
+        assert!(collection_item_type(&quote! { Option<Item> }).is_none());
+        assert!(collection_item_type(&quote! { u64 }).is_none());
+    }
+
+    #[test]
+    fn test_public_methods_feed_fallback_adds_feed_variant() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(feed_fallback = 16)]
+                pub fn items(&self, owner: Address) -> BTreeMap<ItemId, Item> {
+                    self.items.clone()
+                }
+            }
+        };
+        let functions = match public_methods(&impl_block, "new") {
+            Ok(functions) => functions,
+            Err(err) => panic!("expected success, got: {err}"),
+        };
+        assert_eq!(functions.len(), 2);
+
+        let direct = &functions[0];
+        assert_eq!(direct.name.to_string(), "items");
+        assert!(direct.feed_type.is_none());
+        assert!(matches!(
+            direct.feed_fallback,
+            Some(FeedFallback::Direct { limit: Some(16) })
+        ));
+
+        let stream = &functions[1];
+        assert_eq!(stream.name.to_string(), "items_feed");
+        assert_eq!(normalize_tokens(&stream.input_type.clone()), "Address");
+        assert_eq!(normalize_tokens(&stream.output_type.clone()), "()");
+        assert_eq!(
+            normalize_tokens(&stream.feed_type.clone().unwrap()),
+            "(ItemId , Item)"
+        );
+        let Some(FeedFallback::Stream { source }) = &stream.feed_fallback else {
+            panic!("expected stream variant");
+        };
+        assert_eq!(source.to_string(), "items");
+    }
+
+    #[test]
+    fn test_public_methods_feed_fallback_requires_collection() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(feed_fallback)]
+                pub fn counter(&self) -> u64 {
+                    self.counter
+                }
+            }
+        };
+        let Err(err) = public_methods(&impl_block, "new") else {
+            panic!("expected error for non-collection return type");
+        };
+        let msg = err.to_string();
+        assert!(
+            msg.contains("feed_fallback"),
+            "error should mention directive: {msg}"
+        );
+        assert!(msg.contains("u64"), "error should show return type: {msg}");
+    }
+
     // ========================================================================
     // extract_doc_comment tests
     // ========================================================================
@@ -10,6 +10,41 @@ use syn::{ItemUse, UseTree};
 
 use crate::{ImportExtraction, ImportInfo};
 
+/// Names exported by `dusk_forge::prelude` that the module can refer to.
+///
+/// Kept in sync with `src/prelude.rs` in the `dusk-forge` crate. Only
+/// capitalized names are listed; modules, functions and macros need no
+/// import entry.
+pub(super) const FORGE_PRELUDE: &[&str] = &[
+    "BTreeMap",
+    "BTreeSet",
+    "Box",
+    "CallError",
+    "ContractId",
+    "PublicKey",
+    "String",
+    "ToString",
+    "Vec",
+    "VecDeque",
+];
+
+/// Glob imports whose contents the macro knows, by module path.
+///
+/// `#[contract(prelude = "...", names = [...])]` declares further ones.
+pub(super) const KNOWN_PRELUDES: &[(&str, &[&str])] = &[
+    ("dusk_forge::prelude", FORGE_PRELUDE),
+    (
+        "alloc::collections",
+        &[
+            "BTreeMap",
+            "BTreeSet",
+            "BinaryHeap",
+            "LinkedList",
+            "VecDeque",
+        ],
+    ),
+];
+
 /// Check if an identifier is a relative path keyword.
 fn is_relative_path_keyword(ident: &str) -> bool {
     matches!(ident, "self" | "super" | "crate")
@@ -20,6 +55,29 @@ pub(super) fn imports_from_use(item_use: &ItemUse) -> ImportExtraction {
     extract_imports_from_tree(&item_use.tree, "")
 }
 
+/// The module path of a plain glob import, `a::b` for `use a::b::*;`.
+///
+/// Returns `None` for imports without a glob, and for globs nested in a group
+/// (`use a::{b::*, C};`).
+pub(super) fn glob_path(item_use: &ItemUse) -> Option<String> {
+    let mut segments = Vec::new();
+    let mut tree = &item_use.tree;
+    while let UseTree::Path(path) = tree {
+        segments.push(path.ident.to_string());
+        tree = &path.tree;
+    }
+    matches!(tree, UseTree::Glob(_)).then(|| segments.join("::"))
+}
+
+/// Render a path as written in a `use` statement, without spaces.
+pub(super) fn path_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
 /// Recursively extract imports from a use tree.
 fn extract_imports_from_tree(tree: &UseTree, prefix: &str) -> ImportExtraction {
     match tree {
@@ -223,4 +281,16 @@ mod tests {
         assert!(!extraction.has_glob);
         assert!(extraction.has_relative);
     }
+
+    #[test]
+    fn test_glob_path() {
+        let use_stmt: ItemUse = syn::parse_quote! { use dusk_forge::prelude::*; };
+        assert_eq!(glob_path(&use_stmt).as_deref(), Some("dusk_forge::prelude"));
+
+        let use_stmt: ItemUse = syn::parse_quote! { use types::{events::*, Item}; };
+        assert_eq!(glob_path(&use_stmt), None);
+
+        let use_stmt: ItemUse = syn::parse_quote! { use types::Item; };
+        assert_eq!(glob_path(&use_stmt), None);
+    }
 }
@@ -0,0 +1,303 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Interface-file parsing: reading the `.forge-abi` file named by
+//! `#[contract(implements = "...")]` into `InterfaceInfo` IR, by
+//! `#[contract(expose_extern = "...", methods = "...")]` into method stubs,
+//! or by `#[contract(calls = "...")]` into `CalleeInfo`.
+//!
+//! An interface file declares function signatures and events, without
+//! bodies:
+//!
+//! ```text
+//! /// Returns the balance of an account.
+//! fn balance_of(&self, account: PublicKey) -> u64;
+//! fn transfer(&mut self, to: PublicKey, value: u64);
+//!
+//! event "transfer": events::Transfer;
+//! event events::Approval::TOPIC: events::Approval;
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Ident, ImplItemFn, LitStr, Signature, Token, Type};
+
+use crate::parse::functions;
+use crate::{CalleeInfo, EventInfo, InterfaceFunction, InterfaceInfo};
+
+/// Read and parse the interface file at `path`, relative to the crate root.
+///
+/// Returns an error spanned to `path` if the file cannot be read or is not a
+/// valid interface.
+pub(crate) fn interface(path: &LitStr) -> Result<InterfaceInfo, syn::Error> {
+    read(path)?.info()
+}
+
+/// Read the interface file at `path` as method stubs with empty bodies, along
+/// with its declared events.
+///
+/// Doc comments on the declared functions are kept on the stubs.
+pub(crate) fn stubs(path: &LitStr) -> Result<(Vec<ImplItemFn>, Vec<EventInfo>), syn::Error> {
+    let file = read(path)?;
+    let stubs = file
+        .functions
+        .into_iter()
+        .map(|(attrs, sig)| syn::parse_quote! { #(#attrs)* #sig {} })
+        .collect();
+    Ok((stubs, file.events))
+}
+
+/// Read the interfaces of the contracts called through `dusk_forge::call!`,
+/// naming the ID newtype of each after its file.
+///
+/// Returns `None` if no interface is given.
+pub(crate) fn callees(paths: &[LitStr]) -> Result<Option<CalleeInfo>, syn::Error> {
+    if paths.is_empty() {
+        return Ok(None);
+    }
+
+    let mut interfaces: Vec<(String, Ident)> = Vec::new();
+    let mut functions = BTreeMap::new();
+    for path in paths {
+        let id = id_type(path)?;
+        if let Some((other, _)) = interfaces.iter().find(|(_, other)| *other == id) {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "`{}` and `{other}` both name the contract ID type `{id}`",
+                    path.value()
+                ),
+            ));
+        }
+
+        for (_, sig) in read(path)?.functions {
+            let (output_type, _) = functions::output_type(&sig.output);
+            let input_type = functions::input_type(&functions::parameters(&sig)?);
+            functions
+                .entry(sig.ident.to_string())
+                .or_insert((input_type, output_type, id.clone()));
+        }
+        interfaces.push((path.value(), id));
+    }
+
+    Ok(Some(CalleeInfo {
+        interfaces,
+        functions,
+    }))
+}
+
+/// The ID newtype of the interface at `path`: its file name up to the first
+/// `.` in `PascalCase`, followed by `Id` (`token_bridge.forge-abi` names
+/// `TokenBridgeId`).
+fn id_type(path: &LitStr) -> Result<Ident, syn::Error> {
+    let value = path.value();
+    let stem = Path::new(&value)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split('.').next())
+        .unwrap_or_default();
+    let name: String = stem
+        .split(['_', '-'])
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars))
+                .into_iter()
+                .flatten()
+        })
+        .collect();
+
+    syn::parse_str::<Ident>(&format!("{name}Id")).map_err(|_| {
+        syn::Error::new(
+            path.span(),
+            format!("cannot name a contract ID type after the interface `{value}`"),
+        )
+    })
+}
+
+fn read(path: &LitStr) -> Result<InterfaceFile, syn::Error> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let file = Path::new(&manifest_dir).join(path.value());
+
+    let source = std::fs::read_to_string(&file).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read interface `{}`: {e}", file.display()),
+        )
+    })?;
+
+    syn::parse_str::<InterfaceFile>(&source).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("invalid interface `{}`: {e}", path.value()),
+        )
+    })
+}
+
+/// Parsed contents of a `.forge-abi` file.
+struct InterfaceFile {
+    /// The declared functions, with their doc comments.
+    functions: Vec<(Vec<Attribute>, Signature)>,
+    /// The declared events.
+    events: Vec<EventInfo>,
+}
+
+impl InterfaceFile {
+    fn info(self) -> Result<InterfaceInfo, syn::Error> {
+        let functions = self
+            .functions
+            .iter()
+            .map(|(_, sig)| {
+                let (output_type, _) = functions::output_type(&sig.output);
+                Ok(InterfaceFunction {
+                    name: sig.ident.clone(),
+                    receiver: functions::extract_receiver(sig),
+                    params: functions::parameters(sig)?,
+                    output_type,
+                })
+            })
+            .collect::<Result<_, syn::Error>>()?;
+
+        Ok(InterfaceInfo {
+            functions,
+            events: self.events,
+        })
+    }
+}
+
+impl Parse for InterfaceFile {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut functions = Vec::new();
+        let mut events = Vec::new();
+
+        while !input.is_empty() {
+            // Doc comments are allowed for readability but not compared
+            let attrs = input.call(Attribute::parse_outer)?;
+
+            if input.peek(Token![fn]) {
+                // fn name(&self, param: Type, ...) -> Output;
+                let sig: Signature = input.parse()?;
+                input.parse::<Token![;]>()?;
+                functions.push((attrs, sig));
+            } else {
+                // event <topic>: Type;
+                let keyword = input.call(syn::Ident::parse_any)?;
+                if keyword != "event" {
+                    return Err(syn::Error::new(
+                        keyword.span(),
+                        format!("expected `fn` or `event`, found `{keyword}`"),
+                    ));
+                }
+
+                let topic = if input.peek(LitStr) {
+                    input.parse::<LitStr>()?.value()
+                } else {
+                    let path: syn::Path = input.parse()?;
+                    path.segments
+                        .iter()
+                        .map(|s| s.ident.to_string())
+                        .collect::<Vec<_>>()
+                        .join("::")
+                };
+                input.parse::<Token![:]>()?;
+                let data: Type = input.parse()?;
+                input.parse::<Token![;]>()?;
+
+                events.push(EventInfo {
+                    topic,
+                    data_type: quote! { #data },
+                });
+            }
+        }
+
+        Ok(Self { functions, events })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+    use crate::Receiver;
+
+    fn parse(source: &str) -> syn::Result<InterfaceInfo> {
+        syn::parse_str::<InterfaceFile>(source)?.info()
+    }
+
+    #[test]
+    fn test_interface_functions_and_events() {
+        let interface = parse(
+            r#"
+            /// Returns the balance of an account.
+            fn balance_of(&self, account: PublicKey) -> u64;
+            fn transfer(&mut self, to: PublicKey, value: u64);
+
+            event "transfer": events::Transfer;
+            event events::Approval::TOPIC: events::Approval;
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(interface.functions.len(), 2);
+        let balance_of = &interface.functions[0];
+        assert_eq!(balance_of.name.to_string(), "balance_of");
+        assert!(balance_of.receiver == Receiver::Ref);
+        assert_eq!(balance_of.params.len(), 1);
+        assert_eq!(balance_of.output_type.to_string(), "u64");
+
+        let transfer = &interface.functions[1];
+        assert!(transfer.receiver == Receiver::RefMut);
+        assert_eq!(transfer.params.len(), 2);
+        assert_eq!(transfer.output_type.to_string(), "()");
+
+        assert_eq!(interface.events.len(), 2);
+        assert_eq!(interface.events[0].topic, "transfer");
+        assert_eq!(interface.events[1].topic, "events::Approval::TOPIC");
+        assert_eq!(
+            interface.events[1].data_type.to_string(),
+            "events :: Approval"
+        );
+    }
+
+    #[test]
+    fn test_id_type() {
+        let id =
+            |path: &str| id_type(&LitStr::new(path, Span::call_site())).map(|id| id.to_string());
+
+        assert_eq!(id("bridge.forge-abi").unwrap(), "BridgeId");
+        assert_eq!(id("abi/token_bridge.forge-abi").unwrap(), "TokenBridgeId");
+        assert_eq!(id("price-oracle.v2.forge-abi").unwrap(), "PriceOracleId");
+        assert!(id("abi/2fa.forge-abi").is_err());
+    }
+
+    #[test]
+    fn test_interface_rejects_bodies() {
+        let Err(err) = parse("fn owner(&self) -> u64 { 0 }") else {
+            panic!("expected error for function body");
+        };
+        assert!(
+            err.to_string().contains("expected `;`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_interface_rejects_unknown_item() {
+        let Err(err) = parse("struct Token;") else {
+            panic!("expected error for unknown item");
+        };
+        assert!(
+            err.to_string().contains("expected `fn` or `event`"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -15,6 +15,7 @@
 //! - [`events`]       `abi::emit()` / `abi::feed()` discovery ->
 //!   [`crate::EventInfo`]
 //! - [`directives`]   `#[contract(...)]` directive parsers
+//! - [`interface`]    `.forge-abi` interface files -> [`crate::InterfaceInfo`]
 //!
 //! The [`contract_data`] orchestrator below is the entry point used by
 //! `lib.rs`.
@@ -23,27 +24,45 @@ mod directives;
 mod events;
 mod functions;
 mod imports;
+mod interface;
 mod module;
 
 pub(crate) use events::{
-    dedup_events_by_topic, emit_calls, inherent_method_emits, trait_method_emits,
+    config_events, dedup_events_by_topic, emit_calls, inherent_method_emits, init_events,
+    past_events, trait_method_emits,
 };
-pub(crate) use functions::{public_methods, trait_methods};
-use syn::{Item, ItemMod};
+pub(crate) use functions::{extern_trait_methods, invariants, public_methods, trait_methods};
+pub(crate) use interface::{callees, interface};
+pub(crate) use module::options as contract_options;
+use quote::quote;
+use syn::{Field, Fields, ImplItem, Item, ItemImpl, ItemMod, ItemStruct};
 
-use crate::{ContractData, validate};
+use crate::{ContractData, PreludeInfo, StateField, validate};
 
 /// Extract contract data from the module, validating constraints.
+/// `constructor` names the `const fn` initializing `STATE`.
 ///
 /// Returns an error if validation fails.
 pub(crate) fn contract_data<'a>(
     module: &'a ItemMod,
     items: &'a [Item],
+    preludes: &[PreludeInfo],
+    constructor: &str,
 ) -> Result<ContractData<'a>, syn::Error> {
-    let imports = module::imports(items)?;
+    let imports = module::imports(items, preludes)?;
     let struct_ = module::contract_struct(module, items)?;
     let name = struct_.ident.to_string();
 
+    if !struct_.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &struct_.generics,
+            format!(
+                "`{name}` cannot have generic or lifetime parameters; \
+                 the contract state is a single `static STATE` of a concrete type"
+            ),
+        ));
+    }
+
     let impl_blocks = module::impl_blocks(items, &name);
     if impl_blocks.is_empty() {
         return Err(syn::Error::new_spanned(
@@ -53,23 +72,117 @@ pub(crate) fn contract_data<'a>(
     }
 
     for impl_block in &impl_blocks {
-        validate::impl_block_methods(impl_block)?;
+        validate::impl_generics(impl_block)?;
+        validate::impl_block_methods(impl_block, constructor)?;
     }
 
-    validate::new_constructor(&name, &impl_blocks, struct_)?;
+    validate::new_constructor(&name, constructor, &impl_blocks, struct_)?;
     validate::init_method(&name, &impl_blocks)?;
 
     let trait_impls = module::trait_impls(items, &name);
+    for trait_impl in &trait_impls {
+        validate::impl_generics(trait_impl.impl_block)?;
+    }
+
+    let getters = getter_fields(struct_, &impl_blocks)?;
 
     Ok(ContractData {
         imports,
         contract_name: name,
         contract_ident: struct_.ident.clone(),
+        state: state_fields(&struct_.fields),
         impl_blocks,
         trait_impls,
+        getters,
     })
 }
 
+/// The fields `#[contract(getters ...)]` on the contract struct generates
+/// getters for.
+///
+/// Returns an error for tuple structs, for listed fields the struct does not
+/// have, and for fields whose getter would clash with a method of the
+/// contract.
+fn getter_fields<'a>(
+    struct_: &'a ItemStruct,
+    impl_blocks: &[&ItemImpl],
+) -> Result<Vec<&'a Field>, syn::Error> {
+    let Some(selection) = directives::getters(&struct_.attrs)? else {
+        return Ok(Vec::new());
+    };
+    if !matches!(struct_.fields, Fields::Named(_)) {
+        return Err(syn::Error::new_spanned(
+            &struct_.ident,
+            "`#[contract(getters)]` needs named fields to name the getters after",
+        ));
+    }
+
+    let field = |ident: &syn::Ident| {
+        struct_
+            .fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(ident))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ident,
+                    format!("`{}` has no field `{ident}`", struct_.ident),
+                )
+            })
+    };
+    for ident in &selection.exclude {
+        field(ident)?;
+    }
+    let selected = match &selection.fields {
+        Some(idents) => idents.iter().map(field).collect::<Result<Vec<_>, _>>()?,
+        None => struct_.fields.iter().collect(),
+    };
+
+    let mut getters = Vec::new();
+    for field in selected {
+        let Some(ident) = &field.ident else { continue };
+        if selection.exclude.contains(ident) {
+            continue;
+        }
+        let method =
+            impl_blocks
+                .iter()
+                .flat_map(|block| &block.items)
+                .find_map(|item| match item {
+                    ImplItem::Fn(method) if method.sig.ident == *ident => Some(method),
+                    _ => None,
+                });
+        if let Some(method) = method {
+            return Err(syn::Error::new_spanned(
+                &method.sig.ident,
+                format!(
+                    "`{ident}` clashes with the getter `#[contract(getters)]` generates \
+                     for the field `{ident}`; add it to `exclude = [...]` to keep this method"
+                ),
+            ));
+        }
+        getters.push(field);
+    }
+    Ok(getters)
+}
+
+/// The fields of the contract struct, named by their index in tuple structs.
+fn state_fields(fields: &Fields) -> Vec<StateField> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let ty = &field.ty;
+            StateField {
+                name: field
+                    .ident
+                    .as_ref()
+                    .map_or_else(|| index.to_string(), ToString::to_string),
+                ty: quote! { #ty },
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,7 +198,7 @@ mod tests {
         };
         let items = module.content.as_ref().unwrap().1.clone();
 
-        let result = contract_data(&module, &items);
+        let result = contract_data(&module, &items, &[], "new");
         let Err(err) = result else {
             panic!("expected error for missing impl block");
         };
@@ -116,7 +229,7 @@ mod tests {
         };
         let items = module.content.as_ref().unwrap().1.clone();
 
-        let result = contract_data(&module, &items);
+        let result = contract_data(&module, &items, &[], "new");
         let Err(err) = result else {
             panic!("expected error for impl on wrong type");
         };
@@ -127,6 +240,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_contract_data_generic_impl_rejected() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                pub struct MyContract {
+                    value: u64,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { Self { value: 0 } }
+                }
+                impl<'a> MyContract {
+                    pub fn value(&'a self) -> u64 { self.value }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for generic impl block");
+        };
+        assert!(
+            err.to_string()
+                .contains("impl blocks cannot have generic or lifetime parameters"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_contract_data_generic_struct_rejected() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                pub struct MyContract<'a> {
+                    value: &'a u64,
+                }
+                impl<'a> MyContract<'a> {
+                    pub fn value(&self) -> u64 { *self.value }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for generic contract struct");
+        };
+        assert!(
+            err.to_string()
+                .contains("`MyContract` cannot have generic or lifetime parameters"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_contract_data_where_clause_accepted() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                pub struct MyContract {
+                    value: u64,
+                }
+                impl MyContract where Self: Sized {
+                    pub const fn new() -> Self { Self { value: 0 } }
+                    pub fn value(&self) -> u64 { self.value }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let data =
+            contract_data(&module, &items, &[], "new").expect("where clause should be accepted");
+        assert_eq!(data.impl_blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_contract_data_state_fields() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                pub struct MyContract(u64, Option<PublicKey>);
+                impl MyContract {
+                    pub const fn new() -> Self { Self(0, None) }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let data = contract_data(&module, &items, &[], "new").expect("tuple structs are accepted");
+        let state: Vec<_> = data
+            .state
+            .iter()
+            .map(|f| (f.name.as_str(), f.ty.to_string()))
+            .collect();
+        assert_eq!(
+            state,
+            [
+                ("0", "u64".to_string()),
+                ("1", "Option < PublicKey >".to_string())
+            ]
+        );
+    }
+
     #[test]
     fn test_contract_data_glob_import_rejected() {
         let module: ItemMod = syn::parse_quote! {
@@ -142,7 +353,7 @@ mod tests {
         };
         let items = module.content.as_ref().unwrap().1.clone();
 
-        let result = contract_data(&module, &items);
+        let result = contract_data(&module, &items, &[], "new");
         let Err(err) = result else {
             panic!("expected error for glob import");
         };
@@ -153,6 +364,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_contract_data_glob_import_suggestion() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                use dusk_core::abi;
+                use types::*;
+                pub struct MyContract {
+                    owner: Option<Address>,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { Self { owner: None } }
+                    pub fn deposit(&mut self, deposit: Deposit) {
+                        abi::emit(DepositEvent::TOPIC, DepositEvent::from(deposit));
+                    }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for glob import");
+        };
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains(
+            "\n= help: import the names the module uses explicitly: `{Address, Deposit, DepositEvent}`"
+        ));
+    }
+
+    #[test]
+    fn test_contract_data_forge_prelude_import() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                use dusk_forge::prelude::*;
+                use types::Item;
+                pub struct MyContract {
+                    owner: Option<PublicKey>,
+                    items: Vec<Item>,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { Self { owner: None, items: Vec::new() } }
+                    pub fn owner(&self) -> Option<PublicKey> { self.owner }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let data =
+            contract_data(&module, &items, &[], "new").expect("the forge prelude is accepted");
+        let imports: Vec<_> = data
+            .imports
+            .iter()
+            .map(|i| (i.name.as_str(), i.path.as_str()))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("Item", "types::Item"),
+                ("PublicKey", "dusk_forge::prelude::PublicKey"),
+                ("Vec", "dusk_forge::prelude::Vec"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contract_data_declared_prelude_import() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                use alloc::collections::*;
+                use types::prelude::*;
+                pub struct MyContract {
+                    items: BTreeMap<ItemId, Item>,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { Self { items: BTreeMap::new() } }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+        let preludes = [PreludeInfo {
+            path: "types::prelude".to_string(),
+            names: vec!["Item".to_string(), "ItemId".to_string()],
+        }];
+
+        let data = contract_data(&module, &items, &preludes, "new").expect("both globs are known");
+        let imports: Vec<_> = data
+            .imports
+            .iter()
+            .map(|i| (i.name.as_str(), i.path.as_str()))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("BTreeMap", "alloc::collections::BTreeMap"),
+                ("Item", "types::prelude::Item"),
+                ("ItemId", "types::prelude::ItemId"),
+            ]
+        );
+
+        // Without the declaration, the prelude is an unknown glob
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for an undeclared prelude");
+        };
+        assert!(err.to_string().contains("glob import"), "{err}");
+    }
+
     #[test]
     fn test_contract_data_relative_import_rejected() {
         let module: ItemMod = syn::parse_quote! {
@@ -168,7 +485,7 @@ mod tests {
         };
         let items = module.content.as_ref().unwrap().1.clone();
 
-        let result = contract_data(&module, &items);
+        let result = contract_data(&module, &items, &[], "new");
         let Err(err) = result else {
             panic!("expected error for relative import");
         };
@@ -178,4 +495,67 @@ mod tests {
             "error should mention 'relative import': {msg}"
         );
     }
+
+    #[test]
+    fn test_contract_data_getters() {
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                #[contract(getters, exclude = [pending])]
+                pub struct MyContract {
+                    owner: PublicKey,
+                    finalization_period: u64,
+                    pending: Vec<u64>,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { todo!() }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let data = contract_data(&module, &items, &[], "new").expect("getters are valid");
+        let getters: Vec<_> = data
+            .getters
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(getters, ["owner", "finalization_period"]);
+
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                #[contract(getters = [owner])]
+                pub struct MyContract {
+                    owner: PublicKey,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { todo!() }
+                    pub fn owner(&self) -> PublicKey { self.owner }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for a getter clashing with a method");
+        };
+        assert!(err.to_string().contains("exclude"), "{err}");
+
+        let module: ItemMod = syn::parse_quote! {
+            mod my_contract {
+                #[contract(getters = [owner])]
+                pub struct MyContract {
+                    admin: PublicKey,
+                }
+                impl MyContract {
+                    pub const fn new() -> Self { todo!() }
+                }
+            }
+        };
+        let items = module.content.as_ref().unwrap().1.clone();
+
+        let Err(err) = contract_data(&module, &items, &[], "new") else {
+            panic!("expected error for an unknown field");
+        };
+        assert!(err.to_string().contains("no field `owner`"), "{err}");
+    }
 }
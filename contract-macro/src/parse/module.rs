@@ -4,29 +4,253 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-//! Module-shape parsing: walking the user `mod { ... }` body to extract
-//! imports, the contract struct, inherent impl blocks, and trait impl blocks
-//! that carry a `#[contract(expose = [...])]` attribute.
+//! Module-shape parsing: reading the options on `#[contract(...)]` and
+//! walking the user `mod { ... }` body to extract imports, the contract
+//! struct, inherent impl blocks, and trait impl blocks that carry a
+//! `#[contract(expose = [...])]` attribute.
 
-use syn::{Item, ItemImpl, ItemMod, Type, Visibility};
+use std::collections::BTreeSet;
 
+use proc_macro2::TokenStream as TokenStream2;
+use syn::parse::{ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Ident, Item, ItemImpl, ItemMod, LitStr, Signature, Token, Type, Visibility};
+
+use crate::diagnostic::Diagnostic;
 use crate::parse::{directives, imports as imports_parse};
-use crate::{ImportInfo, TraitImplInfo};
+use crate::{
+    ContractOptions, ExternMethods, ExternTraitInfo, ImportInfo, PreludeInfo, TraitImplInfo,
+};
+
+/// Generated items `#[contract(reexport = [...])]` may name.
+const REEXPORTABLE: [&str; 2] = ["STATE", "CONTRACT_SCHEMA"];
+
+/// Parse the options given to the module-level `#[contract(...)]` attribute.
+///
+/// Returns an error for unknown options, and for `expose_extern` entries
+/// without a `methods` list or with a trait path that does not parse, and
+/// for `reexport` without `generated` or naming an item it cannot re-export.
+pub(crate) fn options(attr: TokenStream2) -> Result<ContractOptions, syn::Error> {
+    let mut options = ContractOptions::default();
+    // `expose_extern = "..."` entries, each completed by the `methods` that follows
+    let mut expose_extern: Vec<(LitStr, Option<ExternMethods>)> = Vec::new();
+    // `prelude = "..."` entries, each completed by the `names` that follows
+    let mut preludes: Vec<(LitStr, Option<Vec<String>>)> = Vec::new();
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("compact_dispatch") {
+            options.compact_dispatch = true;
+            Ok(())
+        } else if meta.path.is_ident("metering") {
+            options.metering = true;
+            Ok(())
+        } else if meta.path.is_ident("metadata") {
+            options.metadata = true;
+            Ok(())
+        } else if meta.path.is_ident("storage_stats") {
+            options.storage_stats = true;
+            Ok(())
+        } else if meta.path.is_ident("deny_unchecked_math") {
+            options.deny_unchecked_math = true;
+            Ok(())
+        } else if meta.path.is_ident("implements") {
+            options.implements = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("calls") {
+            options.calls.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("expose_extern") {
+            let path: LitStr = meta.value()?.parse()?;
+            path.parse::<syn::Path>().map_err(|_| {
+                syn::Error::new(path.span(), "`expose_extern` expects a trait path")
+            })?;
+            expose_extern.push((path, None));
+            Ok(())
+        } else if meta.path.is_ident("methods") {
+            let Some((_, methods @ None)) = expose_extern.last_mut() else {
+                return Err(meta.error("`methods` must follow an `expose_extern = \"...\"` option"));
+            };
+            *methods = Some(extern_methods(meta.value()?)?);
+            Ok(())
+        } else if meta.path.is_ident("prelude") {
+            let path: LitStr = meta.value()?.parse()?;
+            path.parse::<syn::Path>()
+                .map_err(|_| syn::Error::new(path.span(), "`prelude` expects a module path"))?;
+            preludes.push((path, None));
+            Ok(())
+        } else if meta.path.is_ident("names") {
+            let Some((_, names @ None)) = preludes.last_mut() else {
+                return Err(meta.error("`names` must follow a `prelude = \"...\"` option"));
+            };
+            let value = meta.value()?;
+            let content;
+            syn::bracketed!(content in value);
+            let idents = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            *names = Some(idents.iter().map(ToString::to_string).collect());
+            Ok(())
+        } else if meta.path.is_ident("constructor") {
+            let name: LitStr = meta.value()?.parse()?;
+            options.constructor = Some(name.parse::<Ident>().map_err(|_| {
+                syn::Error::new(name.span(), "`constructor` expects a method name")
+            })?);
+            Ok(())
+        } else if meta.path.is_ident("generated") {
+            let name: LitStr = meta.value()?.parse()?;
+            options.generated =
+                Some(name.parse::<Ident>().map_err(|_| {
+                    syn::Error::new(name.span(), "`generated` expects a module name")
+                })?);
+            Ok(())
+        } else if meta.path.is_ident("reexport") {
+            options.reexports.extend(reexports(meta.value()?)?);
+            Ok(())
+        } else if meta.path.is_ident("past_events") {
+            let value = meta.value()?;
+            let content;
+            syn::bracketed!(content in value);
+            let paths = Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?;
+            options.past_events.extend(paths);
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unknown #[contract] option; expected `compact_dispatch`, `metering`, \
+                 `metadata`, `storage_stats`, `deny_unchecked_math`, `implements = \"...\"`, \
+                 `calls = \"...\"`, \
+                 `expose_extern = \"...\", methods = ...`, `prelude = \"...\", names = [...]`, \
+                 `constructor = \"...\"`, `generated = \"...\"`, `reexport = [...]` or \
+                 `past_events = [...]`",
+            ))
+        }
+    });
+    parser.parse2(attr)?;
+
+    complete(options, expose_extern, preludes)
+}
+
+/// Check the parsed options and attach each `methods` and `names` list to the
+/// `expose_extern` and `prelude` entry it follows.
+fn complete(
+    mut options: ContractOptions,
+    expose_extern: Vec<(LitStr, Option<ExternMethods>)>,
+    preludes: Vec<(LitStr, Option<Vec<String>>)>,
+) -> Result<ContractOptions, syn::Error> {
+    if options.generated.is_none()
+        && let Some(name) = options.reexports.first()
+    {
+        return Err(syn::Error::new(
+            name.span(),
+            "`reexport` needs a `generated = \"...\"` module to re-export from",
+        ));
+    }
+
+    for (path, methods) in expose_extern {
+        let Some(methods) = methods else {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "`expose_extern = \"{}\"` needs a `methods = [...]` list of signatures \
+                     or `methods = \"<file>.forge-abi\"`",
+                    path.value()
+                ),
+            ));
+        };
+        options
+            .expose_extern
+            .push(ExternTraitInfo { path, methods });
+    }
+
+    for (path, names) in preludes {
+        let Some(names) = names else {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "`prelude = \"{}\"` needs a `names = [...]` list of the names it exports",
+                    path.value()
+                ),
+            ));
+        };
+        let path = path.parse::<syn::Path>()?;
+        options.preludes.push(PreludeInfo {
+            path: imports_parse::path_string(&path),
+            names,
+        });
+    }
+
+    Ok(options)
+}
+
+/// Parse the value of `reexport`: a bracketed list of generated items.
+fn reexports(input: ParseStream) -> Result<Vec<Ident>, syn::Error> {
+    let content;
+    syn::bracketed!(content in input);
+
+    let names = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+    for name in &names {
+        if !REEXPORTABLE.iter().any(|item| name == item) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!(
+                    "`{name}` cannot be re-exported; expected {}",
+                    REEXPORTABLE.map(|item| format!("`{item}`")).join(" or ")
+                ),
+            ));
+        }
+    }
+    Ok(names.into_iter().collect())
+}
+
+/// Parse the value of `methods`: either an interface file path or a bracketed,
+/// comma-separated list of signatures, each optionally preceded by doc
+/// comments and `#[contract(...)]` attributes.
+fn extern_methods(input: ParseStream) -> Result<ExternMethods, syn::Error> {
+    if input.peek(LitStr) {
+        return Ok(ExternMethods::Interface(input.parse()?));
+    }
+
+    let content;
+    syn::bracketed!(content in input);
+
+    let mut stubs = Vec::new();
+    while !content.is_empty() {
+        let attrs = content.call(Attribute::parse_outer)?;
+        let sig: Signature = content.parse()?;
+        stubs.push(syn::parse_quote! { #(#attrs)* #sig {} });
+
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+
+    Ok(ExternMethods::Declared(stubs))
+}
 
 /// Extract and validate imports from the module items.
 ///
 /// Returns an error if glob or relative imports are found.
-pub(super) fn imports(items: &[Item]) -> Result<Vec<ImportInfo>, syn::Error> {
+pub(super) fn imports(
+    items: &[Item],
+    preludes: &[PreludeInfo],
+) -> Result<Vec<ImportInfo>, syn::Error> {
     let mut result = Vec::new();
-    let mut glob_import = None;
+    let mut glob_imports = Vec::new();
     let mut relative_import = None;
+    // Names exported by the known preludes the module glob-imports
+    let mut prelude_names: Vec<(String, &str)> = Vec::new();
 
     for item in items {
         if let Item::Use(item_use) = item {
+            if let Some(path) = imports_parse::glob_path(item_use)
+                && let Some(names) = known_prelude(&path, preludes)
+            {
+                prelude_names.extend(names.into_iter().map(|name| (path.clone(), name)));
+                continue;
+            }
             let extraction = imports_parse::imports_from_use(item_use);
             result.extend(extraction.imports);
-            if extraction.has_glob && glob_import.is_none() {
-                glob_import = Some(item_use);
+            if extraction.has_glob {
+                glob_imports.push(item_use);
             }
             if extraction.has_relative && relative_import.is_none() {
                 relative_import = Some(item_use);
@@ -34,12 +258,39 @@ pub(super) fn imports(items: &[Item]) -> Result<Vec<ImportInfo>, syn::Error> {
         }
     }
 
-    if let Some(item_use) = glob_import {
-        return Err(syn::Error::new_spanned(
+    // The contents of known preludes are listed: import the names used from
+    // them, from the first prelude exporting each
+    let used = unresolved_names(items, &result);
+    for name in used {
+        if let Some((path, _)) = prelude_names.iter().find(|(_, export)| *export == name) {
+            result.push(ImportInfo {
+                path: format!("{path}::{name}"),
+                name,
+            });
+        }
+    }
+
+    if let Some(item_use) = glob_imports.first() {
+        let mut diagnostic = Diagnostic::spanned(
             item_use,
-            "#[contract] does not support glob imports (`use foo::*`); \
+            "#[contract] does not support glob imports (`use foo::*`) of unknown modules; \
              import types explicitly so their paths can be tracked",
-        ));
+        );
+        let names = glob_candidates(items, &result);
+        // With several globs there is no telling which one provides a name
+        diagnostic = if glob_imports.len() == 1 && !names.is_empty() {
+            diagnostic.suggestion(
+                "import the names the module uses explicitly",
+                format!("{{{}}}", names.into_iter().collect::<Vec<_>>().join(", ")),
+            )
+        } else {
+            diagnostic.help("list the imported names instead of `*`")
+        };
+        diagnostic = diagnostic.note(
+            "globs of `dusk_forge::prelude` and `alloc::collections` are accepted, \
+             and `#[contract(prelude = \"...\", names = [...])]` declares the contents of others",
+        );
+        return Err(diagnostic.into());
     }
 
     if let Some(item_use) = relative_import {
@@ -53,6 +304,118 @@ pub(super) fn imports(items: &[Item]) -> Result<Vec<ImportInfo>, syn::Error> {
     Ok(result)
 }
 
+/// The names exported by the prelude at `path`, if the macro knows it, either
+/// built in or declared with `#[contract(prelude = "...", names = [...])]`.
+fn known_prelude<'a>(path: &str, preludes: &'a [PreludeInfo]) -> Option<Vec<&'a str>> {
+    if let Some(prelude) = preludes.iter().find(|prelude| prelude.path == path) {
+        return Some(prelude.names.iter().map(String::as_str).collect());
+    }
+    imports_parse::KNOWN_PRELUDES
+        .iter()
+        .find(|(known, _)| *known == path)
+        .map(|(_, names)| names.to_vec())
+}
+
+/// Capitalized names the module refers to without defining or explicitly
+/// importing them, i.e. the names a glob import may be providing.
+fn glob_candidates(items: &[Item], imports: &[ImportInfo]) -> BTreeSet<String> {
+    /// Capitalized names from the prelude, which need no import.
+    const PRELUDE: &[&str] = &[
+        "Self",
+        "Option",
+        "Some",
+        "None",
+        "Result",
+        "Ok",
+        "Err",
+        "Vec",
+        "String",
+        "Box",
+        "Clone",
+        "Copy",
+        "Default",
+        "Debug",
+        "PartialEq",
+        "Eq",
+        "PartialOrd",
+        "Ord",
+        "Hash",
+        "From",
+        "Into",
+        "AsRef",
+        "AsMut",
+        "Iterator",
+        "IntoIterator",
+        "Extend",
+        "ToString",
+        "ToOwned",
+        "Drop",
+        "Fn",
+        "FnMut",
+        "FnOnce",
+        "Send",
+        "Sync",
+        "Sized",
+    ];
+
+    unresolved_names(items, imports)
+        .into_iter()
+        .filter(|name| !PRELUDE.contains(&name.as_str()))
+        .collect()
+}
+
+/// Capitalized names the module refers to without defining them or
+/// importing them explicitly.
+fn unresolved_names(items: &[Item], imports: &[ImportInfo]) -> BTreeSet<String> {
+    #[derive(Default)]
+    struct Names {
+        used: BTreeSet<String>,
+        defined: BTreeSet<String>,
+    }
+
+    impl<'ast> Visit<'ast> for Names {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if path.leading_colon.is_none()
+                && let Some(first) = path.segments.first()
+                && first.ident.to_string().starts_with(char::is_uppercase)
+            {
+                self.used.insert(first.ident.to_string());
+            }
+            visit::visit_path(self, path);
+        }
+
+        fn visit_type_param(&mut self, param: &'ast syn::TypeParam) {
+            self.defined.insert(param.ident.to_string());
+            visit::visit_type_param(self, param);
+        }
+    }
+
+    let mut names = Names::default();
+    for item in items {
+        let defined = match item {
+            Item::Use(_) => continue,
+            Item::Struct(item) => Some(&item.ident),
+            Item::Enum(item) => Some(&item.ident),
+            Item::Union(item) => Some(&item.ident),
+            Item::Type(item) => Some(&item.ident),
+            Item::Trait(item) => Some(&item.ident),
+            Item::Const(item) => Some(&item.ident),
+            Item::Static(item) => Some(&item.ident),
+            _ => None,
+        };
+        names.defined.extend(defined.map(ToString::to_string));
+        names.visit_item(item);
+    }
+
+    names
+        .used
+        .into_iter()
+        .filter(|name| {
+            !names.defined.contains(name) && !imports.iter().any(|import| &import.name == name)
+        })
+        .collect()
+}
+
 /// Find the contract struct in the module.
 ///
 /// The module must contain exactly one `pub struct` which serves as the
@@ -93,6 +456,23 @@ pub(super) fn contract_struct<'a>(
     Ok(pub_structs[0])
 }
 
+/// Whether `ty` names the contract struct.
+///
+/// Generic arguments are ignored, so `impl<'a> ContractName<'a>` is still
+/// found and can be rejected with a dedicated error rather than silently
+/// dropped.
+fn is_contract_type(ty: &Type, contract_name: &str) -> bool {
+    if let Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+        && type_path.path.leading_colon.is_none()
+        && let [segment] = type_path.path.segments.iter().collect::<Vec<_>>()[..]
+    {
+        segment.ident == contract_name
+    } else {
+        false
+    }
+}
+
 /// Find inherent impl blocks for the contract struct.
 ///
 /// Returns all `impl ContractName { ... }` blocks (without a trait).
@@ -102,8 +482,7 @@ pub(super) fn impl_blocks<'a>(items: &'a [Item], contract_name: &str) -> Vec<&'a
         .filter_map(|item| {
             if let Item::Impl(impl_block) = item
                 && impl_block.trait_.is_none()
-                && let Type::Path(type_path) = &*impl_block.self_ty
-                && type_path.path.is_ident(contract_name)
+                && is_contract_type(&impl_block.self_ty, contract_name)
             {
                 Some(impl_block)
             } else {
@@ -124,8 +503,7 @@ pub(super) fn trait_impls<'a>(items: &'a [Item], contract_name: &str) -> Vec<Tra
         .filter_map(|item| {
             if let Item::Impl(impl_block) = item
                 && let Some((_, trait_path, _)) = &impl_block.trait_
-                && let Type::Path(type_path) = &*impl_block.self_ty
-                && type_path.path.is_ident(contract_name)
+                && is_contract_type(&impl_block.self_ty, contract_name)
                 && let Some(list) = directives::expose_list(&impl_block.attrs)
             {
                 let trait_name = trait_path
@@ -264,6 +642,34 @@ mod tests {
         assert_eq!(blocks.len(), 1, "should only find inherent impl block");
     }
 
+    #[test]
+    fn test_impl_blocks_finds_generic_and_where_clause() {
+        let items: Vec<Item> = vec![
+            syn::parse_quote! {
+                impl<'a> MyContract<'a> {
+                    pub fn method_a(&self) -> u64 { 0 }
+                }
+            },
+            syn::parse_quote! {
+                impl MyContract where Self: Sized {
+                    pub fn method_b(&self) -> u64 { 1 }
+                }
+            },
+            syn::parse_quote! {
+                impl other::MyContract {
+                    pub fn method_c(&self) -> u64 { 2 }
+                }
+            },
+        ];
+
+        let blocks = impl_blocks(&items, "MyContract");
+        assert_eq!(
+            blocks.len(),
+            2,
+            "should find generic and where-clause blocks"
+        );
+    }
+
     #[test]
     fn test_trait_impls_finds_with_expose() {
         let items: Vec<Item> = vec![syn::parse_quote! {
@@ -315,4 +721,220 @@ mod tests {
         let trait_impls = trait_impls(&items, "MyContract");
         assert_eq!(trait_impls.len(), 2);
     }
+
+    #[test]
+    fn test_options_default() {
+        let options = options(TokenStream2::new()).unwrap();
+        assert!(!options.compact_dispatch);
+    }
+
+    #[test]
+    fn test_options_compact_dispatch() {
+        let options = options(quote::quote! { compact_dispatch }).unwrap();
+        assert!(options.compact_dispatch);
+    }
+
+    #[test]
+    fn test_options_metering() {
+        let options = options(quote::quote! { metering }).unwrap();
+        assert!(options.metering);
+        assert!(!options.compact_dispatch);
+    }
+
+    #[test]
+    fn test_options_metadata() {
+        let options = options(quote::quote! { metadata }).unwrap();
+        assert!(options.metadata);
+        assert!(!options.metering);
+    }
+
+    #[test]
+    fn test_options_storage_stats() {
+        let options = options(quote::quote! { storage_stats }).unwrap();
+        assert!(options.storage_stats);
+        assert!(!options.metadata);
+    }
+
+    #[test]
+    fn test_options_deny_unchecked_math() {
+        let options = options(quote::quote! { metering, deny_unchecked_math }).unwrap();
+        assert!(options.deny_unchecked_math);
+        assert!(options.metering);
+    }
+
+    #[test]
+    fn test_options_implements() {
+        let options =
+            options(quote::quote! { compact_dispatch, implements = "token.forge-abi" }).unwrap();
+        assert!(options.compact_dispatch);
+        assert_eq!(options.implements.unwrap().value(), "token.forge-abi");
+    }
+
+    #[test]
+    fn test_options_constructor() {
+        let renamed = options(quote::quote! { constructor = "empty" }).unwrap();
+        assert_eq!(renamed.constructor.unwrap(), "empty");
+
+        let Err(err) = options(quote::quote! { constructor = "Self::empty" }) else {
+            panic!("expected error for a constructor path");
+        };
+        assert!(err.to_string().contains("expects a method name"));
+    }
+
+    #[test]
+    fn test_options_generated() {
+        let generated =
+            options(quote::quote! { generated = "forge", reexport = [CONTRACT_SCHEMA, STATE] })
+                .unwrap();
+        assert_eq!(generated.generated.unwrap(), "forge");
+        let reexports: Vec<_> = generated
+            .reexports
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(reexports, ["CONTRACT_SCHEMA", "STATE"]);
+
+        let Err(err) = options(quote::quote! { generated = "forge::items" }) else {
+            panic!("expected error for a module path");
+        };
+        assert!(err.to_string().contains("expects a module name"));
+    }
+
+    #[test]
+    fn test_options_reexport_invalid() {
+        let Err(err) = options(quote::quote! { generated = "forge", reexport = [INIT_CALLS] })
+        else {
+            panic!("expected error for an item that cannot be re-exported");
+        };
+        assert!(
+            err.to_string()
+                .contains("`INIT_CALLS` cannot be re-exported")
+        );
+
+        let Err(err) = options(quote::quote! { reexport = [STATE] }) else {
+            panic!("expected error for `reexport` without `generated`");
+        };
+        assert!(err.to_string().contains("needs a `generated"));
+    }
+
+    #[test]
+    fn test_options_unknown() {
+        let Err(err) = options(quote::quote! { compact }) else {
+            panic!("expected error for unknown option");
+        };
+        let msg = err.to_string();
+        assert!(
+            msg.contains("unknown #[contract] option"),
+            "error should mention the unknown option: {msg}"
+        );
+    }
+
+    #[test]
+    fn test_options_calls() {
+        let options =
+            options(quote::quote! { calls = "token.forge-abi", calls = "oracle.forge-abi" })
+                .unwrap();
+        let calls: Vec<_> = options.calls.iter().map(LitStr::value).collect();
+        assert_eq!(calls, ["token.forge-abi", "oracle.forge-abi"]);
+    }
+
+    #[test]
+    fn test_options_expose_extern_declared() {
+        let options = options(quote::quote! {
+            expose_extern = "ownable::Ownable",
+            methods = [
+                /// Returns the owner.
+                fn owner(&self) -> Owner,
+                #[contract(emits = [(events::OwnershipTransferred::TOPIC, events::OwnershipTransferred)])]
+                fn transfer_ownership(&mut self, new_owner: Owner),
+            ]
+        })
+        .unwrap();
+
+        assert_eq!(options.expose_extern.len(), 1);
+        let extern_trait = &options.expose_extern[0];
+        assert_eq!(extern_trait.path.value(), "ownable::Ownable");
+        let ExternMethods::Declared(stubs) = &extern_trait.methods else {
+            panic!("expected declared methods");
+        };
+        assert_eq!(stubs.len(), 2);
+        assert_eq!(stubs[0].sig.ident.to_string(), "owner");
+        assert_eq!(stubs[0].attrs.len(), 1);
+        assert!(stubs[0].block.stmts.is_empty());
+        assert_eq!(stubs[1].sig.ident.to_string(), "transfer_ownership");
+    }
+
+    #[test]
+    fn test_options_prelude() {
+        let parsed = options(quote::quote! {
+            prelude = "types::prelude", names = [Item, ItemId, events],
+        })
+        .unwrap();
+
+        assert_eq!(parsed.preludes.len(), 1);
+        assert_eq!(parsed.preludes[0].path, "types::prelude");
+        assert_eq!(parsed.preludes[0].names, ["Item", "ItemId", "events"]);
+
+        let Err(err) = options(quote::quote! { prelude = "types::prelude" }) else {
+            panic!("expected error for a prelude without names");
+        };
+        assert!(
+            err.to_string().contains("needs a `names = [...]` list"),
+            "{err}"
+        );
+
+        let Err(err) = options(quote::quote! { names = [Item] }) else {
+            panic!("expected error for names without a prelude");
+        };
+        assert!(err.to_string().contains("must follow a `prelude"), "{err}");
+    }
+
+    #[test]
+    fn test_options_expose_extern_interface() {
+        let options = options(quote::quote! {
+            expose_extern = "ownable::Ownable", methods = "ownable.forge-abi",
+            expose_extern = "pausable::Pausable", methods = [fn paused(&self) -> bool],
+        })
+        .unwrap();
+
+        assert_eq!(options.expose_extern.len(), 2);
+        let ExternMethods::Interface(path) = &options.expose_extern[0].methods else {
+            panic!("expected interface methods");
+        };
+        assert_eq!(path.value(), "ownable.forge-abi");
+        assert_eq!(options.expose_extern[1].path.value(), "pausable::Pausable");
+    }
+
+    #[test]
+    fn test_options_expose_extern_without_methods() {
+        let Err(err) = options(quote::quote! { expose_extern = "ownable::Ownable" }) else {
+            panic!("expected error for missing methods");
+        };
+        assert!(
+            err.to_string().contains("needs a `methods = [...]`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_options_methods_without_expose_extern() {
+        let Err(err) = options(quote::quote! { methods = [fn owner(&self) -> Owner] }) else {
+            panic!("expected error for methods without expose_extern");
+        };
+        assert!(
+            err.to_string().contains("must follow an `expose_extern"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_options_expose_extern_invalid_path() {
+        let Err(err) = options(quote::quote! { expose_extern = "not a path", methods = [] }) else {
+            panic!("expected error for invalid trait path");
+        };
+        assert!(
+            err.to_string().contains("expects a trait path"),
+            "unexpected error: {err}"
+        );
+    }
 }
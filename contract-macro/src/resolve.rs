@@ -201,6 +201,13 @@ pub(crate) fn build_type_map(
             let feed_resolved = resolve_type(feed_type, &import_map);
             type_map.insert(feed_key, feed_resolved);
         }
+
+        // Resolve parameter types, looked up for their format hints
+        for param in &func.params {
+            let param_key = param.ty.to_string();
+            let param_resolved = resolve_type(&param.ty, &import_map);
+            type_map.insert(param_key, param_resolved);
+        }
     }
 
     // Resolve event data types and topic paths
@@ -402,6 +409,15 @@ mod tests {
             receiver: crate::Receiver::Ref,
             trait_name: None,
             feed_type: None,
+            feed_fallback: None,
+            reinit_guard: None,
+            cooldown: None,
+            when_not_paused: false,
+            errors: Vec::new(),
+            deprecated: None,
+            internal: false,
+            cfg: Vec::new(),
+            alias_of: None,
         };
 
         let type_map = build_type_map(&imports, std::slice::from_ref(&func), &[]);
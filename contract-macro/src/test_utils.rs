@@ -0,0 +1,19 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Helpers shared by the expansion tests.
+
+use proc_macro2::TokenStream as TokenStream2;
+
+/// Normalize token stream to a string with consistent whitespace for
+/// comparison.
+pub(crate) fn normalize_tokens(tokens: &TokenStream2) -> String {
+    tokens
+        .to_string()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -6,7 +6,17 @@
 
 //! Validation functions for contract macro.
 
-use syn::{FnArg, ImplItem, ImplItemFn, ItemImpl, ReturnType, Type, Visibility};
+use std::collections::BTreeSet;
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use syn::visit::Visit;
+use syn::{
+    BinOp, Expr, ExprBinary, FnArg, ImplItem, ImplItemFn, ItemConst, ItemImpl, ItemStatic, LitStr,
+    ReturnType, Type, Visibility,
+};
+
+use crate::diagnostic::Diagnostic;
+use crate::{EventInfo, FunctionInfo, InterfaceInfo, ParameterInfo, Receiver};
 
 /// Validate that a public method has a supported signature for extern wrapper
 /// generation.
@@ -16,6 +26,7 @@ use syn::{FnArg, ImplItem, ImplItemFn, ItemImpl, ReturnType, Type, Visibility};
 /// - Is async
 /// - Consumes `self` (not `&self` or `&mut self`)
 /// - Uses `impl Trait` in parameters or return type
+/// - Returns a `&mut` reference
 pub(crate) fn public_method(method: &ImplItemFn) -> Result<(), syn::Error> {
     let name = &method.sig.ident;
 
@@ -69,11 +80,16 @@ pub(crate) fn public_method(method: &ImplItemFn) -> Result<(), syn::Error> {
         ));
     }
 
+    // Check for &mut return
+    if let Some(error) = mut_return(method, &format!("public method `{name}`")) {
+        return Err(error);
+    }
+
     // Check for self receiver: if present, must be borrowed (not consumed)
     if let Some(FnArg::Receiver(receiver)) = method.sig.inputs.first()
         && receiver.reference.is_none()
     {
-        return Err(syn::Error::new_spanned(
+        return Err(consumed_self(
             receiver,
             format!(
                 "public method `{name}` cannot consume `self`; \
@@ -85,16 +101,85 @@ pub(crate) fn public_method(method: &ImplItemFn) -> Result<(), syn::Error> {
     Ok(())
 }
 
+/// Error for an exported method returning `&mut`, named by `subject`: the
+/// wrapper serializes a clone, so callers would never observe mutations made
+/// through the returned reference.
+fn mut_return(method: &ImplItemFn, subject: &str) -> Option<syn::Error> {
+    if let ReturnType::Type(_, ty) = &method.sig.output
+        && let Type::Reference(type_ref) = &**ty
+        && type_ref.mutability.is_some()
+    {
+        return Some(syn::Error::new_spanned(
+            ty,
+            format!(
+                "{subject} cannot return `&mut`; \
+                 the extern wrapper returns a copy, so mutations through it would be lost. \
+                 Return `&T` or `T` instead"
+            ),
+        ));
+    }
+    None
+}
+
+/// Error for an exported method taking `self` by value, suggesting the
+/// matching borrow.
+fn consumed_self(receiver: &syn::Receiver, message: String) -> syn::Error {
+    let borrow = if receiver.mutability.is_some() {
+        "&mut self"
+    } else {
+        "&self"
+    };
+
+    Diagnostic::spanned(receiver, message)
+        .note(
+            "exported methods are called on the contract's `STATE`, \
+             which cannot be moved out of",
+        )
+        .suggestion("borrow the contract state instead", borrow)
+        .into()
+}
+
+/// Validate that an impl block exporting contract methods is not generic.
+///
+/// The contract state lives in a single `static STATE` of the contract
+/// type, so wrappers can only call methods on a concrete type. Where clauses
+/// are accepted.
+pub(crate) fn impl_generics(impl_block: &ItemImpl) -> Result<(), syn::Error> {
+    if !impl_block.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &impl_block.generics,
+            "#[contract] impl blocks cannot have generic or lifetime parameters; \
+             the contract state is a single `static STATE` of a concrete type",
+        ));
+    }
+
+    if let Type::Path(type_path) = &*impl_block.self_ty
+        && let Some(segment) = type_path.path.segments.last()
+        && !segment.arguments.is_none()
+    {
+        return Err(syn::Error::new_spanned(
+            &segment.arguments,
+            "#[contract] impl blocks must name the contract type without generic arguments; \
+             the contract state is a single `static STATE` of a concrete type",
+        ));
+    }
+
+    Ok(())
+}
+
 /// Validate all public methods in an impl block.
 ///
-/// Note: The `new` method is skipped because it's a special constructor
-/// that is validated separately by `new_constructor` and is not
-/// exported as an extern function.
-pub(crate) fn impl_block_methods(impl_block: &ItemImpl) -> Result<(), syn::Error> {
+/// Note: The constructors are skipped (see [`is_constructor`]); the one
+/// initializing STATE is validated separately by `new_constructor`, and
+/// neither is exported as an extern function.
+pub(crate) fn impl_block_methods(
+    impl_block: &ItemImpl,
+    constructor: &str,
+) -> Result<(), syn::Error> {
     for item in &impl_block.items {
         if let ImplItem::Fn(method) = item
             && matches!(method.vis, Visibility::Public(_))
-            && method.sig.ident != "new"
+            && !is_constructor(method, constructor)
         {
             public_method(method)?;
         }
@@ -102,24 +187,34 @@ pub(crate) fn impl_block_methods(impl_block: &ItemImpl) -> Result<(), syn::Error
     Ok(())
 }
 
-/// Validate that the contract struct has a `const fn new() -> Self` method.
+/// Whether `method` is one of the contract's constructors, which are not
+/// exported: the `constructor` initializing STATE, and `new`, which stays
+/// free for a runtime builder when the constructor is renamed with
+/// `#[contract(constructor = "...")]`.
+pub(crate) fn is_constructor(method: &ImplItemFn, constructor: &str) -> bool {
+    method.sig.ident == constructor || method.sig.ident == "new"
+}
+
+/// Validate that the contract struct has a `const fn new() -> Self` method,
+/// or the `constructor` named by `#[contract(constructor = "...")]`.
 ///
 /// This method is required to initialize the static `STATE` variable.
 /// It must be:
-/// - Named `new`
+/// - Named `new` (or `constructor`)
 /// - Marked `const`
 /// - Have no parameters
 /// - Return `Self` (or the contract type name)
 pub(crate) fn new_constructor(
     contract_name: &str,
+    constructor: &str,
     impl_blocks: &[&ItemImpl],
     contract_struct: &syn::ItemStruct,
 ) -> Result<(), syn::Error> {
-    // Find the `new` method in any impl block
+    // Find the constructor in any impl block
     let new_method = impl_blocks.iter().find_map(|impl_block| {
         impl_block.items.iter().find_map(|item| {
             if let ImplItem::Fn(method) = item
-                && method.sig.ident == "new"
+                && method.sig.ident == constructor
             {
                 Some(method)
             } else {
@@ -132,21 +227,28 @@ pub(crate) fn new_constructor(
         return Err(syn::Error::new_spanned(
             contract_struct,
             format!(
-                "#[contract] requires `{contract_name}` to have a `const fn new() -> Self` method \
-                 to initialize the static STATE variable"
+                "#[contract] requires `{contract_name}` to have a `const fn {constructor}() -> Self` \
+                 method to initialize the static STATE variable"
             ),
         ));
     };
 
     // Must be const
     if new_method.sig.constness.is_none() {
-        return Err(syn::Error::new_spanned(
+        return Err(Diagnostic::spanned(
             &new_method.sig,
             format!(
-                "`{contract_name}::new` must be a `const fn` to initialize the static STATE variable; \
-                 add `const` to the function signature"
+                "`{contract_name}::{constructor}` must be a `const fn` to initialize the static STATE \
+                 variable; add `const` to the function signature"
             ),
-        ));
+        )
+        .note(format!(
+                "the state is declared as `static STATE: ContractState<{contract_name}> = \
+                 ContractState::new({contract_name}::{constructor}());`, \
+                 which is evaluated at compile time"
+        ))
+        .suggestion(format!("make `{constructor}` a const fn"), "const fn")
+        .into());
     }
 
     // Must have no parameters (no self, no other args)
@@ -154,8 +256,8 @@ pub(crate) fn new_constructor(
         return Err(syn::Error::new_spanned(
             &new_method.sig.inputs,
             format!(
-                "`{contract_name}::new` must have no parameters; \
-                 use `const fn new() -> Self` to create a default state"
+                "`{contract_name}::{constructor}` must have no parameters; \
+                 use `const fn {constructor}() -> Self` to create a default state"
             ),
         ));
     }
@@ -176,7 +278,7 @@ pub(crate) fn new_constructor(
     if !has_valid_return {
         return Err(syn::Error::new_spanned(
             &new_method.sig.output,
-            format!("`{contract_name}::new` must return `Self` or `{contract_name}`"),
+            format!("`{contract_name}::{constructor}` must return `Self` or `{contract_name}`"),
         ));
     }
 
@@ -265,6 +367,169 @@ pub(crate) fn init_method(
     Ok(())
 }
 
+/// Validate that `#[contract(reinit_guard)]` is placed on `init`.
+///
+/// The guard counts calls to the initializer, so it has no meaning on any
+/// other method.
+pub(crate) fn reinit_guard(method: &ImplItemFn) -> Result<(), syn::Error> {
+    let name = &method.sig.ident;
+    if name != "init" {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "`#[contract(reinit_guard)]` is only valid on `init`, found on `{name}`; \
+                 move the directive to the contract's `init` method"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate the signature of a `#[contract(invariant)]` method.
+///
+/// Invariants are called on the state after every mutating export, so they
+/// take `&self` and nothing else, and report a violation by panicking.
+pub(crate) fn invariant(method: &ImplItemFn) -> Result<(), syn::Error> {
+    let sig = &method.sig;
+    let name = &sig.ident;
+    let takes_ref_self = matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    if !takes_ref_self
+        || sig.inputs.len() != 1
+        || !matches!(sig.output, ReturnType::Default)
+        || !sig.generics.params.is_empty()
+        || sig.asyncness.is_some()
+    {
+        return Err(syn::Error::new_spanned(
+            sig,
+            format!(
+                "`#[contract(invariant)]` method `{name}` must be `fn {name}(&self)`; \
+                 panic in its body when the invariant is violated"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Export names owned by the VM, the WASM toolchain, or the Rust allocator.
+///
+/// `init` is not listed: it is the VM's initializer entry point and is
+/// validated separately by [`init_method`].
+const RESERVED_EXPORTS: &[&str] = &[
+    "memory",
+    "A",
+    "__data_end",
+    "__heap_base",
+    "__indirect_function_table",
+    "__stack_pointer",
+    "__rust_alloc",
+    "__rust_alloc_zeroed",
+    "__rust_dealloc",
+    "__rust_realloc",
+];
+
+/// Items the macro generates next to the extern wrappers.
+const GENERATED_ITEMS: &[&str] = &[
+    "STATE",
+    "INIT_CALLS",
+    "CONTRACT_SCHEMA",
+    "__contract_dispatch",
+    "contract_schema",
+    "__forge_host_calls",
+    "contract_metadata",
+    "__storage_stats",
+];
+
+/// Validate that no exported function collides with a reserved symbol or
+/// with another exported function.
+///
+/// Each function becomes a `#[no_mangle]` export of the same name, so a
+/// collision would otherwise only surface at link or deploy time. Functions
+/// exported twice, e.g. an inherent method and a method of an exposed trait
+/// with the same name, are reported at both definitions.
+pub(crate) fn export_names(functions: &[FunctionInfo]) -> Result<(), syn::Error> {
+    let mut exported = BTreeSet::new();
+
+    for f in functions {
+        let name = f.name.to_string();
+
+        if exported.contains(&name) {
+            return Err(Diagnostic::spanned(
+                &f.name,
+                format!(
+                    "`{name}` is exported more than once; \
+                     each exported method needs a unique name"
+                ),
+            )
+            .note(format!(
+                "an earlier method or `expose` entry already exports `{name}`"
+            ))
+            .help("rename one of the methods, or leave it out of the trait's `expose` list")
+            .into());
+        }
+        exported.insert(name.clone());
+
+        if RESERVED_EXPORTS.contains(&name.as_str()) {
+            return Err(syn::Error::new(
+                f.name.span(),
+                format!(
+                    "`{name}` is a reserved WASM export name; \
+                     rename the method so its extern wrapper does not collide with it"
+                ),
+            ));
+        }
+
+        if GENERATED_ITEMS.contains(&name.as_str()) {
+            return Err(syn::Error::new(
+                f.name.span(),
+                format!(
+                    "`{name}` collides with an item generated by #[contract]; \
+                     rename the method"
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that the `replacement` of each deprecated function names another
+/// exported function, so clients following it reach a callable one.
+pub(crate) fn replacements(functions: &[FunctionInfo]) -> Result<(), syn::Error> {
+    for f in functions {
+        let Some(replacement) = f
+            .deprecated
+            .as_ref()
+            .and_then(|deprecated| deprecated.replacement.as_deref())
+        else {
+            continue;
+        };
+
+        if f.name == replacement {
+            return Err(syn::Error::new(
+                f.name.span(),
+                format!("`{replacement}` cannot be its own replacement"),
+            ));
+        }
+        if !functions.iter().any(|other| other.name == replacement) {
+            return Err(syn::Error::new(
+                f.name.span(),
+                format!(
+                    "`{}` is deprecated in favor of `{replacement}`, which the contract does not \
+                     export",
+                    f.name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate a method from a trait impl block.
 ///
 /// Similar to `public_method` but with trait-specific error messages.
@@ -327,6 +592,11 @@ pub(crate) fn trait_method(
         ));
     }
 
+    // Check for &mut return
+    if let Some(error) = mut_return(method, &format!("trait method `{trait_name}::{name}`")) {
+        return Err(error);
+    }
+
     // Check for self receiver
     let receiver = method.sig.inputs.first().and_then(|arg| {
         if let FnArg::Receiver(r) = arg {
@@ -340,7 +610,7 @@ pub(crate) fn trait_method(
     if let Some(receiver) = receiver {
         // Check that self is borrowed, not consumed
         if receiver.reference.is_none() {
-            return Err(syn::Error::new_spanned(
+            return Err(consumed_self(
                 receiver,
                 format!(
                     "trait method `{trait_name}::{name}` cannot consume `self`; \
@@ -362,6 +632,54 @@ pub(crate) fn trait_method(
     Ok(())
 }
 
+/// Validate that a method forwarding events declares them.
+///
+/// Forwarded payloads are raw bytes, so the forwarded topics and their types
+/// must be listed with `#[contract(forwards = [...])]` for the schema and
+/// data-driver to cover them.
+pub(crate) fn forwarded_events(
+    method: &ImplItemFn,
+    has_forward_call: bool,
+    has_forwards: bool,
+) -> Result<(), syn::Error> {
+    if has_forward_call && !has_forwards {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "method `{}` forwards events but does not declare them; \
+                 add `#[contract(forwards = [(topic, Type), ...])]`",
+                method.sig.ident
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate that a method batching events declares the envelope.
+///
+/// `batch!` and `flush!` emit a `dusk_forge::events::Batch` under the topic
+/// of a `Batcher` value, which the macro cannot read, so the topic and the
+/// envelope type must be listed with `#[contract(emits = [...])]`.
+pub(crate) fn batched_events(
+    method: &ImplItemFn,
+    has_batch_call: bool,
+    has_emits: bool,
+) -> Result<(), syn::Error> {
+    if has_batch_call && !has_emits {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "method `{}` batches events but does not declare the batch; \
+                 add `#[contract(emits = [(topic, dusk_forge::events::Batch<Type>)])]`",
+                method.sig.ident
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 /// Validate that a mutating method emits events.
 ///
 /// Public `&mut self` methods should emit events for observability. This
@@ -414,9 +732,178 @@ pub(crate) fn method_emits_event(
     Ok(())
 }
 
+/// Validate that the contract's methods use no unchecked `+`, `-` or `*`
+/// (from `#[contract(deny_unchecked_math)]`).
+///
+/// The macro cannot see types, so every arithmetic operator in a method body
+/// is reported, as integers are the only numbers contract code can use.
+/// Operations between two literals are left to the compiler, which rejects
+/// overflowing constants itself, as are const and static items.
+///
+/// Returns one error listing every offending operator.
+pub(crate) fn checked_math<'a>(
+    impl_blocks: impl IntoIterator<Item = &'a ItemImpl>,
+) -> Result<(), syn::Error> {
+    let mut visitor = UncheckedMath::default();
+    for impl_block in impl_blocks {
+        for item in &impl_block.items {
+            if let ImplItem::Fn(method) = item {
+                visitor.method = Some(method.sig.ident.clone());
+                visitor.visit_block(&method.block);
+            }
+        }
+    }
+
+    let mut errors = visitor.errors.into_iter().map(syn::Error::from);
+    let Some(mut error) = errors.next() else {
+        return Ok(());
+    };
+    for other in errors {
+        error.combine(other);
+    }
+    Err(error)
+}
+
+/// Collects the unchecked arithmetic of method bodies for `checked_math`.
+#[derive(Default)]
+struct UncheckedMath {
+    /// The method being visited.
+    method: Option<Ident>,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'ast> Visit<'ast> for UncheckedMath {
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        let op = match node.op {
+            BinOp::Add(_) | BinOp::AddAssign(_) => Some(("+", "add")),
+            BinOp::Sub(_) | BinOp::SubAssign(_) => Some(("-", "sub")),
+            BinOp::Mul(_) | BinOp::MulAssign(_) => Some(("*", "mul")),
+            _ => None,
+        };
+        let literals = matches!((&*node.left, &*node.right), (Expr::Lit(_), Expr::Lit(_)));
+
+        if let Some((symbol, name)) = op
+            && !literals
+        {
+            let mut diagnostic = Diagnostic::spanned(
+                node.op,
+                format!("unchecked `{symbol}` is denied by #[contract(deny_unchecked_math)]"),
+            );
+            if let Some(method) = &self.method {
+                diagnostic = diagnostic.note(format!("in method `{method}`"));
+            }
+            self.errors.push(diagnostic.help(format!(
+                "use `checked_{name}`, `saturating_{name}` or `wrapping_{name}` \
+                 to decide what happens on overflow"
+            )));
+        }
+
+        syn::visit::visit_expr_binary(self, node);
+    }
+
+    fn visit_item_const(&mut self, _: &'ast ItemConst) {}
+
+    fn visit_item_static(&mut self, _: &'ast ItemStatic) {}
+}
+
+/// Validate that the contract implements a `.forge-abi` interface.
+///
+/// Every declared function must be exported with the same receiver,
+/// parameter types and output type, and every declared event must be emitted
+/// with the same topic and data type. Types are compared as written. The
+/// contract may export functions and events beyond the interface.
+///
+/// Returns a single error spanned to `path` listing all mismatches.
+pub(crate) fn implements(
+    interface: &InterfaceInfo,
+    path: &LitStr,
+    functions: &[FunctionInfo],
+    events: &[EventInfo],
+) -> Result<(), syn::Error> {
+    let mut problems = Vec::new();
+
+    for expected in &interface.functions {
+        let name = &expected.name;
+        let Some(found) = functions.iter().find(|f| f.name == *name) else {
+            problems.push(format!("missing function `{name}`"));
+            continue;
+        };
+
+        let expected_sig = signature(expected.receiver, &expected.params, &expected.output_type);
+        let found_sig = signature(found.receiver, &found.params, &found.output_type);
+        if expected_sig != found_sig {
+            problems.push(format!(
+                "function `{name}` is `{found_sig}`, expected `{expected_sig}`"
+            ));
+        }
+    }
+
+    for expected in &interface.events {
+        let topic = &expected.topic;
+        let expected_data = expected.data_type.to_string();
+        match events.iter().find(|e| e.topic == *topic) {
+            None => problems.push(format!("missing event `{topic}` ({expected_data})")),
+            Some(found) if found.data_type.to_string() != expected_data => {
+                problems.push(format!(
+                    "event `{topic}` carries `{}`, expected `{expected_data}`",
+                    found.data_type
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    Err(syn::Error::new(
+        path.span(),
+        format!(
+            "contract does not implement interface `{}`:\n  - {}",
+            path.value(),
+            problems.join("\n  - ")
+        ),
+    ))
+}
+
+/// Render a function signature for interface conformance messages.
+fn signature(receiver: Receiver, params: &[ParameterInfo], output: &TokenStream2) -> String {
+    let mut inputs = match receiver {
+        Receiver::None => Vec::new(),
+        Receiver::Ref => vec!["&self".to_string()],
+        Receiver::RefMut => vec!["&mut self".to_string()],
+    };
+    inputs.extend(params.iter().map(|p| p.ty.to_string()));
+
+    format!("fn({}) -> {output}", inputs.join(", "))
+}
+
+/// Display units `unit = "..."` can attach to parameters and event fields.
+const UNITS: &[&str] = &["lux", "dusk", "wei", "blocks"];
+
+/// Validate a `unit = "..."` value against the display units wallets know.
+pub(crate) fn unit(lit: &LitStr) -> Result<String, syn::Error> {
+    let unit = lit.value();
+    if UNITS.contains(&unit.as_str()) {
+        return Ok(unit);
+    }
+
+    Err(syn::Error::new_spanned(
+        lit,
+        format!(
+            "unknown unit `{unit}`; expected one of {}",
+            UNITS.join(", ")
+        ),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
+    use quote::{format_ident, quote};
+
     use super::*;
+    use crate::InterfaceFunction;
 
     #[test]
     fn test_validate_method_valid_ref_self() {
@@ -451,6 +938,18 @@ mod tests {
         assert!(err.to_string().contains("cannot consume `self`"));
     }
 
+    #[test]
+    fn test_validate_method_consuming_self_suggestion() {
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn reset(mut self) { }
+        };
+        let err = public_method(&method).unwrap_err();
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("\n= note: exported methods are called"));
+        assert!(messages[0].ends_with("\n= help: borrow the contract state instead: `&mut self`"));
+    }
+
     #[test]
     fn test_validate_method_generic() {
         let method: ImplItemFn = syn::parse_quote! {
@@ -508,6 +1007,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_method_mut_ref_return() {
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn value_mut(&mut self) -> &mut u64 { &mut self.value }
+        };
+        let err = public_method(&method).unwrap_err();
+        assert!(err.to_string().contains("cannot return `&mut`"));
+
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn value(&self) -> &u64 { &self.value }
+        };
+        assert!(public_method(&method).is_ok());
+    }
+
     #[test]
     fn test_new_constructor_valid() {
         let impl_block: ItemImpl = syn::parse_quote! {
@@ -523,7 +1036,36 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        assert!(new_constructor("MyContract", &impl_blocks, &contract_struct).is_ok());
+        assert!(new_constructor("MyContract", "new", &impl_blocks, &contract_struct).is_ok());
+    }
+
+    #[test]
+    fn test_new_constructor_renamed() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub const fn empty() -> Self {
+                    Self { value: 0 }
+                }
+
+                pub fn new(value: u64) -> Self {
+                    Self { value }
+                }
+            }
+        };
+        let contract_struct: syn::ItemStruct = syn::parse_quote! {
+            pub struct MyContract {
+                value: u64,
+            }
+        };
+        let impl_blocks = vec![&impl_block];
+        assert!(new_constructor("MyContract", "empty", &impl_blocks, &contract_struct).is_ok());
+        assert!(impl_block_methods(&impl_block, "empty").is_ok());
+
+        let err = new_constructor("MyContract", "new", &impl_blocks, &contract_struct).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`MyContract::new` must be a `const fn`")
+        );
     }
 
     #[test]
@@ -541,7 +1083,7 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        assert!(new_constructor("MyContract", &impl_blocks, &contract_struct).is_ok());
+        assert!(new_constructor("MyContract", "new", &impl_blocks, &contract_struct).is_ok());
     }
 
     #[test]
@@ -557,7 +1099,7 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        let err = new_constructor("MyContract", &impl_blocks, &contract_struct).unwrap_err();
+        let err = new_constructor("MyContract", "new", &impl_blocks, &contract_struct).unwrap_err();
         assert!(err.to_string().contains("const fn new() -> Self"));
     }
 
@@ -576,8 +1118,13 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        let err = new_constructor("MyContract", &impl_blocks, &contract_struct).unwrap_err();
+        let err = new_constructor("MyContract", "new", &impl_blocks, &contract_struct).unwrap_err();
         assert!(err.to_string().contains("must be a `const fn`"));
+
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("ContractState::new(MyContract::new());"));
+        assert!(messages[0].contains("\n= help: make `new` a const fn: `const fn`"));
     }
 
     #[test]
@@ -595,7 +1142,7 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        let err = new_constructor("MyContract", &impl_blocks, &contract_struct).unwrap_err();
+        let err = new_constructor("MyContract", "new", &impl_blocks, &contract_struct).unwrap_err();
         assert!(err.to_string().contains("must have no parameters"));
     }
 
@@ -614,7 +1161,7 @@ mod tests {
             }
         };
         let impl_blocks = vec![&impl_block];
-        let err = new_constructor("MyContract", &impl_blocks, &contract_struct).unwrap_err();
+        let err = new_constructor("MyContract", "new", &impl_blocks, &contract_struct).unwrap_err();
         assert!(err.to_string().contains("must return `Self`"));
     }
 
@@ -719,6 +1266,48 @@ mod tests {
         assert!(err.to_string().contains("must return `()`"));
     }
 
+    #[test]
+    fn test_reinit_guard_on_init() {
+        let method: ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard)]
+            pub fn init(&mut self, owner: Address) {}
+        };
+        assert!(reinit_guard(&method).is_ok());
+    }
+
+    #[test]
+    fn test_reinit_guard_on_other_method() {
+        let method: ImplItemFn = syn::parse_quote! {
+            #[contract(reinit_guard)]
+            pub fn setup(&mut self, owner: Address) {}
+        };
+        let err = reinit_guard(&method).unwrap_err();
+        assert!(err.to_string().contains("only valid on `init`"));
+        assert!(err.to_string().contains("setup"));
+    }
+
+    #[test]
+    fn test_invariant_signature() {
+        let method: ImplItemFn = syn::parse_quote! {
+            #[contract(invariant)]
+            fn check(&self) { assert!(self.total <= self.cap); }
+        };
+        assert!(invariant(&method).is_ok());
+
+        for method in [
+            syn::parse_quote! { fn check(&mut self) {} },
+            syn::parse_quote! { fn check(&self, limit: u64) {} },
+            syn::parse_quote! { fn check(&self) -> bool { true } },
+            syn::parse_quote! { fn check() {} },
+        ] {
+            let err = invariant(&method).unwrap_err();
+            assert!(
+                err.to_string().contains("must be `fn check(&self)`"),
+                "{err}"
+            );
+        }
+    }
+
     #[test]
     fn test_trait_method_valid() {
         let method: ImplItemFn = syn::parse_quote! {
@@ -827,6 +1416,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trait_method_mut_ref_return() {
+        let method: ImplItemFn = syn::parse_quote! {
+            fn owner_mut(&mut self) -> &mut Owner {}
+        };
+        let err = trait_method(&method, "Ownable", true).unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("cannot return `&mut`"),
+            "error should mention `&mut` return: {msg}"
+        );
+        assert!(
+            msg.contains("Ownable::owner_mut"),
+            "error should include trait::method name: {msg}"
+        );
+    }
+
     // ========================================================================
     // method_emits_event tests
     // ========================================================================
@@ -890,4 +1496,309 @@ mod tests {
         };
         assert!(method_emits_event(&method, false, false, false).is_ok());
     }
+
+    #[test]
+    fn test_export_names_reserved() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn counter(&self) -> u64 { 0 }
+                pub fn memory(&self) -> u64 { 0 }
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+
+        let Err(err) = export_names(&functions) else {
+            panic!("expected error for reserved export name");
+        };
+        let msg = err.to_string();
+        assert!(
+            msg.contains("`memory` is a reserved WASM export name"),
+            "unexpected error: {msg}"
+        );
+    }
+
+    #[test]
+    fn test_export_names_generated_item() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[allow(non_snake_case)]
+                pub fn STATE(&self) -> u64 { 0 }
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+
+        let Err(err) = export_names(&functions) else {
+            panic!("expected error for generated item collision");
+        };
+        assert!(
+            err.to_string()
+                .contains("collides with an item generated by #[contract]"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_replacements_must_be_exported() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(deprecated = "use transfer_v2", replacement = "transfer_v2")]
+                pub fn transfer(&self, value: u64) {}
+                pub fn transfer_v2(&self, value: u64) {}
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+        assert!(replacements(&functions).is_ok());
+
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(deprecated = "use transfer_v3", replacement = "transfer_v3")]
+                pub fn transfer(&self, value: u64) {}
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+        let Err(err) = replacements(&functions) else {
+            panic!("expected error for a replacement that is not exported");
+        };
+        assert!(
+            err.to_string()
+                .contains("in favor of `transfer_v3`, which the contract does not export"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_forwarded_events_must_be_declared() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn relay(&mut self, raw: Vec<u8>) {
+                    dusk_forge::events::forward!("transfer", raw);
+                }
+            }
+        };
+
+        let Err(err) = crate::parse::public_methods(&impl_block, "new") else {
+            panic!("expected error for undeclared forwarded events");
+        };
+        assert!(
+            err.to_string()
+                .contains("forwards events but does not declare them"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_batched_events_must_be_declared() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn withdraw_all(&mut self, amounts: Vec<u64>) {
+                    let mut batcher = dusk_forge::events::Batcher::new("withdrawals");
+                    for amount in amounts {
+                        dusk_forge::events::batch!(batcher, Withdrawn { amount });
+                    }
+                    dusk_forge::events::flush!(batcher);
+                }
+            }
+        };
+
+        let Err(err) = crate::parse::public_methods(&impl_block, "new") else {
+            panic!("expected error for an undeclared batch");
+        };
+        assert!(
+            err.to_string()
+                .contains("batches events but does not declare the batch"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_export_names_duplicate_across_impls() {
+        let inherent: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                pub fn paused(&self) -> bool { false }
+            }
+        };
+        let trait_impl: ItemImpl = syn::parse_quote! {
+            #[contract(expose = [paused])]
+            impl Pausable for MyContract {
+                fn paused(&self) -> bool { self.pause.is_paused() }
+            }
+        };
+        let trait_impl = crate::TraitImplInfo {
+            trait_name: "Pausable".to_string(),
+            impl_block: &trait_impl,
+            expose_list: vec!["paused".to_string()],
+        };
+        let mut functions = crate::parse::public_methods(&inherent, "new").unwrap();
+        functions.extend(crate::parse::trait_methods(&trait_impl).unwrap());
+
+        let Err(err) = export_names(&functions) else {
+            panic!("expected error for a duplicate export");
+        };
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with(
+            "`paused` is exported more than once; each exported method needs a unique name\n\
+             = note: an earlier method or `expose` entry already exports `paused`"
+        ));
+    }
+
+    #[test]
+    fn test_export_names_init_allowed() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyContract {
+                #[contract(no_event)]
+                pub fn init(&mut self, owner: u64) {}
+                pub fn counter(&self) -> u64 { 0 }
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+
+        assert!(export_names(&functions).is_ok());
+    }
+
+    fn param(name: &str, ty: TokenStream2) -> ParameterInfo {
+        ParameterInfo {
+            name: format_ident!("{}", name),
+            ty,
+            is_ref: false,
+            is_mut_ref: false,
+            unit: None,
+        }
+    }
+
+    fn token_interface() -> InterfaceInfo {
+        InterfaceInfo {
+            functions: vec![
+                InterfaceFunction {
+                    name: format_ident!("balance_of"),
+                    receiver: Receiver::Ref,
+                    params: vec![param("account", quote! { PublicKey })],
+                    output_type: quote! { u64 },
+                },
+                InterfaceFunction {
+                    name: format_ident!("transfer"),
+                    receiver: Receiver::RefMut,
+                    params: vec![
+                        param("to", quote! { PublicKey }),
+                        param("value", quote! { u64 }),
+                    ],
+                    output_type: quote! { () },
+                },
+            ],
+            events: vec![EventInfo {
+                topic: "transfer".to_string(),
+                data_type: quote! { events::Transfer },
+            }],
+        }
+    }
+
+    #[test]
+    fn test_implements_conforming_contract() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl Token {
+                pub fn balance_of(&self, account: PublicKey) -> u64 { 0 }
+                pub fn transfer(&mut self, to: PublicKey, value: u64) {
+                    abi::emit("transfer", events::Transfer { to, value });
+                }
+                pub fn mint(&mut self, value: u64) {
+                    abi::emit("mint", value);
+                }
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+        let events = crate::parse::emit_calls(&impl_block);
+        let path: LitStr = syn::parse_quote! { "token.forge-abi" };
+
+        assert!(implements(&token_interface(), &path, &functions, &events).is_ok());
+    }
+
+    #[test]
+    fn test_implements_lists_all_mismatches() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl Token {
+                pub fn transfer(&mut self, to: PublicKey, value: u128) {
+                    abi::emit("transfer", events::Moved { to, value });
+                }
+            }
+        };
+        let functions = crate::parse::public_methods(&impl_block, "new").unwrap();
+        let events = crate::parse::emit_calls(&impl_block);
+        let path: LitStr = syn::parse_quote! { "token.forge-abi" };
+
+        let Err(err) = implements(&token_interface(), &path, &functions, &events) else {
+            panic!("expected error for non-conforming contract");
+        };
+        let msg = err.to_string();
+        assert!(
+            msg.contains("does not implement interface `token.forge-abi`"),
+            "unexpected error: {msg}"
+        );
+        assert!(
+            msg.contains("missing function `balance_of`"),
+            "error should list the missing function: {msg}"
+        );
+        assert!(
+            msg.contains("function `transfer` is `fn(&mut self, PublicKey, u128) -> ()`"),
+            "error should list the mismatched function: {msg}"
+        );
+        assert!(
+            msg.contains("event `transfer` carries"),
+            "error should list the mismatched event: {msg}"
+        );
+    }
+
+    #[test]
+    fn test_checked_math_reports_unchecked_operators() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl Vault {
+                pub fn deposit(&mut self, amount: u64) {
+                    self.total += amount;
+                    self.count = self.count.checked_add(1).expect("count overflow");
+                }
+
+                fn fee(amount: u64) -> u64 {
+                    const SCALE: u64 = 100 * 100;
+                    amount * 3 / SCALE - 1
+                }
+            }
+        };
+
+        let Err(err) = checked_math([&impl_block]) else {
+            panic!("expected errors for unchecked arithmetic");
+        };
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        let denied: Vec<_> = messages
+            .iter()
+            .filter(|m| m.starts_with("unchecked"))
+            .collect();
+
+        assert_eq!(denied.len(), 3, "{messages:?}");
+        assert!(denied[0].contains("unchecked `+`"), "{messages:?}");
+        assert!(
+            denied
+                .iter()
+                .any(|m| m.contains("\n= note: in method `fee`"))
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("use `checked_mul`, `saturating_mul` or `wrapping_mul`")),
+            "{messages:?}"
+        );
+    }
+
+    #[test]
+    fn test_checked_math_allows_checked_and_literal_arithmetic() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl Vault {
+                pub fn deposit(&mut self, amount: u64) {
+                    const LIMIT: u64 = 10 * 1000;
+                    self.total = self.total.saturating_add(amount).min(LIMIT);
+                    let _ = 2 * 3;
+                }
+            }
+        };
+
+        assert!(checked_math([&impl_block]).is_ok());
+    }
 }
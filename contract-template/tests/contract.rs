@@ -3,13 +3,26 @@
 use dusk_core::abi::ContractId;
 use dusk_vm::{ContractData, Session, VM};
 
-const CONTRACT_BYTECODE: &[u8] =
-    include_bytes!("../target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm");
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/target/contract/wasm32-unknown-unknown/release/YOUR_CONTRACT_NAME.wasm"
+    ),
+};
 const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
 const CHAIN_ID: u8 = 1;
 const GAS_LIMIT: u64 = u64::MAX;
 const OWNER: [u8; 32] = [0; 32];
 
+fn contract_bytecode() -> Vec<u8> {
+    std::fs::read(CONTRACT_WASM).unwrap_or_else(|err| {
+        panic!("reading {CONTRACT_WASM} should succeed (build the contract first): {err}")
+    })
+}
+
 struct TestHarness {
     _vm: VM,
     session: Session,
@@ -21,7 +34,7 @@ fn deploy_counter() -> TestHarness {
 
     let deployed_id = session
         .deploy(
-            CONTRACT_BYTECODE,
+            &contract_bytecode(),
             ContractData::builder()
                 .owner(OWNER)
                 .contract_id(CONTRACT_ID),
@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Wire format for Dusk addresses carried in a bridge message's
+//! `extra_data`.
+//!
+//! An encoded address is a version byte ([`VERSION`]), a kind byte
+//! ([`MOONLIGHT`] or [`PHOENIX`]) and the raw public key bytes. Anything
+//! after the key belongs to the caller: [`decode_ds_address`] returns it
+//! untouched, so further fields can be decoded from the rest.
+//!
+//! ```ignore
+//! let mut extra_data = address_codec::encode_ds_address(&to);
+//! extra_data.extend_from_slice(&memo);
+//!
+//! let (to, memo) = address_codec::decode_ds_address(&extra_data)?;
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Current version of the wire format.
+pub const VERSION: u8 = 1;
+
+/// Kind byte of a Moonlight (BLS public key) address.
+pub const MOONLIGHT: u8 = 0;
+
+/// Kind byte of a Phoenix public key address.
+pub const PHOENIX: u8 = 1;
+
+/// Size of a compressed BLS public key.
+pub const MOONLIGHT_KEY_SIZE: usize = 96;
+
+/// Size of a Phoenix public key.
+pub const PHOENIX_KEY_SIZE: usize = 64;
+
+/// A Dusk address as raw public key bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DsAddress {
+    /// A Moonlight account, by its compressed BLS public key.
+    Moonlight([u8; MOONLIGHT_KEY_SIZE]),
+    /// A Phoenix address, by its public key.
+    Phoenix([u8; PHOENIX_KEY_SIZE]),
+}
+
+impl DsAddress {
+    /// The kind byte of the address.
+    #[must_use]
+    pub const fn kind(&self) -> u8 {
+        match self {
+            Self::Moonlight(_) => MOONLIGHT,
+            Self::Phoenix(_) => PHOENIX,
+        }
+    }
+
+    /// The public key bytes of the address.
+    #[must_use]
+    pub fn key(&self) -> &[u8] {
+        match self {
+            Self::Moonlight(key) => key,
+            Self::Phoenix(key) => key,
+        }
+    }
+}
+
+/// Error decoding an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressError {
+    /// The input ended before the version and kind bytes.
+    Empty,
+    /// The version byte is not one this crate can decode.
+    UnsupportedVersion(u8),
+    /// The kind byte is neither [`MOONLIGHT`] nor [`PHOENIX`].
+    UnknownKind(u8),
+    /// The input ended before the key, with the key size and the bytes left.
+    Truncated {
+        /// Bytes the key needs.
+        expected: usize,
+        /// Bytes available.
+        found: usize,
+    },
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("missing address header"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported address version {v}"),
+            Self::UnknownKind(k) => write!(f, "unknown address kind {k}"),
+            Self::Truncated { expected, found } => {
+                write!(f, "address key needs {expected} bytes, found {found}")
+            }
+        }
+    }
+}
+
+/// Append the encoding of `address` to `out`.
+pub fn encode_ds_address_into(address: &DsAddress, out: &mut Vec<u8>) {
+    out.reserve(2 + address.key().len());
+    out.push(VERSION);
+    out.push(address.kind());
+    out.extend_from_slice(address.key());
+}
+
+/// Encode `address`.
+#[must_use]
+pub fn encode_ds_address(address: &DsAddress) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_ds_address_into(address, &mut out);
+    out
+}
+
+/// Decode an address from the start of `bytes`, returning it with the bytes
+/// that follow it.
+///
+/// # Errors
+///
+/// See [`AddressError`].
+pub fn decode_ds_address(bytes: &[u8]) -> Result<(DsAddress, &[u8]), AddressError> {
+    let [version, kind, rest @ ..] = bytes else {
+        return Err(AddressError::Empty);
+    };
+    if *version != VERSION {
+        return Err(AddressError::UnsupportedVersion(*version));
+    }
+
+    match *kind {
+        MOONLIGHT => {
+            let (key, rest) = split_key(rest)?;
+            Ok((DsAddress::Moonlight(key), rest))
+        }
+        PHOENIX => {
+            let (key, rest) = split_key(rest)?;
+            Ok((DsAddress::Phoenix(key), rest))
+        }
+        other => Err(AddressError::UnknownKind(other)),
+    }
+}
+
+fn split_key<const N: usize>(bytes: &[u8]) -> Result<([u8; N], &[u8]), AddressError> {
+    let Some((key, rest)) = bytes.split_first_chunk::<N>() else {
+        return Err(AddressError::Truncated {
+            expected: N,
+            found: bytes.len(),
+        });
+    };
+    Ok((*key, rest))
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Inter-contract calls through [`call!`](crate::call!).
+//!
+//! `call!` wraps `dusk_core::abi::call`, mapping the host's error into
+//! [`CallError`] and tracking the call while it is in progress. Contracts
+//! using `call!` get exported wrappers that refuse to be re-entered during an
+//! outgoing call, since the caller still holds a borrow of the contract
+//! state.
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt;
+
+use dusk_core::abi::ContractId;
+
+use crate::revert::Revert;
+
+/// Number of `call!` invocations currently in progress.
+static mut DEPTH: u32 = 0;
+
+/// Error of a failed inter-contract call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallError {
+    /// The callee panicked, with its panic message.
+    Panic(String),
+    /// The call ran out of gas.
+    OutOfGas,
+    /// No contract is deployed at the called ID.
+    DoesNotExist,
+    /// The host reported an error not covered above.
+    Unknown,
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(msg) => write!(f, "callee panicked: {msg}"),
+            Self::OutOfGas => f.write_str("call ran out of gas"),
+            Self::DoesNotExist => f.write_str("callee does not exist"),
+            Self::Unknown => f.write_str("call failed"),
+        }
+    }
+}
+
+impl CallError {
+    /// The callee's failure message split into function, code and message,
+    /// if it failed through [`revert!`](crate::revert!) or
+    /// [`ensure!`](crate::ensure!).
+    #[must_use]
+    pub fn revert(&self) -> Option<Revert<'_>> {
+        match self {
+            Self::Panic(msg) => Revert::parse(msg),
+            _ => None,
+        }
+    }
+}
+
+/// A contract ID [`call!`](crate::call!) accepts for the functions of the
+/// interface whose ID newtype is `Id`: the newtype itself, or an untyped
+/// [`ContractId`].
+///
+/// `#[contract(calls = "bridge.forge-abi")]` generates a `BridgeId` newtype
+/// implementing `Callee<BridgeId>`, and calls to the interface's functions
+/// pass their contract through this trait, so the ID of a contract of
+/// another interface is rejected at compile time.
+pub trait Callee<Id> {
+    /// The untyped contract ID.
+    fn contract_id(self) -> ContractId;
+}
+
+impl<Id> Callee<Id> for ContractId {
+    fn contract_id(self) -> ContractId {
+        self
+    }
+}
+
+impl<Id, T: Callee<Id> + Copy> Callee<Id> for &T {
+    fn contract_id(self) -> ContractId {
+        (*self).contract_id()
+    }
+}
+
+/// Whether a call made through `call!` is in progress.
+#[must_use]
+pub fn in_progress() -> bool {
+    // SAFETY: contracts execute single-threaded.
+    unsafe { DEPTH > 0 }
+}
+
+/// Marks a `call!` as in progress until dropped.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct OutgoingCall(());
+
+impl OutgoingCall {
+    #[doc(hidden)]
+    #[must_use]
+    pub fn start() -> Self {
+        // SAFETY: contracts execute single-threaded.
+        unsafe { DEPTH += 1 };
+        Self(())
+    }
+}
+
+impl Drop for OutgoingCall {
+    fn drop(&mut self) {
+        // SAFETY: contracts execute single-threaded.
+        unsafe { DEPTH -= 1 };
+    }
+}
+
+/// Panic if the contract is entered while one of its `call!`s is in
+/// progress. Called by the wrappers `#[contract]` exports for contracts using
+/// `call!`.
+///
+/// # Panics
+///
+/// Panics on re-entry.
+#[doc(hidden)]
+pub fn reject_reentry() {
+    assert!(
+        !in_progress(),
+        "reentrant call: the contract was called back during an outgoing `call!`"
+    );
+}
@@ -0,0 +1,187 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Bounded `u64` settings, such as periods, fees and gas limits.
+//!
+//! A contract keeps a [`U64Config`] field in its state instead of one field
+//! per setting. The settings are declared once, with their defaults and
+//! bounds, and [`U64Config::set`] refuses out-of-range values and returns the
+//! [`ConfigChanged`] event to emit:
+//!
+//! ```ignore
+//! const SETTINGS: &[ConfigKey] = &[
+//!     ConfigKey::new("finalization_period", 2_160).min(1),
+//!     ConfigKey::new("deposit_fee", 0).max(1_000_000),
+//! ];
+//!
+//! pub struct Bridge {
+//!     owner: Option<PublicKey>,
+//!     config: U64Config,
+//! }
+//!
+//! impl Bridge {
+//!     pub const fn new() -> Self {
+//!         Self { owner: None, config: U64Config::new(SETTINGS) }
+//!     }
+//!
+//!     pub fn set_config(&mut self, key: String, value: u64) {
+//!         self.only_owner();
+//!         abi::emit(ConfigChanged::TOPIC, self.config.set(&key, value));
+//!     }
+//! }
+//! ```
+//!
+//! The `#[contract]` macro registers [`ConfigChanged`] in the schema and
+//! data-driver of any contract whose state has a `U64Config` field.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+#[allow(unused_imports)]
+use rkyv::bytecheck::CheckBytes;
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// A setting of a [`U64Config`]: its name, default value and bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigKey {
+    /// The setting name.
+    pub name: &'static str,
+    /// The value until the setting is first set.
+    pub default: u64,
+    /// The smallest value accepted.
+    pub min: u64,
+    /// The largest value accepted.
+    pub max: u64,
+}
+
+impl ConfigKey {
+    /// An unbounded setting named `name`, worth `default` until set.
+    #[must_use]
+    pub const fn new(name: &'static str, default: u64) -> Self {
+        Self {
+            name,
+            default,
+            min: 0,
+            max: u64::MAX,
+        }
+    }
+
+    /// Refuse values below `min`.
+    #[must_use]
+    pub const fn min(self, min: u64) -> Self {
+        Self { min, ..self }
+    }
+
+    /// Refuse values above `max`.
+    #[must_use]
+    pub const fn max(self, max: u64) -> Self {
+        Self { max, ..self }
+    }
+}
+
+/// Event returned by [`U64Config::set`], for the contract to emit.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Archive,
+    Serialize,
+    Deserialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ConfigChanged {
+    /// The setting changed.
+    pub key: String,
+    /// The value before the change.
+    pub previous: u64,
+    /// The new value.
+    pub value: u64,
+}
+
+impl ConfigChanged {
+    /// Event topic for setting changes.
+    pub const TOPIC: &'static str = "config_changed";
+}
+
+/// A set of bounded `u64` settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct U64Config {
+    keys: &'static [ConfigKey],
+    values: BTreeMap<&'static str, u64>,
+}
+
+impl U64Config {
+    /// A component holding the settings declared in `keys`, all at their
+    /// default values.
+    #[must_use]
+    pub const fn new(keys: &'static [ConfigKey]) -> Self {
+        Self {
+            keys,
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// The declared settings.
+    #[must_use]
+    pub const fn keys(&self) -> &'static [ConfigKey] {
+        self.keys
+    }
+
+    /// The value of `key`, or `None` if no such setting is declared.
+    #[must_use]
+    pub fn try_get(&self, key: &str) -> Option<u64> {
+        let declared = self.key(key)?;
+        Some(self.values.get(key).copied().unwrap_or(declared.default))
+    }
+
+    /// The value of `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such setting is declared.
+    #[must_use]
+    pub fn get(&self, key: &str) -> u64 {
+        self.try_get(key)
+            .unwrap_or_else(|| panic!("unknown setting `{key}`"))
+    }
+
+    /// Set `key` to `value`, returning the event recording the change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such setting is declared, or if `value` is out of its
+    /// bounds.
+    pub fn set(&mut self, key: &str, value: u64) -> ConfigChanged {
+        let declared = self
+            .key(key)
+            .unwrap_or_else(|| panic!("unknown setting `{key}`"));
+        assert!(
+            (declared.min..=declared.max).contains(&value),
+            "`{key}` must be between {} and {}, got {value}",
+            declared.min,
+            declared.max
+        );
+
+        let previous = self
+            .values
+            .insert(declared.name, value)
+            .unwrap_or(declared.default);
+        ConfigChanged {
+            key: String::from(declared.name),
+            previous,
+            value,
+        }
+    }
+
+    fn key(&self, key: &str) -> Option<&'static ConfigKey> {
+        self.keys.iter().find(|declared| declared.name == key)
+    }
+}
@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Call windows for `#[contract(cooldown_blocks = N)]`.
+//!
+//! Each method with the directive gets a hidden [`Cooldowns`] static in the
+//! contract's exported wrappers, keyed by the transaction's public sender
+//! (`per = "caller"`, see [`caller_key`]) or shared by everyone
+//! (`per = "global"`). The wrapper calls [`Cooldowns::enter`] before the
+//! method runs, which panics while the key's window since its last accepted
+//! call is still open.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use dusk_bytes::Serializable;
+use dusk_core::signatures::bls::PublicKey;
+
+/// Window key of a caller: the compressed bytes of its public key.
+pub type CallerKey = [u8; PublicKey::SIZE];
+
+/// The window key of a transaction's public sender.
+///
+/// # Panics
+///
+/// Panics if there is no public sender, as in shielded (Phoenix)
+/// transactions: their callers cannot be told apart, so a per-caller window
+/// would either be shared by all of them or not apply at all.
+#[must_use]
+pub fn caller_key(sender: Option<PublicKey>, method: &str) -> CallerKey {
+    match sender {
+        Some(sender) => sender.to_bytes(),
+        None => panic!("`{method}` is rate-limited per caller and needs a public sender"),
+    }
+}
+
+/// Block height of the last accepted call, per key.
+///
+/// Only keys whose window is still open are kept: entries are pruned on
+/// every accepted call, so the map does not grow with the number of callers
+/// ever seen.
+#[derive(Debug)]
+pub struct Cooldowns<K> {
+    last: BTreeMap<K, u64>,
+}
+
+impl<K> Cooldowns<K> {
+    /// An empty set of windows.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K> Default for Cooldowns<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord> Cooldowns<K> {
+    /// Number of blocks `key` still has to wait at block `height` before a
+    /// call is accepted, given a window of `blocks`. Zero if it may call now.
+    #[must_use]
+    pub fn remaining(&self, key: &K, height: u64, blocks: u64) -> u64 {
+        self.last
+            .get(key)
+            .map_or(0, |last| last.saturating_add(blocks).saturating_sub(height))
+    }
+
+    /// Accept a call to `method` by `key` at block `height` and start its
+    /// next window of `blocks`, dropping the windows that have passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window opened by the key's previous call has not yet
+    /// passed.
+    pub fn enter(&mut self, key: K, height: u64, blocks: u64, method: &str) {
+        let remaining = self.remaining(&key, height, blocks);
+        assert!(
+            remaining == 0,
+            "`{method}` is on cooldown for another {remaining} block(s)"
+        );
+
+        self.last
+            .retain(|_, last| last.saturating_add(blocks) > height);
+        self.last.insert(key, height);
+    }
+}
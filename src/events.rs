@@ -0,0 +1,321 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Helpers for emitting events and reading them back from call receipts.
+
+extern crate alloc;
+
+#[doc(hidden)]
+pub use alloc::vec::Vec;
+
+use dusk_core::abi::Event;
+use rkyv::bytecheck::CheckBytes;
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+
+/// Buffer an event in a [`Batcher`], emitting the batch when it is due.
+///
+/// `batch!(batcher, event)` pushes `event` with the gas left in the call
+/// (`abi::limit() - abi::spent()`) and emits the returned [`Batch`] under the
+/// batcher's topic, so loops emit one envelope per [`Batcher::max_events`]
+/// events instead of one event each:
+///
+/// ```ignore
+/// const WITHDRAWALS: &str = "withdrawals";
+///
+/// #[contract(emits = [(WITHDRAWALS, dusk_forge::events::Batch<events::Withdrawn>)])]
+/// pub fn withdraw_all(&mut self, requests: Vec<Withdrawal>) {
+///     let mut batcher = dusk_forge::events::Batcher::new(WITHDRAWALS);
+///     for request in requests {
+///         let event = self.withdraw(request);
+///         dusk_forge::events::batch!(batcher, event);
+///     }
+///     dusk_forge::events::flush!(batcher);
+/// }
+/// ```
+///
+/// The `#[contract]` macro cannot tell the batched type from the call, so it
+/// requires every method invoking `batch!` or `flush!` to declare the
+/// envelope with `#[contract(emits = [...])]`.
+pub use crate::__batch as batch;
+/// Emit the events left in a [`Batcher`], if any.
+///
+/// `flush!(batcher)` ends a batched loop, see [`batch!`].
+pub use crate::__flush as flush;
+/// Re-emit an event received from another contract.
+///
+/// `forward!(topic, raw)` expands to `dusk_core::abi::emit_raw(topic, raw)`,
+/// emitting the callee's already-serialized event bytes unchanged, so proxy
+/// and aggregator contracts can surface the events of the contracts they
+/// call:
+///
+/// ```ignore
+/// #[contract(forwards = [(events::Transfer::TOPIC, events::Transfer)])]
+/// pub fn relay(&mut self, raw: Vec<u8>) {
+///     dusk_forge::events::forward!(events::Transfer::TOPIC, raw);
+/// }
+/// ```
+///
+/// The payload carries no type information, so the `#[contract]` macro
+/// requires every method invoking `forward!` to declare the forwarded topics
+/// and types with `#[contract(forwards = [...])]`; they are added to the
+/// schema and data-driver next to the contract's own events.
+pub use crate::__forward as forward;
+
+/// Default number of events a [`Batcher`] holds before its batch is due.
+pub const DEFAULT_MAX_EVENTS: usize = 64;
+
+/// Envelope of the events buffered by a [`Batcher`], emitted as one event.
+///
+/// Declared in the schema as `dusk_forge::events::Batch<T>`, so the
+/// data-driver decodes the envelope with the events it carries.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Archive,
+    Serialize,
+    Deserialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Batch<T> {
+    /// The batched events, in push order.
+    pub events: Vec<T>,
+}
+
+/// Buffer of small events emitted together as one [`Batch`].
+///
+/// [`push`](Self::push) returns the batch once it holds
+/// [`max_events`](Self::max_events) events, or as soon as the gas left in the
+/// call drops below [`gas_reserve`](Self::gas_reserve), so the buffered
+/// events are emitted while the call can still pay for it.
+/// [`take`](Self::take) returns what is left at the end. The [`batch!`] and
+/// [`flush!`] macros read the gas left and emit the batches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Batcher<T> {
+    topic: &'static str,
+    max_events: usize,
+    gas_reserve: u64,
+    events: Vec<T>,
+}
+
+impl<T> Batcher<T> {
+    /// An empty batcher emitting under `topic`, due every
+    /// [`DEFAULT_MAX_EVENTS`] events and without a gas reserve.
+    #[must_use]
+    pub const fn new(topic: &'static str) -> Self {
+        Self {
+            topic,
+            max_events: DEFAULT_MAX_EVENTS,
+            gas_reserve: 0,
+            events: Vec::new(),
+        }
+    }
+
+    /// Make the batch due every `max_events` events (at least one).
+    #[must_use]
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = max_events.max(1);
+        self
+    }
+
+    /// Make the batch due as soon as less than `points` of gas are left.
+    #[must_use]
+    pub fn gas_reserve(mut self, points: u64) -> Self {
+        self.gas_reserve = points;
+        self
+    }
+
+    /// The topic batches are emitted under.
+    #[must_use]
+    pub const fn topic(&self) -> &'static str {
+        self.topic
+    }
+
+    /// Number of buffered events.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no event is buffered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Buffer `event`, returning the batch if it is now due given the
+    /// `gas_left` in the call.
+    #[must_use]
+    pub fn push(&mut self, event: T, gas_left: u64) -> Option<Batch<T>> {
+        self.events.push(event);
+        if self.events.len() >= self.max_events || gas_left < self.gas_reserve {
+            self.take()
+        } else {
+            None
+        }
+    }
+
+    /// The buffered events, or `None` if there are none.
+    #[must_use]
+    pub fn take(&mut self) -> Option<Batch<T>> {
+        if self.events.is_empty() {
+            None
+        } else {
+            Some(Batch {
+                events: core::mem::take(&mut self.events),
+            })
+        }
+    }
+}
+
+/// Indexed fields of event types without `#[event(indexed)]` fields.
+///
+/// `#[derive(Event)]` gives structs with indexed fields an inherent
+/// `INDEXED` const, which takes precedence over this one, so the schema can
+/// read `<Data>::INDEXED` for any event data type.
+#[doc(hidden)]
+pub trait Unindexed {
+    /// No indexed fields.
+    const INDEXED: &'static [&'static str] = &[];
+}
+
+impl<T: ?Sized> Unindexed for T {}
+
+/// Version of event types without `#[event(version = N)]`.
+///
+/// `#[derive(Event)]` gives versioned structs an inherent `VERSION` const,
+/// which takes precedence over this one, so the schema can read
+/// `<Data>::VERSION` for any event data type.
+#[doc(hidden)]
+pub trait Unversioned {
+    /// The first version.
+    const VERSION: u32 = 1;
+}
+
+impl<T: ?Sized> Unversioned for T {}
+
+/// Field units of event types without `#[event(unit = "...")]` fields.
+///
+/// `#[derive(Event)]` gives structs with such fields an inherent `UNITS`
+/// const, which takes precedence over this one, so the schema can read
+/// `<Data>::UNITS` for any event data type.
+#[doc(hidden)]
+pub trait Unitless {
+    /// No field units.
+    const UNITS: &'static [crate::schema::FieldUnit] = &[];
+}
+
+impl<T: ?Sized> Unitless for T {}
+
+/// Events recorded by a call, as listed in a `dusk_vm::CallReceipt`.
+///
+/// Implemented for event slices and, with the `vm` feature, for
+/// `dusk_vm::CallReceipt` itself, so `from_receipt` helpers take either.
+pub trait EventLog {
+    /// The recorded events, in emission order.
+    fn events(&self) -> &[Event];
+}
+
+impl EventLog for [Event] {
+    fn events(&self) -> &[Event] {
+        self
+    }
+}
+
+impl EventLog for Vec<Event> {
+    fn events(&self) -> &[Event] {
+        self
+    }
+}
+
+#[cfg(feature = "vm")]
+impl<T> EventLog for dusk_vm::CallReceipt<T> {
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+/// Event data types decodable from the rkyv bytes of an event.
+///
+/// Implemented for every type whose archive can be checked, which is what
+/// `#[archive_attr(derive(CheckBytes))]` on an rkyv type provides.
+pub trait Decode: Sized {
+    /// Check and deserialize `data`, or `None` if it is not a valid archive
+    /// of the type.
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+impl<T> Decode for T
+where
+    T: Archive,
+    for<'a> T::Archived: CheckBytes<DefaultValidator<'a>> + Deserialize<T, Infallible>,
+{
+    fn decode(data: &[u8]) -> Option<Self> {
+        let archived = rkyv::check_archived_root::<T>(data).ok()?;
+        archived.deserialize(&mut Infallible).ok()
+    }
+}
+
+/// The data of the events emitted under `topic` in `log`, in emission order.
+///
+/// Events from every contract in the call tree are included, since a
+/// receipt lists them all.
+///
+/// # Panics
+///
+/// Panics if the data of an event under `topic` does not decode as `T`, so
+/// a test reading the wrong type fails instead of missing events.
+#[must_use]
+pub fn decode_all<T: Decode>(log: &(impl EventLog + ?Sized), topic: &str) -> Vec<T> {
+    log.events()
+        .iter()
+        .filter(|event| event.topic == topic)
+        .map(|event| {
+            T::decode(&event.data).unwrap_or_else(|| {
+                panic!(
+                    "event \"{topic}\" does not decode as {}",
+                    core::any::type_name::<T>()
+                )
+            })
+        })
+        .collect()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward {
+    ($topic:expr, $raw:expr $(,)?) => {
+        $crate::__dusk_core::abi::emit_raw($topic, $raw)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __batch {
+    ($batcher:expr, $event:expr $(,)?) => {{
+        let gas_left =
+            $crate::__dusk_core::abi::limit().saturating_sub($crate::__dusk_core::abi::spent());
+        let batcher = &mut $batcher;
+        if let Some(batch) = batcher.push($event, gas_left) {
+            $crate::__dusk_core::abi::emit(batcher.topic(), batch);
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flush {
+    ($batcher:expr $(,)?) => {{
+        let batcher = &mut $batcher;
+        if let Some(batch) = batcher.take() {
+            $crate::__dusk_core::abi::emit(batcher.topic(), batch);
+        }
+    }};
+}
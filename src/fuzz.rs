@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `arbitrary` generators for Dusk types that don't implement
+//! `arbitrary::Arbitrary`, for fuzzing contract inputs.
+//!
+//! `#[contract_input]` derives `Arbitrary` behind the crate's `arbitrary`
+//! feature; fields of these types name their generator with
+//! `#[arbitrary(with = ...)]`:
+//!
+//! ```ignore
+//! #[dusk_forge::contract_input]
+//! pub struct Transfer {
+//!     #[arbitrary(with = dusk_forge::fuzz::public_key)]
+//!     pub to: PublicKey,
+//!     pub amount: u64,
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// The trait [`samples`] draws values through, for code that cannot name
+/// the `arbitrary` crate itself.
+pub use arbitrary::Arbitrary;
+use arbitrary::{Result, Unstructured};
+use dusk_core::BlsScalar;
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::{PublicKey, SecretKey};
+
+/// A contract ID of arbitrary bytes.
+///
+/// # Errors
+///
+/// Returns an error if `u` cannot provide the bytes.
+pub fn contract_id(u: &mut Unstructured<'_>) -> Result<ContractId> {
+    Ok(ContractId::from_bytes(u.arbitrary()?))
+}
+
+/// A valid BLS public key, derived from an arbitrary `u64` secret so that
+/// distinct inputs give distinct keys.
+///
+/// # Errors
+///
+/// Returns an error if `u` cannot provide the secret.
+pub fn public_key(u: &mut Unstructured<'_>) -> Result<PublicKey> {
+    let secret = SecretKey::from(BlsScalar::from(u.arbitrary::<u64>()?));
+    Ok(PublicKey::from(&secret))
+}
+
+/// Number of values [`samples`] draws.
+const SAMPLES: u64 = 4;
+
+/// Length of the byte buffer each sample is drawn from.
+const SAMPLE_LEN: usize = 512;
+
+/// A few values of `T` drawn from fixed pseudo-random bytes, so that every
+/// run sees the same ones. Unlike `T::default()`, they fill collections and
+/// reach other enum variants; the generated data-driver round-trip tests
+/// use them. Draws that `T` rejects are left out.
+#[must_use]
+pub fn samples<T: for<'a> Arbitrary<'a>>() -> Vec<T> {
+    (1..=SAMPLES)
+        .filter_map(|seed| {
+            let bytes = sample_bytes(seed);
+            T::arbitrary(&mut Unstructured::new(&bytes)).ok()
+        })
+        .collect()
+}
+
+/// Bytes from an xorshift generator seeded with `seed`.
+fn sample_bytes(seed: u64) -> [u8; SAMPLE_LEN] {
+    let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    let mut bytes = [0; SAMPLE_LEN];
+    for chunk in bytes.chunks_exact_mut(8) {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        chunk.copy_from_slice(&state.to_le_bytes());
+    }
+    bytes
+}
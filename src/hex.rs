@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Serde helpers encoding byte fields as hex strings.
+//!
+//! Used through `#[serde(with = "dusk_forge::hex")]`, which
+//! `#[contract_input]` emits for fields marked `#[hex]`. Works for any field
+//! that is `AsRef<[u8]>` and `TryFrom<Vec<u8>>`, e.g. `[u8; N]` and
+//! `Vec<u8>`. Byte array newtypes get the same encoding, as well as
+//! `Display` and `FromStr`, from `#[derive(HexBytes)]`.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Why a hex string could not be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The string has an odd number of digits.
+    OddLength,
+    /// The string holds a character that is not a hex digit.
+    InvalidDigit,
+    /// The string decodes to this many bytes, the wrong number for the type.
+    Length(usize),
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => f.write_str("hex string has an odd number of digits"),
+            Self::InvalidDigit => f.write_str("invalid hex digit"),
+            Self::Length(len) => write!(f, "unexpected byte length {len}"),
+        }
+    }
+}
+
+/// Encode bytes as a lowercase hex string, without prefix.
+#[must_use]
+pub fn encode(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push(char::from(DIGITS[usize::from(byte >> 4)]));
+        hex.push(char::from(DIGITS[usize::from(byte & 0x0f)]));
+    }
+    hex
+}
+
+/// Decode a hex string, with or without a `0x` prefix.
+///
+/// # Errors
+///
+/// Returns an error if the string is not valid hex.
+pub fn decode(hex: &str) -> Result<Vec<u8>, HexError> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return Err(HexError::OddLength);
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| Some((nibble(pair[0])? << 4) | nibble(pair[1])?))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or(HexError::InvalidDigit)
+}
+
+/// Serialize bytes as a lowercase hex string.
+///
+/// # Errors
+///
+/// Returns the serializer's error if writing the string fails.
+pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    serializer.serialize_str(&encode(bytes.as_ref()))
+}
+
+/// Deserialize bytes from a hex string, with or without a `0x` prefix.
+///
+/// # Errors
+///
+/// Returns an error if the string is not valid hex or does not decode to a
+/// value of the field's type (e.g. an array of the wrong length).
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<Vec<u8>>,
+{
+    let hex = String::deserialize(deserializer)?;
+    let bytes = decode(&hex).map_err(D::Error::custom)?;
+    let len = bytes.len();
+
+    T::try_from(bytes).map_err(|_| D::Error::custom(HexError::Length(len)))
+}
+
+fn nibble(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
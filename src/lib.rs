@@ -17,5 +17,189 @@
 /// Contract schema types and utilities.
 pub mod schema;
 
+/// The cell holding the contract state.
+pub mod state;
+
+/// Event helpers for contracts.
+pub mod events;
+
+/// Hex encoding for byte fields in serde representations.
+pub mod hex;
+
+/// Host-call counters for metered contract builds.
+pub mod metering;
+
+/// Inter-contract calls with typed returns and re-entry protection.
+pub mod call;
+
+/// Self-description export for deployed contracts.
+pub mod metadata;
+
+/// Per-caller call windows for rate-limited methods.
+pub mod cooldown;
+
+/// State size and item counts for the `__storage_stats` export.
+pub mod storage;
+
+/// Failure messages with the function name and an error code.
+pub mod revert;
+
+/// Pause switch guarding `#[contract(when_not_paused)]` methods.
+pub mod pause;
+
+/// Bounded `u64` settings with a standard change event.
+pub mod config;
+
+/// Encrypted state fields for sensitive metadata.
+pub mod sealed;
+
+/// Checked Lux/Wei conversions for bridge-like contracts.
+pub mod units;
+
+/// Versioned encoding of Dusk addresses in bridge `extra_data`.
+pub mod address_codec;
+
+/// `arbitrary` generators for fuzzing contract inputs.
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+
+/// Mock contracts for integration tests.
+pub mod mock;
+
+/// Local VM sessions for contract integration tests.
+#[cfg(feature = "vm")]
+pub mod testing;
+
+/// Common contract-building imports, for `use dusk_forge::prelude::*;`.
+pub mod prelude;
+
+/// `dusk_core`, for the macros below to reach its host calls however the
+/// contract names (or renames) the crate.
+#[doc(hidden)]
+pub use dusk_core as __dusk_core;
+/// Re-export the derive generating event topic consts.
+pub use dusk_forge_contract::Event;
+/// Re-export the derive encoding byte array newtypes as hex.
+pub use dusk_forge_contract::HexBytes;
 /// Re-export the contract proc macro.
 pub use dusk_forge_contract::contract;
+/// Re-export the attribute deriving the contract boundary trait stack.
+pub use dusk_forge_contract::contract_input;
+/// Re-export the macro generating a mock contract from an interface.
+pub use dusk_forge_contract::mock_contract;
+
+/// Emit an event, taking the topic from the event type's `TOPIC` const.
+///
+/// `emit!(Event { .. })` expands to
+/// `dusk_core::abi::emit(Event::TOPIC, Event { .. })`, so the topic is always
+/// the one declared next to the event type and a missing `TOPIC` const is a
+/// compile error. Struct, tuple-struct and unit-struct expressions are
+/// accepted:
+///
+/// ```ignore
+/// dusk_forge::emit!(events::CounterUpdated { previous, new: value });
+/// dusk_forge::emit!(events::CounterReset());
+/// ```
+///
+/// The `#[contract]` macro recognizes `emit!` invocations and registers the
+/// event under the `<event path>::TOPIC` topic in the schema and data-driver.
+#[macro_export]
+macro_rules! emit {
+    ($($event:ident)::+ { $($fields:tt)* }) => {
+        $crate::__dusk_core::abi::emit(<$($event)::+>::TOPIC, $($event)::+ { $($fields)* })
+    };
+    ($($event:ident)::+ ( $($args:tt)* )) => {
+        $crate::__dusk_core::abi::emit(<$($event)::+>::TOPIC, $($event)::+ ( $($args)* ))
+    };
+    ($($event:ident)::+) => {
+        $crate::__dusk_core::abi::emit(<$($event)::+>::TOPIC, $($event)::+)
+    };
+}
+
+/// Call another contract, mapping failures to [`call::CallError`].
+///
+/// `call!(contract_id, "fn", &args)` wraps
+/// `dusk_core::abi::call(contract_id, "fn", &args)`, with the argument and
+/// return types inferred from the context. An explicit
+/// `; Args => Ret` suffix fixes them:
+///
+/// ```ignore
+/// let balance: u64 = dusk_forge::call!(TOKEN_ID, "balance_of", &account)?;
+/// dusk_forge::call!(TOKEN_ID, "transfer", &(to, value); (PublicKey, u64) => ())?;
+/// ```
+///
+/// When the module names the callee's interface with
+/// `#[contract(calls = "token.forge-abi")]`, the `#[contract]` macro fills in
+/// the types from the interface and rejects calls to functions it does not
+/// declare. It also generates a `TokenId` newtype of `ContractId`, and calls
+/// to the interface's functions take either a `TokenId` or an untyped
+/// `ContractId` (see [`call::Callee`]). The exported wrappers of a contract
+/// using `call!` panic if the contract is re-entered while a call is in
+/// progress.
+#[macro_export]
+macro_rules! call {
+    ($contract:expr, $fn_name:expr, $args:expr $(,)?) => {{
+        let _call = $crate::call::OutgoingCall::start();
+        $crate::__dusk_core::abi::call($contract, $fn_name, $args).map_err($crate::__call_error!())
+    }};
+    ($contract:expr, $fn_name:expr, $args:expr; $args_ty:ty => $ret_ty:ty) => {{
+        let _call = $crate::call::OutgoingCall::start();
+        $crate::__dusk_core::abi::call::<$args_ty, $ret_ty>($contract, $fn_name, $args)
+            .map_err($crate::__call_error!())
+    }};
+}
+
+/// Fail the call with an error code, naming the exported function.
+///
+/// `revert!("CODE", "message {}", args)` panics with
+/// `<function>: [CODE] message ...`, where `<function>` is the exported
+/// function being called. The code must be a string literal, so the
+/// `#[contract]` macro can list it in the function's `errors` in the schema:
+///
+/// ```ignore
+/// if amount > self.balance {
+///     dusk_forge::revert!("INSUFFICIENT_BALANCE", "balance is {}", self.balance);
+/// }
+/// ```
+///
+/// See [`revert::Revert`] to parse the message on the caller's side.
+#[macro_export]
+macro_rules! revert {
+    ($code:literal, $($message:tt)+) => {
+        $crate::revert::fail($code, format_args!($($message)+))
+    };
+}
+
+/// Fail the call with an error code unless a condition holds.
+///
+/// `ensure!(cond, "CODE", "message")` is
+/// `if !cond { revert!("CODE", "message") }`:
+///
+/// ```ignore
+/// dusk_forge::ensure!(self.is_owner(), "NOT_OWNER", "caller not owner");
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($condition:expr, $code:literal, $($message:tt)+) => {
+        if !$condition {
+            $crate::revert!($code, $($message)+)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __call_error {
+    () => {
+        |err| match err {
+            $crate::__dusk_core::abi::ContractError::Panic(msg) => {
+                $crate::call::CallError::Panic(msg)
+            }
+            $crate::__dusk_core::abi::ContractError::OutOfGas => $crate::call::CallError::OutOfGas,
+            $crate::__dusk_core::abi::ContractError::DoesNotExist => {
+                $crate::call::CallError::DoesNotExist
+            }
+            _ => $crate::call::CallError::Unknown,
+        }
+    };
+}
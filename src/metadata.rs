@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Deployment metadata for `#[contract(metadata)]`.
+//!
+//! With the option set, the contract exports `contract_metadata` returning a
+//! [`Metadata`] tuple, so explorers and other contracts can identify a
+//! deployed contract without its off-chain artifacts.
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+
+use crate::schema::Contract;
+
+/// Environment variable read at compile time for the source hash.
+/// `dusk-forge build` sets it to the hex BLAKE3 of the project's sources.
+pub const SOURCE_HASH_ENV: &str = "DUSK_FORGE_SOURCE_HASH";
+
+/// `(name, version, schema hash, source hash)` of a contract.
+///
+/// - `name`: the contract name from its schema.
+/// - `version`: the contract crate's `CARGO_PKG_VERSION`.
+/// - `schema hash`: hex BLAKE3 of the schema JSON, matching the `schema-blake3`
+///   of `dusk-forge verify` attestations.
+/// - `source hash`: the value of [`SOURCE_HASH_ENV`] at compile time, if set.
+pub type Metadata = (String, String, String, Option<String>);
+
+/// Collect the metadata of the contract described by `schema`. Called by the
+/// `contract_metadata` export.
+#[doc(hidden)]
+#[must_use]
+pub fn collect(schema: &Contract, version: &str, source: Option<&str>) -> Metadata {
+    let schema_hash = blake3::hash(schema.to_json().as_bytes());
+    (
+        schema.name.to_string(),
+        version.to_string(),
+        schema_hash.to_hex().as_str().to_string(),
+        source.map(ToString::to_string),
+    )
+}
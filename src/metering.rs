@@ -0,0 +1,49 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Host-call counters for `#[contract(metering)]`.
+//!
+//! With the option set and the contract's `metering` feature enabled, every
+//! `abi::*` call in the contract's impl blocks is preceded by [`record`], and
+//! the contract exports `__forge_host_calls` returning (and resetting) the
+//! counts via [`take`].
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Host function name -> number of calls since the last [`take`].
+static mut COUNTS: Vec<(&str, u64)> = Vec::new();
+
+/// Count one call to the host function `name`.
+pub fn record(name: &'static str) {
+    let counts = &raw mut COUNTS;
+    // SAFETY: contracts execute single-threaded and the reference does not
+    // outlive this call.
+    let counts = unsafe { &mut *counts };
+
+    match counts.iter_mut().find(|(host_call, _)| *host_call == name) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((name, 1)),
+    }
+}
+
+/// Take the counts recorded since the last call, sorted by host function
+/// name.
+#[must_use]
+pub fn take() -> Vec<(String, u64)> {
+    let counts = &raw mut COUNTS;
+    // SAFETY: see `record`.
+    let counts = unsafe { &mut *counts };
+
+    let mut taken: Vec<_> = counts
+        .drain(..)
+        .map(|(name, count)| (name.to_string(), count))
+        .collect();
+    taken.sort_unstable();
+    taken
+}
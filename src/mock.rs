@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Mock contracts for integration tests, built by
+//! [`mock_contract!`](crate::mock_contract!).
+//!
+//! A mock exports every function of a `.forge-abi` interface, so a contract
+//! under test can call it like the real contract. Each function has a
+//! [`Mock`] in a hidden static: calls are recorded there, and answered with
+//! the response the test programmed. Two more exports per function drive it
+//! from the test session:
+//!
+//! - `__mock_<name>` sets the response, typed as the function's output;
+//! - `__mock_calls_<name>` returns the inputs of the calls received since the
+//!   last time it was called, typed as a `Vec` of the function's input.
+//!
+//! ```ignore
+//! let token = session.deploy(MOCK_TOKEN_BYTECODE, ContractData::builder().owner(OWNER))?;
+//! session.call::<_, ()>(token, "__mock_balance_of", &1_000u64, GAS_LIMIT)?;
+//!
+//! session.call::<_, ()>(bridge, "deposit", &(token, 500u64), GAS_LIMIT)?;
+//!
+//! let calls = session.call::<_, Vec<(Account, Account, u64)>>(
+//!     token, "__mock_calls_transfer_from", &(), GAS_LIMIT,
+//! )?;
+//! assert_eq!(calls.data.len(), 1);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::mem;
+
+/// The programmed response and the recorded inputs of a mocked function.
+#[derive(Debug)]
+pub struct Mock<I, O> {
+    response: Option<O>,
+    calls: Vec<I>,
+}
+
+impl<I, O> Mock<I, O> {
+    /// A mock with no response programmed.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            response: None,
+            calls: Vec::new(),
+        }
+    }
+
+    /// A mock answering `response` until programmed otherwise.
+    #[must_use]
+    pub const fn responding(response: O) -> Self {
+        Self {
+            response: Some(response),
+            calls: Vec::new(),
+        }
+    }
+
+    /// Answer the following calls with `response`.
+    pub fn respond(&mut self, response: O) {
+        self.response = Some(response);
+    }
+
+    /// The inputs of the calls recorded since the last time they were taken,
+    /// in call order.
+    pub fn take_calls(&mut self) -> Vec<I> {
+        mem::take(&mut self.calls)
+    }
+}
+
+impl<I, O> Default for Mock<I, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, O: Clone> Mock<I, O> {
+    /// Record a call to `function` with `input` and answer it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no response is programmed.
+    pub fn call(&mut self, input: I, function: &str) -> O {
+        self.calls.push(input);
+        self.response.clone().unwrap_or_else(|| {
+            panic!("no response programmed for `{function}`; call `__mock_{function}` first")
+        })
+    }
+}
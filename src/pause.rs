@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! An emergency stop for `#[contract(when_not_paused)]`.
+//!
+//! A contract keeps a [`Pausable`] field in its state and exports its own
+//! `pause` / `unpause` methods, with whatever access control it needs. The
+//! exported wrapper of every method marked `#[contract(when_not_paused)]`
+//! calls [`Pausable::ensure_not_paused`] on that field before the method
+//! runs:
+//!
+//! ```ignore
+//! pub struct Bridge {
+//!     owner: PublicKey,
+//!     pause: Pausable,
+//! }
+//!
+//! impl Bridge {
+//!     pub fn pause(&mut self) {
+//!         self.only_owner();
+//!         self.pause.pause();
+//!         abi::emit("paused", ());
+//!     }
+//!
+//!     #[contract(when_not_paused)]
+//!     pub fn deposit(&mut self, amount: u64) { /* ... */ }
+//! }
+//! ```
+
+/// Whether a contract is paused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pausable {
+    paused: bool,
+}
+
+impl Pausable {
+    /// A component in the unpaused state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { paused: false }
+    }
+
+    /// Whether the contract is paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pause the contract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contract is already paused.
+    pub fn pause(&mut self) {
+        assert!(!self.paused, "contract is already paused");
+        self.paused = true;
+    }
+
+    /// Resume the contract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contract is not paused.
+    pub fn unpause(&mut self) {
+        assert!(self.paused, "contract is not paused");
+        self.paused = false;
+    }
+
+    /// Refuse a call to `method` while the contract is paused. Called by the
+    /// wrappers of `#[contract(when_not_paused)]` methods.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contract is paused.
+    pub fn ensure_not_paused(&self, method: &str) {
+        assert!(
+            !self.paused,
+            "`{method}` is unavailable while the contract is paused"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! The imports most contract modules start with.
+//!
+//! ```ignore
+//! #[dusk_forge::contract]
+//! mod counter {
+//!     use dusk_forge::prelude::*;
+//!     // ...
+//! }
+//! ```
+//!
+//! `#[contract]` rejects glob imports since it cannot see what they bring
+//! into scope, except for this one: it knows the names exported here and
+//! records the ones the module uses as imports from `dusk_forge::prelude`.
+
+extern crate alloc;
+
+pub use alloc::boxed::Box;
+pub use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+pub use alloc::string::{String, ToString};
+pub use alloc::vec::Vec;
+pub use alloc::{format, vec};
+
+pub use dusk_core::abi::{self, ContractId};
+pub use dusk_core::signatures::bls::PublicKey;
+
+pub use crate::call::CallError;
+pub use crate::{Event, call, contract, contract_input, emit, ensure, revert};
@@ -0,0 +1,137 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Failure messages naming the function and an error code.
+//!
+//! A contract cannot catch its own panics: the runtime's panic handler aborts
+//! the call, and the panic message becomes the error the caller or the
+//! receipt sees. [`revert!`](crate::revert!) and
+//! [`ensure!`](crate::ensure!) give that message a fixed shape,
+//! `<function>: [<code>] <message>`:
+//!
+//! ```ignore
+//! pub fn transfer_ownership(&mut self, new_owner: PublicKey) {
+//!     dusk_forge::ensure!(self.is_owner(), "NOT_OWNER", "caller not owner");
+//!     // ...
+//! }
+//! // fails with "transfer_ownership: [NOT_OWNER] caller not owner"
+//! ```
+//!
+//! Every wrapper `#[contract]` exports records its function's name while
+//! calling it, so the macros name the function even when a helper it calls
+//! fails. The schema lists the error codes a function uses directly, so
+//! clients match on the code rather than on the message.
+//! [`Revert::parse`] splits a failure message back into its parts.
+
+use core::fmt;
+
+/// Name of the exported function being called, or `""` outside a call.
+static mut FUNCTION: &str = "";
+
+/// Record the exported function being called until the returned guard is
+/// dropped. Called by every wrapper `#[contract]` exports.
+#[doc(hidden)]
+#[must_use = "the function is only recorded while the guard is alive"]
+pub fn enter(function: &'static str) -> Entered {
+    // SAFETY: contracts execute single-threaded.
+    let previous = unsafe {
+        let previous = FUNCTION;
+        FUNCTION = function;
+        previous
+    };
+    Entered { previous }
+}
+
+/// Guard returned by [`enter`], restoring the previously recorded function
+/// when the call returns.
+///
+/// The recorded name lives in contract memory, which persists across calls.
+/// Restoring the previous name rather than clearing it keeps the caller's
+/// name once a re-entrant call returns.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Entered {
+    previous: &'static str,
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        // SAFETY: contracts execute single-threaded.
+        unsafe { FUNCTION = self.previous };
+    }
+}
+
+/// The exported function being called, or `""` outside the wrappers
+/// `#[contract]` exports.
+#[must_use]
+pub fn function() -> &'static str {
+    // SAFETY: contracts execute single-threaded.
+    unsafe { FUNCTION }
+}
+
+/// Panic with a failure message for `code`.
+///
+/// # Panics
+///
+/// Always.
+// Kept out of line so `dusk-forge build --deny-panics` sees the panic in
+// `dusk_forge` rather than in each contract function using `revert!`.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn fail(code: &str, message: fmt::Arguments<'_>) -> ! {
+    match function() {
+        "" => panic!("[{code}] {message}"),
+        function => panic!("{function}: [{code}] {message}"),
+    }
+}
+
+/// A failure message produced by [`revert!`](crate::revert!) or
+/// [`ensure!`](crate::ensure!).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Revert<'a> {
+    /// The exported function that failed, or `""` if not recorded.
+    pub function: &'a str,
+    /// The error code.
+    pub code: &'a str,
+    /// The message describing the failure.
+    pub message: &'a str,
+}
+
+impl<'a> Revert<'a> {
+    /// Split a panic message into its function, code and message, or
+    /// `None` if it was not produced by `revert!` or `ensure!`.
+    ///
+    /// Lines before the last one, such as a panic location added by the
+    /// runtime, are ignored.
+    #[must_use]
+    pub fn parse(panic: &'a str) -> Option<Self> {
+        let line = panic.lines().last()?;
+        let (function, rest) = match line.split_once(": [") {
+            Some((function, rest)) if !function.contains('[') => (function, rest),
+            _ => ("", line.strip_prefix('[')?),
+        };
+        let (code, message) = rest.split_once(']')?;
+        if code.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            function,
+            code,
+            message: message.strip_prefix(' ').unwrap_or(message),
+        })
+    }
+}
+
+impl fmt::Display for Revert<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.function.is_empty() {
+            write!(f, "{}: ", self.function)?;
+        }
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
@@ -13,6 +13,11 @@ extern crate alloc;
 
 use serde::Serialize;
 
+/// Environment variable read at compile time for [`Contract::abi`].
+/// `dusk-forge build` sets it to the `dusk-core` version in the project's
+/// `Cargo.lock`.
+pub const ABI_VERSION_ENV: &str = "DUSK_FORGE_ABI_VERSION";
+
 /// Schema for a contract function.
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct Function {
@@ -22,17 +27,122 @@ pub struct Function {
     pub doc: &'static str,
     /// Input type name (or "()" for no input).
     pub input: &'static str,
+    /// Named parameters in declaration order. `input` is their tuple, or the
+    /// single parameter's type.
+    pub inputs: &'static [Parameter],
     /// Output type name (or "()" for no output).
     pub output: &'static str,
+    /// Item type streamed via `abi::feed()` (from `#[contract(feeds = "T")]`),
+    /// or `None` if the function returns its output directly.
+    pub feeds: Option<&'static str>,
+    /// Minimum number of blocks between accepted calls (from
+    /// `#[contract(cooldown_blocks = N)]`), or `None` if unrestricted.
+    pub cooldown: Option<Cooldown>,
+    /// Whether calls are refused while the contract is paused (from
+    /// `#[contract(when_not_paused)]`).
+    pub when_not_paused: bool,
+    /// Error codes of the `dusk_forge::revert!` / `ensure!` calls in the
+    /// function's body, prefixed in its failure messages as `[CODE]`.
+    pub errors: &'static [&'static str],
+    /// Deprecation notice (from `#[contract(deprecated = "...")]`), or `None`
+    /// if the function is current. Deprecated functions stay exported.
+    pub deprecated: Option<Deprecation>,
+    /// Whether explorers and wallets should hide the function by default
+    /// (from `#[contract(internal)]`), as for operator-only maintenance.
+    /// Internal functions stay exported and callable.
+    pub internal: bool,
+    /// The `#[cfg(...)]` predicate the function is compiled under (`all(...)`
+    /// of several), or `None` if it always is. The schema of a build only
+    /// lists the functions its features enable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<&'static str>,
+}
+
+/// Schema for the deprecation of a function.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Deprecation {
+    /// Why the function is deprecated, or what to use instead.
+    pub message: &'static str,
+    /// Name of the function replacing it, if any.
+    pub replacement: Option<&'static str>,
+}
+
+/// Schema for the call window of a rate-limited function.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Cooldown {
+    /// Number of blocks after an accepted call before the next one.
+    pub blocks: u64,
+    /// Whom the window applies to: `"caller"` (each public sender) or
+    /// `"global"` (all callers at once).
+    pub per: &'static str,
+}
+
+/// Schema for a function parameter.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Parameter {
+    /// Parameter name.
+    pub name: &'static str,
+    /// Parameter type name (dereferenced for reference parameters).
+    #[serde(rename = "type")]
+    pub ty: &'static str,
+    /// How the type is written in JSON, if not as its structure: `"hex"`
+    /// for `#[derive(HexBytes)]` types, empty otherwise.
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pub format: &'static str,
+    /// Display unit of the value, from `#[contract(unit = "...")]`: `"lux"`,
+    /// `"dusk"`, `"wei"` or `"blocks"`; empty if unset.
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pub unit: &'static str,
+}
+
+/// Display unit of an event field, from `#[event(unit = "...")]`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FieldUnit {
+    /// Field name, or its index for tuple structs.
+    pub field: &'static str,
+    /// Display unit: `"lux"`, `"dusk"`, `"wei"` or `"blocks"`.
+    pub unit: &'static str,
+}
+
+/// Schema for a field of the contract state.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Field {
+    /// Field name, or its index for tuple structs.
+    pub name: &'static str,
+    /// Field type name.
+    #[serde(rename = "type")]
+    pub ty: &'static str,
+    /// Whether the field is a [`Sealed`](crate::sealed::Sealed) value, stored
+    /// encrypted.
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    pub sealed: bool,
 }
 
 /// Schema for a contract event.
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct Event {
-    /// Event topic string.
+    /// Event topic as written in the contract: a string literal, or the path
+    /// of the const holding it (e.g. `events::Transfer::TOPIC`).
     pub topic: &'static str,
+    /// The topic string the event is emitted under, resolved from the const
+    /// named by `topic`. `None` when `topic` is a bare lowercase identifier,
+    /// which the macro cannot tell apart from a local variable.
+    pub value: Option<&'static str>,
     /// Event data type name.
     pub data: &'static str,
+    /// Whether `init` emits the event, so that it can appear among the
+    /// events of the contract's deployment.
+    pub genesis: bool,
+    /// Fields of the event data marked `#[event(indexed)]`, which indexers
+    /// should make searchable.
+    pub indexed: &'static [&'static str],
+    /// Version of the event data's shape, from `#[event(version = N)]`; 1
+    /// for unversioned events.
+    pub version: u32,
+    /// Fields of the event data with a display unit, so wallets can render
+    /// amounts and block counts.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub units: &'static [FieldUnit],
 }
 
 /// Schema for an imported type.
@@ -44,17 +154,38 @@ pub struct Import {
     pub path: &'static str,
 }
 
+/// Schema for an interface the contract calls through
+/// [`call!`](crate::call!), declared with `#[contract(calls = "...")]`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Callee {
+    /// The contract ID newtype generated for the interface (e.g.,
+    /// `TokenId`).
+    pub id: &'static str,
+    /// The interface file, as written (e.g., `token.forge-abi`).
+    pub interface: &'static str,
+}
+
 /// Complete schema for a contract.
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct Contract {
     /// Contract name.
     pub name: &'static str,
+    /// Version of `dusk-core` the contract was compiled against, from
+    /// [`ABI_VERSION_ENV`]; `None` when built without `dusk-forge build`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abi: Option<&'static str>,
     /// List of imported types with their full paths.
     pub imports: &'static [Import],
+    /// Fields of the contract state in declaration order, the layout
+    /// `dusk-forge migrate-gen` compares between versions.
+    pub state: &'static [Field],
     /// List of contract functions.
     pub functions: &'static [Function],
     /// List of contract events.
     pub events: &'static [Event],
+    /// Interfaces of the contracts called, with their ID newtypes.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub callees: &'static [Callee],
 }
 
 impl Contract {
@@ -68,11 +199,22 @@ impl Contract {
         self.functions.iter()
     }
 
+    /// Returns an iterator over the functions not marked internal, which
+    /// explorers and wallets list by default.
+    pub fn iter_public_functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.iter().filter(|f| !f.internal)
+    }
+
     /// Returns an iterator over all events.
     pub fn iter_events(&self) -> impl Iterator<Item = &Event> {
         self.events.iter()
     }
 
+    /// Returns an iterator over the called interfaces.
+    pub fn iter_callees(&self) -> impl Iterator<Item = &Callee> {
+        self.callees.iter()
+    }
+
     /// Find an import by short name.
     #[must_use]
     pub fn get_import(&self, name: &str) -> Option<&Import> {
@@ -85,10 +227,34 @@ impl Contract {
         self.functions.iter().find(|f| f.name == name)
     }
 
-    /// Find an event by topic.
+    /// The named arguments of the contract's `init`, in order.
+    ///
+    /// These are the fields of the JSON object the data-driver accepts as
+    /// `init` input (`{"owner": ...}`), as an alternative to the positional
+    /// form. Empty if the contract has no `init` or it takes no arguments.
+    #[must_use]
+    pub fn init_args(&self) -> &'static [Parameter] {
+        self.get_function("init").map_or(&[], |init| init.inputs)
+    }
+
+    /// Returns an iterator over the events emitted by `init`, which a
+    /// deployment can produce.
+    pub fn iter_genesis_events(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(|e| e.genesis)
+    }
+
+    /// Returns an iterator over the events with `#[event(indexed)]` fields.
+    pub fn iter_indexed_events(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(|e| !e.indexed.is_empty())
+    }
+
+    /// Find an event by topic, given either as written in the contract or as
+    /// the resolved topic string.
     #[must_use]
     pub fn get_event(&self, topic: &str) -> Option<&Event> {
-        self.events.iter().find(|e| e.topic == topic)
+        self.events
+            .iter()
+            .find(|e| e.topic == topic || e.value == Some(topic))
     }
 
     /// Serialize the schema to a JSON string.
@@ -97,3 +263,16 @@ impl Contract {
         serde_json::to_string(self).unwrap_or_else(|_| alloc::string::String::from("{}"))
     }
 }
+
+/// Format hint of types without `#[derive(HexBytes)]`.
+///
+/// `#[derive(HexBytes)]` gives types an inherent `FORMAT` const, which takes
+/// precedence over this one, so the schema can read `<Type>::FORMAT` for any
+/// parameter type.
+#[doc(hidden)]
+pub trait Unformatted {
+    /// No format hint.
+    const FORMAT: &'static str = "";
+}
+
+impl<T: ?Sized> Unformatted for T {}
@@ -0,0 +1,194 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Encrypted state fields.
+//!
+//! A [`Sealed<T>`] holds the rkyv bytes of a `T` encrypted and authenticated
+//! with a [`SealingKey`], using XChaCha20-Poly1305 under a random nonce. The
+//! key belongs to the user and never reaches the chain: clients seal values
+//! before passing them to the contract and open the ones it returns, while
+//! the contract only stores and returns the sealed bytes, so sensitive
+//! metadata does not sit in the state as plaintext:
+//!
+//! ```ignore
+//! // In the contract
+//! pub struct Registry {
+//!     contacts: BTreeMap<PublicKey, Sealed<Contact>>,
+//! }
+//!
+//! impl Registry {
+//!     pub fn set_contact(&mut self, owner: PublicKey, contact: Sealed<Contact>) {
+//!         self.contacts.insert(owner, contact);
+//!     }
+//!
+//!     pub fn contact(&self, owner: PublicKey) -> Option<Sealed<Contact>> {
+//!         self.contacts.get(&owner).cloned()
+//!     }
+//! }
+//!
+//! // In the user's client
+//! let key = SealingKey::new(&user_secret);
+//! let sealed = key.seal(&contact, &mut OsRng);
+//! // ... call `set_contact` with `sealed`, and later open what `contact`
+//! // returns:
+//! let contact = returned.open(&key).expect("sealed with the user's key");
+//! ```
+//!
+//! Sealing hides the value, not that there is one: the length of the
+//! ciphertext gives away the length of the value's rkyv bytes, and anyone
+//! watching the state sees when a sealed value is written or replaced. The
+//! nonce is drawn anew for every seal, so two seals of the same value cannot
+//! be told apart from seals of different ones. A sealed value is not bound
+//! to the entry holding it, and opens wherever it is copied to.
+//!
+//! The contract state, call arguments included, can be read by anyone
+//! running a node, so a key stored in the state or passed to a call protects
+//! nothing. [`SealingKey`] therefore implements none of the rkyv or serde
+//! traits, and a contract must not hold one.
+//!
+//! The `#[contract]` macro marks the state fields holding `Sealed` values,
+//! directly or inside containers, with `"sealed": true` in the schema.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use chacha20poly1305::aead::rand_core::{CryptoRng, RngCore};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::{Archive, Deserialize, Serialize};
+
+use crate::events::Decode;
+
+/// Context of the key derivation, per the BLAKE3 `derive_key` convention.
+const KEY_CONTEXT: &str = "dusk-forge 2025 sealed field key";
+
+/// Length of an XChaCha20-Poly1305 nonce.
+const NONCE_LEN: usize = 24;
+
+/// Scratch space of the serializer sealing values.
+const SCRATCH_SPACE: usize = 256;
+
+/// The key a user seals their values with, held off-chain.
+#[derive(Clone)]
+pub struct SealingKey {
+    cipher: XChaCha20Poly1305,
+}
+
+impl SealingKey {
+    /// A key derived from `secret`, such as a wallet's secret key bytes or
+    /// random bytes the user keeps.
+    #[must_use]
+    pub fn new(secret: &[u8]) -> Self {
+        let key = blake3::derive_key(KEY_CONTEXT, secret);
+        Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(&key)),
+        }
+    }
+
+    /// Seal `value` under a nonce drawn from `rng`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` fails to serialize, which only an allocation
+    /// failure causes.
+    #[must_use]
+    pub fn seal<T, R>(&self, value: &T, rng: &mut R) -> Sealed<T>
+    where
+        T: Serialize<AllocSerializer<SCRATCH_SPACE>>,
+        R: RngCore + CryptoRng,
+    {
+        let bytes = rkyv::to_bytes::<_, SCRATCH_SPACE>(value).expect("sealed value serializes");
+
+        let mut nonce = [0; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(XNonce::from_slice(&nonce), bytes.as_slice())
+            .expect("sealed value fits in a XChaCha20-Poly1305 message");
+
+        Sealed {
+            nonce,
+            ciphertext,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl fmt::Debug for SealingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SealingKey(..)")
+    }
+}
+
+/// A `T` encrypted with a [`SealingKey`].
+///
+/// The ciphertext is the XChaCha20-Poly1305 encryption of the value's rkyv
+/// bytes, its authentication tag included. Both the nonce and the
+/// ciphertext serialize as hex strings.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Archive,
+    Serialize,
+    Deserialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Sealed<T> {
+    #[serde(with = "crate::hex")]
+    nonce: [u8; NONCE_LEN],
+    #[serde(with = "crate::hex")]
+    ciphertext: Vec<u8>,
+    _value: PhantomData<T>,
+}
+
+impl<T: Decode> Sealed<T> {
+    /// The value, or `None` if it was not sealed with `key` or has been
+    /// tampered with.
+    #[must_use]
+    pub fn open(&self, key: &SealingKey) -> Option<T> {
+        let plaintext = key
+            .cipher
+            .decrypt(XNonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+            .ok()?;
+        let mut bytes = rkyv::AlignedVec::with_capacity(plaintext.len());
+        bytes.extend_from_slice(&plaintext);
+        T::decode(&bytes)
+    }
+
+    /// The value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value was not sealed with `key` or has been tampered
+    /// with.
+    #[must_use]
+    pub fn get(&self, key: &SealingKey) -> T {
+        self.open(key).expect("sealed value opens with the key")
+    }
+}
+
+impl<T> Sealed<T>
+where
+    T: Serialize<AllocSerializer<SCRATCH_SPACE>>,
+{
+    /// Replace the value with `value`, sealed with `key` under a nonce drawn
+    /// from `rng`.
+    pub fn set<R>(&mut self, key: &SealingKey, value: &T, rng: &mut R)
+    where
+        R: RngCore + CryptoRng,
+    {
+        *self = key.seal(value, rng);
+    }
+}
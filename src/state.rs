@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! The cell holding the contract state.
+//!
+//! `#[contract]` stores the state in a
+//! `static STATE: ContractState<Contract>` instead of a `static mut`, so the
+//! generated wrappers borrow it through [`ContractState::state`] and
+//! [`ContractState::state_mut`] without creating references to a mutable
+//! static, which recent compilers warn about (`static_mut_refs`). The hidden
+//! `reinit_guard` counter and `cooldown_blocks` windows live in the same cell.
+
+use core::cell::UnsafeCell;
+
+/// The contract state, borrowed through accessors.
+#[derive(Debug)]
+pub struct ContractState<T>(UnsafeCell<T>);
+
+// SAFETY: contracts execute single-threaded, so the state is never accessed
+// from two threads.
+unsafe impl<T> Sync for ContractState<T> {}
+
+impl<T> ContractState<T> {
+    /// A cell holding `state`, usable as a `static` initializer.
+    #[must_use]
+    pub const fn new(state: T) -> Self {
+        Self(UnsafeCell::new(state))
+    }
+
+    /// Borrow the state.
+    ///
+    /// # Safety
+    ///
+    /// No reference returned by [`state_mut`](Self::state_mut) may be alive
+    /// while the returned one is.
+    #[must_use]
+    pub unsafe fn state(&self) -> &T {
+        // SAFETY: upheld by the caller.
+        unsafe { &*self.0.get() }
+    }
+
+    /// Borrow the state mutably.
+    ///
+    /// # Safety
+    ///
+    /// No other reference to the state may be alive while the returned one
+    /// is. The generated wrappers borrow the state for the duration of one
+    /// exported call.
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn state_mut(&self) -> &mut T {
+        // SAFETY: upheld by the caller.
+        unsafe { &mut *self.0.get() }
+    }
+}
@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Storage statistics for `#[contract(storage_stats)]`.
+//!
+//! With the option set, the contract exports `__storage_stats`, feeding one
+//! [`FieldStats`] per field of the contract state: the field name, its
+//! approximate size in bytes and, for collections, the number of items.
+//!
+//! Sizes count the field and, for collections, their items at their
+//! in-memory size, but not the heap data the items own in turn. They follow
+//! the growth of the state rather than its exact serialized size.
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+/// Name, approximate size in bytes and item count (for collections) of a
+/// state field, as fed by `__storage_stats`.
+pub type FieldStats = (String, u64, Option<u64>);
+
+/// A state field, probed for its statistics.
+///
+/// The generated export calls `(&Field(&state.field)).stats(name)` on the
+/// borrowed contract state: the
+/// method of [`Collection`] is picked for the standard collections, and that
+/// of [`Plain`] for any other type.
+#[derive(Debug)]
+pub struct Field<'a, T>(pub &'a T);
+
+/// Statistics of a collection field.
+pub trait Collection {
+    /// The statistics of the field named `name`.
+    fn stats(&self, name: &str) -> FieldStats;
+}
+
+/// Statistics of a field that is not a collection.
+pub trait Plain {
+    /// The statistics of the field named `name`.
+    fn stats(&self, name: &str) -> FieldStats;
+}
+
+impl<T> Plain for &Field<'_, T> {
+    fn stats(&self, name: &str) -> FieldStats {
+        (String::from(name), size_of::<T>() as u64, None)
+    }
+}
+
+/// Statistics of a collection of type `C` holding `len` items of type `I`.
+fn collection<C, I>(name: &str, len: usize) -> FieldStats {
+    let size = size_of::<C>() + len.saturating_mul(size_of::<I>());
+    (String::from(name), size as u64, Some(len as u64))
+}
+
+impl<T> Collection for Field<'_, Vec<T>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<Vec<T>, T>(name, self.0.len())
+    }
+}
+
+impl<T> Collection for Field<'_, VecDeque<T>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<VecDeque<T>, T>(name, self.0.len())
+    }
+}
+
+impl<T> Collection for Field<'_, LinkedList<T>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<LinkedList<T>, T>(name, self.0.len())
+    }
+}
+
+impl<T> Collection for Field<'_, BinaryHeap<T>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<BinaryHeap<T>, T>(name, self.0.len())
+    }
+}
+
+impl<T> Collection for Field<'_, BTreeSet<T>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<BTreeSet<T>, T>(name, self.0.len())
+    }
+}
+
+impl<K, V> Collection for Field<'_, BTreeMap<K, V>> {
+    fn stats(&self, name: &str) -> FieldStats {
+        collection::<BTreeMap<K, V>, (K, V)>(name, self.0.len())
+    }
+}
+
+impl Collection for Field<'_, String> {
+    fn stats(&self, name: &str) -> FieldStats {
+        let size = size_of::<String>() + self.0.len();
+        (String::from(name), size as u64, None)
+    }
+}
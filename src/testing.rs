@@ -0,0 +1,636 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Local VM sessions for contract integration tests.
+//!
+//! A [`TestSession`] runs a `dusk-vm` session with the transfer and stake
+//! contracts deployed at genesis, so contracts can be called through the
+//! transfer contract as on the network, or directly:
+//!
+//! ```ignore
+//! let mut session = TestSession::instantiate(&[(&public_key, dusk(1_000.0))], &[]);
+//! session.deploy(bytecode, ContractData::builder().owner(OWNER).contract_id(ID))?;
+//! session.call_public::<_, ()>(&secret_key, ID, "increment", &())?;
+//!
+//! session.advance_blocks(10);
+//! session.call_public::<_, ()>(&secret_key, ID, "claim", &())?;
+//! ```
+//!
+//! A [`Harness`] builds such a session from named accounts and the
+//! [`Deployable`] contracts to deploy.
+
+extern crate alloc;
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use std::fs;
+use std::sync::mpsc::Sender;
+
+use dusk_core::abi::{
+    CONTRACT_ID_BYTES, ContractError, ContractId, Metadata, StandardBufSerializer,
+};
+use dusk_core::signatures::bls::{PublicKey as AccountPublicKey, SecretKey as AccountSecretKey};
+use dusk_core::stake::STAKE_CONTRACT;
+use dusk_core::transfer::data::ContractCall;
+use dusk_core::transfer::moonlight::AccountData;
+use dusk_core::transfer::phoenix::{Note, PublicKey as ShieldedPublicKey};
+use dusk_core::transfer::{TRANSFER_CONTRACT, Transaction};
+use dusk_core::{JubJubScalar, LUX};
+use dusk_vm::host_queries::{self, HardFork};
+use dusk_vm::{CallReceipt, ContractData, Error as VMError, ExecutionConfig, Session, VM, execute};
+use ff::Field;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rkyv::bytecheck::CheckBytes;
+use rkyv::ser::Serializer;
+use rkyv::ser::serializers::{BufferScratch, BufferSerializer, CompositeSerializer};
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{Archive, Deserialize, Infallible, Serialize, check_archived_root};
+
+const ZERO_ADDRESS: ContractId = ContractId::from_bytes([0; CONTRACT_ID_BYTES]);
+const DEPLOYER: [u8; 64] = [0u8; 64];
+const GAS_LIMIT: u64 = 0x10_000_000;
+const CHAIN_ID: u8 = 0x1;
+const CONFIG: ExecutionConfig = ExecutionConfig {
+    gas_per_deploy_byte: 0u64,
+    gas_per_blob: 0u64,
+    min_deploy_points: 0u64,
+    min_deploy_gas_price: 0u64,
+    with_public_sender: true,
+    with_blob: true,
+    disable_wasm64: false,
+    disable_wasm32: false,
+    disable_3rd_party: false,
+    phoenix_refund_check: false,
+};
+
+/// VM session that has the transfer and stake contracts deployed and behaves
+/// like a mainnet VM.
+///
+/// The VM is kept to reopen the session at another block height.
+pub struct TestSession(pub Session, VM);
+
+impl fmt::Debug for TestSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestSession").finish_non_exhaustive()
+    }
+}
+
+impl TestSession {
+    /// Instantiate the virtual machine with both the transfer and stake
+    /// contracts deployed, and fund the given public and shielded keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if deploying the genesis contracts or funding a key fails.
+    #[must_use]
+    pub fn instantiate(
+        public_pks: &[(&AccountPublicKey, u64)],
+        shielded_pks: &[(&ShieldedPublicKey, u64)],
+    ) -> Self {
+        let vm = VM::ephemeral().expect("Creating VM should succeed");
+
+        let mut session = VM::genesis_session(&vm, CHAIN_ID);
+
+        let transfer_contract = include_bytes!("testing/genesis-contracts/transfer_contract.wasm");
+        session
+            .deploy(
+                transfer_contract,
+                ContractData::builder()
+                    .owner(ZERO_ADDRESS.to_bytes())
+                    .contract_id(TRANSFER_CONTRACT),
+                GAS_LIMIT,
+            )
+            .expect("Deploying the transfer contract should succeed");
+
+        let stake_contract = include_bytes!("testing/genesis-contracts/stake_contract.wasm");
+        session
+            .deploy(
+                stake_contract,
+                ContractData::builder()
+                    .owner(ZERO_ADDRESS.to_bytes())
+                    .contract_id(STAKE_CONTRACT),
+                GAS_LIMIT,
+            )
+            .expect("Deploying the stake contract should succeed");
+
+        // fund shielded keys with DUSK
+        let mut rng = StdRng::seed_from_u64(0xBEEF);
+        for (pos, &(pk, value)) in shielded_pks.iter().enumerate() {
+            let value_blinder = JubJubScalar::random(&mut rng);
+            let sender_blinder = [
+                JubJubScalar::random(&mut rng),
+                JubJubScalar::random(&mut rng),
+            ];
+
+            let note = Note::obfuscated(&mut rng, pk, pk, value, value_blinder, sender_blinder);
+            session
+                .call::<_, Note>(TRANSFER_CONTRACT, "push_note", &(pos, note), GAS_LIMIT)
+                .expect("Pushing genesis note should succeed");
+        }
+        // update the root after the notes have been inserted
+        session
+            .call::<_, ()>(TRANSFER_CONTRACT, "update_root", &(), GAS_LIMIT)
+            .expect("Updating the root should succeed");
+
+        // fund public keys with DUSK
+        for &(pk, value) in public_pks {
+            session
+                .call::<_, ()>(
+                    TRANSFER_CONTRACT,
+                    "add_account_balance",
+                    &(*pk, value),
+                    GAS_LIMIT,
+                )
+                .expect("Add account balance should succeed");
+        }
+
+        let base = session.commit().expect("Committing should succeed");
+
+        let session = vm
+            .session(base, CHAIN_ID, 1)
+            .expect("Instantiating new session should succeed");
+        let mut session = TestSession(session, vm);
+
+        for &(pk, value) in public_pks {
+            let account = session
+                .account(pk)
+                .expect("Getting the account should succeed");
+            assert_eq!(
+                account.balance, value,
+                "The account should own the specified value"
+            );
+            assert_eq!(account.nonce, 0);
+        }
+
+        assert_eq!(
+            session.chain_id(),
+            CHAIN_ID,
+            "the chain id should be as expected"
+        );
+
+        session
+    }
+
+    /// Deploy the `bytecode` of a contract with the maximum gas limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the VM's error if the deployment fails.
+    pub fn deploy<'a, A, D>(
+        &mut self,
+        bytecode: &[u8],
+        deploy_data: D,
+    ) -> Result<ContractId, VMError>
+    where
+        A: 'a + for<'b> Serialize<StandardBufSerializer<'b>>,
+        D: Into<ContractData<'a, A>>,
+    {
+        self.0.deploy(bytecode, deploy_data, u64::MAX)
+    }
+
+    /// Query the transfer contract for the current chain ID.
+    fn chain_id(&self) -> u8 {
+        rkyv_deserialize(
+            self.0
+                .meta(Metadata::CHAIN_ID)
+                .expect("the session should have a chain ID"),
+        )
+    }
+
+    /// Query the transfer contract for the account linked to a public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the VM's error if the query fails.
+    pub fn account(&mut self, pk: &AccountPublicKey) -> Result<AccountData, VMError> {
+        self.0
+            .call(TRANSFER_CONTRACT, "account", pk, GAS_LIMIT)
+            .map(|r| r.data)
+    }
+
+    /// Call a contract through the transfer contract, the standard way any
+    /// contract is called on the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns the contract's error if the call fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sender has no account or the transaction cannot be
+    /// executed.
+    pub fn call_public<A, R>(
+        &mut self,
+        sender_sk: &AccountSecretKey,
+        contract: ContractId,
+        fn_name: &str,
+        fn_arg: &A,
+    ) -> Result<CallReceipt<R>, ContractError>
+    where
+        A: for<'b> Serialize<StandardBufSerializer<'b>>,
+        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
+        R: Archive,
+        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
+    {
+        self.call_public_with_deposit(sender_sk, contract, fn_name, fn_arg, 0)
+    }
+
+    /// Call a contract through the transfer contract with a deposit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the contract's error if the call fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sender has no account or the transaction cannot be
+    /// executed.
+    pub fn call_public_with_deposit<A, R>(
+        &mut self,
+        sender_sk: &AccountSecretKey,
+        contract: ContractId,
+        fn_name: &str,
+        fn_arg: &A,
+        deposit: u64,
+    ) -> Result<CallReceipt<R>, ContractError>
+    where
+        A: for<'b> Serialize<StandardBufSerializer<'b>>,
+        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
+        R: Archive,
+        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
+    {
+        let contract_call = ContractCall {
+            contract,
+            fn_name: String::from(fn_name),
+            fn_args: rkyv_serialize(fn_arg),
+        };
+
+        let moonlight_pk = AccountPublicKey::from(sender_sk);
+
+        let AccountData { nonce, .. } = self
+            .account(&moonlight_pk)
+            .expect("Getting the account should succeed");
+
+        let transaction = Transaction::moonlight(
+            sender_sk,
+            None,
+            0,
+            deposit,
+            GAS_LIMIT,
+            LUX,
+            nonce + 1,
+            CHAIN_ID,
+            Some(contract_call),
+        )
+        .expect("Creating moonlight transaction should succeed");
+
+        let _hf = host_queries::set_hard_fork(HardFork::Aegis);
+        let receipt = execute(&mut self.0, &transaction, &CONFIG)
+            .unwrap_or_else(|e| panic!("Unspendable transaction due to '{e}'"));
+
+        receipt.data.map(|serialized| CallReceipt {
+            gas_limit: receipt.gas_limit,
+            gas_spent: receipt.gas_spent,
+            events: receipt.events,
+            call_tree: receipt.call_tree,
+            data: rkyv_deserialize(&serialized),
+        })
+    }
+
+    /// Call a contract directly, bypassing the transfer contract and with it
+    /// the gas payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the contract's panic or out-of-gas error.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any other VM error.
+    pub fn direct_call<A, R>(
+        &mut self,
+        contract: ContractId,
+        fn_name: &str,
+        fn_arg: &A,
+    ) -> Result<CallReceipt<R>, ContractError>
+    where
+        A: for<'b> Serialize<StandardBufSerializer<'b>>,
+        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
+        R: Archive,
+        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
+    {
+        self.0
+            .call::<_, R>(contract, fn_name, fn_arg, u64::MAX)
+            .map_err(contract_error)
+    }
+
+    /// Call a contract directly, with `feeder` receiving what the contract
+    /// feeds to the host, as streaming functions do.
+    ///
+    /// # Errors
+    ///
+    /// Returns the contract's panic or out-of-gas error.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any other VM error.
+    pub fn feeder_call<A, R>(
+        &mut self,
+        contract: ContractId,
+        fn_name: &str,
+        fn_arg: &A,
+        feeder: Sender<Vec<u8>>,
+    ) -> Result<CallReceipt<R>, ContractError>
+    where
+        A: for<'b> Serialize<StandardBufSerializer<'b>>,
+        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
+        R: Archive,
+        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
+    {
+        self.0
+            .feeder_call::<_, R>(contract, fn_name, fn_arg, u64::MAX, feeder)
+            .map_err(contract_error)
+    }
+
+    /// The block height calls are executed at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session has no block height.
+    #[must_use]
+    pub fn block_height(&self) -> u64 {
+        rkyv_deserialize(
+            self.0
+                .meta(Metadata::BLOCK_HEIGHT)
+                .expect("the session should have a block height"),
+        )
+    }
+
+    /// Move the session to block `height`, keeping its state, so logic
+    /// depending on elapsed blocks (cooldowns, pending withdrawals maturing
+    /// after a finalization period) can be tested.
+    ///
+    /// A `dusk-vm` session executes at a fixed height, so the session is
+    /// committed and reopened on top of that commit at the new height.
+    ///
+    /// # Panics
+    ///
+    /// Panics if committing or reopening the session fails.
+    pub fn set_block_height(&mut self, height: u64) {
+        // `commit` consumes the session, so a blank one stands in for it
+        // until the committed state is reopened
+        let blank = VM::genesis_session(&self.1, CHAIN_ID);
+        let base = core::mem::replace(&mut self.0, blank)
+            .commit()
+            .expect("Committing should succeed");
+        self.0 = self
+            .1
+            .session(base, CHAIN_ID, height)
+            .expect("Instantiating new session should succeed");
+    }
+
+    /// Advance the session by `blocks` blocks. See
+    /// [`set_block_height`](Self::set_block_height).
+    ///
+    /// # Panics
+    ///
+    /// Panics if committing or reopening the session fails.
+    pub fn advance_blocks(&mut self, blocks: u64) {
+        let height = self.block_height() + blocks;
+        self.set_block_height(height);
+    }
+}
+
+/// A contract [`Harness`] fixtures can deploy.
+pub trait Deployable {
+    /// Path of the contract WASM, read when the contract is deployed.
+    const WASM: &'static str;
+    /// The ID the contract is deployed under.
+    const ID: ContractId;
+}
+
+/// A deployment queued on a [`Harness`], run once the session exists.
+type Deployment = Box<dyn FnOnce(&mut TestSession)>;
+
+/// Fluent builder of a [`TestSession`] with funded accounts and deployed
+/// contracts:
+///
+/// ```ignore
+/// let mut session = Harness::new()
+///     .account("alice", dusk(1_000.0))
+///     .deploy::<Counter>((public_key("alice"),))
+///     .build();
+/// session.call_public::<_, ()>(&secret_key("alice"), Counter::ID, "increment", &())?;
+/// ```
+///
+/// Accounts are named; their keys are derived from the name, so
+/// [`secret_key`] and [`public_key`] return the same keys in every test and
+/// can be used before the session is built, e.g. as init arguments.
+///
+/// Sessions always start from a local genesis: a `dusk-vm` session can only
+/// open state committed to its own VM, so there is no way to fork the state
+/// of a live network into it. To test against deployed state, deploy the
+/// contract with the same init arguments and replay the calls that led to
+/// that state.
+#[derive(Default)]
+pub struct Harness {
+    accounts: Vec<(AccountPublicKey, u64)>,
+    deployments: Vec<Deployment>,
+}
+
+impl fmt::Debug for Harness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Harness")
+            .field("accounts", &self.accounts.len())
+            .field("deployments", &self.deployments.len())
+            .finish()
+    }
+}
+
+impl Harness {
+    /// Start a fixture with no accounts and no contracts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fund the account named `name` with `balance` LUX at genesis.
+    #[must_use]
+    pub fn account(mut self, name: &str, balance: u64) -> Self {
+        self.accounts.push((public_key(name), balance));
+        self
+    }
+
+    /// Deploy `C` under [`Deployable::ID`] with `init_arg` passed to its
+    /// `init`, after the accounts are funded.
+    ///
+    /// # Panics
+    ///
+    /// The deployment, run by [`build`](Self::build), panics if the contract
+    /// WASM cannot be read or the deployment fails.
+    #[must_use]
+    pub fn deploy<C: Deployable>(
+        mut self,
+        init_arg: impl 'static + for<'b> Serialize<StandardBufSerializer<'b>>,
+    ) -> Self {
+        self.deployments.push(Box::new(move |session| {
+            let bytecode = fs::read(C::WASM).unwrap_or_else(|err| {
+                panic!(
+                    "reading {} should succeed (build the contract first): {err}",
+                    C::WASM
+                )
+            });
+            let id = session
+                .deploy(
+                    &bytecode,
+                    ContractData::builder()
+                        .owner(DEPLOYER)
+                        .init_arg(&init_arg)
+                        .contract_id(C::ID),
+                )
+                .expect("Deploying the contract should succeed");
+            assert_eq!(id, C::ID, "the contract should be deployed at its ID");
+        }));
+        self
+    }
+
+    /// Instantiate the session, funding the accounts and deploying the
+    /// contracts in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a contract WASM cannot be read or a deployment fails.
+    #[must_use]
+    pub fn build(self) -> TestSession {
+        let accounts: Vec<_> = self
+            .accounts
+            .iter()
+            .map(|(pk, balance)| (pk, *balance))
+            .collect();
+        let mut session = TestSession::instantiate(&accounts, &[]);
+        for deployment in self.deployments {
+            deployment(&mut session);
+        }
+        session
+    }
+}
+
+/// The secret key of the [`Harness`] account named `name`.
+#[must_use]
+pub fn secret_key(name: &str) -> AccountSecretKey {
+    // FNV-1a, so keys stay the same across toolchains
+    let seed = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    AccountSecretKey::random(&mut StdRng::seed_from_u64(seed))
+}
+
+/// The public key of the [`Harness`] account named `name`.
+#[must_use]
+pub fn public_key(name: &str) -> AccountPublicKey {
+    AccountPublicKey::from(&secret_key(name))
+}
+
+/// The [`ContractError`] of a failed direct call.
+fn contract_error(err: VMError) -> ContractError {
+    match err {
+        VMError::Panic(panic_msg) => ContractError::Panic(panic_msg),
+        VMError::OutOfGas => ContractError::OutOfGas,
+        _ => panic!("Unknown error: {err}"),
+    }
+}
+
+/// Deserialize using `rkyv`.
+///
+/// # Panics
+///
+/// Panics if `serialized` is not a valid archive of `R`.
+#[must_use]
+pub fn rkyv_deserialize<R>(serialized: impl AsRef<[u8]>) -> R
+where
+    R: Archive,
+    R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
+{
+    let archived =
+        check_archived_root::<R>(serialized.as_ref()).expect("Failed to deserialize data");
+    archived
+        .deserialize(&mut Infallible)
+        .expect("Failed to deserialize using rkyv")
+}
+
+/// Serialize using `rkyv`.
+///
+/// # Panics
+///
+/// Panics if `fn_arg` does not fit a 4 KiB page.
+#[must_use]
+pub fn rkyv_serialize<A>(fn_arg: &A) -> Vec<u8>
+where
+    A: for<'b> Serialize<StandardBufSerializer<'b>>,
+    A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
+{
+    const SCRATCH_SPACE: usize = 1024;
+    const PAGE_SIZE: usize = 0x1000;
+
+    let mut sbuf = [0u8; SCRATCH_SPACE];
+    let scratch = BufferScratch::new(&mut sbuf);
+    let mut buffer = [0u8; PAGE_SIZE];
+    let ser = BufferSerializer::new(&mut buffer[..]);
+    let mut ser = CompositeSerializer::new(ser, scratch, Infallible);
+
+    ser.serialize_value(fn_arg)
+        .expect("Failed to rkyv serialize fn_arg");
+    let pos = ser.pos();
+
+    buffer[..pos].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use dusk_core::dusk;
+
+    use super::*;
+
+    #[test]
+    fn moves_to_another_block_height_keeping_state() {
+        let sk = AccountSecretKey::random(&mut StdRng::seed_from_u64(0x5EED));
+        let pk = AccountPublicKey::from(&sk);
+        let mut session = TestSession::instantiate(&[(&pk, dusk(1_000.0))], &[]);
+        assert_eq!(session.block_height(), 1);
+
+        session.advance_blocks(10);
+        assert_eq!(session.block_height(), 11);
+
+        session.set_block_height(1_000);
+        assert_eq!(session.block_height(), 1_000);
+
+        let account = session
+            .account(&pk)
+            .expect("Getting the account should succeed");
+        assert_eq!(account.balance, dusk(1_000.0));
+    }
+
+    #[test]
+    fn harness_funds_accounts_under_name_derived_keys() {
+        let mut session = Harness::new()
+            .account("alice", dusk(1_000.0))
+            .account("bob", dusk(5.0))
+            .build();
+
+        assert_eq!(public_key("alice"), public_key("alice"));
+        assert_ne!(public_key("alice"), public_key("bob"));
+        for (name, balance) in [("alice", dusk(1_000.0)), ("bob", dusk(5.0))] {
+            let account = session
+                .account(&public_key(name))
+                .expect("Getting the account should succeed");
+            assert_eq!(account.balance, balance, "balance of {name}");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Checked conversions between Lux and Wei.
+//!
+//! DUSK has 9 decimals (Lux) while EVM chains count in 18 (Wei), so a bridge
+//! scales amounts by [`WEI_PER_LUX`] on the way out and back. Wei amounts
+//! travel as 32-byte big-endian integers; the conversions here fail instead
+//! of silently dropping high bytes or sub-Lux dust.
+//!
+//! ```ignore
+//! let wei = units::lux_to_wei_be(deposit.amount);
+//! let lux = units::wei_be_to_lux(&request.amount)?;
+//! ```
+
+use core::fmt;
+
+/// Wei in one Lux.
+pub const WEI_PER_LUX: u128 = 1_000_000_000;
+
+/// Error of a Lux/Wei conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitsError {
+    /// The amount does not fit the target type.
+    Overflow,
+    /// The Wei amount is not a whole number of Lux; holds the remainder.
+    Dust(u128),
+}
+
+impl fmt::Display for UnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("amount overflows"),
+            Self::Dust(rest) => {
+                write!(f, "amount is not a whole number of Lux ({rest} Wei left)")
+            }
+        }
+    }
+}
+
+/// The Wei worth of `lux`. Every `u64` of Lux fits in a `u128` of Wei.
+#[must_use]
+pub const fn lux_to_wei(lux: u64) -> u128 {
+    lux as u128 * WEI_PER_LUX
+}
+
+/// The Lux worth of `wei`.
+///
+/// # Errors
+///
+/// [`UnitsError::Dust`] if `wei` is not a multiple of [`WEI_PER_LUX`], and
+/// [`UnitsError::Overflow`] if the Lux amount exceeds `u64::MAX`.
+pub const fn wei_to_lux(wei: u128) -> Result<u64, UnitsError> {
+    let rest = wei % WEI_PER_LUX;
+    if rest != 0 {
+        return Err(UnitsError::Dust(rest));
+    }
+    let lux = wei / WEI_PER_LUX;
+    if lux > u64::MAX as u128 {
+        return Err(UnitsError::Overflow);
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(lux as u64)
+}
+
+/// Read a 32-byte big-endian Wei amount.
+///
+/// # Errors
+///
+/// [`UnitsError::Overflow`] if the amount exceeds `u128::MAX`.
+pub fn wei_from_be_bytes(bytes: &[u8; 32]) -> Result<u128, UnitsError> {
+    let (high, low) = bytes.split_at(16);
+    if high.iter().any(|b| *b != 0) {
+        return Err(UnitsError::Overflow);
+    }
+    let mut low_bytes = [0; 16];
+    low_bytes.copy_from_slice(low);
+    Ok(u128::from_be_bytes(low_bytes))
+}
+
+/// Write a Wei amount as 32 big-endian bytes.
+#[must_use]
+pub fn wei_to_be_bytes(wei: u128) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[16..].copy_from_slice(&wei.to_be_bytes());
+    bytes
+}
+
+/// The Wei worth of `lux`, as 32 big-endian bytes.
+#[must_use]
+pub fn lux_to_wei_be(lux: u64) -> [u8; 32] {
+    wei_to_be_bytes(lux_to_wei(lux))
+}
+
+/// The Lux worth of a 32-byte big-endian Wei amount.
+///
+/// # Errors
+///
+/// As [`wei_from_be_bytes`] and [`wei_to_lux`].
+pub fn wei_be_to_lux(bytes: &[u8; 32]) -> Result<u64, UnitsError> {
+    wei_to_lux(wei_from_be_bytes(bytes)?)
+}
@@ -0,0 +1,29 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Standard contract interfaces for Dusk Forge contracts.
+//!
+//! Each standard is a trait meant to be implemented by a `#[contract]`
+//! struct and exposed with `#[contract(expose = [...])]`, together with the
+//! argument and event types of its ABI. Contracts implementing the same
+//! standard share function names, inputs, outputs and event topics, so
+//! wallets, explorers and other contracts can use them interchangeably.
+//!
+//! The traits and their default methods call into the VM and are only
+//! available with the `abi` feature, enabled by contract builds. The types
+//! are always available, with JSON representations behind the `serde`
+//! feature for data-drivers.
+
+#![no_std]
+#![deny(missing_docs)]
+#![deny(clippy::pedantic)]
+
+extern crate alloc;
+
+/// Non-fungible tokens: ownership, transfers, approvals and metadata.
+pub mod nft;
+/// Fungible tokens: balances, transfers and allowances.
+pub mod token;
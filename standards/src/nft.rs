@@ -0,0 +1,279 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! The non-fungible token standard.
+//!
+//! A collection contract keeps the owner, approval and metadata of each token
+//! in its state and implements the storage methods of [`NonFungibleToken`];
+//! transfers, approvals, the events they emit and the metadata feed come from
+//! the trait's default methods:
+//!
+//! ```ignore
+//! #[contract(expose = [
+//!     name, symbol, total_supply, balance_of, owner_of, approved,
+//!     token_metadata, transfer, approve, tokens_of,
+//! ])]
+//! impl NonFungibleToken for Collection {
+//!     fn name(&self) -> String { self.name.clone() }
+//!     // ... the other getters and the `set_*` storage methods
+//!
+//!     #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+//!     fn transfer(&mut self, receiver: Account, token: TokenId) {}
+//!
+//!     #[contract(emits = [(events::Approval::TOPIC, events::Approval)])]
+//!     fn approve(&mut self, spender: Option<Account>, token: TokenId) {}
+//!
+//!     #[contract(feeds = "(TokenId, TokenMetadata)")]
+//!     fn tokens_of(&self, owner: Account) {}
+//! }
+//! ```
+//!
+//! Minting is left to the contract, which decides who may mint and calls
+//! [`NonFungibleToken::mint`] from a method of its own.
+
+use alloc::string::String;
+
+use bytecheck::CheckBytes;
+use rkyv::{Archive, Deserialize, Serialize};
+
+pub use crate::token::Account;
+
+/// Identifier of a token within its collection.
+pub type TokenId = u64;
+
+/// Metadata describing a token.
+#[derive(Debug, Clone, PartialEq, Eq, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenMetadata {
+    /// Name of the token.
+    pub name: String,
+    /// Location of the token's content or off-chain metadata, e.g. an IPFS
+    /// or HTTPS URI.
+    pub uri: String,
+}
+
+/// The non-fungible token standard.
+///
+/// `name`, `symbol`, `total_supply`, `balance_of`, `owner_of`, `approved`,
+/// `token_metadata`, `transfer`, `approve` and `tokens_of` form the
+/// collection's ABI and are meant to be exposed; the other methods are for
+/// the contract's own use.
+#[cfg(feature = "abi")]
+pub trait NonFungibleToken {
+    /// Returns the name of the collection.
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the collection.
+    fn symbol(&self) -> String;
+
+    /// Returns the owner of `token`, or `None` if it does not exist.
+    fn owner_of(&self, token: TokenId) -> Option<Account>;
+
+    /// Returns the account allowed to transfer `token` besides its owner.
+    fn approved(&self, token: TokenId) -> Option<Account>;
+
+    /// Returns the metadata of `token`, or `None` if it does not exist.
+    fn token_metadata(&self, token: TokenId) -> Option<TokenMetadata>;
+
+    /// Returns the identifiers of all existing tokens.
+    fn token_ids(&self) -> impl Iterator<Item = TokenId> + '_;
+
+    /// Stores the owner of `token`; `None` removes it.
+    fn set_owner(&mut self, token: TokenId, owner: Option<Account>);
+
+    /// Stores the account approved for `token`; `None` clears it.
+    fn set_approved(&mut self, token: TokenId, spender: Option<Account>);
+
+    /// Stores the metadata of `token`; `None` removes it.
+    fn set_token_metadata(&mut self, token: TokenId, metadata: Option<TokenMetadata>);
+
+    /// Returns the number of tokens in existence.
+    ///
+    /// Counts [`token_ids`](Self::token_ids); large collections override it
+    /// with a stored count.
+    fn total_supply(&self) -> u64 {
+        self.token_ids().count() as u64
+    }
+
+    /// Returns the number of tokens owned by `owner`.
+    ///
+    /// Scans [`token_ids`](Self::token_ids); large collections override it
+    /// with stored counts.
+    fn balance_of(&self, owner: Account) -> u64 {
+        self.token_ids()
+            .filter(|token| self.owner_of(*token) == Some(owner))
+            .count() as u64
+    }
+
+    /// Transfers `token` from its owner to `receiver`. The caller must be
+    /// the owner or the approved account, and the approval is cleared.
+    ///
+    /// Emits [`events::Transfer`].
+    fn transfer(&mut self, receiver: Account, token: TokenId) {
+        let owner = self.owner_of(token).expect(error::NO_TOKEN);
+        let caller = Account::caller();
+        assert!(
+            caller == owner || self.approved(token) == Some(caller),
+            "{}",
+            error::NOT_ALLOWED
+        );
+
+        self.move_token(owner, receiver, token);
+    }
+
+    /// Allows `spender` to transfer `token`, or clears the approval with
+    /// `None`. The caller must be the owner.
+    ///
+    /// Emits [`events::Approval`].
+    fn approve(&mut self, spender: Option<Account>, token: TokenId) {
+        let owner = self.owner_of(token).expect(error::NO_TOKEN);
+        assert!(Account::caller() == owner, "{}", error::NOT_ALLOWED);
+        self.set_approved(token, spender);
+
+        dusk_core::abi::emit(
+            events::Approval::TOPIC,
+            events::Approval {
+                owner,
+                spender,
+                token,
+            },
+        );
+    }
+
+    /// Feeds the identifier and metadata of every token owned by `owner`.
+    fn tokens_of(&self, owner: Account) {
+        for token in self.token_ids() {
+            if self.owner_of(token) != Some(owner) {
+                continue;
+            }
+            if let Some(metadata) = self.token_metadata(token) {
+                dusk_core::abi::feed((token, metadata));
+            }
+        }
+    }
+
+    /// Moves `token` from `sender` to `receiver` and clears its approval,
+    /// without checking who is calling.
+    ///
+    /// Emits [`events::Transfer`].
+    fn move_token(&mut self, sender: Account, receiver: Account, token: TokenId) {
+        self.set_owner(token, Some(receiver));
+        self.set_approved(token, None);
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: Some(sender),
+                receiver: Some(receiver),
+                token,
+            },
+        );
+    }
+
+    /// Creates `token` with `metadata`, owned by `receiver`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `token` already exists.
+    ///
+    /// Emits [`events::Transfer`] without a sender.
+    fn mint(&mut self, receiver: Account, token: TokenId, metadata: TokenMetadata) {
+        assert!(self.owner_of(token).is_none(), "{}", error::TOKEN_EXISTS);
+        self.set_owner(token, Some(receiver));
+        self.set_token_metadata(token, Some(metadata));
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: None,
+                receiver: Some(receiver),
+                token,
+            },
+        );
+    }
+
+    /// Destroys `token`, with its approval and metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `token` does not exist.
+    ///
+    /// Emits [`events::Transfer`] without a receiver.
+    fn burn(&mut self, token: TokenId) {
+        let owner = self.owner_of(token).expect(error::NO_TOKEN);
+        self.set_owner(token, None);
+        self.set_approved(token, None);
+        self.set_token_metadata(token, None);
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: Some(owner),
+                receiver: None,
+                token,
+            },
+        );
+    }
+}
+
+/// Events of the non-fungible token standard.
+pub mod events {
+    #[allow(unused_imports)]
+    use rkyv::bytecheck::CheckBytes;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    use super::{Account, TokenId};
+
+    /// Event emitted when a token changes owner, is minted (no sender) or is
+    /// burned (no receiver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+    #[archive_attr(derive(CheckBytes))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Transfer {
+        /// The previous owner, or `None` when minted.
+        pub sender: Option<Account>,
+        /// The new owner, or `None` when burned.
+        pub receiver: Option<Account>,
+        /// The token.
+        pub token: TokenId,
+    }
+
+    impl Transfer {
+        /// Event topic for token transfers.
+        pub const TOPIC: &'static str = "transfer";
+    }
+
+    /// Event emitted when the approval of a token is set or cleared.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+    #[archive_attr(derive(CheckBytes))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Approval {
+        /// The owner of the token.
+        pub owner: Account,
+        /// The account allowed to transfer it, or `None` when cleared.
+        pub spender: Option<Account>,
+        /// The token.
+        pub token: TokenId,
+    }
+
+    impl Approval {
+        /// Event topic for approval changes.
+        pub const TOPIC: &'static str = "approval";
+    }
+}
+
+/// Panic messages of the default methods.
+pub mod error {
+    /// Error thrown when a token does not exist.
+    pub const NO_TOKEN: &str = "The token does not exist.";
+
+    /// Error thrown when minting a token that already exists.
+    pub const TOKEN_EXISTS: &str = "The token already exists.";
+
+    /// Error thrown when the caller may not transfer or approve a token.
+    pub const NOT_ALLOWED: &str = "The caller is not allowed to use the token.";
+}
@@ -0,0 +1,299 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! The fungible token standard.
+//!
+//! A token contract keeps the balances and allowances in its state and
+//! implements the storage methods of [`FungibleToken`]; the transfer and
+//! approval logic, and the events it emits, come from the trait's default
+//! methods:
+//!
+//! ```ignore
+//! #[contract(expose = [
+//!     name, symbol, decimals, total_supply, balance_of, allowance,
+//!     transfer, approve, transfer_from,
+//! ])]
+//! impl FungibleToken for Token {
+//!     fn name(&self) -> String { self.name.clone() }
+//!     // ... the other getters and the `set_*` storage methods
+//!
+//!     #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+//!     fn transfer(&mut self, receiver: Account, value: u64) {}
+//!
+//!     #[contract(emits = [(events::Approval::TOPIC, events::Approval)])]
+//!     fn approve(&mut self, spender: Account, value: u64) {}
+//!
+//!     #[contract(emits = [(events::Transfer::TOPIC, events::Transfer)])]
+//!     fn transfer_from(&mut self, owner: Account, receiver: Account, value: u64) {}
+//! }
+//! ```
+//!
+//! The empty bodies make the macro call the default methods. The storage
+//! methods are left out of `expose`, so they stay internal.
+
+#[cfg(feature = "abi")]
+use alloc::string::String;
+
+use bytecheck::CheckBytes;
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::PublicKey;
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// An account holding tokens: a public key, or a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Account {
+    /// An account controlled by a key pair.
+    External(PublicKey),
+    /// A contract.
+    Contract(ContractId),
+}
+
+impl From<PublicKey> for Account {
+    fn from(key: PublicKey) -> Self {
+        Self::External(key)
+    }
+}
+
+impl From<ContractId> for Account {
+    fn from(contract: ContractId) -> Self {
+        Self::Contract(contract)
+    }
+}
+
+#[cfg(feature = "abi")]
+impl Account {
+    /// The account calling the contract: the calling contract, or the public
+    /// sender of the transaction when called through the transfer contract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by the transfer contract without a public sender,
+    /// e.g. from a shielded transaction.
+    #[must_use]
+    pub fn caller() -> Self {
+        use dusk_core::abi;
+        use dusk_core::transfer::TRANSFER_CONTRACT;
+
+        match abi::caller() {
+            Some(contract) if contract != TRANSFER_CONTRACT => Self::Contract(contract),
+            _ => Self::External(abi::public_sender().expect(error::NO_SENDER)),
+        }
+    }
+}
+
+/// The fungible token standard.
+///
+/// `name`, `symbol`, `decimals`, `total_supply`, `balance_of`, `allowance`,
+/// `transfer`, `approve` and `transfer_from` form the token's ABI and are
+/// meant to be exposed; the other methods are for the contract's own use.
+#[cfg(feature = "abi")]
+pub trait FungibleToken {
+    /// Returns the name of the token.
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the token.
+    fn symbol(&self) -> String;
+
+    /// Returns the number of decimals of a displayed amount.
+    fn decimals(&self) -> u8;
+
+    /// Returns the number of tokens in existence.
+    fn total_supply(&self) -> u64;
+
+    /// Returns the balance of `account`.
+    fn balance_of(&self, account: Account) -> u64;
+
+    /// Returns the amount `spender` may still transfer out of `owner`'s
+    /// balance.
+    fn allowance(&self, owner: Account, spender: Account) -> u64;
+
+    /// Stores the balance of `account`.
+    fn set_balance(&mut self, account: Account, value: u64);
+
+    /// Stores the allowance of `spender` over `owner`'s balance.
+    fn set_allowance(&mut self, owner: Account, spender: Account, value: u64);
+
+    /// Stores the number of tokens in existence.
+    fn set_total_supply(&mut self, value: u64);
+
+    /// Transfers `value` tokens from the caller to `receiver`.
+    ///
+    /// Emits [`events::Transfer`].
+    fn transfer(&mut self, receiver: Account, value: u64) {
+        self.move_balance(Account::caller(), receiver, value);
+    }
+
+    /// Allows `spender` to transfer up to `value` tokens out of the caller's
+    /// balance, replacing any previous allowance. An allowance of
+    /// `u64::MAX` is never used up.
+    ///
+    /// Emits [`events::Approval`].
+    fn approve(&mut self, spender: Account, value: u64) {
+        let owner = Account::caller();
+        self.set_allowance(owner, spender, value);
+
+        dusk_core::abi::emit(
+            events::Approval::TOPIC,
+            events::Approval {
+                owner,
+                spender,
+                value,
+            },
+        );
+    }
+
+    /// Transfers `value` tokens from `owner` to `receiver` on behalf of the
+    /// caller, using up the caller's allowance.
+    ///
+    /// Emits [`events::Transfer`].
+    fn transfer_from(&mut self, owner: Account, receiver: Account, value: u64) {
+        let spender = Account::caller();
+        let allowance = self.allowance(owner, spender);
+        if allowance != u64::MAX {
+            let remaining = allowance
+                .checked_sub(value)
+                .expect(error::INSUFFICIENT_ALLOWANCE);
+            self.set_allowance(owner, spender, remaining);
+        }
+
+        self.move_balance(owner, receiver, value);
+    }
+
+    /// Moves `value` tokens from `sender` to `receiver`, without checking
+    /// who is calling.
+    ///
+    /// Emits [`events::Transfer`].
+    fn move_balance(&mut self, sender: Account, receiver: Account, value: u64) {
+        let balance = self
+            .balance_of(sender)
+            .checked_sub(value)
+            .expect(error::INSUFFICIENT_BALANCE);
+        self.set_balance(sender, balance);
+        let balance = self
+            .balance_of(receiver)
+            .checked_add(value)
+            .expect(error::OVERFLOW);
+        self.set_balance(receiver, balance);
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: Some(sender),
+                receiver: Some(receiver),
+                value,
+            },
+        );
+    }
+
+    /// Creates `value` tokens in `receiver`'s balance.
+    ///
+    /// Emits [`events::Transfer`] without a sender.
+    fn mint(&mut self, receiver: Account, value: u64) {
+        let supply = self
+            .total_supply()
+            .checked_add(value)
+            .expect(error::OVERFLOW);
+        self.set_total_supply(supply);
+        let balance = self
+            .balance_of(receiver)
+            .checked_add(value)
+            .expect(error::OVERFLOW);
+        self.set_balance(receiver, balance);
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: None,
+                receiver: Some(receiver),
+                value,
+            },
+        );
+    }
+
+    /// Destroys `value` tokens from `sender`'s balance.
+    ///
+    /// Emits [`events::Transfer`] without a receiver.
+    fn burn(&mut self, sender: Account, value: u64) {
+        let balance = self
+            .balance_of(sender)
+            .checked_sub(value)
+            .expect(error::INSUFFICIENT_BALANCE);
+        self.set_balance(sender, balance);
+        self.set_total_supply(self.total_supply() - value);
+
+        dusk_core::abi::emit(
+            events::Transfer::TOPIC,
+            events::Transfer {
+                sender: Some(sender),
+                receiver: None,
+                value,
+            },
+        );
+    }
+}
+
+/// Events of the fungible token standard.
+pub mod events {
+    #[allow(unused_imports)]
+    use rkyv::bytecheck::CheckBytes;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    use super::Account;
+
+    /// Event emitted when tokens move between accounts, are minted (no
+    /// sender) or are burned (no receiver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+    #[archive_attr(derive(CheckBytes))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Transfer {
+        /// The account the tokens left, or `None` when minted.
+        pub sender: Option<Account>,
+        /// The account the tokens reached, or `None` when burned.
+        pub receiver: Option<Account>,
+        /// The number of tokens.
+        pub value: u64,
+    }
+
+    impl Transfer {
+        /// Event topic for token transfers.
+        pub const TOPIC: &'static str = "transfer";
+    }
+
+    /// Event emitted when an allowance is set.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+    #[archive_attr(derive(CheckBytes))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Approval {
+        /// The account whose tokens may be spent.
+        pub owner: Account,
+        /// The account allowed to spend them.
+        pub spender: Account,
+        /// The number of tokens allowed.
+        pub value: u64,
+    }
+
+    impl Approval {
+        /// Event topic for allowance changes.
+        pub const TOPIC: &'static str = "approval";
+    }
+}
+
+/// Panic messages of the default methods.
+pub mod error {
+    /// Error thrown when the caller has no public sender.
+    pub const NO_SENDER: &str = "No public sender available.";
+
+    /// Error thrown when a balance is too low for a transfer or burn.
+    pub const INSUFFICIENT_BALANCE: &str = "The account balance is insufficient.";
+
+    /// Error thrown when an allowance is too low for a `transfer_from`.
+    pub const INSUFFICIENT_ALLOWANCE: &str = "The spender allowance is insufficient.";
+
+    /// Error thrown when a balance or the total supply would overflow.
+    pub const OVERFLOW: &str = "The token amount overflows.";
+}
@@ -12,15 +12,17 @@
 //! - Event emission, streaming via `abi::feed`
 //! - Trait exposure with default implementations
 //! - Multiple impl blocks, associated functions
+//! - Interface conformance against `counter.forge-abi`
 
 #![no_std]
-#![cfg(target_family = "wasm")]
+// Also built on the host for the generated data-driver round-trip tests
+#![cfg(any(target_family = "wasm", all(test, feature = "data-driver-tests")))]
 #![deny(unused_extern_crates)]
 #![deny(missing_docs)]
 #![deny(clippy::pedantic)]
 
 /// Test contract demonstrating all macro features.
-#[dusk_forge::contract]
+#[dusk_forge::contract(implements = "counter.forge-abi", metering)]
 mod test_contract {
     extern crate alloc;
 
@@ -43,6 +45,9 @@ mod test_contract {
         }
     }
 
+    /// Longest label the contract's invariant accepts, in bytes.
+    pub const MAX_LABEL_LEN: usize = 64;
+
     /// Test contract state.
     ///
     /// Designed to exercise every `#[contract]` macro code path.
@@ -76,7 +81,10 @@ mod test_contract {
         ///
         /// This method intentionally doesn't emit an event as it's only called
         /// during contract deployment.
+        ///
+        /// Exercises: `#[contract(reinit_guard)]` rejecting a second call.
         #[contract(no_event)]
+        #[contract(reinit_guard)]
         pub fn init(&mut self, owner: PublicKey) {
             self.owner = Some(owner);
         }
@@ -121,6 +129,18 @@ mod test_contract {
             );
         }
 
+        /// Checks that the label fits in [`MAX_LABEL_LEN`] bytes.
+        ///
+        /// Exercises: `#[contract(invariant)]` run after every mutating call
+        /// in debug builds and builds with the `invariants` feature.
+        #[contract(invariant)]
+        pub fn check_label(&self) {
+            assert!(
+                self.label.len() <= MAX_LABEL_LEN,
+                "label exceeds {MAX_LABEL_LEN} bytes"
+            );
+        }
+
         /// Resets the counter to zero.
         ///
         /// Exercises: event emission with unit struct event.
@@ -129,6 +149,19 @@ mod test_contract {
             abi::emit(events::CounterReset::TOPIC, events::CounterReset());
         }
 
+        /// Increments the counter by one, saturating at `u64::MAX`.
+        ///
+        /// Exercises: `dusk_forge::emit!` taking the topic from the event
+        /// type, found by the macro's body scanner.
+        pub fn increment_counter(&mut self) {
+            let previous = self.counter;
+            self.counter = previous.saturating_add(1);
+            dusk_forge::emit!(events::CounterUpdated {
+                previous,
+                new: self.counter,
+            });
+        }
+
         /// Returns whether the collection is non-empty.
         ///
         /// Exercises: zero-argument bool return.
@@ -154,6 +187,36 @@ mod test_contract {
             self.counter += 1;
             helpers::emit_tally_bumped();
         }
+
+        /// Adds `value` to the counter.
+        ///
+        /// Exercises: `dusk_forge::revert!` naming the function in its
+        /// failure message, and the error code listed in the schema.
+        #[contract(emits = [(events::CounterUpdated::TOPIC, events::CounterUpdated)])]
+        pub fn add_to_counter(&mut self, value: u64) {
+            let Some(counter) = self.counter.checked_add(value) else {
+                dusk_forge::revert!("OVERFLOW", "counter would exceed u64::MAX");
+            };
+            self.set_counter(counter);
+        }
+
+        /// Subtracts `value` from the counter.
+        ///
+        /// Exercises: `dusk_forge::revert!` in a free helper, whose failure
+        /// message names the function the wrapper records.
+        #[contract(emits = [(events::CounterUpdated::TOPIC, events::CounterUpdated)])]
+        pub fn subtract_from_counter(&mut self, value: u64) {
+            let counter = checked_sub(self.counter, value);
+            self.set_counter(counter);
+        }
+    }
+
+    /// `counter - value`, failing the call if it would underflow.
+    fn checked_sub(counter: u64, value: u64) -> u64 {
+        match counter.checked_sub(value) {
+            Some(counter) => counter,
+            None => dusk_forge::revert!("UNDERFLOW", "counter is only {counter}"),
+        }
     }
 
     // =========================================================================
@@ -220,6 +283,15 @@ mod test_contract {
                 abi::feed(*id);
             }
         }
+
+        /// Returns all items keyed by ID.
+        ///
+        /// Exercises: `#[contract(feed_fallback = N)]` generating the
+        /// `item_map_feed` streaming variant.
+        #[contract(feed_fallback = 64)]
+        pub fn item_map(&self) -> BTreeMap<ItemId, Item> {
+            self.items.clone()
+        }
     }
 
     // =========================================================================
@@ -13,98 +13,45 @@
 
 extern crate alloc;
 
-use std::sync::{LazyLock, mpsc};
+use std::collections::BTreeMap;
+use std::sync::mpsc;
 
-use dusk_core::abi::{ContractError, ContractId, StandardBufSerializer};
+use dusk_core::abi::{ContractError, ContractId};
 use dusk_core::dusk;
 use dusk_core::signatures::bls::{PublicKey as AccountPublicKey, SecretKey as AccountSecretKey};
-use dusk_vm::{CallReceipt, Error as VMError};
-use rkyv::bytecheck::CheckBytes;
-use rkyv::validation::validators::DefaultValidator;
-use rkyv::{Archive, Deserialize, Infallible, Serialize};
-mod test_session;
-
-use rand::SeedableRng;
-use rand::rngs::StdRng;
-use test_session::TestSession;
+use dusk_forge::revert::Revert;
+use dusk_forge::testing::{
+    Deployable, Harness, TestSession, public_key, rkyv_deserialize, secret_key,
+};
+use dusk_vm::CallReceipt;
+use types::events::CounterUpdated;
 use types::{Item, ItemId};
 
-/// Direct/feeder call helpers used only by this test binary.
-///
-/// Lives here (not in `test_session.rs`) so the schema test binary, which
-/// only needs the public-call path, doesn't trip a `dead_code` warning.
-impl TestSession {
-    /// Directly calls the contract, circumventing the transfer contract and
-    /// (among other things) also any gas-payment.
-    fn direct_call<A, R>(
-        &mut self,
-        contract: ContractId,
-        fn_name: &str,
-        fn_arg: &A,
-    ) -> Result<CallReceipt<R>, ContractError>
-    where
-        A: for<'b> Serialize<StandardBufSerializer<'b>>,
-        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
-        R: Archive,
-        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
-    {
-        self.0
-            .call::<_, R>(contract, fn_name, fn_arg, u64::MAX)
-            .map_err(|e| match e {
-                VMError::Panic(panic_msg) => ContractError::Panic(panic_msg),
-                VMError::OutOfGas => ContractError::OutOfGas,
-                _ => panic!("Unknown error: {e}"),
-            })
-    }
-
-    /// Feeder calls let the contract report larger amounts of data to the
-    /// host via the channel included in this call.
-    fn feeder_call<A, R>(
-        &mut self,
-        contract: ContractId,
-        fn_name: &str,
-        fn_arg: &A,
-        feeder: std::sync::mpsc::Sender<Vec<u8>>,
-    ) -> Result<CallReceipt<R>, ContractError>
-    where
-        A: for<'b> Serialize<StandardBufSerializer<'b>>,
-        A::Archived: for<'b> CheckBytes<DefaultValidator<'b>>,
-        R: Archive,
-        R::Archived: Deserialize<R, Infallible> + for<'b> CheckBytes<DefaultValidator<'b>>,
-    {
-        self.0
-            .feeder_call::<_, R>(contract, fn_name, fn_arg, u64::MAX, feeder)
-            .map_err(|e| match e {
-                VMError::Panic(panic_msg) => ContractError::Panic(panic_msg),
-                VMError::OutOfGas => ContractError::OutOfGas,
-                _ => panic!("Unknown error: {e}"),
-            })
-    }
-}
-
-const DEPLOYER: [u8; 64] = [0u8; 64];
-
-const CONTRACT_BYTECODE: &[u8] =
-    include_bytes!("../../../target/contract/wasm32-unknown-unknown/release/test_contract.wasm");
 pub const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
 
 pub const INITIAL_DUSK_BALANCE: u64 = dusk(1_000.0);
 
-// Owner test key-pair
-pub static OWNER_SK: LazyLock<AccountSecretKey> = LazyLock::new(|| {
-    let mut rng = StdRng::seed_from_u64(0x5EAF00D);
-    AccountSecretKey::random(&mut rng)
-});
-pub static OWNER_PK: LazyLock<AccountPublicKey> =
-    LazyLock::new(|| AccountPublicKey::from(&*OWNER_SK));
-
-// Other test key-pair
-pub static TEST_SK: LazyLock<AccountSecretKey> = LazyLock::new(|| {
-    let mut rng = StdRng::seed_from_u64(0xF0CACC1A);
-    AccountSecretKey::random(&mut rng)
-});
-pub static TEST_PK: LazyLock<AccountPublicKey> =
-    LazyLock::new(|| AccountPublicKey::from(&*TEST_SK));
+/// Harness account owning the contract.
+const OWNER: &str = "owner";
+/// Harness account without special rights.
+const TEST: &str = "test";
+
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../target/contract/wasm32-unknown-unknown/release/test_contract.wasm"
+    ),
+};
+
+struct TestContract;
+
+impl Deployable for TestContract {
+    const WASM: &'static str = CONTRACT_WASM;
+    const ID: ContractId = CONTRACT_ID;
+}
 
 struct TestContractSession {
     session: TestSession,
@@ -112,23 +59,11 @@ struct TestContractSession {
 
 impl TestContractSession {
     fn new() -> Self {
-        let mut session = TestSession::instantiate(
-            vec![
-                (&*OWNER_PK, INITIAL_DUSK_BALANCE),
-                (&*TEST_PK, INITIAL_DUSK_BALANCE),
-            ],
-            vec![],
-        );
-
-        session
-            .deploy(
-                CONTRACT_BYTECODE,
-                dusk_vm::ContractData::builder()
-                    .owner(DEPLOYER)
-                    .init_arg(&(*OWNER_PK,))
-                    .contract_id(CONTRACT_ID),
-            )
-            .expect("Deploying test contract should succeed");
+        let session = Harness::new()
+            .account(OWNER, INITIAL_DUSK_BALANCE)
+            .account(TEST, INITIAL_DUSK_BALANCE)
+            .deploy::<TestContract>((public_key(OWNER),))
+            .build();
 
         Self { session }
     }
@@ -149,6 +84,22 @@ impl TestContractSession {
             .data
     }
 
+    fn contract_schema(&mut self) -> String {
+        self.session
+            .direct_call::<_, String>(CONTRACT_ID, "contract_schema", &())
+            .expect("contract_schema should succeed")
+            .data
+    }
+
+    /// Host-call counts recorded since the previous query.
+    #[cfg(feature = "metering")]
+    fn host_calls(&mut self) -> Vec<(String, u64)> {
+        self.session
+            .direct_call::<_, Vec<(String, u64)>>(CONTRACT_ID, "__forge_host_calls", &())
+            .expect("__forge_host_calls should succeed")
+            .data
+    }
+
     // Ownable trait methods
 
     fn owner(&mut self) -> Option<AccountPublicKey> {
@@ -205,6 +156,12 @@ impl TestContractSession {
             .expect("reset_counter should succeed")
     }
 
+    fn increment_counter(&mut self, sender_sk: &AccountSecretKey) -> CallReceipt<()> {
+        self.session
+            .call_public(sender_sk, CONTRACT_ID, "increment_counter", &())
+            .expect("increment_counter should succeed")
+    }
+
     fn add_item(&mut self, sender_sk: &AccountSecretKey, item: Item) -> CallReceipt<()> {
         self.session
             .call_public(sender_sk, CONTRACT_ID, "add_item", &item)
@@ -228,7 +185,29 @@ impl TestContractSession {
 
         receiver
             .into_iter()
-            .map(|data| test_session::rkyv_deserialize::<(ItemId, Item)>(&data))
+            .map(|data| rkyv_deserialize::<(ItemId, Item)>(&data))
+            .collect()
+    }
+
+    /// Return all items via the direct-return half of a `feed_fallback` pair.
+    fn item_map(&mut self) -> BTreeMap<ItemId, Item> {
+        self.session
+            .direct_call::<_, BTreeMap<ItemId, Item>>(CONTRACT_ID, "item_map", &())
+            .expect("item_map should succeed")
+            .data
+    }
+
+    /// Collect all items via the generated `item_map_feed` variant.
+    fn collect_item_map_feed(&mut self) -> Vec<(ItemId, Item)> {
+        let (sender, receiver) = mpsc::channel();
+
+        self.session
+            .feeder_call::<_, ()>(CONTRACT_ID, "item_map_feed", &(), sender)
+            .expect("item_map_feed feeder_call should succeed");
+
+        receiver
+            .into_iter()
+            .map(|data| rkyv_deserialize::<(ItemId, Item)>(&data))
             .collect()
     }
 
@@ -242,7 +221,7 @@ impl TestContractSession {
 
         receiver
             .into_iter()
-            .map(|data| test_session::rkyv_deserialize::<ItemId>(&data))
+            .map(|data| rkyv_deserialize::<ItemId>(&data))
             .collect()
     }
 }
@@ -259,7 +238,7 @@ fn make_item(id: u64, value: u64) -> Item {
 #[test]
 fn test_contract_deploys() {
     let mut session = TestContractSession::new();
-    assert_eq!(session.owner(), Some(*OWNER_PK));
+    assert_eq!(session.owner(), Some(public_key(OWNER)));
 }
 
 #[test]
@@ -271,24 +250,41 @@ fn test_inherent_methods() {
     assert_eq!(session.label(), "");
 
     // Test set_counter
-    session.set_counter(&OWNER_SK, 42);
+    session.set_counter(&secret_key(OWNER), 42);
     assert_eq!(session.counter(), 42);
 
     // Test reset_counter
-    session.reset_counter(&OWNER_SK);
+    session.reset_counter(&secret_key(OWNER));
     assert_eq!(session.counter(), 0);
 }
 
+#[test]
+fn test_block_height_moves_with_state_kept() {
+    let mut session = TestContractSession::new();
+    assert_eq!(session.session.block_height(), 1);
+
+    session.set_counter(&secret_key(OWNER), 42);
+    session.session.advance_blocks(10);
+    assert_eq!(session.session.block_height(), 11);
+    assert_eq!(session.counter(), 42);
+
+    // The sender's nonce survives the reopened session too
+    session.set_counter(&secret_key(OWNER), 7);
+    session.session.set_block_height(1_000);
+    assert_eq!(session.session.block_height(), 1_000);
+    assert_eq!(session.counter(), 7);
+}
+
 #[test]
 fn test_trait_methods_exposed() {
     let mut session = TestContractSession::new();
 
     // owner() should be exposed from Ownable
-    assert_eq!(session.owner(), Some(*OWNER_PK));
+    assert_eq!(session.owner(), Some(public_key(OWNER)));
 
     // transfer_ownership() should be exposed
-    let receipt = session.transfer_ownership(&OWNER_SK, *TEST_PK);
-    assert_eq!(session.owner(), Some(*TEST_PK));
+    let receipt = session.transfer_ownership(&secret_key(OWNER), public_key(TEST));
+    assert_eq!(session.owner(), Some(public_key(TEST)));
 
     // Check that ownership transfer event was emitted
     assert!(
@@ -301,7 +297,7 @@ fn test_trait_methods_exposed() {
 fn test_renounce_ownership() {
     let mut session = TestContractSession::new();
 
-    let receipt = session.renounce_ownership(&OWNER_SK);
+    let receipt = session.renounce_ownership(&secret_key(OWNER));
     assert_eq!(session.owner(), None);
 
     assert!(
@@ -319,7 +315,7 @@ fn test_renounce_ownership() {
 fn test_delegating_inherent_method_emits_event() {
     let mut session = TestContractSession::new();
 
-    let receipt = session.bump_tally(&OWNER_SK);
+    let receipt = session.bump_tally(&secret_key(OWNER));
 
     let tally_event = receipt.events.iter().find(|e| e.topic == "tally_bumped");
     assert!(
@@ -333,7 +329,7 @@ fn test_delegating_inherent_method_emits_event() {
 fn test_set_counter_emits_event() {
     let mut session = TestContractSession::new();
 
-    let receipt = session.set_counter(&OWNER_SK, 99);
+    let receipt = session.set_counter(&secret_key(OWNER), 99);
 
     assert!(
         !receipt.events.is_empty(),
@@ -341,6 +337,29 @@ fn test_set_counter_emits_event() {
     );
 }
 
+#[test]
+fn test_emit_macro_uses_the_event_topic() {
+    let mut session = TestContractSession::new();
+    session.set_counter(&secret_key(OWNER), 41);
+
+    let receipt = session.increment_counter(&secret_key(OWNER));
+    assert_eq!(session.counter(), 42);
+
+    let event = receipt
+        .events
+        .iter()
+        .find(|e| e.topic == CounterUpdated::TOPIC)
+        .expect("increment_counter should emit counter_updated");
+    let event: CounterUpdated = rkyv_deserialize(&event.data);
+    assert_eq!(
+        event,
+        CounterUpdated {
+            previous: 41,
+            new: 42,
+        }
+    );
+}
+
 #[test]
 fn test_method_returning_reference() {
     let mut session = TestContractSession::new();
@@ -349,7 +368,7 @@ fn test_method_returning_reference() {
     assert_eq!(session.label(), "");
 
     // Update with a new label
-    session.update(&OWNER_SK, 10, String::from("hello"));
+    session.update(&secret_key(OWNER), 10, String::from("hello"));
 
     // label() returns &String, macro should generate .clone()
     assert_eq!(session.label(), "hello");
@@ -371,7 +390,7 @@ fn test_method_with_reference_parameter() {
     );
 
     // Add and verify lookup by reference
-    session.add_item(&OWNER_SK, item);
+    session.add_item(&secret_key(OWNER), item);
 
     assert!(
         session.contains_item(item),
@@ -388,7 +407,7 @@ fn test_method_with_multiple_parameters() {
     let mut session = TestContractSession::new();
 
     // update() takes (u64, String) — macro creates tuple input
-    let receipt = session.update(&OWNER_SK, 42, String::from("updated"));
+    let receipt = session.update(&secret_key(OWNER), 42, String::from("updated"));
 
     assert!(
         !receipt.events.is_empty(),
@@ -406,12 +425,12 @@ fn test_method_with_multiple_parameters() {
 fn test_trait_default_implementation_emits_event() {
     let mut session = TestContractSession::new();
 
-    let receipt = session.transfer_ownership(&OWNER_SK, *TEST_PK);
+    let receipt = session.transfer_ownership(&secret_key(OWNER), public_key(TEST));
 
     // Verify the trait's default implementation was called
     assert_eq!(
         session.owner(),
-        Some(*TEST_PK),
+        Some(public_key(TEST)),
         "Ownership should have changed — trait default must set new owner"
     );
 
@@ -434,14 +453,14 @@ fn test_trait_default_implementation_emits_event() {
 fn test_trait_default_only_owner_check() {
     let mut session = TestContractSession::new();
 
-    assert_eq!(session.owner(), Some(*OWNER_PK));
+    assert_eq!(session.owner(), Some(public_key(OWNER)));
 
     // Try to transfer ownership as non-owner
     let result = session.session.call_public::<_, ()>(
-        &TEST_SK,
+        &secret_key(TEST),
         CONTRACT_ID,
         "transfer_ownership",
-        &*OWNER_PK,
+        &public_key(OWNER),
     );
 
     assert!(
@@ -450,19 +469,41 @@ fn test_trait_default_only_owner_check() {
     );
     assert_eq!(
         session.owner(),
-        Some(*OWNER_PK),
+        Some(public_key(OWNER)),
         "Ownership should remain unchanged after failed transfer"
     );
 }
 
+#[test]
+fn test_reinit_guard_rejects_second_init() {
+    let mut session = TestContractSession::new();
+
+    // `init` already ran during deployment
+    let result = session.session.call_public::<_, ()>(
+        &secret_key(TEST),
+        CONTRACT_ID,
+        "init",
+        &public_key(TEST),
+    );
+
+    assert!(result.is_err(), "Second init call should be rejected");
+    assert_eq!(
+        session.owner(),
+        Some(public_key(OWNER)),
+        "Owner should remain unchanged after rejected init"
+    );
+}
+
 #[test]
 fn test_trait_default_renounce_only_owner() {
     let mut session = TestContractSession::new();
 
-    let result =
-        session
-            .session
-            .call_public::<_, ()>(&TEST_SK, CONTRACT_ID, "renounce_ownership", &());
+    let result = session.session.call_public::<_, ()>(
+        &secret_key(TEST),
+        CONTRACT_ID,
+        "renounce_ownership",
+        &(),
+    );
 
     assert!(
         result.is_err(),
@@ -470,7 +511,7 @@ fn test_trait_default_renounce_only_owner() {
     );
     assert_eq!(
         session.owner(),
-        Some(*OWNER_PK),
+        Some(public_key(OWNER)),
         "Ownership should remain unchanged after failed renounce"
     );
 }
@@ -486,7 +527,7 @@ fn test_multiple_trait_implementations() {
     // Test Ownable trait methods (first trait impl)
     assert_eq!(
         session.owner(),
-        Some(*OWNER_PK),
+        Some(public_key(OWNER)),
         "owner() from Ownable should work"
     );
 
@@ -503,7 +544,7 @@ fn test_multiple_trait_implementations() {
     // Verify Ownable still works after using Versioned
     assert_eq!(
         session.owner(),
-        Some(*OWNER_PK),
+        Some(public_key(OWNER)),
         "owner() should still work"
     );
 }
@@ -514,7 +555,7 @@ fn test_nested_generic_return_type() {
 
     // Add an item
     let item = make_item(1, 1000);
-    session.add_item(&OWNER_SK, item);
+    session.add_item(&secret_key(OWNER), item);
 
     // find_item returns Option<(ItemId, Item)>
     let id = ItemId(1);
@@ -561,7 +602,7 @@ fn test_streaming_function_single_item() {
     let mut session = TestContractSession::new();
 
     let item = make_item(1, 1000);
-    session.add_item(&OWNER_SK, item);
+    session.add_item(&secret_key(OWNER), item);
 
     let results = session.collect_items();
     assert_eq!(results.len(), 1, "Should have exactly one item");
@@ -581,7 +622,7 @@ fn test_streaming_function_multiple_items() {
 
     for i in 1..=5u64 {
         let item = make_item(i, i * 1000);
-        session.add_item(&OWNER_SK, item);
+        session.add_item(&secret_key(OWNER), item);
     }
 
     let results = session.collect_items();
@@ -618,7 +659,7 @@ fn test_streaming_function_after_removal() {
 
     for i in 1..=3u64 {
         let item = make_item(i, i * 1000);
-        session.add_item(&OWNER_SK, item);
+        session.add_item(&secret_key(OWNER), item);
     }
 
     let results = session.collect_items();
@@ -628,7 +669,12 @@ fn test_streaming_function_after_removal() {
     let id_to_remove = ItemId(2);
     session
         .session
-        .call_public::<_, ()>(&OWNER_SK, CONTRACT_ID, "remove_item", &id_to_remove)
+        .call_public::<_, ()>(
+            &secret_key(OWNER),
+            CONTRACT_ID,
+            "remove_item",
+            &id_to_remove,
+        )
         .expect("remove_item should succeed");
 
     let results = session.collect_items();
@@ -640,3 +686,115 @@ fn test_streaming_function_after_removal() {
         "Removed item should not be in results"
     );
 }
+
+#[test]
+fn test_feed_fallback_direct_and_feed_variants_agree() {
+    let mut session = TestContractSession::new();
+
+    for i in 1..=3u64 {
+        session.add_item(&secret_key(OWNER), make_item(i, i * 1000));
+    }
+
+    let direct: Vec<(ItemId, Item)> = session.item_map().into_iter().collect();
+    let fed = session.collect_item_map_feed();
+
+    assert_eq!(direct.len(), 3, "Direct call should return all items");
+    assert_eq!(direct, fed, "Feed variant should stream the same entries");
+}
+
+#[test]
+fn test_contract_schema_export() {
+    let mut session = TestContractSession::new();
+
+    let schema: serde_json::Value = serde_json::from_str(&session.contract_schema())
+        .expect("contract_schema should return JSON");
+
+    assert_eq!(schema["name"], "TestContract");
+    let fn_names: Vec<&str> = schema["functions"]
+        .as_array()
+        .expect("functions should be an array")
+        .iter()
+        .map(|f| f["name"].as_str().unwrap())
+        .collect();
+    assert!(fn_names.contains(&"counter"), "missing counter");
+    assert!(
+        !fn_names.contains(&"contract_schema"),
+        "the schema export is not a contract function"
+    );
+}
+
+#[cfg(feature = "invariants")]
+#[test]
+fn test_invariant_fails_the_call_breaking_it() {
+    let mut session = TestContractSession::new();
+    session.update(&secret_key(OWNER), 1, String::from("short"));
+
+    let Err(ContractError::Panic(msg)) =
+        session
+            .session
+            .direct_call::<_, ()>(CONTRACT_ID, "update", &(2u64, "x".repeat(65)))
+    else {
+        panic!("update breaking the label invariant should fail");
+    };
+    assert!(msg.contains("label exceeds 64 bytes"), "{msg}");
+    assert_eq!(session.counter(), 1, "the failed call changes nothing");
+    assert_eq!(session.label(), "short");
+
+    // Calls keeping the invariant still go through
+    session.update(&secret_key(OWNER), 3, "x".repeat(64));
+    assert_eq!(session.counter(), 3);
+}
+
+#[cfg(feature = "metering")]
+#[test]
+fn test_host_call_metering() {
+    let mut session = TestContractSession::new();
+    // Discard the counts recorded during deployment
+    session.host_calls();
+
+    session.set_counter(&secret_key(OWNER), 7);
+
+    let host_calls = session.host_calls();
+    for (host_call, count) in &host_calls {
+        println!("forge-metering: {host_call} {count}");
+    }
+    assert_eq!(host_calls, vec![("emit".to_string(), 1)]);
+    assert!(
+        session.host_calls().is_empty(),
+        "counts reset after a query"
+    );
+}
+
+#[test]
+fn test_revert_names_only_the_function_being_called() {
+    let mut session = TestContractSession::new();
+
+    session
+        .session
+        .direct_call::<_, ()>(CONTRACT_ID, "add_to_counter", &5u64)
+        .expect("add_to_counter should succeed");
+
+    // `subtract_from_counter` reverts from a helper, under its own name
+    // rather than that of the call before
+    let Err(ContractError::Panic(msg)) =
+        session
+            .session
+            .direct_call::<_, ()>(CONTRACT_ID, "subtract_from_counter", &6u64)
+    else {
+        panic!("subtract_from_counter should underflow");
+    };
+    let revert = Revert::parse(&msg).expect("should be a revert! message");
+    assert_eq!(revert.function, "subtract_from_counter", "{msg}");
+    assert_eq!(revert.code, "UNDERFLOW");
+
+    let Err(ContractError::Panic(msg)) =
+        session
+            .session
+            .direct_call::<_, ()>(CONTRACT_ID, "add_to_counter", &u64::MAX)
+    else {
+        panic!("add_to_counter should overflow");
+    };
+    let revert = Revert::parse(&msg).expect("should be a revert! message");
+    assert_eq!(revert.function, "add_to_counter", "{msg}");
+    assert_eq!(revert.code, "OVERFLOW");
+}
@@ -9,18 +9,31 @@
 //! These tests load the data-driver WASM and verify that the schema
 //! generated by the `#[contract]` macro is correct.
 
-mod test_session;
-
 use wasmtime::*;
 
-const DATA_DRIVER_WASM: &[u8] =
-    include_bytes!("../../../target/data-driver/wasm32-unknown-unknown/release/test_contract.wasm");
+/// Path of the data-driver WASM: `DUSK_FORGE_DATA_DRIVER_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const DATA_DRIVER_WASM: &str = match option_env!("DUSK_FORGE_DATA_DRIVER_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../target/data-driver/wasm32-unknown-unknown/release/test_contract.wasm"
+    ),
+};
+
+/// Compile the data-driver WASM.
+fn data_driver_module(engine: &Engine) -> Module {
+    let wasm = std::fs::read(DATA_DRIVER_WASM).unwrap_or_else(|err| {
+        panic!("reading {DATA_DRIVER_WASM} should succeed (build the data-driver first): {err}")
+    });
+    Module::new(engine, wasm).expect("Failed to compile WASM")
+}
 
 /// Load the data-driver WASM and call get_schema to retrieve the contract
 /// schema.
 fn get_schema_from_wasm() -> String {
     let engine = Engine::default();
-    let module = Module::new(&engine, DATA_DRIVER_WASM).expect("Failed to compile WASM");
+    let module = data_driver_module(&engine);
 
     let mut store = Store::new(&engine, ());
     let instance = Instance::new(&mut store, &module, &[]).expect("Failed to instantiate WASM");
@@ -103,6 +116,10 @@ fn test_schema_has_functions() {
     assert!(fn_names.contains(&"set_counter"), "missing set_counter");
     assert!(fn_names.contains(&"update"), "missing update");
     assert!(fn_names.contains(&"reset_counter"), "missing reset_counter");
+    assert!(
+        fn_names.contains(&"increment_counter"),
+        "missing increment_counter"
+    );
     assert!(fn_names.contains(&"add_item"), "missing add_item");
     assert!(fn_names.contains(&"get_item"), "missing get_item");
 
@@ -185,6 +202,34 @@ fn test_schema_has_delegated_inherent_event() {
     );
 }
 
+/// Verify that events emitted under a topic const record the string the const
+/// resolves to, so indexers can subscribe without knowing Rust paths.
+#[test]
+fn test_schema_resolves_topic_const_values() {
+    let schema_json = get_schema_from_wasm();
+    let schema: serde_json::Value =
+        serde_json::from_str(&schema_json).expect("Failed to parse schema JSON");
+
+    let events = schema["events"]
+        .as_array()
+        .expect("events should be an array");
+    let value_of = |topic: &str| {
+        events
+            .iter()
+            .find(|e| e["topic"] == topic)
+            .map(|e| e["value"].clone())
+    };
+
+    assert_eq!(
+        value_of("events::TallyBumped::TOPIC"),
+        Some(serde_json::json!("tally_bumped"))
+    );
+    assert_eq!(
+        value_of("events::CounterReset::TOPIC"),
+        Some(serde_json::json!("counter_reset"))
+    );
+}
+
 /// Verify that manually registered events via `#[contract(emits = [...])]`
 /// appear in the schema.
 ///
@@ -240,6 +285,30 @@ fn test_schema_has_imports() {
     assert!(import_names.contains(&"events"), "missing events import");
 }
 
+#[test]
+fn test_schema_has_state_layout() {
+    let schema_json = get_schema_from_wasm();
+    let schema: serde_json::Value =
+        serde_json::from_str(&schema_json).expect("Failed to parse schema JSON");
+
+    let state: Vec<(&str, &str)> = schema["state"]
+        .as_array()
+        .expect("state should be an array")
+        .iter()
+        .map(|f| (f["name"].as_str().unwrap(), f["type"].as_str().unwrap()))
+        .collect();
+
+    assert_eq!(
+        state,
+        [
+            ("owner", "Option < PublicKey >"),
+            ("counter", "u64"),
+            ("label", "String"),
+            ("items", "BTreeMap < ItemId , Item >"),
+        ]
+    );
+}
+
 #[test]
 #[ignore]
 fn print_schema() {
@@ -258,7 +327,7 @@ struct DataDriverWasm {
 impl DataDriverWasm {
     fn new() -> Self {
         let engine = Engine::default();
-        let module = Module::new(&engine, DATA_DRIVER_WASM).expect("Failed to compile WASM");
+        let module = data_driver_module(&engine);
 
         let mut store = Store::new(&engine, ());
         let instance = Instance::new(&mut store, &module, &[]).expect("Failed to instantiate WASM");
@@ -471,6 +540,24 @@ fn test_schema_has_feed_functions() {
         "()",
         "items should have () as output in schema"
     );
+
+    // The fed item type is surfaced separately for clients decoding the stream
+    let feeds = items_fn["feeds"]
+        .as_str()
+        .expect("items should declare its feed type");
+    assert!(
+        feeds.contains("ItemId") && feeds.contains("Item"),
+        "items should feed (ItemId, Item), got: {feeds}"
+    );
+
+    let counter_fn = functions
+        .iter()
+        .find(|f| f["name"] == "counter")
+        .expect("counter not found");
+    assert!(
+        counter_fn["feeds"].is_null(),
+        "counter should not declare a feed type"
+    );
 }
 
 #[test]
@@ -546,43 +633,38 @@ fn test_feed_function_simple_type_decode() {
 // Tests for decode_event (using actual contract events)
 // =============================================================================
 
-use std::sync::LazyLock;
-
 use dusk_core::abi::ContractId;
 use dusk_core::dusk;
-use dusk_core::signatures::bls::{PublicKey as AccountPublicKey, SecretKey as AccountSecretKey};
-use rand::SeedableRng;
-use rand::rngs::StdRng;
-use test_session::TestSession;
+use dusk_forge::testing::{Deployable, Harness, TestSession, public_key, secret_key};
 use types::{Item, ItemId};
 
-const CONTRACT_BYTECODE: &[u8] =
-    include_bytes!("../../../target/contract/wasm32-unknown-unknown/release/test_contract.wasm");
 const CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
-const DEPLOYER: [u8; 64] = [0u8; 64];
 const INITIAL_BALANCE: u64 = dusk(1_000.0);
-
-static OWNER_SK: LazyLock<AccountSecretKey> = LazyLock::new(|| {
-    let mut rng = StdRng::seed_from_u64(0x5EAF00D);
-    AccountSecretKey::random(&mut rng)
-});
-static OWNER_PK: LazyLock<AccountPublicKey> = LazyLock::new(|| AccountPublicKey::from(&*OWNER_SK));
+const OWNER: &str = "owner";
+
+/// Path of the contract WASM: `DUSK_FORGE_CONTRACT_WASM`, set by
+/// `dusk-forge test` and the Makefile, or the default build output.
+const CONTRACT_WASM: &str = match option_env!("DUSK_FORGE_CONTRACT_WASM") {
+    Some(path) => path,
+    None => concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../target/contract/wasm32-unknown-unknown/release/test_contract.wasm"
+    ),
+};
+
+struct TestContract;
+
+impl Deployable for TestContract {
+    const WASM: &'static str = CONTRACT_WASM;
+    const ID: ContractId = CONTRACT_ID;
+}
 
 /// Set up a contract session for event tests.
 fn setup_contract_session() -> TestSession {
-    let mut session = TestSession::instantiate(vec![(&*OWNER_PK, INITIAL_BALANCE)], vec![]);
-
-    session
-        .deploy(
-            CONTRACT_BYTECODE,
-            dusk_vm::ContractData::builder()
-                .owner(DEPLOYER)
-                .init_arg(&(*OWNER_PK,))
-                .contract_id(CONTRACT_ID),
-        )
-        .expect("Deploying test contract should succeed");
-
-    session
+    Harness::new()
+        .account(OWNER, INITIAL_BALANCE)
+        .deploy::<TestContract>((public_key(OWNER),))
+        .build()
 }
 
 #[test]
@@ -590,7 +672,7 @@ fn test_decode_event_counter_reset() {
     // 1. Call the contract to emit a real event
     let mut session = setup_contract_session();
     let receipt = session
-        .call_public::<_, ()>(&OWNER_SK, CONTRACT_ID, "reset_counter", &())
+        .call_public::<_, ()>(&secret_key(OWNER), CONTRACT_ID, "reset_counter", &())
         .expect("reset_counter should succeed");
 
     // 2. Extract the event from the receipt
@@ -626,7 +708,7 @@ fn test_decode_event_item_added() {
     };
 
     let receipt = session
-        .call_public::<_, ()>(&OWNER_SK, CONTRACT_ID, "add_item", &item)
+        .call_public::<_, ()>(&secret_key(OWNER), CONTRACT_ID, "add_item", &item)
         .expect("add_item should succeed");
 
     // 2. Find the item_added event
@@ -814,6 +896,23 @@ fn test_schema_function_with_multiple_params() {
         input.contains("String"),
         "update input should contain String: {input}"
     );
+
+    // Parameter names and order are preserved in `inputs`
+    let inputs = update["inputs"]
+        .as_array()
+        .expect("inputs should be an array");
+    assert_eq!(inputs.len(), 2, "update should have two inputs");
+    assert_eq!(inputs[0]["name"], "counter");
+    assert_eq!(inputs[0]["type"], "u64");
+    assert_eq!(inputs[1]["name"], "label");
+    assert_eq!(inputs[1]["type"], "String");
+
+    // `init` inputs name the fields of its JSON arguments object
+    let init = functions
+        .iter()
+        .find(|f| f["name"] == "init")
+        .expect("init should exist");
+    assert_eq!(init["inputs"][0]["name"], "owner");
 }
 
 #[test]
@@ -21,7 +21,7 @@ use rkyv::{Archive, Deserialize, Serialize};
 
 /// A unique identifier for an item in the contract's collection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Archive, Serialize, Deserialize)]
-#[archive_attr(derive(CheckBytes))]
+#[archive_attr(derive(CheckBytes, PartialEq, Eq, PartialOrd, Ord))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemId(pub u64);
 